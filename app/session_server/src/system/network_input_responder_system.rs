@@ -9,9 +9,12 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use desync_model::play::GameChecksum;
 use game_input_model::GameInputEvent;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetSessionDevices};
+use network_input_model::play::NetworkInputFrame;
+use network_session_model::play::SessionDeviceId;
 
 use crate::model::SessionDeviceMappings;
 
@@ -22,6 +25,12 @@ pub struct NetworkInputResponderSystem {
     /// Reader ID for the `GameInputEvent` channel.
     #[system_desc(event_channel_reader)]
     game_input_event_rid: ReaderId<NetData<GameInputEvent>>,
+    /// Reader ID for the `NetworkInputFrame` channel.
+    #[system_desc(event_channel_reader)]
+    network_input_frame_rid: ReaderId<NetData<NetworkInputFrame>>,
+    /// Reader ID for the `GameChecksum` channel.
+    #[system_desc(event_channel_reader)]
+    game_checksum_rid: ReaderId<NetData<GameChecksum>>,
 }
 
 #[derive(Derivative, SystemData)]
@@ -30,6 +39,12 @@ pub struct NetworkInputResponderSystemData<'s> {
     /// `InputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_input_nec: Read<'s, NetEventChannel<GameInputEvent>>,
+    /// `NetworkInputFrame` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_input_frame_nec: Read<'s, NetEventChannel<NetworkInputFrame>>,
+    /// `GameChecksum` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_checksum_nec: Read<'s, NetEventChannel<GameChecksum>>,
     /// `SessionDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_device_mappings: Read<'s, SessionDeviceMappings>,
@@ -39,13 +54,27 @@ pub struct NetworkInputResponderSystemData<'s> {
 }
 
 impl NetworkInputResponderSystem {
-    fn send_game_input_event(
+    /// Returns the devices in the sender's session, and the server's record of the sender's ID.
+    fn session_device<'m>(
+        session_device_mappings: &'m SessionDeviceMappings,
+        socket_addr: &SocketAddr,
+    ) -> Option<(&'m NetSessionDevices, SessionDeviceId)> {
+        let net_session_devices = session_device_mappings
+            .session_code(socket_addr)
+            .and_then(|session_code| session_device_mappings.net_session_devices(session_code))?;
+        let session_device_id = net_session_devices
+            .iter()
+            .find(|net_session_device| net_session_device.socket_addr == *socket_addr)
+            .map(|net_session_device| net_session_device.data.id)?;
+
+        Some((net_session_devices, session_device_id))
+    }
+
+    fn send_net_message_event(
         transport_resource: &mut TransportResource,
         socket_addrs: impl Iterator<Item = SocketAddr>,
-        game_input_event: GameInputEvent,
+        net_message_event: NetMessageEvent,
     ) {
-        let net_message_event = NetMessageEvent::from(game_input_event);
-
         match bincode::serialize(&net_message_event) {
             Ok(payload) => {
                 socket_addrs.for_each(|socket_addr| {
@@ -63,8 +92,8 @@ impl NetworkInputResponderSystem {
             }
             Err(e) => {
                 error!(
-                    "Failed to serialize `NetMessageEvent::InputEvent`. Error: `{}`.",
-                    e
+                    "Failed to serialize `{:?}`. Error: `{}`.",
+                    net_message_event, e
                 );
             }
         }
@@ -78,6 +107,8 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
         &mut self,
         NetworkInputResponderSystemData {
             network_input_nec,
+            network_input_frame_nec,
+            game_checksum_nec,
             session_device_mappings,
             mut transport_resource,
         }: Self::SystemData,
@@ -99,10 +130,10 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
                         let socket_addrs = net_session_devices
                             .iter()
                             .map(|net_session_device| net_session_device.socket_addr);
-                        Self::send_game_input_event(
+                        Self::send_net_message_event(
                             &mut transport_resource,
                             socket_addrs,
-                            *game_input_event,
+                            NetMessageEvent::from(*game_input_event),
                         );
                     }
                 } else {
@@ -113,5 +144,79 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
                     // TODO: reject
                 }
             });
+
+        network_input_frame_nec
+            .read(&mut self.network_input_frame_rid)
+            .for_each(|net_network_input_frame| {
+                let NetData {
+                    socket_addr,
+                    data: network_input_frame,
+                } = net_network_input_frame;
+
+                if let Some((net_session_devices, session_device_id)) =
+                    Self::session_device(&session_device_mappings, socket_addr)
+                {
+                    debug!(
+                        "Sending `NetworkInputFrame` {} for device: `{}`.",
+                        network_input_frame.frame_number, session_device_id
+                    );
+
+                    // Use the server's record of the device ID, so devices cannot send input on
+                    // behalf of other devices.
+                    let mut network_input_frame = network_input_frame.clone();
+                    network_input_frame.session_device_id = session_device_id;
+
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    Self::send_net_message_event(
+                        &mut transport_resource,
+                        socket_addrs,
+                        NetMessageEvent::from(network_input_frame),
+                    );
+                } else {
+                    debug!(
+                        "Received `{:?}` from {:?}, but no session device tracked for that socket.",
+                        network_input_frame, socket_addr
+                    );
+                }
+            });
+
+        game_checksum_nec
+            .read(&mut self.game_checksum_rid)
+            .for_each(|net_game_checksum| {
+                let NetData {
+                    socket_addr,
+                    data: game_checksum,
+                } = net_game_checksum;
+
+                if let Some((net_session_devices, session_device_id)) =
+                    Self::session_device(&session_device_mappings, socket_addr)
+                {
+                    debug!(
+                        "Sending `GameChecksum` {} for device: `{}`.",
+                        game_checksum.frame_number, session_device_id
+                    );
+
+                    let mut game_checksum = game_checksum.clone();
+                    game_checksum.session_device_id = session_device_id;
+
+                    // The sender does not need to compare checksums with itself.
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr)
+                        .filter(|socket_addr_device| socket_addr_device != socket_addr);
+                    Self::send_net_message_event(
+                        &mut transport_resource,
+                        socket_addrs,
+                        NetMessageEvent::from(game_checksum),
+                    );
+                } else {
+                    debug!(
+                        "Received `{:?}` from {:?}, but no session device tracked for that socket.",
+                        game_checksum, socket_addr
+                    );
+                }
+            });
     }
 }
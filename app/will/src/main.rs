@@ -9,20 +9,22 @@ use std::{
     path::PathBuf,
 };
 
+use ai_model::config::AiProfiles;
+use ai_play::AiControlSystem;
 use amethyst::{
-    assets::HotReloadBundle,
-    audio::AudioBundle,
-    core::transform::TransformBundle,
+    assets::{HotReloadBundle, Processor},
+    audio::{AudioBundle, Source},
+    core::{transform::TransformBundle, SystemExt},
     input::{Bindings, InputBundle},
     network::simulation::tcp::TcpNetworkBundle,
     renderer::{
         plugins::{RenderFlat2D, RenderToWindow},
         types::DefaultBackend,
-        RenderingBundle,
+        RenderEmptyBundle, RenderingBundle,
     },
     ui::{RenderUi, UiBundle},
     utils::{application_root_dir, ortho_camera::CameraOrthoSystem},
-    window::DisplayConfig,
+    window::{DisplayConfig, ScreenDimensions},
     CoreApplication, Error, GameDataBuilder, LoggerConfig,
 };
 use application::{AppDir, AppFile, Format, IoUtils};
@@ -36,16 +38,23 @@ use asset_selection_ui_play::{
 };
 use asset_ui_play::AssetSelectionHighlightUpdateSystem;
 use audio_loading::AudioLoadingBundle;
+use audio_model::config::AudioSettings;
+use audio_settings::AudioSettingsLabelUpdateSystem;
+use audio_settings_stdio::AudioSettingsStdioBundle;
 use background_loading::BackgroundLoadingBundle;
 use camera_play::CameraPlayBundle;
 use character_loading::CharacterLoadingBundle;
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
+use desync_play::{
+    DesyncDetectionSystem, DesyncDetectionSystemDesc, GameChecksumSystem, GameChecksumSystemDesc,
+};
 use energy_loading::EnergyLoadingBundle;
 use frame_rate::strategy::frame_rate_limit_config;
 use game_input::{
     ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
-    InputToGameInputSystem, InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
+    GamepadAssignmentSystem, GamepadAssignmentSystemDesc, InputToGameInputSystem,
+    InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
 };
 use game_input_model::{
     config::{ControlBindings, PlayerInputConfigs},
@@ -56,18 +65,23 @@ use game_mode_selection::{GameModeSelectionStateBuilder, GameModeSelectionStateD
 use game_mode_selection_stdio::GameModeSelectionStdioBundle;
 use game_mode_selection_ui::GameModeSelectionSfxSystem;
 use game_play::GamePlayBundle;
+use game_play_model::{config::MatchRules, GamePlayStepStatus};
 use game_play_stdio::GamePlayStdioBundle;
+use game_stats_ui_play::{PlayerStatsEntityCreateDeleteSystem, PlayerStatsWidgetUpdateSystem};
 use input_reaction_loading::InputReactionLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 use log::debug;
 use map_loading::MapLoadingBundle;
+use music_loading::MusicLoadingBundle;
+use music_play::MusicPlaySystem;
+use music_stdio::MusicStdioBundle;
 use net_play::{
     NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
 };
 use network_input_play::{
-    NetworkInputRequestSystem, NetworkInputRequestSystemDesc, NetworkInputResponseSystem,
-    NetworkInputResponseSystemDesc,
+    NetworkInputLockstepSystem, NetworkInputLockstepSystemDesc, NetworkInputRequestSystem,
+    NetworkInputRequestSystemDesc, NetworkInputResponseSystem, NetworkInputResponseSystemDesc,
 };
 use network_mode_selection_stdio::NetworkModeSelectionStdioBundle;
 use network_session_model::config::SessionServerConfig;
@@ -76,6 +90,10 @@ use network_session_play::{
     SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
 };
 use parent_play::ChildEntityDeleteSystem;
+use replay_model::{config::Replay, play::ReplayMode};
+use replay_play::{
+    ReplayPlaybackSystem, ReplayPlaybackSystemDesc, ReplayRecordSystem, ReplayRecordSystemDesc,
+};
 use sequence_loading::SequenceLoadingBundle;
 use serde::{Deserialize, Serialize};
 use session_host_play::{
@@ -108,6 +126,7 @@ use stdio_input::StdioInputBundle;
 use stdio_spi::MapperSystem;
 use structopt::StructOpt;
 use structopt_toml::StructOptToml;
+use team_model::play::FriendlyFire;
 use tracker::PrevTrackerSystem;
 use ui_audio_loading::UiAudioLoadingBundle;
 use ui_loading::UiLoadingBundle;
@@ -116,6 +135,7 @@ use ui_play::{
     UiTransformForFovSystemDesc, UiTransformInsertionRectifySystem,
     UiTransformInsertionRectifySystemDesc, WidgetSequenceUpdateSystem,
 };
+use weapon_loading::WeaponLoadingBundle;
 
 /// Default file for application arguments.
 const WILL_CONFIG: &str = "will.toml";
@@ -126,6 +146,12 @@ const LOGGER_CONFIG: &str = "logger.yaml";
 /// `TcpListener` buffer size.
 const TCP_RECV_BUFFER_SIZE: usize = 2048;
 
+/// Screen width used for UI calculations when running headlessly.
+const HEADLESS_SCREEN_WIDTH: u32 = 800;
+
+/// Screen height used for UI calculations when running headlessly.
+const HEADLESS_SCREEN_HEIGHT: u32 = 600;
+
 #[derive(Debug, Deserialize, Serialize, StructOpt, StructOptToml)]
 #[serde(default)]
 #[structopt(name = "Will", rename_all = "snake_case")]
@@ -133,13 +159,30 @@ pub struct WillConfig {
     /// Frame rate to run the game at.
     #[structopt(long)]
     frame_rate: Option<u32>,
+    /// Whether characters on the same team can hit each other.
+    #[serde(default)]
+    #[structopt(long)]
+    friendly_fire: bool,
     /// Run headlessly (no GUI).
+    ///
+    /// Assets are still loaded and game play is still simulated, but nothing is rendered, no
+    /// window is opened, and no audio device is used. Input is received through stdin.
     #[serde(default)]
     #[structopt(long)]
     headless: bool,
     /// Logger configuration file.
     #[structopt(long)]
     logger_config: Option<PathBuf>,
+    /// Replay file to play back.
+    ///
+    /// The recorded characters and map are selected, and the recorded input replaces device input.
+    #[structopt(long)]
+    replay: Option<PathBuf>,
+    /// File to record replays to.
+    ///
+    /// The file is overwritten each time game play ends.
+    #[structopt(long)]
+    replay_record: Option<PathBuf>,
     /// Address of the session server.
     ///
     /// Currently must be an `IpAddr`, in the future we may accept hostnames.
@@ -150,6 +193,18 @@ pub struct WillConfig {
     #[serde(default = "WillConfig::session_server_port_default")]
     #[structopt(long, default_value = "1234")]
     session_server_port: u16,
+    /// Number of frames to delay input by during network play.
+    #[serde(default = "WillConfig::input_delay_default")]
+    #[structopt(long, default_value = "2")]
+    input_delay: u32,
+    /// Maximum number of frames to predict ahead of network input, `0` to disable rollback.
+    #[serde(default = "WillConfig::rollback_frames_default")]
+    #[structopt(long, default_value = "8")]
+    rollback_frames: u32,
+    /// Number of frames between game play checksums during network play, `0` to disable.
+    #[serde(default = "WillConfig::checksum_interval_default")]
+    #[structopt(long, default_value = "60")]
+    checksum_interval: u32,
 }
 
 impl WillConfig {
@@ -160,6 +215,18 @@ impl WillConfig {
     fn session_server_port_default() -> u16 {
         1234
     }
+
+    fn input_delay_default() -> u32 {
+        SessionServerConfig::input_delay_default()
+    }
+
+    fn rollback_frames_default() -> u32 {
+        SessionServerConfig::rollback_frames_default()
+    }
+
+    fn checksum_interval_default() -> u32 {
+        SessionServerConfig::checksum_interval_default()
+    }
 }
 
 fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
@@ -198,10 +265,36 @@ fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
     Ok(())
 }
 
+fn replay_mode(will_config: &WillConfig) -> Result<ReplayMode, Error> {
+    if let Some(replay_path) = will_config.replay.as_ref() {
+        let replay_bytes = IoUtils::read_file(replay_path)?;
+        let replay = serde_yaml::from_slice::<Replay>(&replay_bytes)?;
+        if replay.version != Replay::VERSION {
+            let message = format!(
+                "Replay version `{}` is not supported, expected version `{}`.",
+                replay.version,
+                Replay::VERSION
+            );
+            eprintln!("{}", message);
+
+            return Err(Error::from_string(message));
+        }
+
+        Ok(ReplayMode::Playback(replay))
+    } else if let Some(replay_record_path) = will_config.replay_record.as_ref() {
+        Ok(ReplayMode::Record(replay_record_path.clone()))
+    } else {
+        Ok(ReplayMode::None)
+    }
+}
+
 fn session_server_config(will_config: &WillConfig) -> SessionServerConfig {
     SessionServerConfig {
         address: will_config.session_server_address,
         port: will_config.session_server_port,
+        input_delay: will_config.input_delay,
+        rollback_frames: will_config.rollback_frames,
+        checksum_interval: will_config.checksum_interval,
     }
 }
 
@@ -218,6 +311,7 @@ fn main() -> Result<(), Error> {
         });
 
     let session_server_config = session_server_config(&will_config);
+    let replay_mode = replay_mode(&will_config)?;
 
     logger_setup(will_config.logger_config.take())?;
     debug!("will_config: {:?}", will_config);
@@ -235,284 +329,400 @@ fn main() -> Result<(), Error> {
         Format::Yaml,
     )?;
     let player_controllers = PlayerControllers::from(&player_input_configs);
+    let ai_profiles =
+        AppFile::load_in::<AiProfiles, _>(AppDir::RESOURCES, "ai_profiles.yaml", Format::Yaml)?;
+    let match_rules =
+        AppFile::load_in::<MatchRules, _>(AppDir::RESOURCES, "match_rules.yaml", Format::Yaml)?;
+    let audio_settings = AppFile::load_in::<AudioSettings, _>(
+        AppDir::RESOURCES,
+        "audio_settings.yaml",
+        Format::Yaml,
+    )?;
 
     let mut game_data = GameDataBuilder::default();
-    if !will_config.headless {
-        let display_config = AppFile::load_in::<DisplayConfig, _>(
-            AppDir::RESOURCES,
-            "display_config.ron",
-            Format::Ron,
-        )?;
+    game_data = if will_config.headless {
+        // Without an audio device, `AudioSystem` panics as there is no default `Output`.
+        //
+        // We only include the `Source` processor as that is what's needed to load the audio
+        // files, and the audio play systems do nothing when there is no `Output`.
+        game_data.with(Processor::<Source>::new(), "source_processor", &[])
+    } else {
+        game_data.with_bundle(AudioBundle::default())?
+    };
 
-        // `InputBundle` provides `InputHandler<A, B>`, needed by the `UiBundle` for mouse events.
-        // `UiBundle` registers `Loader<FontAsset>`, needed by `ApplicationUiBundle`.
-        game_data = game_data
-            .with_bundle(AudioBundle::default())?
-            .with_bundle(
-                InputBundle::<ControlBindings>::new()
-                    .with_bindings(Bindings::try_from(&player_input_configs)?),
-            )?
-            .with_bundle(TcpNetworkBundle::new(None, TCP_RECV_BUFFER_SIZE))?
-            .with_bundle(HotReloadBundle::default())?
-            .with_bundle(SpriteLoadingBundle::new())?
-            .with_bundle(SequenceLoadingBundle::new())?
-            .with_bundle(AudioLoadingBundle::new())?
-            .with_bundle(KinematicLoadingBundle::new())?
-            .with_bundle(LoadingBundle::new(assets_dir.clone()))?
-            .with_system_desc(
-                InputToGameInputSystemDesc::default(),
+    // `InputBundle` provides `InputHandler<A, B>`, needed by the `UiBundle` for mouse events.
+    // `UiBundle` registers `Loader<FontAsset>`, needed by `ApplicationUiBundle`.
+    game_data = game_data
+        .with_bundle(
+            InputBundle::<ControlBindings>::new()
+                .with_bindings(Bindings::try_from(&player_input_configs)?),
+        )?
+        .with_bundle(TcpNetworkBundle::new(None, TCP_RECV_BUFFER_SIZE))?
+        .with_bundle(HotReloadBundle::default())?
+        .with_bundle(SpriteLoadingBundle::new())?
+        .with_bundle(SequenceLoadingBundle::new())?
+        .with_bundle(AudioLoadingBundle::new())?
+        .with_bundle(KinematicLoadingBundle::new())?
+        .with_bundle(LoadingBundle::new(assets_dir.clone()))?
+        .with_system_desc(
+            GamepadAssignmentSystemDesc::default(),
+            any::type_name::<GamepadAssignmentSystem>(),
+            &["input_system"],
+        )
+        .with_system_desc(
+            InputToGameInputSystemDesc::default(),
+            any::type_name::<InputToGameInputSystem>(),
+            &["input_system"],
+        )
+        .with_system_desc(
+            ReplayPlaybackSystemDesc::default(),
+            any::type_name::<ReplayPlaybackSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            GameInputToControlInputSystemDesc::default(),
+            any::type_name::<GameInputToControlInputSystem>(),
+            &[
+                any::type_name::<InputToGameInputSystem>(),
+                any::type_name::<ReplayPlaybackSystem>(),
+            ],
+        )
+        .with(
+            MapperSystem::<ControlInputEventStdinMapper>::new(AppEventVariant::ControlInput),
+            any::type_name::<MapperSystem<ControlInputEventStdinMapper>>(),
+            // Depend on the input handler updated system, so that stdin input takes priority.
+            &[any::type_name::<GameInputToControlInputSystem>()],
+        )
+        .with_system_desc(
+            ReplayRecordSystemDesc::default(),
+            any::type_name::<ReplayRecordSystem>(),
+            &[
                 any::type_name::<InputToGameInputSystem>(),
-                &["input_system"],
-            )
-            .with_system_desc(
-                GameInputToControlInputSystemDesc::default(),
-                any::type_name::<GameInputToControlInputSystem>(),
-                &[any::type_name::<InputToGameInputSystem>()],
-            )
-            .with(
-                MapperSystem::<ControlInputEventStdinMapper>::new(AppEventVariant::ControlInput),
                 any::type_name::<MapperSystem<ControlInputEventStdinMapper>>(),
-                // Depend on the input handler updated system, so that stdin input takes priority.
-                &[any::type_name::<GameInputToControlInputSystem>()],
-            )
-            .with(
-                ControllerInputUpdateSystem::new(),
-                any::type_name::<ControllerInputUpdateSystem>(),
-                &[any::type_name::<MapperSystem<ControlInputEventStdinMapper>>()],
-            )
-            .with(
-                SharedControllerInputUpdateSystem::new(),
-                any::type_name::<SharedControllerInputUpdateSystem>(),
-                &[any::type_name::<ControllerInputUpdateSystem>()],
-            )
-            .with_bundle(StdioInputBundle::new())?
-            .with_bundle(StdioCommandStdioBundle::new())?
-            .with_bundle(AssetSelectionStdioBundle::new())?
-            .with_bundle(GamePlayStdioBundle::new())?
-            .with_bundle(GameModeSelectionStdioBundle::new())?
-            .with_bundle(NetworkModeSelectionStdioBundle::new())?
-            .with_bundle(SessionHostStdioBundle::new())?
-            .with_bundle(SessionJoinStdioBundle::new())?
-            .with_bundle(CollisionLoadingBundle::new())?
-            .with_bundle(SpawnLoadingBundle::new())?
-            .with_bundle(BackgroundLoadingBundle::new())?
-            .with_bundle(UiLoadingBundle::new())?
-            .with_bundle(MapLoadingBundle::new())?
-            .with_bundle(CharacterLoadingBundle::new())?
-            .with_bundle(EnergyLoadingBundle::new())?
-            .with_bundle(InputReactionLoadingBundle::new())?
-            .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
-            .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
-            .with(CameraOrthoSystem::default(), "camera_ortho", &[])
-            .with(
-                UiActiveWidgetUpdateSystem::new(),
-                any::type_name::<UiActiveWidgetUpdateSystem>(),
-                &[any::type_name::<StdioCommandProcessingSystem>()],
-            )
-            .with(
-                UiTextColourUpdateSystem::new(),
-                any::type_name::<UiTextColourUpdateSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                WidgetSequenceUpdateSystem::new(),
-                any::type_name::<WidgetSequenceUpdateSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                StateIdEventSystem::new(),
-                any::type_name::<StateIdEventSystem>(),
-                &[any::type_name::<UiActiveWidgetUpdateSystem>()],
-            )
-            .with(
-                StateCameraResetSystem::new(),
-                any::type_name::<StateCameraResetSystem>(),
-                &[any::type_name::<StateIdEventSystem>()],
-            )
-            .with(
-                StateItemSpawnSystem::new(),
-                any::type_name::<StateItemSpawnSystem>(),
-                &[any::type_name::<StateIdEventSystem>()],
-            )
-            .with(
-                ItemIdEventSystem::new(),
-                any::type_name::<ItemIdEventSystem>(),
-                &[any::type_name::<StateItemSpawnSystem>()],
-            )
-            .with(
-                SessionStatusNotifierSystem::new(),
-                any::type_name::<SessionStatusNotifierSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionInputResourcesSyncSystemDesc::default(),
-                any::type_name::<SessionInputResourcesSyncSystem>(),
-                &[],
-            )
-            .with_bundle(AssetPlayBundle::new())?
-            .with_system_desc(
-                SessionHostRequestSystemDesc::default(),
+            ],
+        )
+        // Computer players only decide when game play steps, so their input is the same on every
+        // session device. During rollback, they are run by the rollback dispatcher instead.
+        .with(
+            AiControlSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<AiControlSystem>(),
+            &[any::type_name::<GameInputToControlInputSystem>()],
+        )
+        .with(
+            ControllerInputUpdateSystem::new(),
+            any::type_name::<ControllerInputUpdateSystem>(),
+            &[
+                any::type_name::<MapperSystem<ControlInputEventStdinMapper>>(),
+                any::type_name::<AiControlSystem>(),
+            ],
+        )
+        .with(
+            SharedControllerInputUpdateSystem::new(),
+            any::type_name::<SharedControllerInputUpdateSystem>(),
+            &[any::type_name::<ControllerInputUpdateSystem>()],
+        )
+        .with_bundle(StdioInputBundle::new())?
+        .with_bundle(StdioCommandStdioBundle::new())?
+        .with_bundle(AssetSelectionStdioBundle::new())?
+        .with_bundle(AudioSettingsStdioBundle::new())?
+        .with_bundle(GamePlayStdioBundle::new())?
+        .with_bundle(MusicStdioBundle::new())?
+        .with_bundle(GameModeSelectionStdioBundle::new())?
+        .with_bundle(NetworkModeSelectionStdioBundle::new())?
+        .with_bundle(SessionHostStdioBundle::new())?
+        .with_bundle(SessionJoinStdioBundle::new())?
+        .with_bundle(CollisionLoadingBundle::new())?
+        .with_bundle(SpawnLoadingBundle::new())?
+        .with_bundle(BackgroundLoadingBundle::new())?
+        .with_bundle(UiLoadingBundle::new())?
+        .with_bundle(MapLoadingBundle::new())?
+        .with_bundle(CharacterLoadingBundle::new())?
+        .with_bundle(EnergyLoadingBundle::new())?
+        .with_bundle(WeaponLoadingBundle::new())?
+        .with_bundle(InputReactionLoadingBundle::new())?
+        .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
+        .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
+        .with_bundle(MusicLoadingBundle::new(assets_dir.clone()))?
+        .with(CameraOrthoSystem::default(), "camera_ortho", &[])
+        .with(
+            UiActiveWidgetUpdateSystem::new(),
+            any::type_name::<UiActiveWidgetUpdateSystem>(),
+            &[any::type_name::<StdioCommandProcessingSystem>()],
+        )
+        .with(
+            UiTextColourUpdateSystem::new(),
+            any::type_name::<UiTextColourUpdateSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            WidgetSequenceUpdateSystem::new(),
+            any::type_name::<WidgetSequenceUpdateSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            StateIdEventSystem::new(),
+            any::type_name::<StateIdEventSystem>(),
+            &[any::type_name::<UiActiveWidgetUpdateSystem>()],
+        )
+        .with(
+            StateCameraResetSystem::new(),
+            any::type_name::<StateCameraResetSystem>(),
+            &[any::type_name::<StateIdEventSystem>()],
+        )
+        .with(
+            StateItemSpawnSystem::new(),
+            any::type_name::<StateItemSpawnSystem>(),
+            &[any::type_name::<StateIdEventSystem>()],
+        )
+        .with(
+            ItemIdEventSystem::new(),
+            any::type_name::<ItemIdEventSystem>(),
+            &[any::type_name::<StateItemSpawnSystem>()],
+        )
+        .with(
+            SessionStatusNotifierSystem::new(),
+            any::type_name::<SessionStatusNotifierSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionInputResourcesSyncSystemDesc::default(),
+            any::type_name::<SessionInputResourcesSyncSystem>(),
+            &[],
+        )
+        .with_bundle(AssetPlayBundle::new())?
+        .with_system_desc(
+            SessionHostRequestSystemDesc::default(),
+            any::type_name::<SessionHostRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionJoinRequestSystemDesc::default(),
+            any::type_name::<SessionJoinRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionLobbyRequestSystemDesc::default(),
+            any::type_name::<SessionLobbyRequestSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            NetworkInputRequestSystemDesc::default(),
+            any::type_name::<NetworkInputRequestSystem>(),
+            &["input_system"],
+        )
+        .with_system_desc(
+            GameChecksumSystemDesc::default(),
+            any::type_name::<GameChecksumSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            NetMessageRequestSystemDesc::default(),
+            any::type_name::<NetMessageRequestSystem>(),
+            &[
                 any::type_name::<SessionHostRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionJoinRequestSystemDesc::default(),
                 any::type_name::<SessionJoinRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionLobbyRequestSystemDesc::default(),
                 any::type_name::<SessionLobbyRequestSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                NetworkInputRequestSystemDesc::default(),
                 any::type_name::<NetworkInputRequestSystem>(),
-                &["input_system"],
-            )
-            .with_system_desc(
-                NetMessageRequestSystemDesc::default(),
-                any::type_name::<NetMessageRequestSystem>(),
-                &[
-                    any::type_name::<SessionHostRequestSystem>(),
-                    any::type_name::<SessionJoinRequestSystem>(),
-                    any::type_name::<SessionLobbyRequestSystem>(),
-                    any::type_name::<NetworkInputRequestSystem>(),
-                ],
-            )
-            .with_system_desc(
-                NetListenerSystemDesc::default(),
-                any::type_name::<NetListenerSystem>(),
-                &[],
-            )
-            .with_system_desc(
-                SessionHostResponseSystemDesc::default(),
+                any::type_name::<GameChecksumSystem>(),
+            ],
+        )
+        .with_system_desc(
+            NetListenerSystemDesc::default(),
+            any::type_name::<NetListenerSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            SessionHostResponseSystemDesc::default(),
+            any::type_name::<SessionHostResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionJoinResponseSystemDesc::default(),
+            any::type_name::<SessionJoinResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionLobbyResponseSystemDesc::default(),
+            any::type_name::<SessionLobbyResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            SessionMessageResponseSystemDesc::default(),
+            any::type_name::<SessionMessageResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            NetworkInputResponseSystemDesc::default(),
+            any::type_name::<NetworkInputResponseSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            NetworkInputLockstepSystemDesc::default(),
+            any::type_name::<NetworkInputLockstepSystem>(),
+            &[
+                any::type_name::<NetworkInputRequestSystem>(),
+                any::type_name::<NetworkInputResponseSystem>(),
+                any::type_name::<GameChecksumSystem>(),
+            ],
+        )
+        .with_system_desc(
+            DesyncDetectionSystemDesc::default(),
+            any::type_name::<DesyncDetectionSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with(
+            SessionCodeLabelUpdateSystem::new(),
+            any::type_name::<SessionCodeLabelUpdateSystem>(),
+            &[
                 any::type_name::<SessionHostResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionJoinResponseSystemDesc::default(),
                 any::type_name::<SessionJoinResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionLobbyResponseSystemDesc::default(),
-                any::type_name::<SessionLobbyResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                SessionMessageResponseSystemDesc::default(),
                 any::type_name::<SessionMessageResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with_system_desc(
-                NetworkInputResponseSystemDesc::default(),
-                any::type_name::<NetworkInputResponseSystem>(),
-                &[any::type_name::<NetListenerSystem>()],
-            )
-            .with(
-                SessionCodeLabelUpdateSystem::new(),
-                any::type_name::<SessionCodeLabelUpdateSystem>(),
-                &[
-                    any::type_name::<SessionHostResponseSystem>(),
-                    any::type_name::<SessionJoinResponseSystem>(),
-                    any::type_name::<SessionMessageResponseSystem>(),
-                ],
-            )
-            .with(
-                SessionDeviceEntityCreateDeleteSystem::new(),
-                any::type_name::<SessionDeviceEntityCreateDeleteSystem>(),
-                &[
-                    any::type_name::<SessionHostResponseSystem>(),
-                    any::type_name::<SessionJoinResponseSystem>(),
-                    any::type_name::<SessionMessageResponseSystem>(),
-                ],
-            )
-            .with(
-                SessionDeviceWidgetUpdateSystem::new(),
-                any::type_name::<SessionDeviceWidgetUpdateSystem>(),
-                &[any::type_name::<SessionDeviceEntityCreateDeleteSystem>()],
-            )
-            .with_system_desc(
-                StateItemUiInputAugmentSystemDesc::default(),
-                any::type_name::<StateItemUiInputAugmentSystem>(),
-                &[],
-            )
-            .with(
-                PrevTrackerSystem::<StateId>::new(stringify!(StateId)),
-                "state_id_prev_tracker_system",
-                &[],
-            )
-            .with_barrier()
-            .with_bundle(GamePlayBundle::new())?
-            .with(
-                GameModeSelectionSfxSystem::new(),
-                any::type_name::<GameModeSelectionSfxSystem>(),
-                &[],
-            )
-            .with(
-                AssetSelectionSfxSystem::new(),
-                any::type_name::<AssetSelectionSfxSystem>(),
-                &[],
-            )
-            .with(
-                AssetSelectionHighlightUpdateSystem::new(),
-                any::type_name::<AssetSelectionHighlightUpdateSystem>(),
-                &[],
-            )
-            .with(
-                AswPortraitUpdateSystem::new(),
-                any::type_name::<AswPortraitUpdateSystem>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ApwPreviewSpawnSystemCharacter::new(),
-                any::type_name::<ApwPreviewSpawnSystemCharacter>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ApwPreviewSpawnSystemMap::new(),
-                any::type_name::<ApwPreviewSpawnSystemMap>(),
-                &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
-            )
-            .with(
-                ChildEntityDeleteSystem::new(),
-                any::type_name::<ChildEntityDeleteSystem>(),
-                &[],
-            )
-            .with_barrier()
-            // To remove the 1 frame of flicker issue, we must run `TransformSystem` near the end,
-            // so that the global matrix is updated even when the local matrix is up to date.
-            //
-            // `UiBundle` has a hardcoded dependency on `"transform_system"`, so we have to shift it
-            // down as well.
-            .with_bundle(TransformBundle::new())?
-            .with_bundle(UiBundle::<ControlBindings>::new())?
-            .with_bundle(
-                RenderingBundle::<DefaultBackend>::new()
-                    .with_plugin(
-                        RenderToWindow::from_config(display_config).with_clear([0., 0., 0., 1.0]),
-                    )
-                    .with_plugin(RenderFlat2D::default())
-                    .with_plugin(RenderUi::default()),
-            )?
-            .with_bundle(CameraPlayBundle::new())?
-            .with_system_desc(
-                UiTransformForFovSystemDesc::default(),
-                any::type_name::<UiTransformForFovSystem>(),
-                &["camera_ortho"],
-            )
-            .with_system_desc(
-                UiTransformInsertionRectifySystemDesc::default(),
-                any::type_name::<UiTransformInsertionRectifySystem>(),
-                &[any::type_name::<UiTransformForFovSystem>()],
-            );
+            ],
+        )
+        .with(
+            SessionDeviceEntityCreateDeleteSystem::new(),
+            any::type_name::<SessionDeviceEntityCreateDeleteSystem>(),
+            &[
+                any::type_name::<SessionHostResponseSystem>(),
+                any::type_name::<SessionJoinResponseSystem>(),
+                any::type_name::<SessionMessageResponseSystem>(),
+            ],
+        )
+        .with(
+            SessionDeviceWidgetUpdateSystem::new(),
+            any::type_name::<SessionDeviceWidgetUpdateSystem>(),
+            &[any::type_name::<SessionDeviceEntityCreateDeleteSystem>()],
+        )
+        .with(
+            PlayerStatsEntityCreateDeleteSystem::new(),
+            any::type_name::<PlayerStatsEntityCreateDeleteSystem>(),
+            &[],
+        )
+        .with(
+            PlayerStatsWidgetUpdateSystem::new(),
+            any::type_name::<PlayerStatsWidgetUpdateSystem>(),
+            &[any::type_name::<PlayerStatsEntityCreateDeleteSystem>()],
+        )
+        .with(
+            AudioSettingsLabelUpdateSystem::new(),
+            any::type_name::<AudioSettingsLabelUpdateSystem>(),
+            &[],
+        )
+        .with_system_desc(
+            StateItemUiInputAugmentSystemDesc::default(),
+            any::type_name::<StateItemUiInputAugmentSystem>(),
+            &[],
+        )
+        .with(
+            PrevTrackerSystem::<StateId>::new(stringify!(StateId)),
+            "state_id_prev_tracker_system",
+            &[],
+        )
+        .with_barrier()
+        .with_bundle(GamePlayBundle::new())?
+        .with(
+            MusicPlaySystem::new(),
+            any::type_name::<MusicPlaySystem>(),
+            &[],
+        )
+        .with(
+            GameModeSelectionSfxSystem::new(),
+            any::type_name::<GameModeSelectionSfxSystem>(),
+            &[],
+        )
+        .with(
+            AssetSelectionSfxSystem::new(),
+            any::type_name::<AssetSelectionSfxSystem>(),
+            &[],
+        )
+        .with(
+            AssetSelectionHighlightUpdateSystem::new(),
+            any::type_name::<AssetSelectionHighlightUpdateSystem>(),
+            &[],
+        )
+        .with(
+            AswPortraitUpdateSystem::new(),
+            any::type_name::<AswPortraitUpdateSystem>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ApwPreviewSpawnSystemCharacter::new(),
+            any::type_name::<ApwPreviewSpawnSystemCharacter>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ApwPreviewSpawnSystemMap::new(),
+            any::type_name::<ApwPreviewSpawnSystemMap>(),
+            &[any::type_name::<AssetSelectionHighlightUpdateSystem>()],
+        )
+        .with(
+            ChildEntityDeleteSystem::new(),
+            any::type_name::<ChildEntityDeleteSystem>(),
+            &[],
+        )
+        .with_barrier()
+        // To remove the 1 frame of flicker issue, we must run `TransformSystem` near the end,
+        // so that the global matrix is updated even when the local matrix is up to date.
+        //
+        // `UiBundle` has a hardcoded dependency on `"transform_system"`, so we have to shift it
+        // down as well.
+        .with_bundle(TransformBundle::new())?
+        .with_bundle(UiBundle::<ControlBindings>::new())?;
+
+    game_data = if will_config.headless {
+        // `RenderEmptyBundle` provides the `Texture` and `SpriteSheet` processors needed to load
+        // assets, without opening a window.
+        game_data.with_bundle(RenderEmptyBundle::<DefaultBackend>::new())?
+    } else {
+        let display_config = AppFile::load_in::<DisplayConfig, _>(
+            AppDir::RESOURCES,
+            "display_config.ron",
+            Format::Ron,
+        )?;
+
+        game_data.with_bundle(
+            RenderingBundle::<DefaultBackend>::new()
+                .with_plugin(
+                    RenderToWindow::from_config(display_config).with_clear([0., 0., 0., 1.0]),
+                )
+                .with_plugin(RenderFlat2D::default())
+                .with_plugin(RenderUi::default()),
+        )?
+    };
+
+    game_data = game_data
+        .with_bundle(CameraPlayBundle::new())?
+        .with_system_desc(
+            UiTransformForFovSystemDesc::default(),
+            any::type_name::<UiTransformForFovSystem>(),
+            &["camera_ortho"],
+        )
+        .with_system_desc(
+            UiTransformInsertionRectifySystemDesc::default(),
+            any::type_name::<UiTransformInsertionRectifySystem>(),
+            &[any::type_name::<UiTransformForFovSystem>()],
+        );
+
+    let mut app_builder = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?;
+    if will_config.headless {
+        // Without a window, `ScreenDimensions` is not inserted by the `WindowBundle`, but is still
+        // needed for UI and camera calculations.
+        app_builder = app_builder.with_resource(ScreenDimensions::new(
+            HEADLESS_SCREEN_WIDTH,
+            HEADLESS_SCREEN_HEIGHT,
+            1.,
+        ));
     }
 
-    let mut app = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?
+    let mut app = app_builder
         .with_resource(session_server_config)
+        .with_resource(replay_mode)
         .with_resource(player_controllers)
         .with_resource(player_input_configs)
+        .with_resource(ai_profiles)
+        .with_resource(match_rules)
+        .with_resource(audio_settings)
+        .with_resource(FriendlyFire(will_config.friendly_fire))
         .with_frame_limit_config(frame_rate_limit_config(will_config.frame_rate))
         .build(game_data)?;
 
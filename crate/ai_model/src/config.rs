@@ -0,0 +1,7 @@
+//! Contains data types used for configuration.
+
+pub use self::{ai_difficulty::AiDifficulty, ai_profile::AiProfile, ai_profiles::AiProfiles};
+
+mod ai_difficulty;
+mod ai_profile;
+mod ai_profiles;
@@ -0,0 +1,42 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Difficulty level of a computer controlled player.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiDifficulty {
+    /// Slow to react, and does not defend.
+    Easy,
+    /// Reacts at a moderate pace, and defends against attacks.
+    #[derivative(Default)]
+    Normal,
+    /// Reacts quickly, defends against attacks, and retreats when weak.
+    Hard,
+}
+
+impl AiDifficulty {
+    /// Returns the next harder difficulty, or `None` if this is the hardest difficulty.
+    pub fn next(self) -> Option<Self> {
+        match self {
+            AiDifficulty::Easy => Some(AiDifficulty::Normal),
+            AiDifficulty::Normal => Some(AiDifficulty::Hard),
+            AiDifficulty::Hard => None,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Parameters that control how a computer controlled player behaves.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiProfile {
+    /// Number of ticks between each decision.
+    #[derivative(Default(value = "15"))]
+    pub reaction_delay: usize,
+    /// Horizontal distance within which to attack the target.
+    #[derivative(Default(value = "60."))]
+    pub attack_range: f32,
+    /// Depth distance within which to attack the target.
+    #[derivative(Default(value = "20."))]
+    pub depth_range: f32,
+    /// Whether to defend when the target is attacking within range.
+    #[derivative(Default(value = "true"))]
+    pub defends: bool,
+    /// Health points at or below which to move away from the target.
+    ///
+    /// `0` means never retreat.
+    pub retreat_health: u32,
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AiDifficulty, AiProfile};
+
+/// `AiProfile` for each `AiDifficulty`.
+#[derive(Clone, Debug, Deref, DerefMut, Deserialize, PartialEq, Serialize)]
+pub struct AiProfiles(pub HashMap<AiDifficulty, AiProfile>);
+
+impl AiProfiles {
+    /// Returns the `AiProfile` for the given difficulty.
+    ///
+    /// If the difficulty is not configured, the default `AiProfile` is returned.
+    pub fn profile(&self, ai_difficulty: AiDifficulty) -> AiProfile {
+        self.0.get(&ai_difficulty).copied().unwrap_or_default()
+    }
+}
+
+impl Default for AiProfiles {
+    fn default() -> Self {
+        let mut ai_profiles = HashMap::new();
+        ai_profiles.insert(AiDifficulty::Easy, AiProfile::new(30, 50., 15., false, 0));
+        ai_profiles.insert(AiDifficulty::Normal, AiProfile::default());
+        ai_profiles.insert(AiDifficulty::Hard, AiProfile::new(5, 70., 20., true, 30));
+
+        AiProfiles(ai_profiles)
+    }
+}
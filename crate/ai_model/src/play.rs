@@ -0,0 +1,6 @@
+//! Contains data types used at runtime.
+
+pub use self::{ai_controlled::AiControlled, ai_reaction_clock::AiReactionClock};
+
+mod ai_controlled;
+mod ai_reaction_clock;
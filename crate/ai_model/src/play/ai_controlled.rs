@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::HashMapStorage, Component};
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::config::AiDifficulty;
+
+/// Marks an entity that is controlled by the computer.
+///
+/// The controller ID is the player slot that the entity was selected for, and is used in the
+/// `ControlInputEvent`s sent for this entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(HashMapStorage)]
+pub struct AiControlled {
+    /// ID of the player slot that the entity occupies.
+    pub controller_id: ControllerId,
+    /// Difficulty that the entity is played at.
+    pub difficulty: AiDifficulty,
+}
@@ -0,0 +1,78 @@
+use ai_model::config::AiProfile;
+use game_input_model::play::ControllerInput;
+
+use crate::AiObservation;
+
+/// Decides the input for a computer controlled entity.
+#[derive(Debug)]
+pub struct AiInputDecider;
+
+impl AiInputDecider {
+    /// Returns the `ControllerInput` that a computer controlled entity should have.
+    ///
+    /// # Parameters
+    ///
+    /// * `ai_profile`: Parameters for how the entity behaves.
+    /// * `own`: Observation of the computer controlled entity.
+    /// * `target`: Observation of the nearest opponent, if any.
+    pub fn decide(
+        ai_profile: &AiProfile,
+        own: &AiObservation,
+        target: Option<&AiObservation>,
+    ) -> ControllerInput {
+        let target = match target {
+            Some(target) if own.health_points.0 != 0 => target,
+            _ => return ControllerInput::default(),
+        };
+
+        let mut controller_input = ControllerInput::default();
+
+        let distance_x = target.position.x - own.position.x;
+        let distance_z = target.position.z - own.position.z;
+        let direction_x = Self::direction(distance_x);
+        let direction_z = Self::direction(distance_z);
+
+        // Run away when weak.
+        if own.health_points.0 <= ai_profile.retreat_health
+            && distance_x.abs() <= ai_profile.attack_range * 2.
+        {
+            controller_input.x_axis_value = if direction_x == 0. { 1. } else { -direction_x };
+            controller_input.z_axis_value = -direction_z;
+            return controller_input;
+        }
+
+        let in_range_x = distance_x.abs() <= ai_profile.attack_range;
+        let in_range_z = distance_z.abs() <= ai_profile.depth_range;
+        if in_range_x && in_range_z {
+            let facing_target = direction_x == 0. || (direction_x < 0.) == own.mirrored.0;
+            if ai_profile.defends && target.is_attacking() {
+                controller_input.defend = true;
+            } else if !facing_target {
+                controller_input.x_axis_value = direction_x;
+            } else {
+                // Alternate between pressing and releasing, so that attacks are repeated.
+                controller_input.attack = !own.controller_input.attack;
+            }
+        } else {
+            if !in_range_x {
+                controller_input.x_axis_value = direction_x;
+            }
+            if !in_range_z {
+                controller_input.z_axis_value = direction_z;
+            }
+        }
+
+        controller_input
+    }
+
+    /// Returns `-1.`, `0.`, or `1.` depending on the sign of the distance.
+    fn direction(distance: f32) -> f32 {
+        if distance < 0. {
+            -1.
+        } else if distance > 0. {
+            1.
+        } else {
+            0.
+        }
+    }
+}
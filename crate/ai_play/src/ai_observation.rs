@@ -0,0 +1,34 @@
+use character_model::config::CharacterSequenceName;
+use derive_new::new;
+use game_input_model::play::ControllerInput;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+
+/// Components of an object that a computer controlled entity makes decisions on.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct AiObservation {
+    /// Position of the object.
+    pub position: Position<f32>,
+    /// Health points of the object.
+    pub health_points: HealthPoints,
+    /// Whether the object is facing left.
+    pub mirrored: Mirrored,
+    /// Current sequence of the object, if it is a known character sequence.
+    pub character_sequence_name: Option<CharacterSequenceName>,
+    /// Current input of the object.
+    pub controller_input: ControllerInput,
+}
+
+impl AiObservation {
+    /// Returns whether the object is in an attacking sequence.
+    pub fn is_attacking(&self) -> bool {
+        match self.character_sequence_name {
+            Some(CharacterSequenceName::StandAttack0)
+            | Some(CharacterSequenceName::StandAttack1)
+            | Some(CharacterSequenceName::JumpAttack)
+            | Some(CharacterSequenceName::DashAttack) => true,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for computer controlled objects.
+
+pub use crate::{
+    ai_input_decider::AiInputDecider,
+    ai_observation::AiObservation,
+    system::{AiControlSystem, AiControlSystemData},
+};
+
+mod ai_input_decider;
+mod ai_observation;
+mod system;
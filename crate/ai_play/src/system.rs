@@ -0,0 +1,3 @@
+pub use self::ai_control_system::{AiControlSystem, AiControlSystemData};
+
+mod ai_control_system;
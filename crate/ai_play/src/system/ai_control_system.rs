@@ -0,0 +1,277 @@
+use ai_model::{
+    config::AiProfiles,
+    play::{AiControlled, AiReactionClock},
+};
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use asset_model::loaded::AssetId;
+use character_model::config::CharacterSequenceName;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+};
+use game_play_model::GamePlayStatus;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::{
+    config::SequenceNameString,
+    loaded::{AssetSequenceIdMappings, SequenceId},
+};
+use team_model::play::Team;
+
+use crate::{AiInputDecider, AiObservation};
+
+/// Sends `ControlInputEvent`s for computer controlled entities.
+#[derive(Debug, Default, new)]
+pub struct AiControlSystem;
+
+/// `AiControlSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AiControlSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `AiProfiles` resource.
+    #[derivative(Debug = "ignore")]
+    pub ai_profiles: Read<'s, AiProfiles>,
+    /// `AssetSequenceIdMappings<CharacterSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_character:
+        Read<'s, AssetSequenceIdMappings<CharacterSequenceName>>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `AiReactionClock` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_reaction_clocks: WriteStorage<'s, AiReactionClock>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+impl AiControlSystem {
+    /// Returns the `AiObservation` of an entity, if it has the necessary components.
+    fn observe(
+        AiControlSystemData {
+            asset_sequence_id_mappings_character,
+            controller_inputs,
+            positions,
+            health_pointses,
+            mirroreds,
+            asset_ids,
+            sequence_ids,
+            ..
+        }: &AiControlSystemData,
+        entity: Entity,
+    ) -> Option<AiObservation> {
+        let position = positions.get(entity).copied()?;
+        let health_points = health_pointses.get(entity).copied()?;
+        let mirrored = mirroreds.get(entity).copied().unwrap_or_default();
+        let character_sequence_name = asset_ids
+            .get(entity)
+            .and_then(|asset_id| asset_sequence_id_mappings_character.get(*asset_id))
+            .and_then(|sequence_id_mappings| {
+                sequence_ids
+                    .get(entity)
+                    .and_then(|sequence_id| sequence_id_mappings.name(*sequence_id))
+            })
+            .and_then(|sequence_name_string| {
+                if let SequenceNameString::Name(character_sequence_name) = sequence_name_string {
+                    Some(*character_sequence_name)
+                } else {
+                    None
+                }
+            });
+        let controller_input = controller_inputs.get(entity).copied().unwrap_or_default();
+
+        Some(AiObservation::new(
+            position,
+            health_points,
+            mirrored,
+            character_sequence_name,
+            controller_input,
+        ))
+    }
+
+    /// Returns the nearest entity that is on a different team and still has health points.
+    fn target_find(
+        AiControlSystemData {
+            entities,
+            positions,
+            health_pointses,
+            teams,
+            ..
+        }: &AiControlSystemData,
+        entity: Entity,
+        position: Position<f32>,
+    ) -> Option<Entity> {
+        let team = teams.get(entity).copied();
+
+        (entities, positions, health_pointses, teams)
+            .join()
+            .filter(|(entity_other, _, health_points, team_other)| {
+                *entity_other != entity && health_points.0 > 0 && Some(**team_other) != team
+            })
+            .map(|(entity_other, position_other, _, _)| {
+                let distance_x = position_other.x - position.x;
+                let distance_z = position_other.z - position.z;
+                (
+                    entity_other,
+                    distance_x * distance_x + distance_z * distance_z,
+                )
+            })
+            .fold(
+                None,
+                |nearest, (entity_other, distance_squared)| match nearest {
+                    Some((_, nearest_distance_squared))
+                        if nearest_distance_squared <= distance_squared =>
+                    {
+                        nearest
+                    }
+                    _ => Some((entity_other, distance_squared)),
+                },
+            )
+            .map(|(entity_other, _)| entity_other)
+    }
+
+    /// Returns the `ControlInputEvent`s to change the current input to the desired input.
+    fn control_input_events(
+        controller_id: ControllerId,
+        entity: Entity,
+        current: ControllerInput,
+        desired: ControllerInput,
+    ) -> Vec<ControlInputEvent> {
+        let axis_events = [
+            (Axis::X, current.x_axis_value, desired.x_axis_value),
+            (Axis::Z, current.z_axis_value, desired.z_axis_value),
+        ]
+        .iter()
+        .filter(|(_, value_current, value_desired)| {
+            (value_current - value_desired).abs() > std::f32::EPSILON
+        })
+        .map(|(axis, _, value)| {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                entity,
+                axis: *axis,
+                value: *value,
+            })
+        })
+        .collect::<Vec<ControlInputEvent>>();
+
+        let action_events = [
+            (ControlAction::Defend, current.defend, desired.defend),
+            (ControlAction::Jump, current.jump, desired.jump),
+            (ControlAction::Attack, current.attack, desired.attack),
+            (ControlAction::Special, current.special, desired.special),
+        ]
+        .iter()
+        .filter(|(_, pressed_current, pressed_desired)| pressed_current != pressed_desired)
+        .map(|(control_action, _, pressed)| {
+            let control_action_event_data = ControlActionEventData {
+                controller_id,
+                entity,
+                control_action: *control_action,
+            };
+            if *pressed {
+                ControlInputEvent::ControlActionPress(control_action_event_data)
+            } else {
+                ControlInputEvent::ControlActionRelease(control_action_event_data)
+            }
+        });
+
+        axis_events.into_iter().chain(action_events).collect()
+    }
+}
+
+impl<'s> System<'s> for AiControlSystem {
+    type SystemData = AiControlSystemData<'s>;
+
+    fn run(&mut self, mut ai_control_system_data: Self::SystemData) {
+        if *ai_control_system_data.game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        let ai_decisions = {
+            let AiControlSystemData {
+                entities,
+                ai_profiles,
+                ai_controlleds,
+                ai_reaction_clocks,
+                ..
+            } = &mut ai_control_system_data;
+
+            (&*entities, &*ai_controlleds)
+                .join()
+                .filter_map(|(entity, ai_controlled)| {
+                    let ai_profile = ai_profiles.profile(ai_controlled.difficulty);
+                    let ai_reaction_clock = ai_reaction_clocks
+                        .entry(entity)
+                        .expect("Failed to access `AiReactionClock` entry.")
+                        .or_insert_with(|| AiReactionClock::new(ai_profile.reaction_delay));
+
+                    ai_reaction_clock.tick();
+                    if ai_reaction_clock.is_complete() {
+                        ai_reaction_clock.reset();
+                        Some((entity, *ai_controlled, ai_profile))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let control_input_events = ai_decisions
+            .into_iter()
+            .filter_map(|(entity, ai_controlled, ai_profile)| {
+                let own = Self::observe(&ai_control_system_data, entity)?;
+                let target = Self::target_find(&ai_control_system_data, entity, own.position)
+                    .and_then(|target| Self::observe(&ai_control_system_data, target));
+
+                let desired = AiInputDecider::decide(&ai_profile, &own, target.as_ref());
+                Some(Self::control_input_events(
+                    ai_controlled.controller_id,
+                    entity,
+                    own.controller_input,
+                    desired,
+                ))
+            })
+            .flatten()
+            .collect::<Vec<ControlInputEvent>>();
+
+        ai_control_system_data
+            .control_input_ec
+            .iter_write(control_input_events);
+    }
+}
@@ -7,11 +7,13 @@ use amethyst::{
     winit::Event,
 };
 use asset_selection_model::play::AssetSelectionEvent;
+use audio_settings_model::AudioSettingsEvent;
 use control_settings_model::ControlSettingsEvent;
 use derive_more::From;
 use game_input_model::play::ControlInputEvent;
 use game_mode_selection_model::GameModeSelectionEvent;
 use game_play_model::GamePlayEvent;
+use music_model::MusicEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
@@ -30,6 +32,8 @@ use strum_macros::{Display, EnumDiscriminants, EnumIter, EnumString};
 pub enum AppEvent {
     /// `asset_selection` events.
     AssetSelection(AssetSelectionEvent),
+    /// `audio_settings` events.
+    AudioSettings(AudioSettingsEvent),
     /// `control_input` events.
     ///
     /// Note: This is defined in the `game_input*` crates.
@@ -40,6 +44,8 @@ pub enum AppEvent {
     GameModeSelection(GameModeSelectionEvent),
     /// `game_play` events.
     GamePlay(GamePlayEvent),
+    /// `music` events.
+    Music(MusicEvent),
     /// `network_mode_selection` events.
     NetworkModeSelection(NetworkModeSelectionEvent),
     /// `session_host` events.
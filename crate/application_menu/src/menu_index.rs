@@ -1,4 +1,6 @@
+use audio_model::config::AudioChannel;
 use game_mode_selection_model::GameModeIndex;
+use game_play_model::GamePlayPauseIndex;
 use network_mode_selection_model::NetworkModeIndex;
 use serde::{Deserialize, Serialize};
 
@@ -10,4 +12,8 @@ pub enum MenuIndex {
     GameMode(GameModeIndex),
     /// Network mode menu indicies.
     NetworkMode(NetworkModeIndex),
+    /// Game play pause menu indicies.
+    GamePlayPause(GamePlayPauseIndex),
+    /// Audio settings menu indicies.
+    AudioSettings(AudioChannel),
 }
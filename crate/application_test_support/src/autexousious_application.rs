@@ -28,6 +28,7 @@ use input_reaction_loading::InputReactionLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 use map_loading::MapLoadingBundle;
+use music_loading::MusicLoadingBundle;
 use net_play::{NetListenerSystem, NetListenerSystemDesc};
 use object_type::ObjectType;
 use sequence_loading::SequenceLoadingBundle;
@@ -50,6 +51,7 @@ use ui_play::{
     UiTransformForFovSystemDesc, UiTransformInsertionRectifySystem,
     UiTransformInsertionRectifySystemDesc, WidgetSequenceUpdateSystem,
 };
+use weapon_loading::WeaponLoadingBundle;
 
 use crate::{AssetQueries, SetupFunction};
 
@@ -118,9 +120,11 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(WeaponLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
+            .with_bundle(MusicLoadingBundle::new(ASSETS_PATH.clone()))
             .with_system(
                 CharacterSelectionSystem::new(),
                 any::type_name::<CharacterSelectionSystem>(),
@@ -201,9 +205,11 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(WeaponLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
+            .with_bundle(MusicLoadingBundle::new(ASSETS_PATH.clone()))
             .with_system(
                 CharacterSelectionSystem::new(),
                 any::type_name::<CharacterSelectionSystem>(),
@@ -26,6 +26,7 @@ use derive_new::new;
 use game_input_model::play::{
     ButtonInputControlled, InputControlled, NormalInputControlled, SharedInputControlled,
 };
+use game_stats_ui_model::loaded::PlayerStatsWidgets;
 use input_reaction_model::loaded::InputReactionsSequenceHandles;
 use kinematic_model::{
     config::{PositionInit, ScaleInit, VelocityInit},
@@ -113,6 +114,8 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         asset_world.register::<SessionCodeLabel>();
         asset_world.register::<SessionDevicesWidget>();
 
+        asset_world.register::<PlayerStatsWidgets>();
+
         world.insert(asset_world);
 
         builder.add_barrier();
@@ -390,6 +393,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             &any::type_name::<ItemComponentComponentAugmentSystem<SessionDevicesWidget>>(),
             &[],
         );
+
+        // Game Stats UI
+        builder.add(
+            ItemComponentComponentAugmentSystem::<PlayerStatsWidgets>::new(),
+            &any::type_name::<ItemComponentComponentAugmentSystem<PlayerStatsWidgets>>(),
+            &[],
+        );
         builder.add_barrier();
         Ok(())
     }
@@ -18,6 +18,10 @@ pub enum AssetSelectionEventCommand {
     Select,
     /// Asset has been deselected.
     Deselect,
+    /// Player slot has switched between human and computer control.
+    Cpu,
+    /// Player has switched team.
+    Team,
     /// Asset selections have been confirmed.
     Confirm,
 }
@@ -57,6 +57,30 @@ pub enum AssetSelectionEvent {
         /// ID of the controller that sent the event.
         controller_id: ControllerId,
     },
+    /// Player slot has switched between human and computer control.
+    ///
+    /// Each time this is sent, the slot cycles through each computer difficulty before returning
+    /// to human control.
+    Cpu {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+    },
+    /// Player has switched team.
+    ///
+    /// Each time this is sent, the player cycles through each numbered team before returning to
+    /// an independent team.
+    Team {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+    },
     /// Confirm `AssetSelection`s.
     Confirm,
 }
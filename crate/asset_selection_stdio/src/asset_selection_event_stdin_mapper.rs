@@ -112,6 +112,14 @@ impl StdinMapper for AssetSelectionEventStdinMapper {
                 entity: None,
                 controller_id,
             }),
+            AssetSelectionEventArgs::Cpu { controller_id } => Ok(AssetSelectionEvent::Cpu {
+                entity: None,
+                controller_id,
+            }),
+            AssetSelectionEventArgs::Team { controller_id } => Ok(AssetSelectionEvent::Team {
+                entity: None,
+                controller_id,
+            }),
             AssetSelectionEventArgs::Confirm => Ok(AssetSelectionEvent::Confirm),
         }
     }
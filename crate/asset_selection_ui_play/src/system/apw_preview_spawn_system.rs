@@ -328,7 +328,10 @@ where
                     }
                 }
                 // No update needed -- preview entities are already correct.
-                AssetSelectionEvent::Select { .. } | AssetSelectionEvent::Deselect { .. } => {}
+                AssetSelectionEvent::Select { .. }
+                | AssetSelectionEvent::Deselect { .. }
+                | AssetSelectionEvent::Cpu { .. }
+                | AssetSelectionEvent::Team { .. } => {}
                 AssetSelectionEvent::Confirm => {}
             });
     }
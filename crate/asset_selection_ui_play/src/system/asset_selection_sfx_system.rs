@@ -6,14 +6,14 @@ use amethyst::{
     shrev::{EventChannel, ReaderId},
 };
 use asset_selection_model::play::AssetSelectionEvent;
+use audio_model::config::{AudioChannel, AudioSettings};
 use derivative::Derivative;
 use derive_new::new;
 use ui_audio_model::{config::UiSfxId, loaded::UiSfxMap};
 
-/// Default volume to play sounds at.
-const VOLUME: f32 = 1.0;
-
 /// Plays sounds for the character selection UI.
+///
+/// Sounds are played at the `AudioChannel::Ui` volume.
 #[derive(Debug, Default, new)]
 pub struct AssetSelectionSfxSystem {
     /// Reader ID for the `AssetSelectionEvent` event channel.
@@ -34,6 +34,9 @@ pub struct AssetSelectionSfxSystemData<'s> {
     /// `Source` assets.
     #[derivative(Debug = "ignore")]
     pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `AudioSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings: Read<'s, AudioSettings>,
     /// `Output` resource.
     #[derivative(Debug = "ignore")]
     pub output: Option<Read<'s, Output>>,
@@ -48,6 +51,7 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
             asset_selection_ec,
             ui_sfx_map,
             source_assets,
+            audio_settings,
             output,
         }: Self::SystemData,
     ) {
@@ -59,6 +63,7 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
         );
 
         if let Some(output) = output {
+            let volume = audio_settings.volume(AudioChannel::Ui);
             events_iterator.for_each(|ev| {
                 let ui_sfx_id = match ev {
                     AssetSelectionEvent::Return => UiSfxId::Cancel,
@@ -67,6 +72,8 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
                     AssetSelectionEvent::Select { .. } => UiSfxId::Select,
                     AssetSelectionEvent::Deselect { .. } => UiSfxId::Deselect,
                     AssetSelectionEvent::Leave { .. } => UiSfxId::Deselect,
+                    AssetSelectionEvent::Cpu { .. } => UiSfxId::Switch,
+                    AssetSelectionEvent::Team { .. } => UiSfxId::Switch,
                     AssetSelectionEvent::Confirm => UiSfxId::Confirm,
                 };
 
@@ -75,7 +82,7 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
                     .and_then(|ui_sfx_handle| source_assets.get(ui_sfx_handle));
 
                 if let Some(ui_sfx) = ui_sfx {
-                    output.play_once(ui_sfx, VOLUME);
+                    output.play_once(ui_sfx, volume);
                 }
             });
         }
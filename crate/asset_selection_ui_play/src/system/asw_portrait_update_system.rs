@@ -198,7 +198,10 @@ impl<'s> System<'s> for AswPortraitUpdateSystem {
                 }
                 // Don't need to update sequence for select / deselect, as they should be on the
                 // correct portrait background already.
-                AssetSelectionEvent::Select { .. } | AssetSelectionEvent::Deselect { .. } => {}
+                AssetSelectionEvent::Select { .. }
+                | AssetSelectionEvent::Deselect { .. }
+                | AssetSelectionEvent::Cpu { .. }
+                | AssetSelectionEvent::Team { .. } => {}
                 AssetSelectionEvent::Confirm => {}
             });
     }
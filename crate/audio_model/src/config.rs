@@ -1 +1,6 @@
 //! Contains the types that represent the configuration on disk.
+
+pub use self::{audio_channel::AudioChannel, audio_settings::AudioSettings};
+
+mod audio_channel;
+mod audio_settings;
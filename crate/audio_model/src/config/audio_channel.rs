@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Mixer channel that sounds are played through.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AudioChannel {
+    /// Volume applied to all other channels.
+    Master,
+    /// Object and collision sound effects.
+    Sfx,
+    /// UI sound effects.
+    Ui,
+    /// Background music.
+    Music,
+}
@@ -0,0 +1,61 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::AudioChannel;
+
+/// Volume levels of each mixer channel, from `0.0` to `1.0`.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AudioSettings {
+    /// Level applied to all other channels.
+    #[derivative(Default(value = "1."))]
+    pub master: f32,
+    /// Level of object and collision sound effects.
+    #[derivative(Default(value = "1."))]
+    pub sfx: f32,
+    /// Level of UI sound effects.
+    #[derivative(Default(value = "1."))]
+    pub ui: f32,
+    /// Level of background music.
+    #[derivative(Default(value = "1."))]
+    pub music: f32,
+}
+
+impl AudioSettings {
+    /// Amount that a level is changed by for each volume up or down step.
+    pub const LEVEL_STEP: f32 = 0.1;
+
+    /// Returns the level of a channel, without the master level applied.
+    pub fn level(self, audio_channel: AudioChannel) -> f32 {
+        match audio_channel {
+            AudioChannel::Master => self.master,
+            AudioChannel::Sfx => self.sfx,
+            AudioChannel::Ui => self.ui,
+            AudioChannel::Music => self.music,
+        }
+    }
+
+    /// Sets the level of a channel, clamped between `0.0` and `1.0`.
+    ///
+    /// The level is rounded to the nearest percent, so that repeated steps don't accumulate
+    /// floating point error.
+    pub fn level_set(&mut self, audio_channel: AudioChannel, level: f32) {
+        let level = ((level * 100.).round() / 100.).max(0.).min(1.);
+        match audio_channel {
+            AudioChannel::Master => self.master = level,
+            AudioChannel::Sfx => self.sfx = level,
+            AudioChannel::Ui => self.ui = level,
+            AudioChannel::Music => self.music = level,
+        }
+    }
+
+    /// Returns the volume to play sounds on a channel at, with the master level applied.
+    pub fn volume(self, audio_channel: AudioChannel) -> f32 {
+        match audio_channel {
+            AudioChannel::Master => self.master,
+            _ => self.master * self.level(audio_channel),
+        }
+    }
+}
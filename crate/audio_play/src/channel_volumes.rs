@@ -0,0 +1,86 @@
+use amethyst::core::{math::Vector3, Transform};
+use camera_model::play::CameraZoomDimensions;
+use derivative::Derivative;
+use derive_new::new;
+
+/// Proportion of volume moved to one side when a sound is at the edge of the view.
+pub const PAN_STRENGTH: f32 = 0.75;
+/// Volume of sounds that are far outside the view.
+pub const ATTENUATION_MIN: f32 = 0.3;
+
+/// Volume multipliers for the left and right speakers.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, new)]
+#[derivative(Default)]
+pub struct ChannelVolumes {
+    /// Volume multiplier for the left speaker.
+    #[derivative(Default(value = "1."))]
+    pub left: f32,
+    /// Volume multiplier for the right speaker.
+    #[derivative(Default(value = "1."))]
+    pub right: f32,
+}
+
+impl ChannelVolumes {
+    /// Returns the channel volumes for a sound emitted by an entity.
+    ///
+    /// When there is no camera, or the entity has no `Transform`, the sound is played centred.
+    ///
+    /// # Parameters
+    ///
+    /// * `camera_translation`: Translation of the camera's target coordinates.
+    /// * `camera_zoom_dimensions`: Dimensions of the playable area that is in view.
+    /// * `transform`: `Transform` of the entity emitting the sound.
+    pub fn emitter(
+        camera_translation: Option<Vector3<f32>>,
+        camera_zoom_dimensions: CameraZoomDimensions,
+        transform: Option<&Transform>,
+    ) -> Self {
+        match (camera_translation, transform) {
+            (Some(camera_translation), Some(transform)) => Self::positional(
+                camera_translation,
+                camera_zoom_dimensions,
+                *transform.translation(),
+            ),
+            _ => Self::default(),
+        }
+    }
+
+    /// Returns the channel volumes for a sound emitted at the given translation.
+    ///
+    /// Sounds are panned towards the side of the view they are emitted from, and are quieter the
+    /// further they are outside the view, down to `ATTENUATION_MIN` at one view's length away.
+    ///
+    /// # Parameters
+    ///
+    /// * `camera_translation`: Translation of the camera's target coordinates.
+    /// * `camera_zoom_dimensions`: Dimensions of the playable area that is in view.
+    /// * `emitter_translation`: Translation of the entity emitting the sound.
+    pub fn positional(
+        camera_translation: Vector3<f32>,
+        camera_zoom_dimensions: CameraZoomDimensions,
+        emitter_translation: Vector3<f32>,
+    ) -> Self {
+        let half_width = (camera_zoom_dimensions.width / 2.).max(1.);
+        let half_height = (camera_zoom_dimensions.height / 2.).max(1.);
+
+        // Distance from the centre of the view, where `1.` is the edge of the view.
+        let dx = (emitter_translation.x - camera_translation.x) / half_width;
+        let dy = (emitter_translation.y - camera_translation.y) / half_height;
+
+        let pan = dx.max(-1.).min(1.) * PAN_STRENGTH;
+
+        let distance = (dx * dx + dy * dy).sqrt();
+        let attenuation = if distance <= 1. {
+            1.
+        } else {
+            let fade = (distance - 1.).min(1.);
+            1. - fade * (1. - ATTENUATION_MIN)
+        };
+
+        // Sounds in the centre are played at full volume through both speakers.
+        let left = (1. - pan).min(1.) * attenuation;
+        let right = (1. + pan).min(1.) * attenuation;
+
+        ChannelVolumes::new(left, right)
+    }
+}
@@ -2,6 +2,14 @@
 
 //! Provides collision audio systems for game play.
 
-pub use crate::system::SequenceAudioPlaySystem;
+pub use crate::{
+    channel_volumes::{ChannelVolumes, ATTENUATION_MIN, PAN_STRENGTH},
+    output_device::OutputDevice,
+    sfx_player::SfxPlayer,
+    system::SequenceAudioPlaySystem,
+};
 
+mod channel_volumes;
+mod output_device;
+mod sfx_player;
 mod system;
@@ -0,0 +1,40 @@
+use amethyst::audio::output::Output;
+use log::{error, warn};
+use rodio::{Device, DeviceTrait};
+
+/// Finds the audio device of the `Output` resource.
+///
+/// `Output` does not expose its device, so the device is found by name. Sounds that need more
+/// control than `Output` provides, such as separate speaker volumes, are played through this
+/// device so that all audio is played through the same device.
+#[derive(Debug)]
+pub struct OutputDevice;
+
+impl OutputDevice {
+    /// Returns the device that the `Output` plays through, if it is found.
+    ///
+    /// # Parameters
+    ///
+    /// * `output`: Audio `Output` resource.
+    pub fn find(output: &Output) -> Option<Device> {
+        let output_name = output.name();
+        let device = match rodio::output_devices() {
+            Ok(mut devices) => devices.find(|device| {
+                device
+                    .name()
+                    .map(|device_name| device_name == output_name)
+                    .unwrap_or(false)
+            }),
+            Err(e) => {
+                error!("Failed to list audio output devices: {}", e);
+                None
+            }
+        };
+
+        if device.is_none() {
+            warn!("Audio output device `{}` not found.", output_name);
+        }
+
+        device
+    }
+}
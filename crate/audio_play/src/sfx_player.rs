@@ -0,0 +1,57 @@
+use std::io::Cursor;
+
+use amethyst::audio::{output::Output, Source};
+use derivative::Derivative;
+use log::error;
+use rodio::{source::ChannelVolume, Decoder, Device, Sink};
+
+use crate::{ChannelVolumes, OutputDevice};
+
+/// Plays sound effects with separate left and right volumes.
+///
+/// Sounds are played through the device of the `Output` resource, which is looked up when the first
+/// sound is played.
+#[derive(Derivative, Default)]
+#[derivative(Debug)]
+pub struct SfxPlayer {
+    /// Device that sounds are played through.
+    #[derivative(Debug = "ignore")]
+    device: Option<Device>,
+}
+
+impl SfxPlayer {
+    /// Plays a sound once.
+    ///
+    /// # Parameters
+    ///
+    /// * `output`: Audio `Output` resource, whose device the sound is played through.
+    /// * `source`: Audio data of the sound.
+    /// * `volume`: Volume to play the sound at.
+    /// * `channel_volumes`: Volume multipliers for the left and right speakers.
+    pub fn play_once(
+        &mut self,
+        output: &Output,
+        source: &Source,
+        volume: f32,
+        channel_volumes: ChannelVolumes,
+    ) {
+        if self.device.is_none() {
+            self.device = OutputDevice::find(output);
+        }
+
+        if let Some(device) = self.device.as_ref() {
+            match Decoder::new(Cursor::new(source.clone())) {
+                Ok(decoder) => {
+                    let channel_volumes = vec![
+                        channel_volumes.left * volume,
+                        channel_volumes.right * volume,
+                    ];
+                    let sink = Sink::new(device);
+                    sink.append(ChannelVolume::new(decoder, channel_volumes));
+                    sink.detach();
+                }
+                Err(e) => error!("Failed to decode sound: {}", e),
+            }
+        }
+    }
+}
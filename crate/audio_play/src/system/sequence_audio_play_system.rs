@@ -1,24 +1,34 @@
 use amethyst::{
     assets::AssetStorage,
     audio::{output::Output, Source},
-    ecs::{Read, ReadStorage, System, World},
+    core::Transform,
+    ecs::{Join, Read, ReadStorage, System, World},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use audio_model::loaded::SourceHandleOpt;
+use audio_model::{
+    config::{AudioChannel, AudioSettings},
+    loaded::SourceHandleOpt,
+};
+use camera_model::play::{CameraTargetCoordinates, CameraZoomDimensions};
 use derivative::Derivative;
 use derive_new::new;
 use sequence_model::play::SequenceUpdateEvent;
 
-/// Default volume to play sounds at.
-const VOLUME: f32 = 1.0;
+use crate::{ChannelVolumes, SfxPlayer};
 
 /// Plays a sound at the beginning of a frame.
+///
+/// Sounds are played at the `AudioChannel::Sfx` volume, panned and attenuated by the entity's
+/// `Transform` relative to the camera.
 #[derive(Debug, Default, new)]
 pub struct SequenceAudioPlaySystem {
     /// Reader ID for the `SequenceUpdateEvent` event channel.
     #[new(default)]
     sequence_update_event_rid: Option<ReaderId<SequenceUpdateEvent>>,
+    /// Plays sounds with separate left and right volumes.
+    #[new(default)]
+    sfx_player: SfxPlayer,
 }
 
 #[derive(Derivative, SystemData)]
@@ -30,9 +40,21 @@ pub struct SequenceAudioPlaySystemData<'s> {
     /// `SourceHandleOpt` components.
     #[derivative(Debug = "ignore")]
     pub source_handle_opts: ReadStorage<'s, SourceHandleOpt>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: ReadStorage<'s, Transform>,
+    /// `CameraTargetCoordinates` components.
+    #[derivative(Debug = "ignore")]
+    pub camera_target_coordinateses: ReadStorage<'s, CameraTargetCoordinates>,
+    /// `CameraZoomDimensions` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_zoom_dimensions: Read<'s, CameraZoomDimensions>,
     /// `Source` assets.
     #[derivative(Debug = "ignore")]
     pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `AudioSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings: Read<'s, AudioSettings>,
     /// `Output` resource.
     #[derivative(Debug = "ignore")]
     pub output: Option<Read<'s, Output>>,
@@ -46,7 +68,11 @@ impl<'s> System<'s> for SequenceAudioPlaySystem {
         SequenceAudioPlaySystemData {
             sequence_update_ec,
             source_handle_opts,
+            transforms,
+            camera_target_coordinateses,
+            camera_zoom_dimensions,
             source_assets,
+            audio_settings,
             output,
         }: Self::SystemData,
     ) {
@@ -58,6 +84,12 @@ impl<'s> System<'s> for SequenceAudioPlaySystem {
         );
 
         if let Some(output) = output {
+            let volume = audio_settings.volume(AudioChannel::Sfx);
+            let camera_translation = camera_target_coordinateses
+                .join()
+                .next()
+                .map(|camera_target_coordinates| **camera_target_coordinates);
+            let sfx_player = &mut self.sfx_player;
             events_iterator.for_each(|ev| match ev {
                 SequenceUpdateEvent::SequenceBegin { entity, .. }
                 | SequenceUpdateEvent::FrameBegin { entity, .. } => {
@@ -68,7 +100,12 @@ impl<'s> System<'s> for SequenceAudioPlaySystem {
                         .and_then(|source_handle| source_assets.get(source_handle));
 
                     if let Some(source) = source {
-                        output.play_once(source, VOLUME);
+                        let channel_volumes = ChannelVolumes::emitter(
+                            camera_translation,
+                            *camera_zoom_dimensions,
+                            transforms.get(*entity),
+                        );
+                        sfx_player.play_once(&output, source, volume, channel_volumes);
                     }
                 }
                 SequenceUpdateEvent::SequenceEnd { .. } => {}
@@ -0,0 +1,32 @@
+use std::{fs, path::PathBuf};
+
+use amethyst::Error;
+use application::{AppDir, AppFile, Format};
+use audio_model::config::AudioSettings;
+
+/// Loads and stores `AudioSettings` from the resources directory.
+#[derive(Debug)]
+pub struct AudioSettingsPersister;
+
+impl AudioSettingsPersister {
+    /// Name of the audio settings file.
+    pub const FILE_NAME: &'static str = "audio_settings.yaml";
+
+    /// Returns the `AudioSettings` loaded from disk.
+    pub fn load() -> Result<AudioSettings, Error> {
+        AppFile::load_in::<AudioSettings, _>(AppDir::RESOURCES, Self::FILE_NAME, Format::Yaml)
+    }
+
+    /// Writes the `AudioSettings` to disk, returning the path of the file written.
+    ///
+    /// # Parameters
+    ///
+    /// * `audio_settings`: Audio settings to write.
+    pub fn store(audio_settings: &AudioSettings) -> Result<PathBuf, Error> {
+        let file_path = AppFile::find_in(AppDir::RESOURCES, Self::FILE_NAME)?;
+        let audio_settings_yaml = serde_yaml::to_string(audio_settings)?;
+        fs::write(&file_path, audio_settings_yaml)?;
+
+        Ok(file_path)
+    }
+}
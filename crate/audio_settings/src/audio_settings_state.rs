@@ -0,0 +1,109 @@
+use amethyst::{
+    ecs::{World, WorldExt},
+    input::{is_key_down, VirtualKeyCode},
+    GameData, State, StateData, Trans,
+};
+use application_event::AppEvent;
+use audio_model::config::{AudioChannel, AudioSettings};
+use audio_settings_model::{AudioSettingsEntity, AudioSettingsEvent};
+use derive_new::new;
+use log::{debug, error, info};
+use state_registry::StateId;
+use state_support::StateEntityUtils;
+
+use crate::AudioSettingsPersister;
+
+/// `State` where audio levels are adjusted.
+#[derive(Debug, Default, new)]
+pub struct AudioSettingsState;
+
+impl AudioSettingsState {
+    /// Changes the level of a channel, and writes the `AudioSettings` to disk.
+    fn level_change(world: &World, audio_channel: AudioChannel, step: f32) {
+        let store_result = {
+            let mut audio_settings = world.write_resource::<AudioSettings>();
+            let level = audio_settings.level(audio_channel) + step;
+            audio_settings.level_set(audio_channel, level);
+
+            debug!(
+                "`{}` level: {}",
+                audio_channel,
+                audio_settings.level(audio_channel)
+            );
+
+            AudioSettingsPersister::store(&audio_settings)
+        };
+
+        match store_result {
+            Ok(file_path) => info!("Audio settings written to: `{}`.", file_path.display()),
+            Err(e) => error!("Failed to write audio settings. Error: `{}`.", e),
+        }
+    }
+
+    /// Reloads `AudioSettings` from disk.
+    fn reload(world: &World) {
+        match AudioSettingsPersister::load() {
+            Ok(audio_settings) => *world.write_resource::<AudioSettings>() = audio_settings,
+            Err(e) => error!("Failed to reload audio settings. Error: `{}`.", e),
+        }
+    }
+}
+
+impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for AudioSettingsState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.register::<AudioSettingsEntity>();
+        data.world.insert(StateId::AudioSettings);
+    }
+
+    fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
+        StateEntityUtils::clear::<AudioSettingsEntity>(&mut data.world);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::AudioSettings);
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        match event {
+            AppEvent::Window(window_event) => {
+                if is_key_down(&window_event, VirtualKeyCode::Escape) {
+                    debug!("Returning from `AudioSettingsState`.");
+                    Trans::Pop
+                } else {
+                    Trans::None
+                }
+            }
+            AppEvent::AudioSettings(audio_settings_event) => match audio_settings_event {
+                AudioSettingsEvent::Return => {
+                    debug!("Returning from `AudioSettingsState`.");
+                    Trans::Pop
+                }
+                AudioSettingsEvent::ReloadRequest => {
+                    Self::reload(data.world);
+                    Trans::None
+                }
+                AudioSettingsEvent::VolumeUp { channel } => {
+                    Self::level_change(data.world, channel, AudioSettings::LEVEL_STEP);
+                    Trans::None
+                }
+                AudioSettingsEvent::VolumeDown { channel } => {
+                    Self::level_change(data.world, channel, -AudioSettings::LEVEL_STEP);
+                    Trans::None
+                }
+            },
+            _ => Trans::None,
+        }
+    }
+
+    fn update(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        data.data.update(&data.world);
+        Trans::None
+    }
+}
@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides the state for audio settings.
+
+pub use crate::{
+    audio_settings_persister::AudioSettingsPersister,
+    audio_settings_state::AudioSettingsState,
+    system::{AudioSettingsLabelUpdateSystem, AudioSettingsLabelUpdateSystemData},
+};
+
+mod audio_settings_persister;
+mod audio_settings_state;
+mod system;
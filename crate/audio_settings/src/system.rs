@@ -0,0 +1,5 @@
+pub use self::audio_settings_label_update_system::{
+    AudioSettingsLabelUpdateSystem, AudioSettingsLabelUpdateSystemData,
+};
+
+mod audio_settings_label_update_system;
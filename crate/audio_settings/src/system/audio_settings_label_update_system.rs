@@ -0,0 +1,70 @@
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::UiText,
+};
+use application_menu::MenuIndex;
+use audio_model::config::AudioSettings;
+use derivative::Derivative;
+use derive_new::new;
+use menu_model::MenuItem;
+
+/// Separates the channel name from its level in a label, e.g. "Music: 80%".
+const LEVEL_SEPARATOR: &str = ": ";
+
+/// Updates audio settings menu item labels with the level of their channel.
+///
+/// The text before the `": "` separator is kept, so the channel names are defined by the UI.
+#[derive(Debug, Default, new)]
+pub struct AudioSettingsLabelUpdateSystem;
+
+/// `AudioSettingsLabelUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AudioSettingsLabelUpdateSystemData<'s> {
+    /// `AudioSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings: Read<'s, AudioSettings>,
+    /// `MenuItem<MenuIndex>` components.
+    #[derivative(Debug = "ignore")]
+    pub menu_items: ReadStorage<'s, MenuItem<MenuIndex>>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl<'s> System<'s> for AudioSettingsLabelUpdateSystem {
+    type SystemData = AudioSettingsLabelUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AudioSettingsLabelUpdateSystemData {
+            audio_settings,
+            menu_items,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        (&menu_items, &mut ui_texts)
+            .join()
+            .filter_map(|(menu_item, ui_text)| {
+                if let MenuIndex::AudioSettings(audio_channel) = menu_item.index {
+                    Some((audio_channel, ui_text))
+                } else {
+                    None
+                }
+            })
+            .for_each(|(audio_channel, ui_text)| {
+                let percent = (audio_settings.level(audio_channel) * 100.).round();
+                let label = ui_text
+                    .text
+                    .split(LEVEL_SEPARATOR)
+                    .next()
+                    .unwrap_or_default();
+                let text = format!("{}{}{}%", label, LEVEL_SEPARATOR, percent);
+
+                if ui_text.text != text {
+                    ui_text.text = text;
+                }
+            });
+    }
+}
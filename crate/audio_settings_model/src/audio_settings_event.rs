@@ -0,0 +1,26 @@
+use audio_model::config::AudioChannel;
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+/// Event signalling a change in the `AudioSettings` state.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum AudioSettingsEvent {
+    /// Returns to the previous menu.
+    Return,
+    /// Audio settings should be reloaded.
+    ReloadRequest,
+    /// Increases the level of a channel by one step.
+    VolumeUp {
+        /// Channel to change the level of, e.g. "master", "sfx", "ui", "music".
+        #[structopt(short, long)]
+        channel: AudioChannel,
+    },
+    /// Decreases the level of a channel by one step.
+    VolumeDown {
+        /// Channel to change the level of, e.g. "master", "sfx", "ui", "music".
+        #[structopt(short, long)]
+        channel: AudioChannel,
+    },
+}
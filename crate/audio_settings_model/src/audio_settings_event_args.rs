@@ -0,0 +1,11 @@
+use crate::AudioSettingsEvent;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `audio_settings return`
+/// * `audio_settings reload_request`
+/// * `audio_settings volume_up -c music`
+/// * `audio_settings volume_down -c sfx`
+pub type AudioSettingsEventArgs = AudioSettingsEvent;
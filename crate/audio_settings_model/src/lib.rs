@@ -0,0 +1,12 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used in the audio settings state.
+
+pub use crate::{
+    audio_settings_entity::AudioSettingsEntity, audio_settings_event::AudioSettingsEvent,
+    audio_settings_event_args::AudioSettingsEventArgs,
+};
+
+mod audio_settings_entity;
+mod audio_settings_event;
+mod audio_settings_event_args;
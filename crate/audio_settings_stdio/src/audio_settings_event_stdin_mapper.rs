@@ -0,0 +1,17 @@
+use amethyst::Error;
+use audio_settings_model::{AudioSettingsEvent, AudioSettingsEventArgs};
+use stdio_spi::StdinMapper;
+
+/// Builds an `AudioSettingsEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct AudioSettingsEventStdinMapper;
+
+impl StdinMapper for AudioSettingsEventStdinMapper {
+    type SystemData = ();
+    type Event = AudioSettingsEvent;
+    type Args = AudioSettingsEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        Ok(args)
+    }
+}
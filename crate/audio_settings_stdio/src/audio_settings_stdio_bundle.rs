@@ -0,0 +1,31 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::AudioSettingsEventStdinMapper;
+
+/// Adds a `MapperSystem<AudioSettingsEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct AudioSettingsStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for AudioSettingsStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<AudioSettingsEventStdinMapper>::new(AppEventVariant::AudioSettings),
+            any::type_name::<MapperSystem<AudioSettingsEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
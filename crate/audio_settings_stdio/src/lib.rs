@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable audio settings to be controlled by stdio.
+
+pub use crate::{
+    audio_settings_event_stdin_mapper::AudioSettingsEventStdinMapper,
+    audio_settings_stdio_bundle::AudioSettingsStdioBundle,
+};
+
+mod audio_settings_event_stdin_mapper;
+mod audio_settings_stdio_bundle;
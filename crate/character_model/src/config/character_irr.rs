@@ -26,6 +26,7 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_modes,
             controller_inputs,
             mirroreds,
+            input_histories,
             charge_use_ec,
         }: &mut Self::SystemData,
         entity: Entity,
@@ -37,6 +38,7 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_mode,
             controller_input,
             mirrored,
+            input_history,
         ) = (
             health_pointses.get(entity).copied(),
             skill_pointses.get(entity).copied(),
@@ -44,6 +46,7 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_modes.get(entity).copied(),
             controller_inputs.get(entity).copied(),
             mirroreds.get(entity).copied(),
+            input_histories.get(entity),
         );
 
         let input_reaction_requirement_params = InputReactionRequirementParams {
@@ -53,6 +56,7 @@ impl<'s> InputReactionRequirement<'s> for CharacterIrr {
             charge_use_mode,
             controller_input,
             mirrored,
+            input_history,
         };
 
         let met = self.iter().all(|input_reaction_requirement| {
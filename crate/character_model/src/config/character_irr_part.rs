@@ -1,7 +1,7 @@
 use approx::{relative_eq, relative_ne};
 use charge_model::config::{ChargePoints, ChargeUseMode};
 use game_input_model::{
-    config::{InputDirection, InputDirectionZ},
+    config::{InputCommandSequence, InputDirection, InputDirectionZ},
     play::ControllerInput,
 };
 use mirrored_model::play::Mirrored;
@@ -13,7 +13,7 @@ use crate::config::InputReactionRequirementParams;
 /// `CharacterInputReactionRequirementPart`
 ///
 /// Conditions for a character input reaction to happen.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum CharacterIrrPart {
     /// `ChargePoints` the object must spend to transition.
@@ -26,12 +26,14 @@ pub enum CharacterIrrPart {
     InputDirX(InputDirection),
     /// Whether or not there is z axis input, and the direction it is in.
     InputDirZ(InputDirectionZ),
+    /// Sequence of inputs that must have been entered, such as `[defend, forward, attack]`.
+    InputSequence(InputCommandSequence),
 }
 
 impl CharacterIrrPart {
     /// Returns whether this requirement is met.
     pub fn is_met(
-        self,
+        &self,
         InputReactionRequirementParams {
             health_points,
             skill_points,
@@ -39,28 +41,32 @@ impl CharacterIrrPart {
             charge_use_mode,
             controller_input,
             mirrored,
-        }: InputReactionRequirementParams,
+            input_history,
+        }: InputReactionRequirementParams<'_>,
     ) -> bool {
         match self {
-            Self::Hp(required) => health_points.map(|points| points >= required),
-            Self::Sp(required) => skill_points.map(|points| points >= required),
+            Self::Hp(required) => health_points.map(|points| points >= *required),
+            Self::Sp(required) => skill_points.map(|points| points >= *required),
             Self::Charge(required) => charge_tracker_clock.map(|charge_tracker_clock| {
                 if let Some(ChargeUseMode::NearestPartial) = charge_use_mode {
                     (*charge_tracker_clock).value > 0
                 } else {
-                    (*charge_tracker_clock).value >= (*required) as usize
+                    (*charge_tracker_clock).value >= (**required) as usize
                 }
             }),
             Self::InputDirX(input_direction) => {
                 let requirement_met =
-                    Self::input_requirement_met_x(controller_input, mirrored, input_direction);
+                    Self::input_requirement_met_x(controller_input, mirrored, *input_direction);
                 Some(requirement_met)
             }
             Self::InputDirZ(input_direction_z) => {
                 let requirement_met =
-                    Self::input_requirement_met_z(controller_input, input_direction_z);
+                    Self::input_requirement_met_z(controller_input, *input_direction_z);
                 Some(requirement_met)
             }
+            Self::InputSequence(input_command_sequence) => {
+                input_history.map(|input_history| input_history.matches(input_command_sequence))
+            }
         }
         .unwrap_or(false)
     }
@@ -95,6 +95,16 @@ pub enum CharacterSequenceName {
     DashDescendLand,
     /// Attack while dashing.
     DashAttack,
+    /// Character is guarding.
+    ///
+    /// Hits from the direction the character is facing deal reduced damage and knockback.
+    Defend,
+    /// Character blocked a hit while guarding.
+    DefendHit,
+    /// Character is holding another character.
+    ///
+    /// This sequence is expected to repeat until the held character is thrown.
+    Grabbing,
     /// Character is hit while on ground.
     #[serde(rename = "flinch_0")]
     Flinch0,
@@ -103,6 +113,12 @@ pub enum CharacterSequenceName {
     Flinch1,
     /// Character is dazed / seeing stars.
     Dazed,
+    /// Character's guard has been broken.
+    GuardBreak,
+    /// Character is held by another character.
+    Held,
+    /// Character has been thrown, moving upwards.
+    Thrown,
     /// Knocked off balance, moving upwards.
     FallForwardAscend,
     /// Knocked off balance, moving downwards.
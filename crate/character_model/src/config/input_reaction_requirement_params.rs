@@ -1,11 +1,11 @@
 use charge_model::{config::ChargeUseMode, play::ChargeTrackerClock};
-use game_input_model::play::ControllerInput;
+use game_input_model::play::{ControllerInput, InputHistory};
 use mirrored_model::play::Mirrored;
 use object_model::play::{HealthPoints, SkillPoints};
 
 /// Parameters to check if a `InputReactionRequirement` is met.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
-pub struct InputReactionRequirementParams {
+pub struct InputReactionRequirementParams<'p> {
     /// `HealthPoints` of the entity.
     pub health_points: Option<HealthPoints>,
     /// `SkillPoints` of the entity.
@@ -18,4 +18,6 @@ pub struct InputReactionRequirementParams {
     pub controller_input: Option<ControllerInput>,
     /// `Mirrored` of the entity.
     pub mirrored: Option<Mirrored>,
+    /// `InputHistory` of the entity.
+    pub input_history: Option<&'p InputHistory>,
 }
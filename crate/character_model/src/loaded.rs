@@ -5,6 +5,7 @@
 
 pub use self::{
     asset_character_definition_handle::AssetCharacterDefinitionHandle,
+    character_grab_transitions::CharacterGrabTransitions,
     character_hit_transitions::CharacterHitTransitions,
     character_input_reaction::CharacterInputReaction,
     character_input_reactions::{CharacterInputReactions, CharacterInputReactionsHandle},
@@ -13,6 +14,7 @@ pub use self::{
 };
 
 mod asset_character_definition_handle;
+mod character_grab_transitions;
 mod character_hit_transitions;
 mod character_input_reaction;
 mod character_input_reactions;
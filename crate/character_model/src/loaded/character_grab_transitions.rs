@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when grabbing, held, or thrown.
+///
+/// This is a hack to allow the `CharacterGrabEffectSystem` to transition character sequences.
+// TODO: Commonize Transition systems <https://gitlab.com/azriel91/autexousious/issues/157>
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct CharacterGrabTransitions {
+    /// Sequence ID to transition to when grabbing another character, if the character has one.
+    pub grabbing: Option<SequenceId>,
+    /// Sequence ID to transition to when held, if the character has one.
+    pub held: Option<SequenceId>,
+    /// Sequence ID to transition to when thrown or released.
+    pub thrown: SequenceId,
+}
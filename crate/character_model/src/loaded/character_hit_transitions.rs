@@ -17,4 +17,28 @@ pub struct CharacterHitTransitions {
     pub high_stun: SequenceId,
     /// Sequence ID to transition to when falling.
     pub falling: SequenceId,
+    /// Sequence ID of the defend sequence, if the character has one.
+    pub defend: Option<SequenceId>,
+    /// Sequence ID to transition to when a hit is blocked.
+    pub defend_hit: SequenceId,
+    /// Sequence ID to transition to when guard points run out.
+    pub guard_break: SequenceId,
+}
+
+impl CharacterHitTransitions {
+    /// Returns whether the given sequence is one where the character is guarding.
+    pub fn is_defending(&self, sequence_id: SequenceId) -> bool {
+        self.defend.map_or(false, |defend| {
+            sequence_id == defend || sequence_id == self.defend_hit
+        })
+    }
+
+    /// Returns whether the given sequence is one that the character is in after being hit.
+    pub fn is_stunned(&self, sequence_id: SequenceId) -> bool {
+        sequence_id == self.low_stun
+            || sequence_id == self.mid_stun
+            || sequence_id == self.high_stun
+            || sequence_id == self.falling
+            || sequence_id == self.guard_break
+    }
 }
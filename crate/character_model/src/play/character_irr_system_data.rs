@@ -8,7 +8,7 @@ use charge_model::{
     play::{ChargeTrackerClock, ChargeUseEvent},
 };
 use derivative::Derivative;
-use game_input_model::play::ControllerInput;
+use game_input_model::play::{ControllerInput, InputHistory};
 use mirrored_model::play::Mirrored;
 use object_model::play::{HealthPoints, SkillPoints};
 
@@ -34,6 +34,9 @@ pub struct CharacterIrrSystemData<'s> {
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `InputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub input_histories: ReadStorage<'s, InputHistory>,
     /// `ChargeUseEvent` channel.
     #[derivative(Debug = "ignore")]
     pub charge_use_ec: Write<'s, EventChannel<ChargeUseEvent>>,
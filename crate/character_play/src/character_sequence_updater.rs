@@ -2,11 +2,11 @@ use character_model::config::CharacterSequenceName;
 
 use crate::{
     sequence_handler::{
-        CharacterSequenceHandler, DashAttack, DashBack, DashBackAscend, DashBackDescend,
-        DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend, Dodge,
-        FallForwardAscend, FallForwardDescend, FallForwardLand, Jump, JumpAscend, JumpAttack,
-        JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, Run, RunStop, Stand, StandAttack,
-        StandOnSequenceEnd, Walk,
+        common::status::AliveCheck, CharacterSequenceHandler, DashAttack, DashBack, DashBackAscend,
+        DashBackDescend, DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend,
+        Defend, Dodge, FallForwardAscend, FallForwardDescend, FallForwardLand, Jump, JumpAscend,
+        JumpAttack, JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, Run, RunStop, Stand,
+        StandAttack, StandOnSequenceEnd, Walk,
     },
     CharacterSequenceUpdateComponents,
 };
@@ -43,7 +43,8 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::JumpAttack => &JumpAttack::update,
             CharacterSequenceName::Flinch0
             | CharacterSequenceName::Flinch1
-            | CharacterSequenceName::Dazed => &StandOnSequenceEnd::update,
+            | CharacterSequenceName::Dazed
+            | CharacterSequenceName::GuardBreak => &StandOnSequenceEnd::update,
             CharacterSequenceName::FallForwardAscend => &FallForwardAscend::update,
             CharacterSequenceName::FallForwardDescend => &FallForwardDescend::update,
             CharacterSequenceName::FallForwardLand => &FallForwardLand::update,
@@ -56,6 +57,9 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::DashBackDescend => &DashBackDescend::update,
             CharacterSequenceName::DashDescendLand => &DashDescendLand::update,
             CharacterSequenceName::DashAttack => &DashAttack::update,
+            CharacterSequenceName::Defend | CharacterSequenceName::DefendHit => &Defend::update,
+            CharacterSequenceName::Grabbing | CharacterSequenceName::Held => &AliveCheck::update,
+            CharacterSequenceName::Thrown => &FallForwardAscend::update,
         };
 
         sequence_handler(components)
@@ -0,0 +1,46 @@
+use character_model::config::CharacterSequenceName;
+use sequence_model::play::SequenceStatus;
+
+use crate::{
+    sequence_handler::{
+        common::{grounding::AirborneCheck, status::AliveCheck},
+        CharacterSequenceHandler,
+    },
+    CharacterSequenceUpdateComponents,
+};
+
+/// `Defend` and `DefendHit` sequence update.
+///
+/// The character keeps guarding while `Defend` is held. A `DefendHit` sequence returns to guarding
+/// when it ends.
+#[derive(Debug)]
+pub struct Defend;
+
+impl CharacterSequenceHandler for Defend {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        [AliveCheck::update, AirborneCheck::update]
+            .iter()
+            .fold(None, |status_update, fn_update| {
+                status_update.or_else(|| fn_update(components))
+            })
+            .or_else(|| {
+                let defend_hit_ended = components.character_sequence_name
+                    == CharacterSequenceName::DefendHit
+                    && components.sequence_status == SequenceStatus::End;
+
+                if !components.controller_input.defend {
+                    if components.character_sequence_name == CharacterSequenceName::Defend
+                        || defend_hit_ended
+                    {
+                        Some(CharacterSequenceName::Stand)
+                    } else {
+                        None
+                    }
+                } else if defend_hit_ended {
+                    Some(CharacterSequenceName::Defend)
+                } else {
+                    None
+                }
+            })
+    }
+}
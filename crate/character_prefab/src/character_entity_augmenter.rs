@@ -3,13 +3,15 @@ use std::convert::TryInto;
 use amethyst::ecs::Entity;
 use asset_model::loaded::AssetId;
 use character_model::{
-    config::CharacterSequenceName, loaded::CharacterHitTransitions, play::RunCounter,
+    config::CharacterSequenceName,
+    loaded::{CharacterGrabTransitions, CharacterHitTransitions},
+    play::RunCounter,
 };
 use charge_model::play::{ChargeRetention, ChargeTrackerClock};
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
-use object_status_model::config::StunPoints;
+use object_status_model::config::{GuardPoints, StunPoints};
 use sequence_model::{config::SequenceNameString, loaded::SequenceId};
 
 use crate::{CharacterComponentStorages, CharacterSpawningResources};
@@ -40,6 +42,7 @@ impl CharacterEntityAugmenter {
             controller_inputs,
             health_pointses,
             stun_pointses,
+            guard_pointses,
             run_counters,
             masses,
             map_boundeds,
@@ -49,6 +52,7 @@ impl CharacterEntityAugmenter {
             charge_use_modes,
             charge_retentions,
             character_hit_transitionses,
+            character_grab_transitionses,
         }: &mut CharacterComponentStorages<'s>,
         asset_id: AssetId,
         entity: Entity,
@@ -92,11 +96,44 @@ impl CharacterEntityAugmenter {
             .copied()
             .unwrap_or(SequenceId(0));
 
+        let defend = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::Defend))
+            .copied();
+        let defend_hit = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::DefendHit))
+            .copied()
+            .or(defend)
+            .unwrap_or(low_stun);
+        let guard_break = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::GuardBreak))
+            .copied()
+            .unwrap_or(high_stun);
+
         let character_hit_transitions = CharacterHitTransitions {
             low_stun,
             mid_stun,
             high_stun,
             falling,
+            defend,
+            defend_hit,
+            guard_break,
+        };
+
+        let grabbing = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::Grabbing))
+            .copied();
+        let held = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::Held))
+            .copied();
+        let thrown = sequence_id_mappings
+            .id(&SequenceNameString::Name(CharacterSequenceName::Thrown))
+            .copied()
+            .unwrap_or(falling);
+
+        let character_grab_transitions = CharacterGrabTransitions {
+            grabbing,
+            held,
+            thrown,
         };
 
         // Controller of this entity
@@ -109,6 +146,9 @@ impl CharacterEntityAugmenter {
         stun_pointses
             .insert(entity, StunPoints::default())
             .expect("Failed to insert `StunPoints` component.");
+        guard_pointses
+            .insert(entity, GuardPoints::default())
+            .expect("Failed to insert `GuardPoints` component.");
         run_counters
             .insert(entity, RunCounter::default())
             .expect("Failed to insert `RunCounter` component.");
@@ -146,5 +186,8 @@ impl CharacterEntityAugmenter {
         character_hit_transitionses
             .insert(entity, character_hit_transitions)
             .expect("Failed to insert `CharacterHitTransitions` component.");
+        character_grab_transitionses
+            .insert(entity, character_grab_transitions)
+            .expect("Failed to insert `CharacterGrabTransitions` component.");
     }
 }
@@ -2,7 +2,10 @@ use amethyst::{
     ecs::{World, WriteStorage},
     shred::{ResourceId, SystemData},
 };
-use character_model::{loaded::CharacterHitTransitions, play::RunCounter};
+use character_model::{
+    loaded::{CharacterGrabTransitions, CharacterHitTransitions},
+    play::RunCounter,
+};
 use charge_model::{
     config::{ChargeDelay, ChargeLimit, ChargeUseMode},
     play::{ChargeRetention, ChargeTrackerClock},
@@ -11,7 +14,7 @@ use derivative::Derivative;
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
-use object_status_model::config::StunPoints;
+use object_status_model::config::{GuardPoints, StunPoints};
 
 /// Character specific `Component` storages.
 ///
@@ -29,6 +32,9 @@ pub struct CharacterComponentStorages<'s> {
     /// `StunPoints` component storage.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `GuardPoints` component storage.
+    #[derivative(Debug = "ignore")]
+    pub guard_pointses: WriteStorage<'s, GuardPoints>,
     /// `RunCounter` component storage.
     #[derivative(Debug = "ignore")]
     pub run_counters: WriteStorage<'s, RunCounter>,
@@ -56,4 +62,7 @@ pub struct CharacterComponentStorages<'s> {
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: WriteStorage<'s, CharacterHitTransitions>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: WriteStorage<'s, CharacterGrabTransitions>,
 }
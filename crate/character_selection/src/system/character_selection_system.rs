@@ -1,3 +1,4 @@
+use ai_model::config::AiDifficulty;
 use amethyst::{
     ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
@@ -8,8 +9,12 @@ use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
 use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
-use log::warn;
+use log::{debug, warn};
 use object_type::ObjectType;
+use team_model::play::TeamCounter;
+
+/// Number of numbered teams that players may choose from.
+const TEAM_COUNT: u32 = 4;
 
 /// Populates the `CharacterSelections` based on user input.
 #[derive(Debug, Default, new)]
@@ -86,6 +91,55 @@ impl<'s> System<'s> for CharacterSelectionSystem {
                 AssetSelectionEvent::Deselect { controller_id, .. } => {
                     character_selections.selections.remove(&controller_id);
                 }
+                AssetSelectionEvent::Leave { controller_id, .. } => {
+                    character_selections.cpu_difficulties.remove(&controller_id);
+                    character_selections.teams.remove(&controller_id);
+                }
+                AssetSelectionEvent::Team { controller_id, .. } => {
+                    // Cycle from an independent team through each numbered team, then back to an
+                    // independent team.
+                    let team_counter_next = match character_selections.teams.get(&controller_id) {
+                        Some(team_counter) if team_counter.0 < TEAM_COUNT => {
+                            Some(TeamCounter(team_counter.0 + 1))
+                        }
+                        Some(_) => None,
+                        None => Some(TeamCounter(1)),
+                    };
+
+                    match team_counter_next {
+                        Some(team_counter) => {
+                            debug!("Controller `{}` is on team `{}`.", controller_id, team_counter);
+                            character_selections
+                                .teams
+                                .insert(controller_id, team_counter);
+                        }
+                        None => {
+                            debug!("Controller `{}` is on an independent team.", controller_id);
+                            character_selections.teams.remove(&controller_id);
+                        }
+                    }
+                }
+                AssetSelectionEvent::Cpu { controller_id, .. } => {
+                    // Cycle from human control through each difficulty, then back to human control.
+                    let ai_difficulty_next =
+                        match character_selections.cpu_difficulties.get(&controller_id) {
+                            Some(ai_difficulty) => ai_difficulty.next(),
+                            None => Some(AiDifficulty::Easy),
+                        };
+
+                    match ai_difficulty_next {
+                        Some(ai_difficulty) => {
+                            debug!("Controller `{}` is CPU `{}`.", controller_id, ai_difficulty);
+                            character_selections
+                                .cpu_difficulties
+                                .insert(controller_id, ai_difficulty);
+                        }
+                        None => {
+                            debug!("Controller `{}` is human controlled.", controller_id);
+                            character_selections.cpu_difficulties.remove(&controller_id);
+                        }
+                    }
+                }
                 _ => {}
             });
     }
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
+use ai_model::config::AiDifficulty;
 use asset_model::loaded::AssetId;
 use derive_new::new;
 use game_input_model::config::ControllerId;
+use team_model::play::TeamCounter;
 
 /// Stores the selected characters for each controller.
 ///
@@ -11,4 +13,12 @@ use game_input_model::config::ControllerId;
 pub struct CharacterSelections {
     /// Map of controller ID to character asset ID.
     pub selections: HashMap<ControllerId, AssetId>,
+    /// Map of controller ID to difficulty, for slots that are computer controlled.
+    #[new(default)]
+    pub cpu_difficulties: HashMap<ControllerId, AiDifficulty>,
+    /// Map of controller ID to team, for players that have chosen a numbered team.
+    ///
+    /// Players without an entry are on their own independent team.
+    #[new(default)]
+    pub teams: HashMap<ControllerId, TeamCounter>,
 }
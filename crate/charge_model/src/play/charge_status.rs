@@ -1,7 +1,7 @@
 use amethyst::ecs::{storage::VecStorage, Component};
 
 /// Whether or not an object is charging.
-#[derive(Clone, Component, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Component, Copy, Debug, Hash, PartialEq, Eq)]
 #[storage(VecStorage)]
 pub enum ChargeStatus {
     /// Object is not charging.
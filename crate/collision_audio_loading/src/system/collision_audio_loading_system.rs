@@ -153,7 +153,7 @@ impl<'s> System<'s> for CollisionAudioLoadingSystem {
                                     load!(WavFormat)
                                 }
                             };
-                            collision_sfx_map.insert(*collision_sfx_id, source_handle);
+                            collision_sfx_map.insert(collision_sfx_id.clone(), source_handle);
                         });
 
                     let all_loaded = collision_sfx_map
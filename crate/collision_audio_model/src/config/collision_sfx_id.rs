@@ -1,26 +1,53 @@
-use std::str::FromStr;
-
-use serde::{de, Deserialize, Deserializer, Serialize};
-use strum_macros::{Display, EnumIter, EnumString};
-
-/// Logical IDs to reference audio used for collision.
-#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize)]
-#[serde(deny_unknown_fields, rename_all = "snake_case")]
-#[strum(serialize_all = "snake_case")]
-pub enum CollisionSfxId {
-    /// `Interaction` hit a normal `Body`.
-    HitNormal,
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Logical ID to reference audio used for collision (`String` newtype).
+///
+/// Any ID may be used, but the following sound categories are conventionally provided:
+///
+/// * `hit_normal`: Played when a `Hit` does not name a sound.
+/// * `hit_blunt`: Blunt hits, such as punches and kicks.
+/// * `hit_slash`: Slashing hits, such as blades.
+/// * `hit_fire`: Fire hits.
+/// * `hit_guard`: Hits against a guard, such as shields.
+#[derive(
+    Clone, Debug, Default, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, new,
+)]
+#[serde(transparent)]
+pub struct CollisionSfxId(pub String);
+
+impl CollisionSfxId {
+    /// ID of the sound played when a `Hit` does not name a sound.
+    pub const HIT_NORMAL: &'static str = "hit_normal";
+
+    /// Returns the `CollisionSfxId` of the sound played when a `Hit` does not name a sound.
+    pub fn hit_normal() -> Self {
+        CollisionSfxId::new(String::from(Self::HIT_NORMAL))
+    }
+}
+
+impl Display for CollisionSfxId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl From<String> for CollisionSfxId {
+    fn from(s: String) -> CollisionSfxId {
+        CollisionSfxId(s)
+    }
 }
 
-// Necessary to allow enums to be in key position in YAML.
-//
-// See <https://github.com/serde-rs/serde/issues/908>.
-impl<'de> Deserialize<'de> for CollisionSfxId {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        FromStr::from_str(&s).map_err(de::Error::custom)
+impl FromStr for CollisionSfxId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(CollisionSfxId::new(String::from(s)))
     }
 }
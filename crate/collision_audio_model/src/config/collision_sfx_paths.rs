@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use crate::config::CollisionSfxId;
 
 /// Map of `CollisionSfxId` to the path of the SFX file.
-#[derive(Asset, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derive(
+    Asset, Clone, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Eq, Serialize, new,
+)]
 #[serde(deny_unknown_fields, transparent)]
 pub struct CollisionSfxPaths(HashMap<CollisionSfxId, PathBuf>);
@@ -1,5 +1,6 @@
 //! Types representing collision audio.
 
-pub use self::collision_sfx_map::CollisionSfxMap;
+pub use self::{asset_collision_sfx_map::AssetCollisionSfxMap, collision_sfx_map::CollisionSfxMap};
 
+mod asset_collision_sfx_map;
 mod collision_sfx_map;
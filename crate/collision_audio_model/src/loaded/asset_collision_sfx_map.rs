@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SparseSecondaryMap;
+
+use crate::loaded::CollisionSfxMap;
+
+/// `CollisionSfxMap` overrides of an asset.
+pub type AssetCollisionSfxMap = SparseSecondaryMap<AssetId, CollisionSfxMap>;
@@ -7,5 +7,5 @@ use derive_new::new;
 use crate::config::CollisionSfxId;
 
 /// Map of `CollisionSfxId` to the loaded SFX data.
-#[derive(Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
 pub struct CollisionSfxMap(HashMap<CollisionSfxId, SourceHandle>);
@@ -1,38 +1,124 @@
 use amethyst::{
     assets::AssetStorage,
-    audio::{output::Output, Source},
-    ecs::{Read, System, SystemData, World},
+    audio::{output::Output, Source, SourceHandle},
+    core::Transform,
+    ecs::{Join, Read, ReadStorage, System, World},
+    shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use collision_audio_model::{config::CollisionSfxId, loaded::CollisionSfxMap};
+use asset_model::loaded::AssetId;
+use audio_model::config::{AudioChannel, AudioSettings};
+use audio_play::{ChannelVolumes, SfxPlayer};
+use camera_model::play::{CameraTargetCoordinates, CameraZoomDimensions};
+use collision_audio_model::{
+    config::CollisionSfxId,
+    loaded::{AssetCollisionSfxMap, CollisionSfxMap},
+};
 use collision_model::{
-    config::{Hit, Interaction, InteractionKind},
+    config::{Hit, InteractionKind},
     play::HitEvent,
 };
+use derivative::Derivative;
 use derive_new::new;
 
-/// Default volume to play sounds at.
-const VOLUME: f32 = 1.0;
-
 /// Plays a sound for `Hit` collisions.
+///
+/// The sound named by the `Hit` is played, falling back to `hit_normal` when it is not named or
+/// does not exist. Sounds overridden by the hitting object are played in place of the default
+/// sounds.
+///
+/// Sounds are played at the `AudioChannel::Sfx` volume, panned and attenuated by the hit entity's
+/// `Transform` relative to the camera.
 #[derive(Debug, Default, new)]
 pub struct HitSfxSystem {
     /// Reader ID for the `HitEvent` event channel.
     #[new(default)]
     hit_event_rid: Option<ReaderId<HitEvent>>,
+    /// Plays sounds with separate left and right volumes.
+    #[new(default)]
+    sfx_player: SfxPlayer,
+}
+
+/// `HitSfxSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct HitSfxSystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `CollisionSfxMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub collision_sfx_map: Read<'s, CollisionSfxMap>,
+    /// `AssetCollisionSfxMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_collision_sfx_map: Read<'s, AssetCollisionSfxMap>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `Source` assets.
+    #[derivative(Debug = "ignore")]
+    pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: ReadStorage<'s, Transform>,
+    /// `CameraTargetCoordinates` components.
+    #[derivative(Debug = "ignore")]
+    pub camera_target_coordinateses: ReadStorage<'s, CameraTargetCoordinates>,
+    /// `CameraZoomDimensions` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_zoom_dimensions: Read<'s, CameraZoomDimensions>,
+    /// `AudioSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings: Read<'s, AudioSettings>,
+    /// `Output` resource.
+    #[derivative(Debug = "ignore")]
+    pub output: Option<Read<'s, Output>>,
 }
 
-type HitSfxSystemData<'s> = (
-    Read<'s, EventChannel<HitEvent>>,
-    Read<'s, CollisionSfxMap>,
-    Read<'s, AssetStorage<Source>>,
-    Option<Read<'s, Output>>,
-);
+impl HitSfxSystem {
+    /// Returns the handle to the sound to play for a `Hit`.
+    ///
+    /// # Parameters
+    ///
+    /// * `collision_sfx_map`: Default collision sounds.
+    /// * `collision_sfx_map_override`: Collision sounds of the hitting object.
+    /// * `sfx`: Sound named by the `Hit`.
+    fn hit_sfx_handle<'f>(
+        collision_sfx_map: &'f CollisionSfxMap,
+        collision_sfx_map_override: Option<&'f CollisionSfxMap>,
+        sfx: Option<&CollisionSfxId>,
+    ) -> Option<&'f SourceHandle> {
+        let hit_normal = CollisionSfxId::hit_normal();
+        let sfx_lookup = |collision_sfx_id: &CollisionSfxId| {
+            collision_sfx_map_override
+                .and_then(|collision_sfx_map_override| {
+                    collision_sfx_map_override.get(collision_sfx_id)
+                })
+                .or_else(|| collision_sfx_map.get(collision_sfx_id))
+        };
+
+        sfx.and_then(sfx_lookup).or_else(|| sfx_lookup(&hit_normal))
+    }
+}
 
 impl<'s> System<'s> for HitSfxSystem {
     type SystemData = HitSfxSystemData<'s>;
 
-    fn run(&mut self, (hit_ec, collision_sfx_map, source_assets, output): Self::SystemData) {
+    fn run(
+        &mut self,
+        HitSfxSystemData {
+            hit_ec,
+            collision_sfx_map,
+            asset_collision_sfx_map,
+            asset_ids,
+            source_assets,
+            transforms,
+            camera_target_coordinateses,
+            camera_zoom_dimensions,
+            audio_settings,
+            output,
+        }: Self::SystemData,
+    ) {
         // Make sure we empty the event channel, even if we don't have an output device.
         let events_iterator = hit_ec.read(
             self.hit_event_rid
@@ -41,19 +127,33 @@ impl<'s> System<'s> for HitSfxSystem {
         );
 
         if let Some(output) = output {
+            let volume = audio_settings.volume(AudioChannel::Sfx);
+            let camera_translation = camera_target_coordinateses
+                .join()
+                .next()
+                .map(|camera_target_coordinates| **camera_target_coordinates);
+            let sfx_player = &mut self.sfx_player;
             events_iterator.for_each(|ev| {
                 // Play sound for `Hit` interactions.
-                let Interaction {
-                    kind: InteractionKind::Hit(Hit { .. }),
-                    ..
-                } = ev.interaction;
-
-                let hit_sfx = collision_sfx_map
-                    .get(&CollisionSfxId::HitNormal)
+                if let InteractionKind::Hit(Hit { sfx, .. }) = &ev.interaction.kind {
+                    let collision_sfx_map_override = asset_ids
+                        .get(ev.from)
+                        .and_then(|asset_id| asset_collision_sfx_map.get(*asset_id));
+                    let hit_sfx = Self::hit_sfx_handle(
+                        &collision_sfx_map,
+                        collision_sfx_map_override,
+                        sfx.as_ref(),
+                    )
                     .and_then(|hit_sfx_handle| source_assets.get(hit_sfx_handle));
 
-                if let Some(hit_sfx) = hit_sfx {
-                    output.play_once(hit_sfx, VOLUME);
+                    if let Some(hit_sfx) = hit_sfx {
+                        let channel_volumes = ChannelVolumes::emitter(
+                            camera_translation,
+                            *camera_zoom_dimensions,
+                            transforms.get(ev.to),
+                        );
+                        sfx_player.play_once(&output, hit_sfx, volume, channel_volumes);
+                    }
                 }
             });
         }
@@ -0,0 +1,11 @@
+use derive_new::new;
+use kinematic_model::config::Position;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a grab interaction.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Grab {
+    /// Position of the held object relative to the grabbing object, when facing right.
+    pub offset: Position<i32>,
+}
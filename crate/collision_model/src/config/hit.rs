@@ -1,3 +1,4 @@
+use collision_audio_model::config::CollisionSfxId;
 use derive_new::new;
 use kinematic_model::config::Acceleration;
 use object_status_model::config::StunPoints;
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::config::{HitLimit, HitRepeatDelay};
 
 /// Configuration of a hit interaction.
-#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct Hit {
     /// Number of ticks to wait before another hit may occur.
@@ -21,4 +22,8 @@ pub struct Hit {
     pub stun: StunPoints,
     /// Acceleration to inflict on collision.
     pub acceleration: Acceleration<i32>,
+    /// Sound, or sound category, to play on collision.
+    ///
+    /// When not specified, the `hit_normal` sound is played.
+    pub sfx: Option<CollisionSfxId>,
 }
@@ -1,10 +1,10 @@
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Hit;
+use crate::config::{Grab, Hit, Throw};
 
 /// Type of collision -- hit, picking weapon, grabbing, and so on.
-#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
 #[derivative(Default)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub enum InteractionKind {
@@ -12,4 +12,8 @@ pub enum InteractionKind {
     /// other entities.
     #[derivative(Default)]
     Hit(Hit),
+    /// Collision holds the other object, attaching it to the from entity.
+    Grab(Grab),
+    /// Collision releases the held object with a velocity.
+    Throw(Throw),
 }
@@ -0,0 +1,15 @@
+use derive_new::new;
+use kinematic_model::config::Acceleration;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a throw interaction.
+///
+/// A throw only affects the object held by the throwing object.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Throw {
+    /// Amount of health points (HP) to subtract on release.
+    pub hp_damage: u32,
+    /// Acceleration to inflict on release.
+    pub acceleration: Acceleration<i32>,
+}
@@ -1,15 +1,17 @@
 //! Types used during game play.
 
 pub use self::{
-    collision_event::CollisionEvent, contact_event::ContactEvent, hit_event::HitEvent,
-    hit_object_count::HitObjectCount, hit_repeat_clock::HitRepeatClock,
-    hit_repeat_tracker::HitRepeatTracker, hit_repeat_trackers::HitRepeatTrackers,
+    collision_event::CollisionEvent, contact_event::ContactEvent, held_by::HeldBy,
+    hit_event::HitEvent, hit_object_count::HitObjectCount, hit_repeat_clock::HitRepeatClock,
+    hit_repeat_tracker::HitRepeatTracker, hit_repeat_trackers::HitRepeatTrackers, holding::Holding,
 };
 
 mod collision_event;
 mod contact_event;
+mod held_by;
 mod hit_event;
 mod hit_object_count;
 mod hit_repeat_clock;
 mod hit_repeat_tracker;
 mod hit_repeat_trackers;
+mod holding;
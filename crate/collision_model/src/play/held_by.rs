@@ -0,0 +1,11 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a held entity to the entity that is grabbing it.
+///
+/// This component should be attached to the held entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HeldBy {
+    /// The grabbing entity.
+    pub entity: Entity,
+}
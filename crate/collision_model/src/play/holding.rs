@@ -0,0 +1,11 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a grabbing entity to the entity it is holding.
+///
+/// This component should be attached to the grabbing entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct Holding {
+    /// The held entity.
+    pub entity: Entity,
+}
@@ -0,0 +1,79 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use collision_play::{SweepAndPrune, SweepBounds};
+
+/// Width of the area that objects are spread across.
+const MAP_WIDTH: usize = 1600;
+/// Width of each object's bounds.
+const OBJECT_WIDTH: f32 = 40.;
+
+/// Returns bounds for `n` objects spread across the map, each with interactions and a body.
+fn object_bounds(n: usize) -> Vec<SweepBounds<usize>> {
+    (0..n)
+        .map(|id| {
+            // Deterministic spread, so that runs are comparable.
+            let x_min = ((id * 7919) % MAP_WIDTH) as f32;
+            SweepBounds::new(id, x_min, x_min + OBJECT_WIDTH)
+        })
+        .collect::<Vec<SweepBounds<usize>>>()
+}
+
+/// Pairs every interaction with every body, as the collision detection did before sweep and prune.
+fn naive(object_bounds: &[SweepBounds<usize>], candidate_pairs: &mut Vec<(usize, usize)>) {
+    object_bounds.iter().for_each(|interaction_bounds| {
+        object_bounds.iter().for_each(|body_bounds| {
+            if interaction_bounds.id != body_bounds.id
+                && interaction_bounds.x_min <= body_bounds.x_max
+                && body_bounds.x_min <= interaction_bounds.x_max
+            {
+                candidate_pairs.push((interaction_bounds.id, body_bounds.id));
+            }
+        })
+    });
+}
+
+fn sweep_and_prune(
+    sweep_and_prune: &mut SweepAndPrune<usize>,
+    object_bounds: &[SweepBounds<usize>],
+    candidate_pairs: &mut Vec<(usize, usize)>,
+) {
+    sweep_and_prune.clear();
+    object_bounds.iter().copied().for_each(|bounds| {
+        sweep_and_prune.interaction_bounds_insert(bounds);
+        sweep_and_prune.body_bounds_insert(bounds);
+    });
+    sweep_and_prune.candidate_pairs(candidate_pairs);
+}
+
+fn candidate_pairs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("candidate_pairs");
+
+    [16, 64, 256, 1024].iter().copied().for_each(|n| {
+        let object_bounds = object_bounds(n);
+        let mut candidate_pairs = Vec::new();
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &object_bounds, |b, bounds| {
+            b.iter(|| {
+                candidate_pairs.clear();
+                naive(black_box(bounds), &mut candidate_pairs);
+            })
+        });
+
+        let mut sap = SweepAndPrune::new();
+        group.bench_with_input(
+            BenchmarkId::new("sweep_and_prune", n),
+            &object_bounds,
+            |b, bounds| {
+                b.iter(|| {
+                    candidate_pairs.clear();
+                    sweep_and_prune(&mut sap, black_box(bounds), &mut candidate_pairs);
+                })
+            },
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, candidate_pairs);
+criterion_main!(benches);
@@ -2,9 +2,15 @@
 
 //! Provides logic used during game play.
 
-pub use crate::system::{
-    CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
-    HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+pub use crate::{
+    sweep_and_prune::{SweepAndPrune, SweepBounds},
+    system::{
+        CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
+        HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+    },
+    volume_intersector::VolumeIntersector,
 };
 
+mod sweep_and_prune;
 mod system;
+mod volume_intersector;
@@ -0,0 +1,110 @@
+use derivative::Derivative;
+use derive_new::new;
+
+/// Range along the X axis that an object's volumes occupy, in world coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct SweepBounds<T> {
+    /// Identifier of the object, usually its `Entity`.
+    pub id: T,
+    /// Minimum X coordinate.
+    pub x_min: f32,
+    /// Maximum X coordinate.
+    pub x_max: f32,
+}
+
+/// Whether a `SweepBounds` is for an interaction or a body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SweepKind {
+    Interaction,
+    Body,
+}
+
+/// Broad phase collision detection by sweeping interaction and body bounds along the X axis.
+///
+/// Only pairs whose X ranges overlap are returned as candidates, so the narrow phase does not
+/// have to test every interaction against every body.
+#[derive(Clone, Debug, Derivative, new)]
+#[derivative(Default(bound = ""))]
+pub struct SweepAndPrune<T> {
+    /// Bounds of objects with interactions, and objects with bodies.
+    #[new(default)]
+    bounds: Vec<(SweepKind, SweepBounds<T>)>,
+    /// Interaction bounds that the sweep is currently within.
+    #[new(default)]
+    interactions_active: Vec<SweepBounds<T>>,
+    /// Body bounds that the sweep is currently within.
+    #[new(default)]
+    bodies_active: Vec<SweepBounds<T>>,
+}
+
+impl<T> SweepAndPrune<T>
+where
+    T: Copy + PartialEq,
+{
+    /// Removes all bounds, retaining allocated memory.
+    pub fn clear(&mut self) {
+        self.bounds.clear();
+        self.interactions_active.clear();
+        self.bodies_active.clear();
+    }
+
+    /// Adds the bounds of an object with interactions.
+    pub fn interaction_bounds_insert(&mut self, sweep_bounds: SweepBounds<T>) {
+        self.bounds.push((SweepKind::Interaction, sweep_bounds));
+    }
+
+    /// Adds the bounds of an object with a body.
+    pub fn body_bounds_insert(&mut self, sweep_bounds: SweepBounds<T>) {
+        self.bounds.push((SweepKind::Body, sweep_bounds));
+    }
+
+    /// Appends `(interaction_id, body_id)` pairs whose bounds overlap to `candidate_pairs`.
+    ///
+    /// Objects are never paired with themselves. Pairs are appended in order of the sweep, which
+    /// is deterministic for the same bounds inserted in the same order.
+    pub fn candidate_pairs(&mut self, candidate_pairs: &mut Vec<(T, T)>) {
+        // Stable sort, so objects with the same `x_min` keep their insertion order.
+        self.bounds.sort_by(|(_, bounds_a), (_, bounds_b)| {
+            bounds_a
+                .x_min
+                .partial_cmp(&bounds_b.x_min)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let SweepAndPrune {
+            bounds,
+            interactions_active,
+            bodies_active,
+        } = self;
+
+        interactions_active.clear();
+        bodies_active.clear();
+
+        bounds.iter().for_each(|(sweep_kind, sweep_bounds)| {
+            let x_min = sweep_bounds.x_min;
+            interactions_active.retain(|bounds_active| bounds_active.x_max >= x_min);
+            bodies_active.retain(|bounds_active| bounds_active.x_max >= x_min);
+
+            match sweep_kind {
+                SweepKind::Interaction => {
+                    candidate_pairs.extend(
+                        bodies_active
+                            .iter()
+                            .filter(|body_bounds| body_bounds.id != sweep_bounds.id)
+                            .map(|body_bounds| (sweep_bounds.id, body_bounds.id)),
+                    );
+                    interactions_active.push(*sweep_bounds);
+                }
+                SweepKind::Body => {
+                    candidate_pairs.extend(
+                        interactions_active
+                            .iter()
+                            .filter(|interaction_bounds| interaction_bounds.id != sweep_bounds.id)
+                            .map(|interaction_bounds| (interaction_bounds.id, sweep_bounds.id)),
+                    );
+                    bodies_active.push(*sweep_bounds);
+                }
+            }
+        });
+    }
+}
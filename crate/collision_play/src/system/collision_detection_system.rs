@@ -1,14 +1,14 @@
 use amethyst::{
     assets::{AssetStorage, Handle},
     core::{math::Vector3, transform::Transform},
-    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write},
     renderer::{SpriteRender, SpriteSheet},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
 };
 use collision_model::{
     config::{Body, Interaction, Interactions},
-    play::CollisionEvent,
+    play::{CollisionEvent, HeldBy},
 };
 use derivative::Derivative;
 use derive_new::new;
@@ -16,9 +16,24 @@ use log::debug;
 use mirrored_model::play::Mirrored;
 use shape_model::Volume;
 
+use crate::{SweepAndPrune, SweepBounds, VolumeIntersector};
+
 /// Detects collisions for all objects.
+///
+/// Pairs of objects whose bounds overlap along the X axis are found using sweep and prune, and
+/// only those pairs have their volumes tested for intersection.
+///
+/// Interactions of held objects are not tested, as held objects only interact through the object
+/// holding them.
 #[derive(Debug, Default, new)]
-pub struct CollisionDetectionSystem;
+pub struct CollisionDetectionSystem {
+    /// Broad phase of collision detection.
+    #[new(default)]
+    sweep_and_prune: SweepAndPrune<Entity>,
+    /// Pairs of `(interaction_entity, body_entity)` that may be colliding.
+    #[new(default)]
+    candidate_pairs: Vec<(Entity, Entity)>,
+}
 
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
@@ -50,6 +65,9 @@ pub struct CollisionDetectionSystemData<'s> {
     /// `SpriteSheet` assets.
     #[derivative(Debug = "ignore")]
     pub sprite_sheet_assets: Read<'s, AssetStorage<SpriteSheet>>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: ReadStorage<'s, HeldBy>,
     /// `CollisionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub collision_ec: Write<'s, EventChannel<CollisionEvent>>,
@@ -61,96 +79,58 @@ impl CollisionDetectionSystem {
         (interaction, interaction_offsets, interaction_mirrored): (&Interaction, [f32; 2], bool),
         (body, body_offsets, body_mirrored): (&Volume, [f32; 2], bool),
     ) -> bool {
-        // TODO: Use a collision library.
         let Interaction { bounds, .. } = interaction;
 
-        bounds.iter().any(|bound| match (*bound, *body) {
-            (
-                Volume::Box {
-                    x: i_x,
-                    y: i_y,
-                    z: i_z,
-                    w: i_w,
-                    h: i_h,
-                    d: i_d,
-                },
-                Volume::Box {
-                    x: b_x,
-                    y: b_y,
-                    z: b_z,
-                    w: b_w,
-                    h: b_h,
-                    d: b_d,
-                },
-            ) => {
-                let (interaction_x, interaction_x_w) = Self::bound_coordinates(
-                    i_x,
-                    i_w,
-                    interaction_offsets[0],
-                    interaction_mirrored,
-                    None,
-                );
-                let (body_x, body_x_w) = Self::bound_coordinates(
-                    b_x,
-                    b_w,
-                    body_offsets[0],
-                    body_mirrored,
-                    Some(relative_pos[0]),
-                );
-                let x_intersects = (interaction_x >= body_x && interaction_x < body_x_w)
-                    || (interaction_x_w >= body_x && interaction_x_w < body_x_w);
-
-                let (interaction_y, interaction_y_h) =
-                    Self::bound_coordinates(i_y, i_h, interaction_offsets[1], false, None);
-                let (body_y, body_y_h) = Self::bound_coordinates(
-                    b_y,
-                    b_h,
-                    body_offsets[1],
-                    false,
-                    Some(relative_pos[1]),
-                );
-                let y_intersects = (interaction_y >= body_y && interaction_y < body_y_h)
-                    || (interaction_y_h >= body_y && interaction_y_h < body_y_h);
-
-                let (interaction_z, interaction_z_d) =
-                    Self::bound_coordinates(i_z, i_d, 0., false, None);
-                let (body_z, body_z_d) =
-                    Self::bound_coordinates(b_z, b_d, 0., false, Some(relative_pos[2]));
-                let z_intersects = (interaction_z >= body_z && interaction_z < body_z_d)
-                    || (interaction_z_d >= body_z && interaction_z_d < body_z_d);
+        bounds.iter().any(|bound| {
+            VolumeIntersector::intersects(
+                relative_pos,
+                (bound, interaction_offsets, interaction_mirrored),
+                (body, body_offsets, body_mirrored),
+            )
+        })
+    }
 
-                x_intersects && y_intersects && z_intersects
-            }
+    /// Returns the offsets of the sprite, accounting for the half width and height shift from
+    /// Amethyst.
+    fn sprite_offsets(
+        sprite_sheet_assets: &AssetStorage<SpriteSheet>,
+        sprite_render: &SpriteRender,
+    ) -> [f32; 2] {
+        let sprite_sheet = sprite_sheet_assets
+            .get(&sprite_render.sprite_sheet)
+            .expect("Expected sprite sheet for sprite_render to exist.");
+        let sprite = &sprite_sheet.sprites[sprite_render.sprite_number];
 
-            // Explicitly fail because we haven't finished this.
-            _ => unimplemented!(),
-        })
+        [
+            sprite.offsets[0] + sprite.width / 2.,
+            sprite.offsets[1] + sprite.height / 2.,
+        ]
     }
 
-    fn bound_coordinates(
-        i_x: i32,
-        i_w: u32,
+    /// Returns the X range that the volumes occupy in world coordinates.
+    ///
+    /// The range is widened by one unit on each side, as the narrow phase truncates coordinates
+    /// to integers.
+    fn sweep_bounds<'v>(
+        entity: Entity,
+        volumes: impl Iterator<Item = &'v Volume>,
         offset: f32,
         mirrored: bool,
-        relative_pos: Option<f32>,
-    ) -> (i32, i32) {
-        let (mut coord, mut coord_w) = {
-            let mut x1 = i_x as f32 - offset;
-            if mirrored {
-                let x2 = x1 + i_w as f32;
-                x1 = -x1;
-                (-x2, x1)
-            } else {
-                let x2 = x1 + i_w as f32;
-                (x1, x2)
-            }
-        };
-        if let Some(relative_pos) = relative_pos {
-            coord += relative_pos;
-            coord_w += relative_pos;
-        }
+        translation_x: f32,
+    ) -> SweepBounds<Entity> {
+        let (x_min, x_max) =
+            volumes.fold((std::f32::MAX, std::f32::MIN), |(x_min, x_max), volume| {
+                let (volume_x_min, volume_x_max) =
+                    VolumeIntersector::x_range(volume, offset, mirrored);
 
-        (coord as i32, coord_w as i32)
+                (x_min.min(volume_x_min), x_max.max(volume_x_max))
+            });
+
+        SweepBounds::new(
+            entity,
+            x_min + translation_x - 1.,
+            x_max + translation_x + 1.,
+        )
     }
 }
 
@@ -169,103 +149,134 @@ impl<'s> System<'s> for CollisionDetectionSystem {
             sprite_renders,
             mirroreds,
             sprite_sheet_assets,
+            held_bys,
             mut collision_ec,
         }: Self::SystemData,
     ) {
-        // Naive collision detection.
-        // TODO: Use broad sweep + narrow sweep for optimization.
-        for (from, from_transform, interactions_handle, from_sprite_render, from_mirrored) in (
+        self.sweep_and_prune.clear();
+        self.candidate_pairs.clear();
+
+        // Broad phase.
+        for (from, from_transform, interactions_handle, from_sprite_render, from_mirrored, _) in (
             &entities,
             &transforms,
             &interactions_handles,
             &sprite_renders,
             &mirroreds,
+            !&held_bys,
         )
             .join()
         {
-            for (to, to_transform, body_handle, to_sprite_render, to_mirrored) in (
-                &entities,
+            let interactions = interactions_assets
+                .get(interactions_handle)
+                .expect("Expected `Interactions` from handle to exist.");
+            let interaction_offsets =
+                Self::sprite_offsets(&sprite_sheet_assets, from_sprite_render);
+
+            let sweep_bounds = Self::sweep_bounds(
+                from,
+                interactions
+                    .iter()
+                    .flat_map(|interaction| interaction.bounds.iter()),
+                interaction_offsets[0],
+                from_mirrored.0,
+                from_transform.translation()[0],
+            );
+            self.sweep_and_prune.interaction_bounds_insert(sweep_bounds);
+        }
+
+        for (to, to_transform, body_handle, to_sprite_render, to_mirrored) in (
+            &entities,
+            &transforms,
+            &body_handles,
+            &sprite_renders,
+            &mirroreds,
+        )
+            .join()
+        {
+            let body = body_assets
+                .get(body_handle)
+                .expect("Expected `Body` from handle to exist.");
+            let body_offsets = Self::sprite_offsets(&sprite_sheet_assets, to_sprite_render);
+
+            let sweep_bounds = Self::sweep_bounds(
+                to,
+                body.iter(),
+                body_offsets[0],
+                to_mirrored.0,
+                to_transform.translation()[0],
+            );
+            self.sweep_and_prune.body_bounds_insert(sweep_bounds);
+        }
+
+        self.sweep_and_prune
+            .candidate_pairs(&mut self.candidate_pairs);
+
+        // Narrow phase.
+        for (from, to) in self.candidate_pairs.iter().copied() {
+            let from_components = (
                 &transforms,
-                &body_handles,
+                &interactions_handles,
                 &sprite_renders,
                 &mirroreds,
             )
                 .join()
+                .get(from, &entities);
+            let to_components = (&transforms, &body_handles, &sprite_renders, &mirroreds)
+                .join()
+                .get(to, &entities);
+            let (
+                (from_transform, interactions_handle, from_sprite_render, from_mirrored),
+                (to_transform, body_handle, to_sprite_render, to_mirrored),
+            ) = if let (Some(from_components), Some(to_components)) =
+                (from_components, to_components)
             {
-                if from == to {
-                    // Skip self
-                    continue;
-                }
-
-                let interaction_offsets = {
-                    let sprite_sheet = sprite_sheet_assets
-                        .get(&from_sprite_render.sprite_sheet)
-                        .expect("Expected sprite sheet for from_sprite_render to exist.");
-                    let sprite = &sprite_sheet.sprites[from_sprite_render.sprite_number];
-
-                    // Account for half width and height shift from Amethyst
-                    [
-                        sprite.offsets[0] + sprite.width / 2.,
-                        sprite.offsets[1] + sprite.height / 2.,
-                    ]
-                };
-
-                let body_offsets = {
-                    let sprite_sheet = sprite_sheet_assets
-                        .get(&to_sprite_render.sprite_sheet)
-                        .expect("Expected sprite sheet for to_sprite_render to exist.");
-                    let sprite = &sprite_sheet.sprites[to_sprite_render.sprite_number];
+                (from_components, to_components)
+            } else {
+                continue;
+            };
 
-                    // Account for half width and height shift from Amethyst
-                    [
-                        sprite.offsets[0] + sprite.width / 2.,
-                        sprite.offsets[1] + sprite.height / 2.,
-                    ]
-                };
+            let interaction_offsets =
+                Self::sprite_offsets(&sprite_sheet_assets, from_sprite_render);
+            let body_offsets = Self::sprite_offsets(&sprite_sheet_assets, to_sprite_render);
 
-                let mut relative_pos = to_transform.translation() - from_transform.translation();
-                // Undo the Z shift from both entities, see `ObjectTransformUpdateSystem`
-                relative_pos[1] += to_transform.translation()[2] - from_transform.translation()[2];
+            let mut relative_pos = to_transform.translation() - from_transform.translation();
+            // Undo the Z shift from both entities, see `ObjectTransformUpdateSystem`
+            relative_pos[1] += to_transform.translation()[2] - from_transform.translation()[2];
 
-                let interactions = interactions_assets
-                    .get(interactions_handle)
-                    .expect("Expected `Interactions` from handle to exist.");
-                let body = body_assets
-                    .get(body_handle)
-                    .expect("Expected `Body` from handle to exist.");
+            let interactions = interactions_assets
+                .get(interactions_handle)
+                .expect("Expected `Interactions` from handle to exist.");
+            let body = body_assets
+                .get(body_handle)
+                .expect("Expected `Body` from handle to exist.");
 
-                let mut collision_events = {
-                    interactions
-                        .iter()
-                        .flat_map(|interaction| {
-                            // loop through each body, if it hits, generate a collision event.
+            let mut collision_events = {
+                interactions
+                    .iter()
+                    .flat_map(|interaction| {
+                        // loop through each body, if it hits, generate a collision event.
 
-                            body.iter().filter_map(move |volume| {
-                                if Self::intersects(
-                                    &relative_pos,
-                                    (interaction, interaction_offsets, from_mirrored.0),
-                                    (volume, body_offsets, to_mirrored.0),
-                                ) {
-                                    Some(CollisionEvent::new(
-                                        from,
-                                        to,
-                                        interaction.clone(),
-                                        *volume,
-                                    ))
-                                } else {
-                                    None
-                                }
-                            })
+                        body.iter().filter_map(move |volume| {
+                            if Self::intersects(
+                                &relative_pos,
+                                (interaction, interaction_offsets, from_mirrored.0),
+                                (volume, body_offsets, to_mirrored.0),
+                            ) {
+                                Some(CollisionEvent::new(from, to, interaction.clone(), *volume))
+                            } else {
+                                None
+                            }
                         })
-                        .collect::<Vec<CollisionEvent>>()
-                };
+                    })
+                    .collect::<Vec<CollisionEvent>>()
+            };
 
-                if !collision_events.is_empty() {
-                    debug!("Collisions: {:?}", collision_events);
-                }
-
-                collision_ec.drain_vec_write(&mut collision_events);
+            if !collision_events.is_empty() {
+                debug!("Collisions: {:?}", collision_events);
             }
+
+            collision_ec.drain_vec_write(&mut collision_events);
         }
     }
 }
@@ -3,11 +3,14 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use collision_model::play::{CollisionEvent, ContactEvent};
+use collision_model::{
+    config::InteractionKind,
+    play::{CollisionEvent, ContactEvent},
+};
 use derivative::Derivative;
 use derive_new::new;
 use spawn_model::play::SpawnParent;
-use team_model::play::Team;
+use team_model::play::{FriendlyFire, Team};
 
 /// Detects whether a `ContactEvent` occurs when a `CollisionEvent` happens.
 ///
@@ -26,6 +29,9 @@ pub struct ContactDetectionSystemData<'s> {
     /// `CollisionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub collision_ec: Read<'s, EventChannel<CollisionEvent>>,
+    /// `FriendlyFire` resource.
+    #[derivative(Debug = "ignore")]
+    pub friendly_fire: Read<'s, FriendlyFire>,
     /// `SpawnParent` components.
     #[derivative(Debug = "ignore")]
     pub spawn_parents: ReadStorage<'s, SpawnParent>,
@@ -44,6 +50,7 @@ impl<'s> System<'s> for ContactDetectionSystem {
         &mut self,
         ContactDetectionSystemData {
             collision_ec,
+            friendly_fire,
             spawn_parents,
             teams,
             mut contact_ec,
@@ -69,7 +76,13 @@ impl<'s> System<'s> for ContactDetectionSystem {
                 let team_from = teams.get(entity_hitter);
                 let team_to = teams.get(entity_hit);
                 let dont_hit_team = if let (Some(team_from), Some(team_to)) = (team_from, team_to) {
-                    team_from != team_to
+                    // Friendly fire only lets `Hit`s through, objects never grab or throw
+                    // objects on the same team.
+                    let is_hit = match ev.interaction.kind {
+                        InteractionKind::Hit(..) => true,
+                        InteractionKind::Grab(..) | InteractionKind::Throw(..) => false,
+                    };
+                    (friendly_fire.0 && is_hit) || team_from != team_to
                 } else {
                     true
                 };
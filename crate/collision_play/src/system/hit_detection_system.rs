@@ -3,7 +3,7 @@ use amethyst::{
     shrev::{EventChannel, ReaderId},
 };
 use collision_model::{
-    config::{Hit, HitLimit, Interaction, InteractionKind},
+    config::{Hit, HitLimit, InteractionKind},
     play::{ContactEvent, HitEvent, HitObjectCount, HitRepeatTrackers},
 };
 use derive_new::new;
@@ -73,10 +73,13 @@ impl<'s> System<'s> for HitDetectionSystem {
                 //
                 // 2. `HitLimit`: Make sure not more than `HitLimit` entities are hit.
 
-                let Interaction {
-                    kind: InteractionKind::Hit(Hit { hit_limit, .. }),
-                    ..
-                } = ev.interaction;
+                // Only `Hit` interactions produce `HitEvent`s.
+                let hit_limit =
+                    if let InteractionKind::Hit(Hit { hit_limit, .. }) = ev.interaction.kind {
+                        hit_limit
+                    } else {
+                        return false;
+                    };
 
                 // If we contact multiple objects in *this* frame, when previously
                 // there was 1 contact, and the hit limit is 2, then we should only hit 1
@@ -3,7 +3,7 @@ use amethyst::{
     shrev::{EventChannel, ReaderId},
 };
 use collision_model::{
-    config::{Hit, HitRepeatDelay, Interaction, InteractionKind},
+    config::{Hit, HitRepeatDelay, InteractionKind},
     play::{HitEvent, HitRepeatClock, HitRepeatTracker, HitRepeatTrackers},
 };
 use derive_new::new;
@@ -44,10 +44,12 @@ impl<'s> System<'s> for HitRepeatTrackersAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add trackers for `Hit` interactions.
-                let Interaction {
-                    kind: InteractionKind::Hit(Hit { repeat_delay, .. }),
-                    ..
-                } = ev.interaction;
+                let repeat_delay =
+                    if let InteractionKind::Hit(Hit { repeat_delay, .. }) = ev.interaction.kind {
+                        repeat_delay
+                    } else {
+                        return;
+                    };
 
                 // This assumes `ev.to` is the hit object entity. If we have a separate
                 // entity for each `Body`, then this assumption breaks, and we need to
@@ -0,0 +1,327 @@
+use amethyst::core::math::Vector3;
+use shape_model::{Axis, Volume};
+
+/// Volume in coordinates relative to the interaction object.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Shape {
+    /// Axis aligned box.
+    Cuboid {
+        /// Minimum coordinate on each axis.
+        min: [f32; 3],
+        /// Maximum coordinate on each axis.
+        max: [f32; 3],
+    },
+    /// Cylinder aligned with an axis.
+    Cylinder {
+        /// Index of the axis the cylinder is aligned with.
+        axis: usize,
+        /// Minimum coordinate along the axis.
+        min: f32,
+        /// Maximum coordinate along the axis.
+        max: f32,
+        /// Center of the cylinder. The coordinate along the axis is not used.
+        center: [f32; 3],
+        /// Radius of the cylinder.
+        r: f32,
+    },
+    /// Sphere.
+    Sphere {
+        /// Center of the sphere.
+        center: [f32; 3],
+        /// Radius of the sphere.
+        r: f32,
+    },
+}
+
+/// Tests whether `Volume`s intersect.
+///
+/// Volume coordinates are pixel coordinates on the object's sprite, so the sprite offsets are
+/// subtracted to place the volume relative to the object's position. When the object is mirrored,
+/// the X coordinates are negated.
+#[derive(Debug)]
+pub struct VolumeIntersector;
+
+impl VolumeIntersector {
+    /// Returns whether an interaction volume intersects a body volume.
+    ///
+    /// # Parameters
+    ///
+    /// * `relative_pos`: Position of the body object relative to the interaction object.
+    /// * `interaction`: Interaction volume, sprite offsets, and whether the object is mirrored.
+    /// * `body`: Body volume, sprite offsets, and whether the object is mirrored.
+    pub fn intersects(
+        relative_pos: &Vector3<f32>,
+        (interaction, interaction_offsets, interaction_mirrored): (&Volume, [f32; 2], bool),
+        (body, body_offsets, body_mirrored): (&Volume, [f32; 2], bool),
+    ) -> bool {
+        let interaction_shape = Self::shape(
+            interaction,
+            interaction_offsets,
+            interaction_mirrored,
+            [0., 0., 0.],
+        );
+        let body_shape = Self::shape(
+            body,
+            body_offsets,
+            body_mirrored,
+            [relative_pos[0], relative_pos[1], relative_pos[2]],
+        );
+
+        Self::shapes_intersect(interaction_shape, body_shape)
+    }
+
+    /// Returns the range along the X axis that the volume occupies, relative to the object.
+    ///
+    /// # Parameters
+    ///
+    /// * `volume`: Volume to calculate the range of.
+    /// * `offset_x`: X offset of the object's sprite.
+    /// * `mirrored`: Whether the object is mirrored.
+    pub fn x_range(volume: &Volume, offset_x: f32, mirrored: bool) -> (f32, f32) {
+        match *volume {
+            Volume::Box { x, w, .. } => {
+                let x_min = Self::x_coordinate(x as f32, offset_x, mirrored);
+                let x_max = Self::x_coordinate(x as f32 + w as f32, offset_x, mirrored);
+                (x_min.min(x_max), x_min.max(x_max))
+            }
+            Volume::Cylinder {
+                axis: Axis::X,
+                center,
+                l,
+                ..
+            } => {
+                let center = Self::x_coordinate(center as f32, offset_x, mirrored);
+                let half_length = l as f32 / 2.;
+                (center - half_length, center + half_length)
+            }
+            Volume::Cylinder { r, .. } => (-(r as f32), r as f32),
+            Volume::Sphere { x, r, .. } => {
+                let center = Self::x_coordinate(x as f32, offset_x, mirrored);
+                (center - r as f32, center + r as f32)
+            }
+        }
+    }
+
+    /// Returns the X coordinate relative to the object.
+    fn x_coordinate(x: f32, offset_x: f32, mirrored: bool) -> f32 {
+        let x = x - offset_x;
+        if mirrored {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Returns the `Shape` of a volume relative to the interaction object.
+    fn shape(volume: &Volume, offsets: [f32; 2], mirrored: bool, position: [f32; 3]) -> Shape {
+        match *volume {
+            Volume::Box { x, y, z, w, h, d } => {
+                let (x_min, x_max) = Self::x_range(volume, offsets[0], mirrored);
+                let y_min = y as f32 - offsets[1];
+                let z_min = z as f32;
+                Shape::Cuboid {
+                    min: [
+                        x_min + position[0],
+                        y_min + position[1],
+                        z_min + position[2],
+                    ],
+                    max: [
+                        x_max + position[0],
+                        y_min + h as f32 + position[1],
+                        z_min + d as f32 + position[2],
+                    ],
+                }
+            }
+            Volume::Cylinder { axis, center, r, l } => {
+                let (axis, center_axial) = match axis {
+                    Axis::X => (0, Self::x_coordinate(center as f32, offsets[0], mirrored)),
+                    Axis::Y => (1, center as f32 - offsets[1]),
+                    Axis::Z => (2, center as f32),
+                };
+                let center_axial = center_axial + position[axis];
+                let half_length = l as f32 / 2.;
+                Shape::Cylinder {
+                    axis,
+                    min: center_axial - half_length,
+                    max: center_axial + half_length,
+                    center: position,
+                    r: r as f32,
+                }
+            }
+            Volume::Sphere { x, y, z, r } => Shape::Sphere {
+                center: [
+                    Self::x_coordinate(x as f32, offsets[0], mirrored) + position[0],
+                    y as f32 - offsets[1] + position[1],
+                    z as f32 + position[2],
+                ],
+                r: r as f32,
+            },
+        }
+    }
+
+    fn shapes_intersect(shape_a: Shape, shape_b: Shape) -> bool {
+        match (shape_a, shape_b) {
+            (
+                Shape::Cuboid {
+                    min: min_a,
+                    max: max_a,
+                },
+                Shape::Cuboid {
+                    min: min_b,
+                    max: max_b,
+                },
+            ) => (0..3).all(|i| Self::ranges_overlap(min_a[i], max_a[i], min_b[i], max_b[i])),
+            (Shape::Cuboid { min, max }, Shape::Sphere { center, r })
+            | (Shape::Sphere { center, r }, Shape::Cuboid { min, max }) => {
+                let distance_squared = (0..3)
+                    .map(|i| Self::range_distance(center[i], min[i], max[i]).powi(2))
+                    .sum::<f32>();
+                distance_squared <= r * r
+            }
+            (
+                Shape::Sphere {
+                    center: center_a,
+                    r: r_a,
+                },
+                Shape::Sphere {
+                    center: center_b,
+                    r: r_b,
+                },
+            ) => {
+                let distance_squared = (0..3)
+                    .map(|i| (center_a[i] - center_b[i]).powi(2))
+                    .sum::<f32>();
+                distance_squared <= (r_a + r_b).powi(2)
+            }
+            (
+                Shape::Cylinder {
+                    axis,
+                    min,
+                    max,
+                    center,
+                    r,
+                },
+                Shape::Cuboid {
+                    min: cuboid_min,
+                    max: cuboid_max,
+                },
+            )
+            | (
+                Shape::Cuboid {
+                    min: cuboid_min,
+                    max: cuboid_max,
+                },
+                Shape::Cylinder {
+                    axis,
+                    min,
+                    max,
+                    center,
+                    r,
+                },
+            ) => {
+                // Both are prisms along the cylinder's axis, so they intersect when they overlap
+                // along the axis, and the cylinder's circle intersects the cuboid's rectangle.
+                let (p, q) = Self::axes_perpendicular(axis);
+                let distance_squared =
+                    Self::range_distance(center[p], cuboid_min[p], cuboid_max[p]).powi(2)
+                        + Self::range_distance(center[q], cuboid_min[q], cuboid_max[q]).powi(2);
+
+                Self::ranges_overlap(min, max, cuboid_min[axis], cuboid_max[axis])
+                    && distance_squared <= r * r
+            }
+            (
+                Shape::Cylinder {
+                    axis,
+                    min,
+                    max,
+                    center,
+                    r,
+                },
+                Shape::Sphere {
+                    center: sphere_center,
+                    r: sphere_r,
+                },
+            )
+            | (
+                Shape::Sphere {
+                    center: sphere_center,
+                    r: sphere_r,
+                },
+                Shape::Cylinder {
+                    axis,
+                    min,
+                    max,
+                    center,
+                    r,
+                },
+            ) => {
+                let (p, q) = Self::axes_perpendicular(axis);
+                let distance_axial = Self::range_distance(sphere_center[axis], min, max);
+                let distance_radial = ((sphere_center[p] - center[p]).powi(2)
+                    + (sphere_center[q] - center[q]).powi(2))
+                .sqrt();
+                let distance_radial = (distance_radial - r).max(0.);
+
+                distance_axial.powi(2) + distance_radial.powi(2) <= sphere_r * sphere_r
+            }
+            (
+                Shape::Cylinder {
+                    axis: axis_a,
+                    min: min_a,
+                    max: max_a,
+                    center: center_a,
+                    r: r_a,
+                },
+                Shape::Cylinder {
+                    axis: axis_b,
+                    min: min_b,
+                    max: max_b,
+                    center: center_b,
+                    r: r_b,
+                },
+            ) => {
+                if axis_a == axis_b {
+                    let (p, q) = Self::axes_perpendicular(axis_a);
+                    let distance_squared =
+                        (center_a[p] - center_b[p]).powi(2) + (center_a[q] - center_b[q]).powi(2);
+
+                    Self::ranges_overlap(min_a, max_a, min_b, max_b)
+                        && distance_squared <= (r_a + r_b).powi(2)
+                } else {
+                    // Choose the point along each cylinder's axis that is closest to the other
+                    // cylinder, then the cross sections along the remaining axis must overlap.
+                    let axis_c = 3 - axis_a - axis_b;
+                    let distance_a = Self::range_distance(center_b[axis_a], min_a, max_a);
+                    let distance_b = Self::range_distance(center_a[axis_b], min_b, max_b);
+                    if distance_b > r_a || distance_a > r_b {
+                        return false;
+                    }
+
+                    let half_width_a = (r_a * r_a - distance_b * distance_b).sqrt();
+                    let half_width_b = (r_b * r_b - distance_a * distance_a).sqrt();
+
+                    (center_a[axis_c] - center_b[axis_c]).abs() <= half_width_a + half_width_b
+                }
+            }
+        }
+    }
+
+    /// Returns whether two ranges overlap, including when they touch.
+    fn ranges_overlap(min_a: f32, max_a: f32, min_b: f32, max_b: f32) -> bool {
+        min_a <= max_b && min_b <= max_a
+    }
+
+    /// Returns the distance from a value to the closest value in a range.
+    fn range_distance(value: f32, min: f32, max: f32) -> f32 {
+        (value - value.max(min).min(max)).abs()
+    }
+
+    /// Returns the indices of the two axes perpendicular to the given axis.
+    fn axes_perpendicular(axis: usize) -> (usize, usize) {
+        match axis {
+            0 => (1, 2),
+            1 => (0, 2),
+            _ => (0, 1),
+        }
+    }
+}
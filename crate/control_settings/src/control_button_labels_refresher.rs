@@ -0,0 +1,71 @@
+use amethyst::ecs::{World, WorldExt};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetItemIds, AssetTypeMappings, ItemId},
+    play::AssetWorld,
+};
+use loading_model::loaded::{AssetLoadStage, AssetLoadStatus, LoadStage, LoadStatus};
+use log::error;
+
+/// Reloads UI assets so that `ControlButtonLabel`s reflect the current `PlayerInputConfigs`.
+///
+/// `ControlButtonLabel`s are generated when UI sequence components are loaded, so the UI assets'
+/// sequence components are loaded again, and the previous item entities are deleted afterwards.
+#[derive(Debug, Default)]
+pub struct ControlButtonLabelsRefresher {
+    /// UI assets whose sequence components are being reloaded.
+    asset_ids_reloading: Vec<AssetId>,
+    /// Item IDs from before the reload, to be deleted when the reload is complete.
+    item_ids_stale: Vec<ItemId>,
+}
+
+impl ControlButtonLabelsRefresher {
+    /// Queues all loaded UI assets for sequence component reloading.
+    pub fn refresh(&mut self, world: &World) {
+        let asset_ids_ui = world
+            .read_resource::<AssetTypeMappings>()
+            .iter_ids(&AssetType::Ui)
+            .copied()
+            .collect::<Vec<AssetId>>();
+
+        let mut asset_load_stage = world.write_resource::<AssetLoadStage>();
+        let mut asset_load_status = world.write_resource::<AssetLoadStatus>();
+        let asset_item_ids = world.read_resource::<AssetItemIds>();
+
+        asset_ids_ui
+            .into_iter()
+            .filter(|asset_id| asset_load_stage.get(*asset_id) == Some(&LoadStage::Complete))
+            .for_each(|asset_id| {
+                if let Some(item_ids) = asset_item_ids.get(asset_id) {
+                    self.item_ids_stale.extend(item_ids.iter().copied());
+                }
+
+                asset_load_stage.insert(asset_id, LoadStage::SequenceComponentLoading);
+                asset_load_status.insert(asset_id, LoadStatus::Queued);
+                self.asset_ids_reloading.push(asset_id);
+            });
+    }
+
+    /// Returns whether all queued UI assets have been reloaded.
+    ///
+    /// Returns `true` when there is no refresh in progress.
+    pub fn is_complete(&mut self, world: &World) -> bool {
+        let asset_load_stage = world.read_resource::<AssetLoadStage>();
+        self.asset_ids_reloading
+            .retain(|asset_id| asset_load_stage.get(*asset_id) != Some(&LoadStage::Complete));
+
+        self.asset_ids_reloading.is_empty()
+    }
+
+    /// Deletes the item entities from before the reload.
+    ///
+    /// This should only be called once state entities are no longer spawned from these items.
+    pub fn clear_stale(&mut self, world: &World) {
+        let mut asset_world = world.write_resource::<AssetWorld>();
+        self.item_ids_stale.drain(..).for_each(|item_id| {
+            if let Err(e) = asset_world.delete_entity(item_id.0) {
+                error!("Failed to delete stale item entity: {:?}. {}", item_id, e);
+            }
+        });
+    }
+}
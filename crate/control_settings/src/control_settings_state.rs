@@ -1,20 +1,123 @@
 use amethyst::{
-    ecs::WorldExt,
-    input::{is_key_down, VirtualKeyCode},
+    ecs::{World, WorldExt},
+    input::{get_key, is_key_down, Button, InputHandler, VirtualKeyCode},
+    shrev::EventChannel,
+    winit::ElementState,
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
 use control_settings_model::{ControlSettingsEntity, ControlSettingsEvent};
 use derivative::Derivative;
 use derive_new::new;
-use log::debug;
-use state_registry::StateId;
+use game_input_model::{
+    config::{ControlBindings, ControllerId, PlayerInputConfigs},
+    loaded::ControlButton,
+    play::{ControllerIdOffset, GamepadAssignments},
+};
+use log::{debug, error, info, warn};
+use state_registry::{StateId, StateIdUpdateEvent};
 use state_support::StateEntityUtils;
 
+use crate::{ControlButtonLabelsRefresher, PlayerInputConfigsPersister};
+
 /// `State` where game play takes place.
 #[derive(Derivative, Default, new)]
 #[derivative(Debug)]
-pub struct ControlSettingsState;
+pub struct ControlSettingsState {
+    /// Control button to bind the next key press to.
+    #[new(default)]
+    rebind_target: Option<(ControllerId, ControlButton)>,
+    /// Reloads UI assets after `PlayerInputConfigs` change.
+    #[new(default)]
+    control_button_labels_refresher: ControlButtonLabelsRefresher,
+    /// Whether `ControlButtonLabel`s are being refreshed.
+    #[new(default)]
+    refreshing: bool,
+    /// Whether item entities from before the refresh should be deleted.
+    #[new(default)]
+    stale_clear_pending: bool,
+}
+
+impl ControlSettingsState {
+    /// Binds the pressed key to the rebind target, and writes the `PlayerInputConfigs` to disk.
+    fn rebind(
+        &mut self,
+        world: &World,
+        controller_id: ControllerId,
+        control_button: ControlButton,
+        virtual_key_code: VirtualKeyCode,
+    ) {
+        let rebind_result = {
+            let mut player_input_configs = world.write_resource::<PlayerInputConfigs>();
+            player_input_configs
+                .rebind(controller_id, control_button, Button::Key(virtual_key_code))
+                .map(|conflict| {
+                    (
+                        conflict,
+                        PlayerInputConfigsPersister::store(&player_input_configs),
+                    )
+                })
+        };
+
+        match rebind_result {
+            Ok((conflict, store_result)) => {
+                if let Some((controller_id_conflict, control_button_conflict)) = conflict {
+                    warn!(
+                        "`{:?}` was bound to controller `{}` `{:?}`, swapped with controller `{}` \
+                         `{:?}`.",
+                        virtual_key_code,
+                        controller_id_conflict,
+                        control_button_conflict,
+                        controller_id,
+                        control_button
+                    );
+                }
+                match store_result {
+                    Ok(file_path) => {
+                        info!("Control settings written to: `{}`.", file_path.display())
+                    }
+                    Err(e) => error!("Failed to write control settings. Error: `{}`.", e),
+                }
+
+                self.player_input_configs_apply(world);
+            }
+            Err(e) => error!("Failed to rebind control button. Error: `{}`.", e),
+        }
+    }
+
+    /// Reloads `PlayerInputConfigs` from disk.
+    fn reload(&mut self, world: &World) {
+        match PlayerInputConfigsPersister::load() {
+            Ok(player_input_configs) => {
+                *world.write_resource::<PlayerInputConfigs>() = player_input_configs;
+                self.player_input_configs_apply(world);
+            }
+            Err(e) => error!("Failed to reload control settings. Error: `{}`.", e),
+        }
+    }
+
+    /// Updates input `Bindings` and `ControlButtonLabel`s from the `PlayerInputConfigs`.
+    fn player_input_configs_apply(&mut self, world: &World) {
+        let controller_id_offset = *world.read_resource::<ControllerIdOffset>();
+        let bindings_result = world
+            .read_resource::<PlayerInputConfigs>()
+            .generate_bindings(
+                controller_id_offset,
+                &world.read_resource::<GamepadAssignments>(),
+            );
+        match bindings_result {
+            Ok(bindings) => {
+                world
+                    .write_resource::<InputHandler<ControlBindings>>()
+                    .bindings = bindings
+            }
+            Err(e) => error!("Failed to update input `Bindings`. Error: `{}`.", e),
+        }
+
+        self.control_button_labels_refresher.refresh(world);
+        self.refreshing = true;
+    }
+}
 
 impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
@@ -32,30 +135,57 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'_, '_>>,
+        data: StateData<'_, GameData<'_, '_>>,
         event: AppEvent,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
         match event {
             AppEvent::Window(window_event) => {
-                if is_key_down(&window_event, VirtualKeyCode::Escape) {
+                if let Some((controller_id, control_button)) = self.rebind_target {
+                    match get_key(&window_event) {
+                        Some((VirtualKeyCode::Escape, ElementState::Pressed)) => {
+                            debug!("Cancelling control button rebind.");
+                            self.rebind_target = None;
+                        }
+                        Some((virtual_key_code, ElementState::Pressed)) => {
+                            self.rebind_target = None;
+                            self.rebind(
+                                data.world,
+                                controller_id,
+                                control_button,
+                                virtual_key_code,
+                            );
+                        }
+                        _ => {}
+                    }
+                    Trans::None
+                } else if is_key_down(&window_event, VirtualKeyCode::Escape) {
                     debug!("Returning from `ControlSettingsState`.");
                     Trans::Pop
                 } else {
                     Trans::None
                 }
             }
-            AppEvent::ControlSettings(control_settings_event) => {
-                match control_settings_event {
-                    ControlSettingsEvent::Return => {
-                        debug!("Returning from `ControlSettingsState`.");
-                        Trans::Pop
-                    }
-                    ControlSettingsEvent::ReloadRequest => {
-                        // TODO: Reload control settings from file.
-                        Trans::None
-                    }
+            AppEvent::ControlSettings(control_settings_event) => match control_settings_event {
+                ControlSettingsEvent::Return => {
+                    debug!("Returning from `ControlSettingsState`.");
+                    Trans::Pop
                 }
-            }
+                ControlSettingsEvent::ReloadRequest => {
+                    self.reload(data.world);
+                    Trans::None
+                }
+                ControlSettingsEvent::RebindRequest {
+                    controller_id,
+                    control_button,
+                } => {
+                    debug!(
+                        "Press a key to bind to controller `{}` `{:?}`.",
+                        controller_id, control_button
+                    );
+                    self.rebind_target = Some((controller_id, control_button));
+                    Trans::None
+                }
+            },
             _ => Trans::None,
         }
     }
@@ -65,6 +195,26 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for ControlSettingsState {
         data: StateData<'_, GameData<'_, '_>>,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
         data.data.update(&data.world);
+
+        // Stale items are deleted after the state entities have been respawned.
+        if self.stale_clear_pending && !self.refreshing {
+            self.control_button_labels_refresher.clear_stale(data.world);
+            self.stale_clear_pending = false;
+        }
+
+        if self.refreshing && self.control_button_labels_refresher.is_complete(data.world) {
+            self.refreshing = false;
+            self.stale_clear_pending = true;
+
+            // Respawn the state entities from the reloaded items.
+            data.world
+                .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                .single_write(StateIdUpdateEvent::new(
+                    StateId::ControlSettings,
+                    Some(StateId::ControlSettings),
+                ));
+        }
+
         Trans::None
     }
 }
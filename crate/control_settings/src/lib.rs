@@ -2,6 +2,12 @@
 
 //! Provides the state for control settings.
 
-pub use self::control_settings_state::ControlSettingsState;
+pub use crate::{
+    control_button_labels_refresher::ControlButtonLabelsRefresher,
+    control_settings_state::ControlSettingsState,
+    player_input_configs_persister::PlayerInputConfigsPersister,
+};
 
+mod control_button_labels_refresher;
 mod control_settings_state;
+mod player_input_configs_persister;
@@ -0,0 +1,32 @@
+use std::{fs, path::PathBuf};
+
+use amethyst::Error;
+use application::{AppDir, AppFile, Format};
+use game_input_model::config::PlayerInputConfigs;
+
+/// Loads and stores `PlayerInputConfigs` from the resources directory.
+#[derive(Debug)]
+pub struct PlayerInputConfigsPersister;
+
+impl PlayerInputConfigsPersister {
+    /// Name of the player input configuration file.
+    pub const FILE_NAME: &'static str = "player_input_configs.yaml";
+
+    /// Returns the `PlayerInputConfigs` loaded from disk.
+    pub fn load() -> Result<PlayerInputConfigs, Error> {
+        AppFile::load_in::<PlayerInputConfigs, _>(AppDir::RESOURCES, Self::FILE_NAME, Format::Yaml)
+    }
+
+    /// Writes the `PlayerInputConfigs` to disk, returning the path of the file written.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_input_configs`: Player input configuration to write.
+    pub fn store(player_input_configs: &PlayerInputConfigs) -> Result<PathBuf, Error> {
+        let file_path = AppFile::find_in(AppDir::RESOURCES, Self::FILE_NAME)?;
+        let player_input_configs_yaml = serde_yaml::to_string(player_input_configs)?;
+        fs::write(&file_path, player_input_configs_yaml)?;
+
+        Ok(file_path)
+    }
+}
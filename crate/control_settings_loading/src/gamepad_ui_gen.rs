@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use amethyst::input::{ControllerAxis, ControllerButton};
+use control_settings_model::config::{ControlButtonLabel, ControlButtonLabels, GamepadSettings};
+use game_input_model::config::{ControllerId, PlayerInputConfigs};
+use sprite_model::config::{Scale, Tint};
+use ui_model::config::UiSequences;
+
+/// Generates UI items to represent the gamepad control settings.
+#[derive(Debug)]
+pub struct GamepadUiGen;
+
+impl GamepadUiGen {
+    /// Returns `ControlButtonLabel`s for the gamepad's glyphs, and tints player glyphs' sprite
+    /// sequences.
+    ///
+    /// A glyph is tinted with the first controller whose gamepad configuration uses it.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_settings`: Gamepad glyph positions.
+    /// * `controller_tints`: `Tint` for each controller.
+    /// * `player_input_configs`: Player input configuration.
+    /// * `sequences`: UI sequences to tint.
+    pub fn generate_full(
+        gamepad_settings: &GamepadSettings,
+        controller_tints: &[Tint],
+        player_input_configs: &PlayerInputConfigs,
+        sequences: &mut UiSequences,
+    ) -> ControlButtonLabels {
+        let (button_to_player_index, axis_to_player_index) =
+            Self::player_indices(player_input_configs);
+
+        let button_labels =
+            gamepad_settings
+                .button_positions
+                .iter()
+                .map(|(button, ui_sprite_label)| {
+                    (button_to_player_index.get(button).copied(), ui_sprite_label)
+                });
+        let axis_labels = gamepad_settings
+            .axis_positions
+            .iter()
+            .map(|(axis, ui_sprite_label)| {
+                (axis_to_player_index.get(axis).copied(), ui_sprite_label)
+            });
+
+        let control_button_labels = button_labels
+            .chain(axis_labels)
+            .map(|(player_index, ui_sprite_label)| {
+                let mut ui_sprite_label = ui_sprite_label.clone();
+
+                // Sequence to adjust tint.
+                let ui_sequence = sequences.get_mut(&ui_sprite_label.sequence);
+                let tint = player_index
+                    .and_then(|player_index| controller_tints.get(player_index).copied());
+
+                if let Some(ui_sequence) = ui_sequence {
+                    ui_sequence.sequence.frames.iter_mut().for_each(|ui_frame| {
+                        if let Some(tint) = tint {
+                            ui_frame.sprite_frame.tint = tint;
+                        }
+
+                        if let Scale(Some(scale)) = gamepad_settings.scale {
+                            ui_frame.sprite_frame.scale = gamepad_settings.scale;
+                            ui_sprite_label.position *= scale;
+                        }
+                    });
+                }
+                ui_sprite_label.position += gamepad_settings.position;
+
+                ControlButtonLabel::new(ui_sprite_label, player_index)
+            })
+            .collect::<Vec<ControlButtonLabel>>();
+
+        ControlButtonLabels::new(control_button_labels)
+    }
+
+    /// Returns maps of gamepad buttons and axes to the first player index that uses them.
+    fn player_indices(
+        player_input_configs: &PlayerInputConfigs,
+    ) -> (
+        HashMap<ControllerButton, ControllerId>,
+        HashMap<ControllerAxis, ControllerId>,
+    ) {
+        player_input_configs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, player_input_config)| {
+                player_input_config
+                    .controller_config
+                    .gamepad
+                    .as_ref()
+                    .map(|gamepad_config| (index, gamepad_config))
+            })
+            .fold(
+                (HashMap::new(), HashMap::new()),
+                |(mut button_to_player_index, mut axis_to_player_index),
+                 (index, gamepad_config)| {
+                    gamepad_config.actions.values().for_each(|button| {
+                        button_to_player_index.entry(*button).or_insert(index);
+                    });
+                    gamepad_config.axes.values().for_each(|gamepad_axis| {
+                        axis_to_player_index
+                            .entry(gamepad_axis.axis)
+                            .or_insert(index);
+                    });
+
+                    (button_to_player_index, axis_to_player_index)
+                },
+            )
+    }
+}
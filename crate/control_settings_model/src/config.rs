@@ -2,12 +2,13 @@
 
 pub use self::{
     control_button_label::ControlButtonLabel, control_button_labels::ControlButtonLabels,
-    control_settings::ControlSettings, keyboard_layout::KeyboardLayout,
-    keyboard_settings::KeyboardSettings,
+    control_settings::ControlSettings, gamepad_settings::GamepadSettings,
+    keyboard_layout::KeyboardLayout, keyboard_settings::KeyboardSettings,
 };
 
 mod control_button_label;
 mod control_button_labels;
 mod control_settings;
+mod gamepad_settings;
 mod keyboard_layout;
 mod keyboard_settings;
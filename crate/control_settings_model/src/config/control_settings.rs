@@ -2,7 +2,7 @@ use derive_new::new;
 use serde::{Deserialize, Serialize};
 use ui_label_model::config::UiLabel;
 
-use crate::config::KeyboardSettings;
+use crate::config::{GamepadSettings, KeyboardSettings};
 
 /// Control Settings UI configuration.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
@@ -12,6 +12,9 @@ pub struct ControlSettings {
     pub title: UiLabel,
     /// Keyboard settings.
     pub keyboard: KeyboardSettings,
+    /// Gamepad settings.
+    #[serde(default)]
+    pub gamepad: Option<GamepadSettings>,
 }
 
 impl AsRef<UiLabel> for ControlSettings {
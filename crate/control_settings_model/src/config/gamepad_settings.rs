@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use amethyst::input::{ControllerAxis, ControllerButton};
+use derive_new::new;
+use kinematic_model::config::PositionInit;
+use serde::{Deserialize, Serialize};
+use sprite_model::config::Scale;
+use ui_label_model::config::UiSpriteLabel;
+
+/// Gamepad settings.
+///
+/// Gamepad glyphs are tinted with the `KeyboardSettings` `controller_tints`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct GamepadSettings {
+    /// Position of the gamepad on screen.
+    pub position: PositionInit,
+    /// Factor to scale the glyphs by.
+    #[serde(default)]
+    pub scale: Scale,
+    /// Positions of gamepad button glyphs.
+    #[serde(default)]
+    pub button_positions: HashMap<ControllerButton, UiSpriteLabel>,
+    /// Positions of gamepad axis glyphs.
+    #[serde(default)]
+    pub axis_positions: HashMap<ControllerAxis, UiSpriteLabel>,
+}
@@ -1,3 +1,4 @@
+use game_input_model::{config::ControllerId, loaded::ControlButton};
 use serde::{Deserialize, Serialize};
 
 /// Event signalling a change in the `ControlSettings` state.
@@ -8,4 +9,11 @@ pub enum ControlSettingsEvent {
     Return,
     /// Control settings should be reloaded.
     ReloadRequest,
+    /// The next key pressed should be bound to a player's control button.
+    RebindRequest {
+        /// ID of the controller to rebind.
+        controller_id: ControllerId,
+        /// Control button to rebind.
+        control_button: ControlButton,
+    },
 }
@@ -0,0 +1,5 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to detect when game play diverges between devices in a network session.
+
+pub mod play;
@@ -0,0 +1,11 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    entity_checksum::EntityChecksum, game_checksum::GameChecksum, game_checksums::GameChecksums,
+    game_desync::GameDesync,
+};
+
+mod entity_checksum;
+mod game_checksum;
+mod game_checksums;
+mod game_desync;
@@ -0,0 +1,14 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Checksum of an entity's simulation relevant game play state.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
+pub struct EntityChecksum {
+    /// `GameEntityId` of the entity.
+    ///
+    /// Game entity IDs are assigned in spawn order, and game play entities are spawned in the same
+    /// order on every session device, so the same ID refers to the same object.
+    pub entity_id: u32,
+    /// Checksum of the entity's components.
+    pub checksum: u64,
+}
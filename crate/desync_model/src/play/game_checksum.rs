@@ -0,0 +1,17 @@
+use derive_new::new;
+use network_input_model::play::FrameNumber;
+use network_session_model::play::SessionDeviceId;
+use serde::{Deserialize, Serialize};
+
+use crate::play::EntityChecksum;
+
+/// Checksums of game play state from a session device, taken at the beginning of a frame.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct GameChecksum {
+    /// ID of the session device that calculated the checksums.
+    pub session_device_id: SessionDeviceId,
+    /// Frame that the checksums were taken at.
+    pub frame_number: FrameNumber,
+    /// Checksum of each game play entity, in entity ID order.
+    pub entity_checksums: Vec<EntityChecksum>,
+}
@@ -0,0 +1,168 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use derive_new::new;
+use network_input_model::play::FrameNumber;
+
+use crate::play::{EntityChecksum, GameChecksum, GameDesync};
+
+/// Local and received checksums of game play state, used to detect desynchronization.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct GameChecksums {
+    /// Checksums calculated on this device, by frame.
+    #[new(default)]
+    local: BTreeMap<FrameNumber, Vec<EntityChecksum>>,
+    /// Checksums received from other session devices that have not been compared, by frame.
+    #[new(default)]
+    remote: BTreeMap<FrameNumber, Vec<GameChecksum>>,
+    /// Number of received checksums compared with the local checksums, by frame.
+    #[new(default)]
+    compared_counts: BTreeMap<FrameNumber, usize>,
+    /// Last frame whose local checksums have been compared with every other session device.
+    #[new(default)]
+    frame_compared: Option<FrameNumber>,
+    /// First desynchronization detected.
+    #[new(default)]
+    game_desync: Option<GameDesync>,
+}
+
+impl GameChecksums {
+    /// Records the checksums calculated on this device for a frame.
+    pub fn insert_local(
+        &mut self,
+        frame_number: FrameNumber,
+        entity_checksums: Vec<EntityChecksum>,
+    ) {
+        self.local.insert(frame_number, entity_checksums);
+    }
+
+    /// Records checksums received from another session device.
+    ///
+    /// Checksums for frames that have already been compared with every other session device are
+    /// discarded.
+    pub fn insert_remote(&mut self, game_checksum: GameChecksum) {
+        if self
+            .frame_compared
+            .map(|frame_compared| game_checksum.frame_number <= frame_compared)
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        self.remote
+            .entry(game_checksum.frame_number)
+            .or_insert_with(Vec::new)
+            .push(game_checksum);
+    }
+
+    /// Compares received checksums with local checksums for the same frame.
+    ///
+    /// Returns the desynchronization if it is the first one detected. Received checksums for
+    /// frames that have not been calculated locally are kept until they have been.
+    ///
+    /// Once a frame has been compared with every other session device, checksums for that frame
+    /// and earlier frames are discarded.
+    ///
+    /// # Parameters
+    ///
+    /// * `remote_device_count`: Number of other session devices that send checksums.
+    pub fn desync_detect(&mut self, remote_device_count: usize) -> Option<GameDesync> {
+        let frame_numbers = self
+            .remote
+            .keys()
+            .copied()
+            .filter(|frame_number| self.local.contains_key(frame_number))
+            .collect::<Vec<FrameNumber>>();
+
+        let mut game_desync_first = None;
+        frame_numbers.into_iter().for_each(|frame_number| {
+            let entity_checksums_local = &self.local[&frame_number];
+            if let Some(game_checksums_remote) = self.remote.remove(&frame_number) {
+                game_desync_first = game_checksums_remote.iter().fold(
+                    game_desync_first,
+                    |game_desync_first, game_checksum| {
+                        game_desync_first
+                            .or_else(|| Self::desync_find(entity_checksums_local, game_checksum))
+                    },
+                );
+
+                let compared_count = self.compared_counts.entry(frame_number).or_insert(0);
+                *compared_count += game_checksums_remote.len();
+                if *compared_count >= remote_device_count {
+                    self.frame_compared = Some(frame_number);
+                }
+            }
+        });
+
+        if let Some(frame_compared) = self.frame_compared {
+            self.discard_until(frame_compared);
+        }
+
+        if self.game_desync.is_none() && game_desync_first.is_some() {
+            self.game_desync = game_desync_first;
+            game_desync_first
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first desynchronization detected, if any.
+    pub fn game_desync(&self) -> Option<GameDesync> {
+        self.game_desync
+    }
+
+    /// Returns the number of frames that local checksums are stored for.
+    pub fn local_len(&self) -> usize {
+        self.local.len()
+    }
+
+    /// Clears all checksums and the detected desynchronization.
+    pub fn clear(&mut self) {
+        self.local.clear();
+        self.remote.clear();
+        self.compared_counts.clear();
+        self.frame_compared = None;
+        self.game_desync = None;
+    }
+
+    /// Discards checksums for frames up to and including the given frame.
+    fn discard_until(&mut self, frame_number: FrameNumber) {
+        let frame_next = frame_number + 1;
+        self.local = self.local.split_off(&frame_next);
+        self.remote = self.remote.split_off(&frame_next);
+        self.compared_counts = self.compared_counts.split_off(&frame_next);
+    }
+
+    /// Returns the desynchronization for the entity with the lowest ID whose checksum differs.
+    ///
+    /// Checksums are paired by entity ID, so an entity that exists on only one device is reported
+    /// as desynchronized.
+    fn desync_find(
+        entity_checksums_local: &[EntityChecksum],
+        game_checksum: &GameChecksum,
+    ) -> Option<GameDesync> {
+        let checksums_local = Self::checksums_by_entity_id(entity_checksums_local);
+        let checksums_remote = Self::checksums_by_entity_id(&game_checksum.entity_checksums);
+
+        checksums_local
+            .keys()
+            .chain(checksums_remote.keys())
+            .copied()
+            .collect::<BTreeSet<u32>>()
+            .into_iter()
+            .find(|entity_id| checksums_local.get(entity_id) != checksums_remote.get(entity_id))
+            .map(|entity_id| {
+                GameDesync::new(
+                    game_checksum.frame_number,
+                    game_checksum.session_device_id,
+                    entity_id,
+                )
+            })
+    }
+
+    fn checksums_by_entity_id(entity_checksums: &[EntityChecksum]) -> BTreeMap<u32, u64> {
+        entity_checksums
+            .iter()
+            .map(|entity_checksum| (entity_checksum.entity_id, entity_checksum.checksum))
+            .collect::<BTreeMap<u32, u64>>()
+    }
+}
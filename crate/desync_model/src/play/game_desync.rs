@@ -0,0 +1,14 @@
+use derive_new::new;
+use network_input_model::play::FrameNumber;
+use network_session_model::play::SessionDeviceId;
+
+/// Where game play state differed between this device and another session device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct GameDesync {
+    /// Frame that the game play state differed at.
+    pub frame_number: FrameNumber,
+    /// ID of the session device whose game play state differed.
+    pub session_device_id: SessionDeviceId,
+    /// `GameEntityId` of the first entity whose game play state differed.
+    pub entity_id: u32,
+}
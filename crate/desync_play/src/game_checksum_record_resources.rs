@@ -0,0 +1,24 @@
+use amethyst::{
+    ecs::{Read, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use desync_model::play::GameChecksums;
+use net_model::play::NetMessageEvent;
+use network_session_model::play::SessionDeviceId;
+
+/// Resources needed to record and send game play checksums.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameChecksumRecordResources<'s> {
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `GameChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_checksums: Write<'s, GameChecksums>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
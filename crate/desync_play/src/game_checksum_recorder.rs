@@ -0,0 +1,96 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use amethyst::{core::math::Vector3, ecs::Join};
+use desync_model::play::{EntityChecksum, GameChecksum};
+use net_model::play::NetMessageEvent;
+use network_input_model::play::FrameNumber;
+
+use crate::{GameChecksumRecordResources, GameChecksumResources};
+
+/// Calculates and records checksums of game play state.
+#[derive(Debug)]
+pub struct GameChecksumRecorder;
+
+impl GameChecksumRecorder {
+    /// Returns whether checksums should be calculated at the beginning of the given frame.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Frame to check.
+    /// * `checksum_interval`: Number of frames between checksums, `0` to never calculate them.
+    pub fn is_checksum_frame(frame_number: FrameNumber, checksum_interval: u32) -> bool {
+        checksum_interval != 0 && *frame_number % u64::from(checksum_interval) == 0
+    }
+
+    /// Returns the checksum of each game play entity, in `GameEntityId` order.
+    ///
+    /// Game play entities are entities with a `GameEntityId` and a `SequenceId`. UI entities are
+    /// not assigned a `GameEntityId`, so they are not included.
+    pub fn calculate(resources: &GameChecksumResources<'_>) -> Vec<EntityChecksum> {
+        let mut entity_checksums = (
+            &resources.entities,
+            &resources.game_entity_ids,
+            &resources.sequence_ids,
+        )
+            .join()
+            .map(|(entity, game_entity_id, sequence_id)| {
+                let mut hasher = DefaultHasher::new();
+
+                Self::vector_hash(
+                    resources.positions.get(entity).map(|position| &position.0),
+                    &mut hasher,
+                );
+                Self::vector_hash(
+                    resources.velocities.get(entity).map(|velocity| &velocity.0),
+                    &mut hasher,
+                );
+                sequence_id.hash(&mut hasher);
+                resources.health_pointses.get(entity).hash(&mut hasher);
+                resources.skill_pointses.get(entity).hash(&mut hasher);
+                resources.charge_pointses.get(entity).hash(&mut hasher);
+                resources.charge_statuses.get(entity).hash(&mut hasher);
+
+                EntityChecksum::new(game_entity_id.0, hasher.finish())
+            })
+            .collect::<Vec<EntityChecksum>>();
+        entity_checksums.sort_by_key(|entity_checksum| entity_checksum.entity_id);
+
+        entity_checksums
+    }
+
+    /// Records the local checksums for a frame, and sends them to the other session devices.
+    ///
+    /// # Parameters
+    ///
+    /// * `resources`: Resources to record and send the checksums.
+    /// * `frame_number`: Frame that the checksums were taken at.
+    /// * `entity_checksums`: Checksum of each game play entity.
+    pub fn record(
+        resources: &mut GameChecksumRecordResources<'_>,
+        frame_number: FrameNumber,
+        entity_checksums: Vec<EntityChecksum>,
+    ) {
+        resources
+            .game_checksums
+            .insert_local(frame_number, entity_checksums.clone());
+
+        let game_checksum =
+            GameChecksum::new(*resources.session_device_id, frame_number, entity_checksums);
+        resources
+            .net_message_ec
+            .single_write(NetMessageEvent::from(game_checksum));
+    }
+
+    /// Hashes the bits of each coordinate, as `f32` does not implement `Hash`.
+    fn vector_hash<H>(vector: Option<&Vector3<f32>>, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        vector
+            .map(|vector| [vector.x.to_bits(), vector.y.to_bits(), vector.z.to_bits()])
+            .hash(hasher);
+    }
+}
@@ -0,0 +1,43 @@
+use amethyst::{
+    ecs::{Entities, ReadStorage, World},
+    shred::{ResourceId, SystemData},
+};
+use charge_model::{config::ChargePoints, play::ChargeStatus};
+use derivative::Derivative;
+use game_model::play::GameEntityId;
+use kinematic_model::config::{Position, Velocity};
+use object_model::play::{HealthPoints, SkillPoints};
+use sequence_model::loaded::SequenceId;
+
+/// Components used to calculate game play checksums.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameChecksumResources<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GameEntityId` components.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_ids: ReadStorage<'s, GameEntityId>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: ReadStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: ReadStorage<'s, SkillPoints>,
+    /// `ChargePoints` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_pointses: ReadStorage<'s, ChargePoints>,
+    /// `ChargeStatus` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_statuses: ReadStorage<'s, ChargeStatus>,
+}
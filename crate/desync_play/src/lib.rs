@@ -0,0 +1,22 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to detect when game play diverges between devices in a network session.
+//!
+//! Every `checksum_interval` frames, each device calculates checksums of the simulation relevant
+//! game play state, and sends them to the other devices in the session. When the checksums for a
+//! frame differ, the first divergent frame and entity is logged.
+
+pub use crate::{
+    game_checksum_record_resources::GameChecksumRecordResources,
+    game_checksum_recorder::GameChecksumRecorder,
+    game_checksum_resources::GameChecksumResources,
+    system::{
+        DesyncDetectionSystem, DesyncDetectionSystemDesc, GameChecksumSystem,
+        GameChecksumSystemDesc,
+    },
+};
+
+mod game_checksum_record_resources;
+mod game_checksum_recorder;
+mod game_checksum_resources;
+mod system;
@@ -0,0 +1,7 @@
+pub use self::{
+    desync_detection_system::{DesyncDetectionSystem, DesyncDetectionSystemDesc},
+    game_checksum_system::{GameChecksumSystem, GameChecksumSystemDesc},
+};
+
+mod desync_detection_system;
+mod game_checksum_system;
@@ -0,0 +1,98 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use desync_model::play::{GameChecksum, GameChecksums};
+use log::error;
+use net_model::play::{NetData, NetEventChannel};
+use network_session_model::play::{SessionCode, SessionDeviceId, SessionDevices};
+use state_registry::{StateId, StateIdUpdateEvent};
+
+/// Compares game play checksums from other session devices with local checksums.
+///
+/// The first divergent frame and entity is logged for each round of game play.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(DesyncDetectionSystemDesc))]
+pub struct DesyncDetectionSystem {
+    /// Reader ID for the `GameChecksum` channel.
+    #[system_desc(event_channel_reader)]
+    game_checksum_rid: ReaderId<NetData<GameChecksum>>,
+    /// Reader ID for the `StateIdUpdateEvent` channel.
+    #[system_desc(event_channel_reader)]
+    state_id_update_event_rid: ReaderId<StateIdUpdateEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct DesyncDetectionSystemData<'s> {
+    /// `GameChecksum` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_checksum_nec: Read<'s, NetEventChannel<GameChecksum>>,
+    /// `StateIdUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub state_id_update_ec: Read<'s, EventChannel<StateIdUpdateEvent>>,
+    /// `SessionCode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code: Read<'s, SessionCode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `SessionDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_devices: Read<'s, SessionDevices>,
+    /// `GameChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_checksums: Write<'s, GameChecksums>,
+}
+
+impl<'s> System<'s> for DesyncDetectionSystem {
+    type SystemData = DesyncDetectionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        DesyncDetectionSystemData {
+            game_checksum_nec,
+            state_id_update_ec,
+            session_code,
+            session_device_id,
+            session_devices,
+            mut game_checksums,
+        }: Self::SystemData,
+    ) {
+        // Checksums from other devices may arrive before this device has entered game play, so we
+        // only clear them when leaving game play.
+        let game_play_exited = state_id_update_ec
+            .read(&mut self.state_id_update_event_rid)
+            .fold(false, |game_play_exited, ev| {
+                game_play_exited || ev.state_id_prev == Some(StateId::GamePlay)
+            });
+        if game_play_exited {
+            game_checksums.clear();
+        }
+
+        game_checksum_nec
+            .read(&mut self.game_checksum_rid)
+            .for_each(|net_game_checksum| {
+                game_checksums.insert_remote(net_game_checksum.data.clone());
+            });
+
+        let remote_device_count = session_devices
+            .iter()
+            .filter(|session_device| session_device.id != *session_device_id)
+            .count();
+        if let Some(game_desync) = game_checksums.desync_detect(remote_device_count) {
+            error!(
+                "Game play desynchronized in session `{}` at frame {}: \
+                 entity {} differs from device `{}`.",
+                *session_code,
+                game_desync.frame_number,
+                game_desync.entity_id,
+                game_desync.session_device_id
+            );
+        }
+    }
+}
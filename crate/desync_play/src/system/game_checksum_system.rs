@@ -0,0 +1,90 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use network_input_model::play::{FrameNumber, LockstepFrameNumbers};
+use network_session_model::{config::SessionServerConfig, play::SessionStatus};
+use state_registry::StateId;
+
+use crate::{GameChecksumRecordResources, GameChecksumRecorder, GameChecksumResources};
+
+/// Calculates and sends game play checksums every `checksum_interval` frames during lockstep play.
+///
+/// This must run before game play is stepped, so that the checksums are of the game play state at
+/// the beginning of the frame. When rollback is enabled, checksums are calculated by the
+/// `GamePlayState` once a frame's input is confirmed.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GameChecksumSystemDesc))]
+pub struct GameChecksumSystem {
+    /// Frame that checksums were last calculated for.
+    #[new(default)]
+    #[system_desc(skip)]
+    frame_number_last: Option<FrameNumber>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameChecksumSystemData<'s> {
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: Read<'s, SessionServerConfig>,
+    /// `LockstepFrameNumbers` resource.
+    #[derivative(Debug = "ignore")]
+    pub lockstep_frame_numbers: Read<'s, LockstepFrameNumbers>,
+    /// `GameChecksumResources`.
+    pub game_checksum_resources: GameChecksumResources<'s>,
+    /// `GameChecksumRecordResources`.
+    pub game_checksum_record_resources: GameChecksumRecordResources<'s>,
+}
+
+impl<'s> System<'s> for GameChecksumSystem {
+    type SystemData = GameChecksumSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameChecksumSystemData {
+            session_status,
+            state_id,
+            session_server_config,
+            lockstep_frame_numbers,
+            game_checksum_resources,
+            mut game_checksum_record_resources,
+        }: Self::SystemData,
+    ) {
+        let in_session = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if !in_session || *state_id != StateId::GamePlay {
+            self.frame_number_last = None;
+            return;
+        }
+        if session_server_config.rollback_frames > 0 {
+            return;
+        }
+
+        let frame_number = lockstep_frame_numbers.step;
+        if self.frame_number_last != Some(frame_number)
+            && GameChecksumRecorder::is_checksum_frame(
+                frame_number,
+                session_server_config.checksum_interval,
+            )
+        {
+            let entity_checksums = GameChecksumRecorder::calculate(&game_checksum_resources);
+            GameChecksumRecorder::record(
+                &mut game_checksum_record_resources,
+                frame_number,
+                entity_checksums,
+            );
+
+            self.frame_number_last = Some(frame_number);
+        }
+    }
+}
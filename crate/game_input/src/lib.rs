@@ -4,7 +4,9 @@
 
 pub use crate::system::{
     ControllerInputUpdateSystem, GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
-    InputToGameInputSystem, InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
+    GamepadAssignmentSystem, GamepadAssignmentSystemData, GamepadAssignmentSystemDesc,
+    InputHistoryUpdateSystem, InputHistoryUpdateSystemData, InputToGameInputSystem,
+    InputToGameInputSystemDesc, SharedControllerInputUpdateSystem,
 };
 
 mod system;
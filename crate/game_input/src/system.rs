@@ -3,11 +3,17 @@ pub use self::{
     game_input_to_control_input_system::{
         GameInputToControlInputSystem, GameInputToControlInputSystemDesc,
     },
+    gamepad_assignment_system::{
+        GamepadAssignmentSystem, GamepadAssignmentSystemData, GamepadAssignmentSystemDesc,
+    },
+    input_history_update_system::{InputHistoryUpdateSystem, InputHistoryUpdateSystemData},
     input_to_game_input_system::{InputToGameInputSystem, InputToGameInputSystemDesc},
     shared_controller_input_update_system::SharedControllerInputUpdateSystem,
 };
 
 mod controller_input_update_system;
 mod game_input_to_control_input_system;
+mod gamepad_assignment_system;
+mod input_history_update_system;
 mod input_to_game_input_system;
 mod shared_controller_input_update_system;
@@ -48,6 +48,81 @@ pub struct GameInputToControlInputSystemData<'s> {
     pub control_game_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
 }
 
+impl GameInputToControlInputSystem {
+    /// Appends the `ControlInputEvent`s for the entities controlled by a `GameInputEvent`.
+    ///
+    /// The events are for entities whose `InputControlled` controller ID matches the event's
+    /// player, as well as every entity with `SharedInputControlled`.
+    ///
+    /// # Parameters
+    ///
+    /// * `entities`: `Entities` resource.
+    /// * `input_controlleds`: `InputControlled` components.
+    /// * `shared_input_controlleds`: `SharedInputControlled` components.
+    /// * `game_input_event`: Event to map.
+    /// * `control_input_events`: `Vec` to append the `ControlInputEvent`s to.
+    pub fn control_input_events(
+        entities: &Entities<'_>,
+        input_controlleds: &ReadStorage<'_, InputControlled>,
+        shared_input_controlleds: &ReadStorage<'_, SharedInputControlled>,
+        game_input_event: GameInputEvent,
+        control_input_events: &mut Vec<ControlInputEvent>,
+    ) {
+        let player = match game_input_event {
+            GameInputEvent::ActionPressed(PlayerActionControl { player, .. })
+            | GameInputEvent::ActionReleased(PlayerActionControl { player, .. })
+            | GameInputEvent::AxisMoved {
+                axis: PlayerAxisControl { player, .. },
+                ..
+            } => player,
+        };
+
+        // Find the entities that have the `player` control id in their `InputControlled`
+        // component.
+        let shared_input_controlled_entities = (entities, shared_input_controlleds)
+            .join()
+            .map(|(entity, _)| entity);
+
+        let control_input_events_iter = (entities, input_controlleds)
+            .join()
+            .filter_map(|(entity, input_controlled)| {
+                if input_controlled.controller_id == player {
+                    Some(entity)
+                } else {
+                    None
+                }
+            })
+            .chain(shared_input_controlled_entities)
+            .map(|entity| match game_input_event {
+                GameInputEvent::ActionPressed(PlayerActionControl { player, action }) => {
+                    ControlInputEvent::ControlActionPress(ControlActionEventData {
+                        controller_id: player,
+                        entity,
+                        control_action: action,
+                    })
+                }
+                GameInputEvent::ActionReleased(PlayerActionControl { player, action }) => {
+                    ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                        controller_id: player,
+                        entity,
+                        control_action: action,
+                    })
+                }
+                GameInputEvent::AxisMoved {
+                    axis: PlayerAxisControl { player, axis },
+                    value,
+                } => ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id: player,
+                    entity,
+                    axis,
+                    value,
+                }),
+            });
+
+        control_input_events.extend(control_input_events_iter);
+    }
+}
+
 impl<'s> System<'s> for GameInputToControlInputSystem {
     type SystemData = GameInputToControlInputSystemData<'s>;
 
@@ -64,93 +139,13 @@ impl<'s> System<'s> for GameInputToControlInputSystem {
         game_input_ec
             .read(&mut self.input_event_rid)
             .for_each(|ev| {
-                match *ev {
-                    GameInputEvent::ActionPressed(PlayerActionControl { player, action }) => {
-                        // Find the entity has the `player` control id in its `InputControlled`
-                        // component.
-
-                        let shared_input_controlled_entities =
-                            (&entities, &shared_input_controlleds)
-                                .join()
-                                .map(|(entity, _)| entity);
-
-                        let control_input_events_iter = (&entities, &input_controlleds)
-                            .join()
-                            .filter_map(|(entity, input_controlled)| {
-                                if input_controlled.controller_id == player {
-                                    Some(entity)
-                                } else {
-                                    None
-                                }
-                            })
-                            .chain(shared_input_controlled_entities)
-                            .map(|entity| {
-                                ControlInputEvent::ControlActionPress(ControlActionEventData {
-                                    controller_id: player,
-                                    entity,
-                                    control_action: action,
-                                })
-                            });
-
-                        self.control_input_events.extend(control_input_events_iter);
-                    }
-                    GameInputEvent::ActionReleased(PlayerActionControl { player, action }) => {
-                        let shared_input_controlled_entities =
-                            (&entities, &shared_input_controlleds)
-                                .join()
-                                .map(|(entity, _)| entity);
-
-                        let control_input_events_iter = (&entities, &input_controlleds)
-                            .join()
-                            .filter_map(|(entity, input_controlled)| {
-                                if input_controlled.controller_id == player {
-                                    Some(entity)
-                                } else {
-                                    None
-                                }
-                            })
-                            .chain(shared_input_controlled_entities)
-                            .map(|entity| {
-                                ControlInputEvent::ControlActionRelease(ControlActionEventData {
-                                    controller_id: player,
-                                    entity,
-                                    control_action: action,
-                                })
-                            });
-
-                        self.control_input_events.extend(control_input_events_iter);
-                    }
-                    GameInputEvent::AxisMoved {
-                        axis: PlayerAxisControl { player, axis },
-                        value,
-                    } => {
-                        let shared_input_controlled_entities =
-                            (&entities, &shared_input_controlleds)
-                                .join()
-                                .map(|(entity, _)| entity);
-
-                        let control_input_events_iter = (&entities, &input_controlleds)
-                            .join()
-                            .filter_map(|(entity, input_controlled)| {
-                                if input_controlled.controller_id == player {
-                                    Some(entity)
-                                } else {
-                                    None
-                                }
-                            })
-                            .chain(shared_input_controlled_entities)
-                            .map(|entity| {
-                                ControlInputEvent::AxisMoved(AxisMoveEventData {
-                                    controller_id: player,
-                                    entity,
-                                    axis,
-                                    value,
-                                })
-                            });
-
-                        self.control_input_events.extend(control_input_events_iter);
-                    }
-                }
+                Self::control_input_events(
+                    &entities,
+                    &input_controlleds,
+                    &shared_input_controlleds,
+                    *ev,
+                    &mut self.control_input_events,
+                );
             });
 
         control_game_input_ec.drain_vec_write(&mut self.control_input_events);
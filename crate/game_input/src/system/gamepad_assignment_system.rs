@@ -0,0 +1,101 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    input::{InputEvent, InputHandler},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{ControlBindings, PlayerInputConfigs},
+    play::{ControllerIdOffset, GamepadAssignments},
+};
+use log::{error, info, warn};
+
+/// Assigns connected gamepads to controllers, and updates input `Bindings` to match.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamepadAssignmentSystemDesc))]
+pub struct GamepadAssignmentSystem {
+    /// Reader ID for the `InputEvent<ControlBindings>` channel.
+    #[system_desc(event_channel_reader)]
+    input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+}
+
+/// `GamepadAssignmentSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamepadAssignmentSystemData<'s> {
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Read<'s, PlayerInputConfigs>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Write<'s, GamepadAssignments>,
+    /// `InputHandler<ControlBindings>` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_handler: Write<'s, InputHandler<ControlBindings>>,
+}
+
+impl<'s> System<'s> for GamepadAssignmentSystem {
+    type SystemData = GamepadAssignmentSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamepadAssignmentSystemData {
+            input_ec,
+            player_input_configs,
+            controller_id_offset,
+            mut gamepad_assignments,
+            mut input_handler,
+        }: Self::SystemData,
+    ) {
+        let assignments_changed =
+            input_ec
+                .read(&mut self.input_event_rid)
+                .fold(false, |changed, ev| match ev {
+                    InputEvent::ControllerConnected { which } => {
+                        match gamepad_assignments.assign(&player_input_configs, *which) {
+                            Some(controller_id) => {
+                                info!(
+                                    "Gamepad `{}` assigned to controller `{}`.",
+                                    which, controller_id
+                                );
+                                true
+                            }
+                            None => {
+                                warn!("No free controller for gamepad `{}`.", which);
+                                changed
+                            }
+                        }
+                    }
+                    InputEvent::ControllerDisconnected { which } => {
+                        if let Some(controller_id) = gamepad_assignments.unassign(*which) {
+                            info!(
+                                "Gamepad `{}` unassigned from controller `{}`.",
+                                which, controller_id
+                            );
+                            true
+                        } else {
+                            changed
+                        }
+                    }
+                    _ => changed,
+                });
+
+        if assignments_changed {
+            match player_input_configs
+                .generate_bindings(*controller_id_offset, &gamepad_assignments)
+            {
+                Ok(bindings) => input_handler.bindings = bindings,
+                Err(e) => error!("Failed to update input `Bindings`. Error: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use amethyst::{
+    ecs::{Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, InputCommand},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, InputHistory},
+};
+use mirrored_model::play::Mirrored;
+
+/// Records `ControlInputEvent`s as `InputCommand`s in each entity's `InputHistory`.
+///
+/// Axis moves to zero and button releases are not recorded.
+#[derive(Debug, Default, new)]
+pub struct InputHistoryUpdateSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+}
+
+/// `InputHistoryUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct InputHistoryUpdateSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `InputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub input_histories: WriteStorage<'s, InputHistory>,
+}
+
+impl InputHistoryUpdateSystem {
+    /// Returns the `InputCommand` for the event, if it should be recorded.
+    fn input_command(
+        mirroreds: &ReadStorage<'_, Mirrored>,
+        ev: ControlInputEvent,
+    ) -> Option<(Entity, InputCommand)> {
+        match ev {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                entity,
+                axis: Axis::X,
+                value,
+                ..
+            }) => {
+                let mirrored = mirroreds.get(entity).map_or(false, |mirrored| **mirrored);
+                let input_command = if value > 0. {
+                    Some(if mirrored {
+                        InputCommand::Back
+                    } else {
+                        InputCommand::Forward
+                    })
+                } else if value < 0. {
+                    Some(if mirrored {
+                        InputCommand::Forward
+                    } else {
+                        InputCommand::Back
+                    })
+                } else {
+                    None
+                };
+                input_command.map(|input_command| (entity, input_command))
+            }
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                entity,
+                axis: Axis::Z,
+                value,
+                ..
+            }) => {
+                let input_command = if value < 0. {
+                    Some(InputCommand::Up)
+                } else if value > 0. {
+                    Some(InputCommand::Down)
+                } else {
+                    None
+                };
+                input_command.map(|input_command| (entity, input_command))
+            }
+            ControlInputEvent::ControlActionPress(ControlActionEventData {
+                entity,
+                control_action,
+                ..
+            }) => Some((entity, InputCommand::from(control_action))),
+            ControlInputEvent::ControlActionRelease(..) => None,
+        }
+    }
+}
+
+impl<'s> System<'s> for InputHistoryUpdateSystem {
+    type SystemData = InputHistoryUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        InputHistoryUpdateSystemData {
+            control_input_ec,
+            mirroreds,
+            mut input_histories,
+        }: Self::SystemData,
+    ) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+
+        (&mut input_histories)
+            .join()
+            .for_each(|input_history| input_history.tick());
+
+        control_input_ec
+            .read(control_input_event_rid)
+            .filter_map(|ev| Self::input_command(&mirroreds, *ev))
+            .for_each(|(entity, input_command)| {
+                if let Ok(entry) = input_histories.entry(entity) {
+                    entry
+                        .or_insert_with(InputHistory::default)
+                        .push(input_command);
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
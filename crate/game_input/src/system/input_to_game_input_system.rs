@@ -9,8 +9,13 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::{config::ControlBindings, GameInputEvent};
+use game_input_model::{
+    config::{ControlBindings, PlayerInputConfigs},
+    play::{AxisDigitizer, ControllerIdOffset, GamepadAssignments},
+    GameInputEvent,
+};
 use network_session_model::play::SessionStatus;
+use replay_model::play::ReplayMode;
 
 /// Sends `GameInputEvent`s based on a subset of `InputEvent`s.
 #[derive(Debug, SystemDesc, new)]
@@ -19,6 +24,10 @@ pub struct InputToGameInputSystem {
     /// Reader ID for the `InputEvent` channel.
     #[system_desc(event_channel_reader)]
     input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Rounds analog gamepad axis values.
+    #[new(default)]
+    #[system_desc(skip)]
+    axis_digitizer: AxisDigitizer,
 }
 
 #[derive(Derivative, SystemData)]
@@ -27,9 +36,21 @@ pub struct InputToGameInputSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
     /// `InputEvent<ControlBindings>` channel.
     #[derivative(Debug = "ignore")]
     pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Read<'s, PlayerInputConfigs>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Read<'s, GamepadAssignments>,
     /// `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
@@ -42,7 +63,11 @@ impl<'s> System<'s> for InputToGameInputSystem {
         &mut self,
         InputToGameInputSystemData {
             session_status,
+            replay_mode,
             input_ec,
+            player_input_configs,
+            controller_id_offset,
+            gamepad_assignments,
             mut game_input_ec,
         }: Self::SystemData,
     ) {
@@ -50,11 +75,28 @@ impl<'s> System<'s> for InputToGameInputSystem {
 
         let session_status = &*session_status;
 
+        // During replay playback, the recorded input replaces device input.
+        let is_replay_playback = if let ReplayMode::Playback(..) = &*replay_mode {
+            true
+        } else {
+            false
+        };
+
         if session_status != &SessionStatus::HostEstablished
             && session_status != &SessionStatus::JoinEstablished
+            && !is_replay_playback
         {
+            let axis_digitizer = &mut self.axis_digitizer;
             input_events
                 .filter_map(|input_event| GameInputEvent::try_from(input_event).ok())
+                .filter_map(|game_input_event| {
+                    axis_digitizer.digitize(
+                        &player_input_configs,
+                        *controller_id_offset,
+                        &gamepad_assignments,
+                        game_input_event,
+                    )
+                })
                 .for_each(|game_input_event| game_input_ec.single_write(game_input_event));
         }
     }
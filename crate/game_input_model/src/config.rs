@@ -1,12 +1,24 @@
 //! Contains the types that represent the configuration on disk.
 
 pub use self::{
-    axis::Axis, control_action::ControlAction, control_args::ControlArgs,
-    control_bindings::ControlBindings, control_input_event_args::ControlInputEventArgs,
-    controller_config::ControllerConfig, controller_id::ControllerId,
-    input_direction::InputDirection, input_direction_z::InputDirectionZ,
-    player_action_control::PlayerActionControl, player_axis_control::PlayerAxisControl,
-    player_input_config::PlayerInputConfig, player_input_configs::PlayerInputConfigs,
+    axis::Axis,
+    control_action::ControlAction,
+    control_args::ControlArgs,
+    control_bindings::ControlBindings,
+    control_input_event_args::ControlInputEventArgs,
+    controller_config::ControllerConfig,
+    controller_id::ControllerId,
+    gamepad_axis::GamepadAxis,
+    gamepad_config::{GamepadConfig, GAMEPAD_DEAD_ZONE_DEFAULT, GAMEPAD_THRESHOLD_DEFAULT},
+    gamepad_id::GamepadId,
+    input_command::InputCommand,
+    input_command_sequence::{InputCommandSequence, INPUT_COMMAND_WINDOW_DEFAULT},
+    input_direction::InputDirection,
+    input_direction_z::InputDirectionZ,
+    player_action_control::PlayerActionControl,
+    player_axis_control::PlayerAxisControl,
+    player_input_config::PlayerInputConfig,
+    player_input_configs::PlayerInputConfigs,
 };
 
 mod axis;
@@ -16,6 +28,11 @@ mod control_bindings;
 mod control_input_event_args;
 mod controller_config;
 mod controller_id;
+mod gamepad_axis;
+mod gamepad_config;
+mod gamepad_id;
+mod input_command;
+mod input_command_sequence;
 mod input_direction;
 mod input_direction_z;
 mod player_action_control;
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
-use crate::config::{ControlArgs, ControllerId};
+use crate::{
+    config::{ControlArgs, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+};
 
 /// Parameters to the mapper.
 ///
@@ -20,3 +23,40 @@ pub struct ControlInputEventArgs {
     #[structopt(subcommand)]
     pub control: ControlArgs,
 }
+
+impl From<ControlInputEvent> for ControlInputEventArgs {
+    /// Returns the arguments that map to the event, without the entity it applies to.
+    fn from(control_input_event: ControlInputEvent) -> Self {
+        match control_input_event {
+            ControlInputEvent::AxisMoved(AxisMoveEventData {
+                controller_id,
+                axis,
+                value,
+                ..
+            }) => ControlInputEventArgs {
+                controller_id,
+                control: ControlArgs::Axis { axis, value },
+            },
+            ControlInputEvent::ControlActionPress(ControlActionEventData {
+                controller_id,
+                control_action,
+                ..
+            }) => ControlInputEventArgs {
+                controller_id,
+                control: ControlArgs::ActionPressed {
+                    action: control_action,
+                },
+            },
+            ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                controller_id,
+                control_action,
+                ..
+            }) => ControlInputEventArgs {
+                controller_id,
+                control: ControlArgs::ActionReleased {
+                    action: control_action,
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use amethyst::input::ControllerAxis;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Analog axis on a gamepad.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct GamepadAxis {
+    /// Axis on the gamepad.
+    pub axis: ControllerAxis,
+    /// Whether the axis value should be inverted.
+    #[serde(default)]
+    pub invert: bool,
+}
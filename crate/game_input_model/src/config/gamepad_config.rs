@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use amethyst::input::{Axis as InputAxis, Button, ControllerButton};
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Axis, ControlAction, GamepadAxis, GamepadId};
+
+/// Default dead zone for analog axes.
+pub const GAMEPAD_DEAD_ZONE_DEFAULT: f64 = 0.15;
+/// Default threshold for analog axes to be treated as fully pressed.
+pub const GAMEPAD_THRESHOLD_DEFAULT: f32 = 0.5;
+
+/// Gamepad bindings for a player.
+///
+/// The gamepad ID is not configured, as gamepads are assigned to players when they are connected.
+#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct GamepadConfig {
+    /// Axis control configuration.
+    #[serde(default)]
+    pub axes: HashMap<Axis, GamepadAxis>,
+    /// Action control configuration.
+    #[serde(default)]
+    pub actions: HashMap<ControlAction, ControllerButton>,
+    /// Analog axis values within this distance from `0.0` are ignored.
+    #[derivative(Default(value = "GAMEPAD_DEAD_ZONE_DEFAULT"))]
+    #[serde(default = "GamepadConfig::dead_zone_default")]
+    pub dead_zone: f64,
+    /// Analog axis values at least this distance from `0.0` are treated as fully pressed.
+    #[derivative(Default(value = "GAMEPAD_THRESHOLD_DEFAULT"))]
+    #[serde(default = "GamepadConfig::threshold_default")]
+    pub threshold: f32,
+}
+
+impl GamepadConfig {
+    fn dead_zone_default() -> f64 {
+        GAMEPAD_DEAD_ZONE_DEFAULT
+    }
+
+    fn threshold_default() -> f32 {
+        GAMEPAD_THRESHOLD_DEFAULT
+    }
+
+    /// Returns the `InputAxis` for an `Axis` on the given gamepad, if configured.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the connected gamepad.
+    /// * `axis`: Control axis to look up.
+    pub fn input_axis(&self, gamepad_id: GamepadId, axis: Axis) -> Option<InputAxis> {
+        self.axes.get(&axis).map(|gamepad_axis| InputAxis::Controller {
+            controller_id: gamepad_id,
+            axis: gamepad_axis.axis,
+            invert: gamepad_axis.invert,
+            dead_zone: self.dead_zone,
+        })
+    }
+
+    /// Returns the `Button` for a `ControlAction` on the given gamepad, if configured.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the connected gamepad.
+    /// * `control_action`: Control action to look up.
+    pub fn button(&self, gamepad_id: GamepadId, control_action: ControlAction) -> Option<Button> {
+        self.actions
+            .get(&control_action)
+            .map(|controller_button| Button::Controller(gamepad_id, *controller_button))
+    }
+
+    /// Returns the digital value of an analog axis value.
+    ///
+    /// Values at least `threshold` away from `0.0` are rounded to `-1.0` or `1.0`, and other
+    /// values are treated as `0.0`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: Analog axis value.
+    pub fn axis_value_digital(&self, value: f32) -> f32 {
+        if value.abs() >= self.threshold {
+            value.signum()
+        } else {
+            0.
+        }
+    }
+}
@@ -0,0 +1,5 @@
+/// Type for a connected gamepad's ID.
+///
+/// This is the ID that `amethyst::input` assigns to each connected controller, which is distinct
+/// from the player `ControllerId`.
+pub type GamepadId = u32;
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::ControlAction;
+
+/// Input that is part of an `InputCommandSequence`.
+///
+/// `Forward` and `Back` are relative to the direction the object is facing when the input is
+/// entered.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum InputCommand {
+    /// X axis input in the direction the object is facing.
+    Forward,
+    /// X axis input opposite to the direction the object is facing.
+    Back,
+    /// Z axis input upwards.
+    Up,
+    /// Z axis input downwards.
+    Down,
+    /// Defend button press.
+    Defend,
+    /// Jump button press.
+    Jump,
+    /// Attack button press.
+    Attack,
+    /// Special button press.
+    Special,
+}
+
+impl From<ControlAction> for InputCommand {
+    fn from(control_action: ControlAction) -> Self {
+        match control_action {
+            ControlAction::Defend => InputCommand::Defend,
+            ControlAction::Jump => InputCommand::Jump,
+            ControlAction::Attack => InputCommand::Attack,
+            ControlAction::Special => InputCommand::Special,
+        }
+    }
+}
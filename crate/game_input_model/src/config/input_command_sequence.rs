@@ -0,0 +1,22 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::InputCommand;
+
+/// Default number of ticks within which an `InputCommandSequence` must be entered.
+pub const INPUT_COMMAND_WINDOW_DEFAULT: u32 = 20;
+
+/// `InputCommand`s that must be entered in order, within a number of ticks.
+///
+/// For example, LF2's `D>A` is `[defend, forward, attack]`.
+#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct InputCommandSequence {
+    /// Commands to enter, from first to last.
+    pub commands: Vec<InputCommand>,
+    /// Maximum number of ticks from the first command until the sequence is checked.
+    #[derivative(Default(value = "INPUT_COMMAND_WINDOW_DEFAULT"))]
+    pub window: u32,
+}
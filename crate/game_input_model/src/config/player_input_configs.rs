@@ -1,4 +1,4 @@
-use std::{convert::TryFrom, iter};
+use std::{convert::TryFrom, iter, mem};
 
 use amethyst::{
     error::{format_err, ResultExt},
@@ -9,9 +9,14 @@ use derive_deref::{Deref, DerefMut};
 use derive_new::new;
 use log::error;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
 use crate::{
-    config::{ControlBindings, PlayerActionControl, PlayerAxisControl, PlayerInputConfig},
+    config::{
+        Axis, ControlAction, ControlBindings, ControllerId, PlayerActionControl, PlayerAxisControl,
+        PlayerInputConfig,
+    },
+    loaded::{ControlAxis, ControlButton},
     play::ControllerIdOffset,
 };
 
@@ -25,12 +30,17 @@ impl PlayerInputConfigs {
     /// The `ControllerIdOffset` is used when local controllers should start with a higher index as
     /// remote controllers may use the lower indices.
     ///
+    /// Gamepads in the `GamepadAssignments` are bound alongside each controller's keyboard
+    /// configuration.
+    ///
     /// # Parameters
     ///
     /// * `controller_id_offset`: The offset for controller IDs.
+    /// * `gamepad_assignments`: Gamepads assigned to each controller.
     pub fn generate_bindings(
         &self,
         controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
     ) -> Result<Bindings<ControlBindings>, Error> {
         let mut bindings = Bindings::new();
 
@@ -41,15 +51,29 @@ impl PlayerInputConfigs {
             // The enumeration index is used as the controller ID
             .flat_map(|(index, player_input_config)| {
                 let controller_id = index + controller_id_offset.0;
-                player_input_config
-                    .controller_config
-                    .axes
-                    .iter()
-                    .map(|(&axis, input_axis)| {
-                        (
-                            PlayerAxisControl::new(controller_id, axis),
-                            input_axis.clone(),
-                        )
+                let controller_config = &player_input_config.controller_config;
+                let gamepad = Self::gamepad(gamepad_assignments, index, player_input_config);
+                Axis::iter()
+                    .filter_map(|axis| {
+                        let input_axis_keyboard = controller_config.axes.get(&axis).cloned();
+                        let input_axis_gamepad =
+                            gamepad.and_then(|(gamepad_id, gamepad_config)| {
+                                gamepad_config.input_axis(gamepad_id, axis)
+                            });
+                        let input_axis = match (input_axis_keyboard, input_axis_gamepad) {
+                            (Some(input_axis_keyboard), Some(input_axis_gamepad)) => {
+                                Some(InputAxis::Multiple(vec![
+                                    input_axis_keyboard,
+                                    input_axis_gamepad,
+                                ]))
+                            }
+                            (input_axis_keyboard, None) => input_axis_keyboard,
+                            (None, input_axis_gamepad) => input_axis_gamepad,
+                        };
+
+                        input_axis.map(|input_axis| {
+                            (PlayerAxisControl::new(controller_id, axis), input_axis)
+                        })
                     })
                     .collect::<Vec<(PlayerAxisControl, InputAxis)>>()
             })
@@ -75,12 +99,31 @@ impl PlayerInputConfigs {
             // The enumeration index is used as the controller ID
             .flat_map(|(index, player_input_config)| {
                 let controller_id = index + controller_id_offset.0;
+                let gamepad = Self::gamepad(gamepad_assignments, index, player_input_config);
+                let gamepad_buttons =
+                    gamepad
+                        .into_iter()
+                        .flat_map(|(gamepad_id, gamepad_config)| {
+                            gamepad_config
+                                .actions
+                                .keys()
+                                .filter_map(move |&control_action| {
+                                    gamepad_config
+                                        .button(gamepad_id, control_action)
+                                        .map(|input_button| (control_action, input_button))
+                                })
+                        });
                 player_input_config
                     .controller_config
                     .actions
                     .iter()
-                    .map(|(&axis, input_button)| {
-                        (PlayerActionControl::new(controller_id, axis), *input_button)
+                    .map(|(&control_action, input_button)| (control_action, *input_button))
+                    .chain(gamepad_buttons)
+                    .map(|(control_action, input_button)| {
+                        (
+                            PlayerActionControl::new(controller_id, control_action),
+                            input_button,
+                        )
                     })
                     .collect::<Vec<(PlayerActionControl, Button)>>()
             })
@@ -108,6 +151,136 @@ impl PlayerInputConfigs {
         }
         axis_result.and(action_result).map(|_| bindings)
     }
+
+    /// Returns the gamepad ID and configuration for a controller, if a gamepad is assigned.
+    fn gamepad<'f>(
+        gamepad_assignments: &GamepadAssignments,
+        index: usize,
+        player_input_config: &'f PlayerInputConfig,
+    ) -> Option<(GamepadId, &'f GamepadConfig)> {
+        let gamepad_id = gamepad_assignments.get(&index).copied()?;
+        player_input_config
+            .controller_config
+            .gamepad
+            .as_ref()
+            .map(|gamepad_config| (gamepad_id, gamepad_config))
+    }
+
+    /// Returns the `Button` bound to a controller's control button, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `controller_id`: ID of the controller.
+    /// * `control_button`: Logical control button to look up.
+    pub fn button(
+        &self,
+        controller_id: ControllerId,
+        control_button: ControlButton,
+    ) -> Option<Button> {
+        let controller_config = &self.get(controller_id)?.controller_config;
+        match control_button {
+            ControlButton::Axis(control_axis) => {
+                let (axis, positive) = Self::axis_direction(control_axis);
+                if let InputAxis::Emulated { pos, neg } = controller_config.axes.get(&axis)? {
+                    Some(if positive { *pos } else { *neg })
+                } else {
+                    None
+                }
+            }
+            ControlButton::Action(control_action) => {
+                controller_config.actions.get(&control_action).copied()
+            }
+        }
+    }
+
+    /// Returns the controller ID and control button that a `Button` is bound to, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `button`: The `Button` to look up.
+    pub fn control_button(&self, button: Button) -> Option<(ControllerId, ControlButton)> {
+        (0..self.len()).find_map(|controller_id| {
+            ControlAxis::iter()
+                .map(ControlButton::Axis)
+                .chain(ControlAction::iter().map(ControlButton::Action))
+                .find(|control_button| self.button(controller_id, *control_button) == Some(button))
+                .map(|control_button| (controller_id, control_button))
+        })
+    }
+
+    /// Binds a `Button` to a controller's control button.
+    ///
+    /// If the `Button` is already bound to a different control button, that control button is
+    /// bound to the `Button` that was replaced, so that no `Button` is bound twice.
+    ///
+    /// Returns the conflicting controller ID and control button, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `controller_id`: ID of the controller.
+    /// * `control_button`: Logical control button to bind.
+    /// * `button`: The `Button` to bind to the control button.
+    pub fn rebind(
+        &mut self,
+        controller_id: ControllerId,
+        control_button: ControlButton,
+        button: Button,
+    ) -> Result<Option<(ControllerId, ControlButton)>, Error> {
+        let conflict = self
+            .control_button(button)
+            .filter(|binding| *binding != (controller_id, control_button));
+
+        let button_prev = self
+            .button_mut(controller_id, control_button)
+            .map(|button_existing| mem::replace(button_existing, button))
+            .ok_or_else(|| {
+                Error::from_string(format!(
+                    "`{:?}` is not rebindable for controller `{}`.",
+                    control_button, controller_id
+                ))
+            })?;
+
+        if let Some((controller_id_conflict, control_button_conflict)) = conflict {
+            if let Some(button_conflict) =
+                self.button_mut(controller_id_conflict, control_button_conflict)
+            {
+                *button_conflict = button_prev;
+            }
+        }
+
+        Ok(conflict)
+    }
+
+    fn button_mut(
+        &mut self,
+        controller_id: ControllerId,
+        control_button: ControlButton,
+    ) -> Option<&mut Button> {
+        let controller_config = &mut self.get_mut(controller_id)?.controller_config;
+        match control_button {
+            ControlButton::Axis(control_axis) => {
+                let (axis, positive) = Self::axis_direction(control_axis);
+                if let InputAxis::Emulated { pos, neg } = controller_config.axes.get_mut(&axis)? {
+                    Some(if positive { pos } else { neg })
+                } else {
+                    None
+                }
+            }
+            ControlButton::Action(control_action) => {
+                controller_config.actions.get_mut(&control_action)
+            }
+        }
+    }
+
+    /// Returns the `Axis` and whether the `ControlAxis` is its positive direction.
+    fn axis_direction(control_axis: ControlAxis) -> (Axis, bool) {
+        match control_axis {
+            ControlAxis::Left => (Axis::X, false),
+            ControlAxis::Right => (Axis::X, true),
+            ControlAxis::Up => (Axis::Z, false),
+            ControlAxis::Down => (Axis::Z, true),
+        }
+    }
 }
 
 impl<'config> TryFrom<&'config PlayerInputConfigs> for Bindings<ControlBindings> {
@@ -116,6 +289,7 @@ impl<'config> TryFrom<&'config PlayerInputConfigs> for Bindings<ControlBindings>
     fn try_from(
         player_input_configs: &'config PlayerInputConfigs,
     ) -> Result<Bindings<ControlBindings>, Error> {
-        player_input_configs.generate_bindings(ControllerIdOffset::new(0))
+        player_input_configs
+            .generate_bindings(ControllerIdOffset::new(0), &GamepadAssignments::default())
     }
 }
@@ -1,9 +1,13 @@
+use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter, EnumString};
 
 /// Control axis input for characters.
 ///
 /// This is not used in `PlayerInputConfigs`, but as a logical representation
-#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Hash, PartialEq, Eq)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, Hash, PartialEq, Eq, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum ControlAxis {
     /// Up button.
@@ -1,9 +1,12 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{config::ControlAction, loaded::ControlAxis};
 
 /// Enum representing all possible control buttons.
 ///
 /// This is not used in `PlayerInputConfigs`, but as a logical representation
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ControlButton {
     /// `ControlAxis` button.
     Axis(ControlAxis),
@@ -1,14 +1,23 @@
 //! Contains data types used at runtime.
 
 pub use self::{
-    axis_move_event_data::AxisMoveEventData, button_input_controlled::ButtonInputControlled,
-    control_action_event_data::ControlActionEventData, control_input_event::ControlInputEvent,
-    controller_id_offset::ControllerIdOffset, controller_input::ControllerInput,
-    game_input_model_error::GameInputModelError, input_controlled::InputControlled,
-    move_direction::MoveDirection, normal_input_controlled::NormalInputControlled,
+    axis_digitizer::AxisDigitizer,
+    axis_move_event_data::AxisMoveEventData,
+    button_input_controlled::ButtonInputControlled,
+    control_action_event_data::ControlActionEventData,
+    control_input_event::ControlInputEvent,
+    controller_id_offset::ControllerIdOffset,
+    controller_input::ControllerInput,
+    game_input_model_error::GameInputModelError,
+    gamepad_assignments::GamepadAssignments,
+    input_controlled::InputControlled,
+    input_history::{InputHistory, InputHistoryEntry, INPUT_HISTORY_LIMIT},
+    move_direction::MoveDirection,
+    normal_input_controlled::NormalInputControlled,
     shared_input_controlled::SharedInputControlled,
 };
 
+mod axis_digitizer;
 mod axis_move_event_data;
 mod button_input_controlled;
 mod control_action_event_data;
@@ -16,7 +25,9 @@ mod control_input_event;
 mod controller_id_offset;
 mod controller_input;
 mod game_input_model_error;
+mod gamepad_assignments;
 mod input_controlled;
+mod input_history;
 mod move_direction;
 mod normal_input_controlled;
 mod shared_input_controlled;
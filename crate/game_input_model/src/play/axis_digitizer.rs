@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use derive_new::new;
+
+use crate::{
+    config::{PlayerAxisControl, PlayerInputConfigs},
+    play::{ControllerIdOffset, GamepadAssignments},
+    GameInputEvent,
+};
+
+/// Converts analog gamepad axis values into digital values.
+///
+/// Axis values for controllers with an assigned gamepad are rounded using the gamepad
+/// configuration's `threshold`. Axis events whose value is unchanged after rounding are dropped.
+#[derive(Clone, Debug, Default, new)]
+pub struct AxisDigitizer {
+    /// Last axis value sent for each `PlayerAxisControl`.
+    #[new(default)]
+    axis_values: HashMap<PlayerAxisControl, f32>,
+}
+
+impl AxisDigitizer {
+    /// Returns the `GameInputEvent` with a digital axis value, or `None` if the value is unchanged.
+    ///
+    /// Non-axis events are returned as is.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_input_configs`: Player input configuration.
+    /// * `controller_id_offset`: The offset for controller IDs.
+    /// * `gamepad_assignments`: Gamepads assigned to each controller.
+    /// * `game_input_event`: The event to digitize.
+    pub fn digitize(
+        &mut self,
+        player_input_configs: &PlayerInputConfigs,
+        controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
+        game_input_event: GameInputEvent,
+    ) -> Option<GameInputEvent> {
+        if let GameInputEvent::AxisMoved { axis, value } = game_input_event {
+            let gamepad_config = axis
+                .player
+                .checked_sub(controller_id_offset.0)
+                .filter(|index| gamepad_assignments.contains_key(index))
+                .and_then(|index| player_input_configs.get(index))
+                .and_then(|player_input_config| {
+                    player_input_config.controller_config.gamepad.as_ref()
+                });
+            let value = if let Some(gamepad_config) = gamepad_config {
+                gamepad_config.axis_value_digital(value)
+            } else {
+                value
+            };
+
+            let value_prev = self.axis_values.insert(axis, value);
+            if value_prev == Some(value) {
+                None
+            } else {
+                Some(GameInputEvent::AxisMoved { axis, value })
+            }
+        } else {
+            Some(game_input_event)
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::{ControllerId, GamepadId, PlayerInputConfigs};
+
+/// Gamepads assigned to each controller (`HashMap<ControllerId, GamepadId>` newtype).
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct GamepadAssignments(pub HashMap<ControllerId, GamepadId>);
+
+impl GamepadAssignments {
+    /// Assigns a gamepad to the first controller that has a gamepad configuration and no gamepad.
+    ///
+    /// Returns the controller ID that the gamepad is assigned to, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `player_input_configs`: Player input configuration.
+    /// * `gamepad_id`: ID of the connected gamepad.
+    pub fn assign(
+        &mut self,
+        player_input_configs: &PlayerInputConfigs,
+        gamepad_id: GamepadId,
+    ) -> Option<ControllerId> {
+        if let Some(controller_id) = self.controller_id(gamepad_id) {
+            return Some(controller_id);
+        }
+
+        let controller_id = player_input_configs
+            .iter()
+            .enumerate()
+            .filter(|(_, player_input_config)| {
+                player_input_config.controller_config.gamepad.is_some()
+            })
+            .map(|(controller_id, _)| controller_id)
+            .find(|controller_id| !self.contains_key(controller_id));
+
+        if let Some(controller_id) = controller_id {
+            self.insert(controller_id, gamepad_id);
+        }
+
+        controller_id
+    }
+
+    /// Removes the assignment for a gamepad, returning the controller ID it was assigned to.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the disconnected gamepad.
+    pub fn unassign(&mut self, gamepad_id: GamepadId) -> Option<ControllerId> {
+        let controller_id = self.controller_id(gamepad_id);
+        if let Some(controller_id) = controller_id {
+            self.remove(&controller_id);
+        }
+
+        controller_id
+    }
+
+    /// Returns the controller ID that a gamepad is assigned to, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `gamepad_id`: ID of the gamepad.
+    pub fn controller_id(&self, gamepad_id: GamepadId) -> Option<ControllerId> {
+        self.iter()
+            .find(|(_, gamepad_id_assigned)| **gamepad_id_assigned == gamepad_id)
+            .map(|(controller_id, _)| *controller_id)
+    }
+}
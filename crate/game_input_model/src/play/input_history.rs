@@ -0,0 +1,77 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+
+use crate::config::{InputCommand, InputCommandSequence};
+
+/// Maximum number of `InputCommand`s kept in an `InputHistory`.
+pub const INPUT_HISTORY_LIMIT: usize = 16;
+
+/// `InputCommand` entered at a particular tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct InputHistoryEntry {
+    /// Tick that the command was entered.
+    pub tick: u32,
+    /// The entered command.
+    pub command: InputCommand,
+}
+
+/// Most recent `InputCommand`s entered for an entity.
+#[derive(Clone, Debug, Default, PartialEq, Eq, new)]
+pub struct InputHistory {
+    /// Number of ticks that this history has been tracked for.
+    #[new(default)]
+    pub tick: u32,
+    /// Entered commands, from oldest to newest.
+    #[new(default)]
+    pub entries: VecDeque<InputHistoryEntry>,
+}
+
+impl Component for InputHistory {
+    type Storage = DenseVecStorage<Self>;
+}
+
+impl InputHistory {
+    /// Advances the history by one tick.
+    pub fn tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+    }
+
+    /// Records a command entered in the current tick.
+    ///
+    /// The oldest command is discarded when there are more than `INPUT_HISTORY_LIMIT` commands.
+    pub fn push(&mut self, command: InputCommand) {
+        self.entries
+            .push_back(InputHistoryEntry::new(self.tick, command));
+        if self.entries.len() > INPUT_HISTORY_LIMIT {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Returns whether the most recent commands match the sequence, within its window.
+    ///
+    /// Empty sequences always match.
+    pub fn matches(&self, input_command_sequence: &InputCommandSequence) -> bool {
+        let commands = &input_command_sequence.commands;
+        if commands.is_empty() {
+            return true;
+        }
+        if commands.len() > self.entries.len() {
+            return false;
+        }
+
+        let first_index = self.entries.len() - commands.len();
+        let within_window = self.entries.get(first_index).map_or(false, |entry| {
+            self.tick.wrapping_sub(entry.tick) <= input_command_sequence.window
+        });
+
+        within_window
+            && self
+                .entries
+                .iter()
+                .skip(first_index)
+                .zip(commands.iter())
+                .all(|(entry, command)| entry.command == *command)
+    }
+}
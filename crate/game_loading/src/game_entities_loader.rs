@@ -0,0 +1,41 @@
+use amethyst::{
+    core::SystemBundle,
+    ecs::{DispatcherBuilder, World, WorldExt},
+};
+
+use crate::{GameLoadingBundle, GameLoadingStatus};
+
+/// Spawns game entities for the current character and map selections, outside of the
+/// `GameLoadingState`.
+///
+/// This is used to restart game play with the same selections.
+#[derive(Debug)]
+pub struct GameEntitiesLoader;
+
+impl GameEntitiesLoader {
+    /// Runs the game loading systems until all game entities are spawned.
+    ///
+    /// Assets for the selected characters and map must already be loaded.
+    ///
+    /// # Parameters
+    ///
+    /// * `world`: `World` to spawn the entities in.
+    pub fn load(world: &mut World) {
+        world.write_resource::<GameLoadingStatus>().reset();
+
+        let mut dispatcher_builder = DispatcherBuilder::new();
+        GameLoadingBundle::new()
+            .build(world, &mut dispatcher_builder)
+            .expect("Failed to register `GameLoadingBundle`.");
+
+        let mut dispatcher = dispatcher_builder.build();
+        dispatcher.setup(world);
+
+        // Entities are augmented over multiple dispatches, and `LazyUpdate`s are only applied
+        // when the world is maintained.
+        while !world.read_resource::<GameLoadingStatus>().loaded() {
+            dispatcher.dispatch(world);
+            world.maintain();
+        }
+    }
+}
@@ -8,6 +8,7 @@
 
 pub use crate::{
     character_augment_status::CharacterAugmentStatus,
+    game_entities_loader::GameEntitiesLoader,
     game_loading_bundle::GameLoadingBundle,
     game_loading_state::GameLoadingState,
     game_loading_status::GameLoadingStatus,
@@ -19,6 +20,7 @@ pub use crate::{
 };
 
 mod character_augment_status;
+mod game_entities_loader;
 mod game_loading_bundle;
 mod game_loading_state;
 mod game_loading_status;
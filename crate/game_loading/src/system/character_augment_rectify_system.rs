@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     assets::PrefabData,
     ecs::{
@@ -9,7 +10,7 @@ use camera_model::play::CameraTracked;
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::InputControlled;
-use game_play_hud::{CpBarPrefab, HpBarPrefab};
+use game_play_hud::{ComboBarPrefab, ComboCountPrefab, CpBarPrefab, HpBarPrefab};
 use game_play_model::GamePlayEntity;
 use kinematic_model::config::Position;
 use map_model::loaded::AssetMapBounds;
@@ -40,6 +41,9 @@ pub struct CharacterAugmentRectifySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `CameraTracked` components.
     #[derivative(Debug = "ignore")]
     pub camera_trackeds: WriteStorage<'s, CameraTracked>,
@@ -48,8 +52,8 @@ pub struct CharacterAugmentRectifySystemData<'s> {
     pub positions: WriteStorage<'s, Position<f32>>,
     /// `LazyUpdate` resource.
     ///
-    /// This is used because both the `HpBarPrefab` and `CpBarPrefab` request `Write` access to the
-    /// same resources.
+    /// This is used because the `HpBarPrefab`, `CpBarPrefab`, `ComboBarPrefab`, and
+    /// `ComboCountPrefab` request `Write` access to the same resources.
     #[derivative(Debug = "ignore")]
     pub lazy_update: Read<'s, LazyUpdate>,
 }
@@ -90,6 +94,52 @@ impl CharacterAugmentRectifySystem {
             .insert(cp_bar_entity, GamePlayEntity)
             .expect("Failed to insert `GamePlayEntity` component.");
     }
+
+    fn combo_bar_augment(world: &World, game_object_entity: Entity) {
+        let (entities, mut combo_bar_prefab_system_data, mut game_play_entities) = world
+            .system_data::<(
+                Entities<'_>,
+                <ComboBarPrefab as PrefabData<'_>>::SystemData,
+                WriteStorage<'_, GamePlayEntity>,
+            )>();
+
+        let combo_bar_entity = entities.create();
+        let combo_bar_prefab = ComboBarPrefab::new(game_object_entity);
+        combo_bar_prefab
+            .add_to_entity(
+                combo_bar_entity,
+                &mut combo_bar_prefab_system_data,
+                &[],
+                &[],
+            )
+            .expect("`ComboBarPrefab` failed to augment entity.");
+        game_play_entities
+            .insert(combo_bar_entity, GamePlayEntity)
+            .expect("Failed to insert `GamePlayEntity` component.");
+    }
+
+    fn combo_count_augment(world: &World, game_object_entity: Entity) {
+        let (entities, mut combo_count_prefab_system_data, mut game_play_entities) = world
+            .system_data::<(
+                Entities<'_>,
+                <ComboCountPrefab as PrefabData<'_>>::SystemData,
+                WriteStorage<'_, GamePlayEntity>,
+            )>();
+
+        let combo_count_entity = entities.create();
+        let combo_count_prefab = ComboCountPrefab::new(game_object_entity);
+        combo_count_prefab
+            .add_to_entity(
+                combo_count_entity,
+                &mut combo_count_prefab_system_data,
+                &[],
+                &[],
+            )
+            .expect("`ComboCountPrefab` failed to augment entity.");
+        game_play_entities
+            .insert(combo_count_entity, GamePlayEntity)
+            .expect("Failed to insert `GamePlayEntity` component.");
+    }
 }
 
 impl<'s> System<'s> for CharacterAugmentRectifySystem {
@@ -103,6 +153,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
             map_selection,
             asset_map_bounds,
             input_controlleds,
+            ai_controlleds,
             mut camera_trackeds,
             mut positions,
             lazy_update,
@@ -138,7 +189,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
         // This `Position` moves the entity to the middle of a screen wide map.
         let position = Position::<f32>::new(width / 2., height / 2., depth / 2.);
 
-        (&entities, &input_controlleds)
+        (&entities, input_controlleds.mask() | ai_controlleds.mask())
             .join()
             .for_each(|(entity, _)| {
                 // Set character `position` based on the map.
@@ -153,6 +204,8 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
 
                 lazy_update.exec(move |world| Self::hp_bar_augment(world, entity));
                 lazy_update.exec(move |world| Self::cp_bar_augment(world, entity));
+                lazy_update.exec(move |world| Self::combo_bar_augment(world, entity));
+                lazy_update.exec(move |world| Self::combo_count_augment(world, entity));
             });
 
         game_loading_status.character_augment_status = CharacterAugmentStatus::Complete;
@@ -163,6 +216,8 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
 
         <HpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
         <CpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
+        <ComboBarPrefab as PrefabData<'_>>::SystemData::setup(world);
+        <ComboCountPrefab as PrefabData<'_>>::SystemData::setup(world);
         <WriteStorage<'_, GamePlayEntity>>::setup(world);
     }
 }
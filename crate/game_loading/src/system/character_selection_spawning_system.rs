@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Entity, Read, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -10,7 +11,8 @@ use character_selection_model::CharacterSelections;
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::InputControlled;
-use game_model::play::GameEntities;
+use game_model::play::{GameEntities, GameEntityId, GameEntityIdAllocator};
+use game_play_model::{config::MatchRules, play::Stocks};
 use object_type::ObjectType;
 use team_model::play::{IndependentCounter, Team};
 
@@ -33,6 +35,9 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `GameLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     pub game_loading_status: Write<'s, GameLoadingStatus>,
+    /// `MatchRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub match_rules: Read<'s, MatchRules>,
     /// `IndependentCounter` resource.
     #[derivative(Debug = "ignore")]
     pub independent_counter: Write<'s, IndependentCounter>,
@@ -57,12 +62,24 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: WriteStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
     /// `Team` components.
     #[derivative(Debug = "ignore")]
     pub teams: WriteStorage<'s, Team>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
     /// `GameEntities` resource.
     #[derivative(Debug = "ignore")]
     pub game_entities: Write<'s, GameEntities>,
+    /// `GameEntityIdAllocator` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_id_allocator: Write<'s, GameEntityIdAllocator>,
+    /// `GameEntityId` components.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_ids: WriteStorage<'s, GameEntityId>,
 }
 
 impl<'s> System<'s> for CharacterSelectionSpawningSystem {
@@ -74,6 +91,7 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             entities,
             character_selections,
             mut game_loading_status,
+            match_rules,
             mut independent_counter,
             asset_item_ids,
             asset_id_mappings,
@@ -82,17 +100,27 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             character_spawning_resources,
             mut character_component_storages,
             mut input_controlleds,
+            mut ai_controlleds,
             mut teams,
+            mut stockses,
             mut game_entities,
+            mut game_entity_id_allocator,
+            mut game_entity_ids,
         }: Self::SystemData,
     ) {
         if game_loading_status.character_augment_status != CharacterAugmentStatus::Prefab {
             return;
         }
 
-        let character_entities = character_selections
-            .selections
-            .iter()
+        // IDs are allocated from the start for each game, so they match on every device.
+        *game_entity_id_allocator = GameEntityIdAllocator::default();
+
+        // Selections are sorted so that characters are spawned in the same order on every device.
+        let mut selections = character_selections.selections.iter().collect::<Vec<_>>();
+        selections.sort_by_key(|(controller_id, _)| **controller_id);
+
+        let character_entities = selections
+            .into_iter()
             .map(|(controller_id, asset_id)| {
                 let asset_id = *asset_id;
                 let asset_slug = asset_id_mappings.slug(asset_id).unwrap_or_else(|| {
@@ -123,15 +151,33 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
                 item_ids
                     .insert(entity, item_id)
                     .expect("Failed to insert `ItemId` for character.");
-                input_controlleds
-                    .insert(entity, InputControlled::new(*controller_id))
-                    .expect("Failed to insert `InputControlled` for character.");
+                // Computer controlled characters do not receive input from the controller.
+                if let Some(ai_difficulty) =
+                    character_selections.cpu_difficulties.get(controller_id)
+                {
+                    ai_controlleds
+                        .insert(entity, AiControlled::new(*controller_id, *ai_difficulty))
+                        .expect("Failed to insert `AiControlled` for character.");
+                } else {
+                    input_controlleds
+                        .insert(entity, InputControlled::new(*controller_id))
+                        .expect("Failed to insert `InputControlled` for character.");
+                }
+                let team = character_selections
+                    .teams
+                    .get(controller_id)
+                    .copied()
+                    .map(Team::Number)
+                    .unwrap_or_else(|| Team::Independent(independent_counter.get_and_increment()));
                 teams
-                    .insert(
-                        entity,
-                        Team::Independent(independent_counter.get_and_increment()),
-                    )
+                    .insert(entity, team)
                     .expect("Failed to insert `Team` for character.");
+                stockses
+                    .insert(entity, Stocks::new(match_rules.stocks))
+                    .expect("Failed to insert `Stocks` for character.");
+                game_entity_ids
+                    .insert(entity, game_entity_id_allocator.allocate())
+                    .expect("Failed to insert `GameEntityId` for character.");
 
                 entity
             })
@@ -2,6 +2,7 @@ use std::any;
 
 use amethyst::{GameData, State, Trans};
 use application_event::AppEvent;
+use audio_settings::AudioSettingsState;
 use character_selection::{
     CharacterSelectionStateBuilder, CharacterSelectionStateDelegate, CharacterSelectionSystem,
 };
@@ -33,6 +34,7 @@ impl GameModeSelectionTrans {
                 Trans::Push(network_mode_selection_state)
             }
             GameModeIndex::ControlSettings => Trans::Push(Box::new(ControlSettingsState::new())),
+            GameModeIndex::AudioSettings => Trans::Push(Box::new(AudioSettingsState::new())),
             GameModeIndex::Exit => Trans::Quit,
         }
     } // kcov-ignore
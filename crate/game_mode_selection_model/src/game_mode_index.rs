@@ -14,6 +14,8 @@ pub enum GameModeIndex {
     NetworkPlay,
     /// Opens control settings.
     ControlSettings,
+    /// Opens audio settings.
+    AudioSettings,
     /// Exits the application.
     Exit,
 }
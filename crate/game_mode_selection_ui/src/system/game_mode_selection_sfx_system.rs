@@ -4,15 +4,15 @@ use amethyst::{
     ecs::{Read, System, SystemData, World},
     shrev::{EventChannel, ReaderId},
 };
+use audio_model::config::{AudioChannel, AudioSettings};
 use derive_new::new;
 use game_mode_selection_model::GameModeSelectionEvent;
 use menu_model::MenuEvent;
 use ui_audio_model::{config::UiSfxId, loaded::UiSfxMap};
 
-/// Default volume to play sounds at.
-const VOLUME: f32 = 1.0;
-
 /// Plays sounds for the game mode selection UI.
+///
+/// Sounds are played at the `AudioChannel::Ui` volume.
 #[derive(Debug, Default, new)]
 pub struct GameModeSelectionSfxSystem {
     /// Reader ID for the `GameModeSelectionEvent` event channel.
@@ -24,6 +24,7 @@ type GameModeSelectionSfxSystemData<'s> = (
     Read<'s, EventChannel<GameModeSelectionEvent>>,
     Read<'s, UiSfxMap>,
     Read<'s, AssetStorage<Source>>,
+    Read<'s, AudioSettings>,
     Option<Read<'s, Output>>,
 );
 
@@ -32,7 +33,13 @@ impl<'s> System<'s> for GameModeSelectionSfxSystem {
 
     fn run(
         &mut self,
-        (game_mode_selection_ec, ui_sfx_map, source_assets, output): Self::SystemData,
+        (
+            game_mode_selection_ec,
+            ui_sfx_map,
+            source_assets,
+            audio_settings,
+            output,
+        ): Self::SystemData,
     ) {
         // Make sure we empty the event channel, even if we don't have an output device.
         let events_iterator = game_mode_selection_ec.read(
@@ -42,6 +49,7 @@ impl<'s> System<'s> for GameModeSelectionSfxSystem {
         );
 
         if let Some(output) = output {
+            let volume = audio_settings.volume(AudioChannel::Ui);
             events_iterator.for_each(|ev| {
                 let ui_sfx_id = match ev {
                     MenuEvent::Select(..) => Some(UiSfxId::Confirm),
@@ -54,7 +62,7 @@ impl<'s> System<'s> for GameModeSelectionSfxSystem {
                         .and_then(|ui_sfx_handle| source_assets.get(ui_sfx_handle));
 
                     if let Some(ui_sfx) = ui_sfx {
-                        output.play_once(ui_sfx, VOLUME);
+                        output.play_once(ui_sfx, volume);
                     }
                 }
             });
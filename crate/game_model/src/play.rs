@@ -1,5 +1,11 @@
 //! Types used for game play.
 
-pub use self::game_entities::GameEntities;
+pub use self::{
+    game_entities::GameEntities, game_entity_deletions::GameEntityDeletions,
+    game_entity_id::GameEntityId, game_entity_id_allocator::GameEntityIdAllocator,
+};
 
 mod game_entities;
+mod game_entity_deletions;
+mod game_entity_id;
+mod game_entity_id_allocator;
@@ -0,0 +1,39 @@
+use amethyst::ecs::{error::WrongGeneration, Entities, Entity};
+use derive_new::new;
+
+/// Game play entities whose deletion is held back until the frame they are deleted in is final.
+///
+/// During network play, a frame may be re-simulated, so entities deleted in that frame must be
+/// restorable. When `deferred` is `false`, entities are deleted immediately.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct GameEntityDeletions {
+    /// Whether entity deletion is held back.
+    #[new(default)]
+    pub deferred: bool,
+    /// Entities that were deleted while deletion was held back.
+    #[new(default)]
+    pub entities: Vec<Entity>,
+}
+
+impl GameEntityDeletions {
+    /// Deletes the entity, or records it for deletion if deletion is held back.
+    ///
+    /// # Parameters
+    ///
+    /// * `entities`: `Entities` resource.
+    /// * `entity`: Entity to delete.
+    pub fn delete(
+        &mut self,
+        entities: &Entities<'_>,
+        entity: Entity,
+    ) -> Result<(), WrongGeneration> {
+        if self.deferred {
+            if !self.entities.contains(&entity) {
+                self.entities.push(entity);
+            }
+            Ok(())
+        } else {
+            entities.delete(entity)
+        }
+    }
+}
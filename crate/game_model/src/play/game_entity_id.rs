@@ -0,0 +1,10 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+
+/// Identifies a game play entity consistently across devices.
+///
+/// Entity IDs are allocated by the ECS and may be reused, so they differ between devices when one
+/// device creates entities that another does not, such as UI entities. `GameEntityId`s are only
+/// assigned to game play entities, in spawn order, so the same entity has the same ID everywhere.
+#[derive(Clone, Component, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, new)]
+pub struct GameEntityId(pub u32);
@@ -0,0 +1,20 @@
+use crate::play::GameEntityId;
+
+/// Allocates `GameEntityId`s in spawn order.
+///
+/// This should be reset at the start of each game, so that IDs are allocated from the same value
+/// on every device.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GameEntityIdAllocator {
+    /// ID to assign to the next spawned game play entity.
+    pub next: u32,
+}
+
+impl GameEntityIdAllocator {
+    /// Returns the next `GameEntityId`, and increments the counter.
+    pub fn allocate(&mut self) -> GameEntityId {
+        let game_entity_id = GameEntityId::new(self.next);
+        self.next += 1;
+        game_entity_id
+    }
+}
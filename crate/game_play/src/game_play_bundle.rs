@@ -26,8 +26,12 @@ use collision_play::{
     HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
 };
 use derive_new::new;
+use game_input::InputHistoryUpdateSystem;
 use game_input_model::play::ControllerInput;
-use game_play_hud::{CpBarUpdateSystem, HpBarUpdateSystem};
+use game_play_hud::{
+    ComboBarUpdateSystem, ComboCountUpdateSystem, CpBarUpdateSystem, HpBarUpdateSystem,
+};
+use game_play_model::GamePlayStepStatus;
 use input_reaction_model::{
     config::BasicIrr,
     loaded::{InputReactionsSequence, InputReactionsSequenceHandles},
@@ -44,10 +48,12 @@ use map_play::{
     KeepWithinMapBoundsSystem, MapEnterExitDetectionSystem, MapOutOfBoundsClockAugmentSystem,
     MapOutOfBoundsDeletionSystem, MapSpawnOutOfBoundsDetectionSystem,
 };
+use object_model::play::{HealthPoints, SkillPoints};
 use object_play::{
     ObjectAccelerationSystem, ObjectGravitySystem, ObjectGroundingSystem, ObjectMirroringSystem,
+    ObjectTerrainCollisionSystem,
 };
-use object_status_play::StunPointsReductionSystem;
+use object_status_play::{GuardPointsRecoverySystem, StunPointsReductionSystem};
 use sequence_model::loaded::{SequenceEndTransitions, WaitSequence, WaitSequenceHandles};
 use sequence_play::{
     FrameComponentUpdateSystem, SequenceComponentUpdateSystem, SequenceEndTransitionSystem,
@@ -62,12 +68,15 @@ use sprite_model::loaded::{
 use sprite_play::SpriteScaleUpdateSystem;
 use state_registry::StateId;
 use tracker::LastTrackerSystem;
+use weapon_play::{WeaponHeldSystem, WeaponPickUpSystem};
 
 use crate::{
-    CharacterHitEffectSystem, CharacterSequenceUpdateSystem, FrameFreezeClockAugmentSystem,
-    GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem, GamePlayEndTransitionSystem,
-    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GroundingFrictionSystem,
-    ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    CharacterGrabEffectSystem, CharacterHitEffectSystem, CharacterRespawnSystem,
+    CharacterSequenceUpdateSystem, ComboResetSystem, FrameFreezeClockAugmentSystem,
+    FrameNumberUpdateSystem, GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem,
+    GamePlayEndTransitionSystem, GamePlayPauseMenuSystem, GamePlayRemovalAugmentSystem,
+    GamePlayRoundStartSystem, GamePlayStatusDisplaySystem, GameStatsTrackingSystem,
+    GroundingFrictionSystem, ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
 };
 
 /// Adds the object type update systems to the provided dispatcher.
@@ -80,10 +89,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         world: &mut World,
         builder: &mut DispatcherBuilder<'a, 'b>,
     ) -> Result<(), Error> {
+        // Every system is paused while `GamePlayStepStatus` is `Waiting`, so that game play only
+        // steps when input for the frame is available from all session devices.
+
         // === Component augmentation === //
 
         builder.add(
-            SequenceStatusUpdateSystem::new(),
+            SequenceStatusUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SequenceStatusUpdateSystem>(),
             &[],
         ); // kcov-ignore
@@ -96,7 +108,8 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
                     concat!("<", stringify!($component_data_type), ">")
                 );
                 builder.add(
-                    SequenceComponentUpdateSystem::<$component_data_type>::new(),
+                    SequenceComponentUpdateSystem::<$component_data_type>::new()
+                        .pausable(GamePlayStepStatus::Ready),
                     &system_name,
                     &[any::type_name::<SequenceStatusUpdateSystem>()],
                 ); // kcov-ignore
@@ -129,7 +142,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
 
         // Updates frame limit and ticks the sequence logic clocks.
         builder.add(
-            SequenceUpdateSystem::new(),
+            SequenceUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SequenceUpdateSystem>(),
             &[
                 // any::type_name::<SequenceComponentUpdateSystem::<_, _>>(),
@@ -139,7 +152,8 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         macro_rules! frame_component_update_system {
             ($frame_component_data:ident) => {
                 builder.add(
-                    FrameComponentUpdateSystem::<$frame_component_data>::new(),
+                    FrameComponentUpdateSystem::<$frame_component_data>::new()
+                        .pausable(GamePlayStepStatus::Ready),
                     any::type_name::<FrameComponentUpdateSystem<$frame_component_data>>(),
                     &[any::type_name::<SequenceUpdateSystem>()],
                 ); // kcov-ignore
@@ -158,43 +172,49 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         frame_component_update_system!(InputReactionsSequence);
 
         builder.add(
-            FrameFreezeClockAugmentSystem::new(),
+            FrameFreezeClockAugmentSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<FrameFreezeClockAugmentSystem>(),
             &[any::type_name::<SequenceUpdateSystem>()],
         ); // kcov-ignore
         builder.add(
-            HitRepeatTrackersAugmentSystem::new(),
+            HitRepeatTrackersAugmentSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HitRepeatTrackersAugmentSystem>(),
             &[],
         ); // kcov-ignore
 
-        builder.add(HitSfxSystem::new(), any::type_name::<HitSfxSystem>(), &[]);
+        builder.add(
+            HitSfxSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<HitSfxSystem>(),
+            &[],
+        ); // kcov-ignore
 
         // Play sounds from sequence updates.
         builder.add(
-            SequenceAudioPlaySystem::new(),
+            SequenceAudioPlaySystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SequenceAudioPlaySystem>(),
             &[any::type_name::<FrameComponentUpdateSystem<SourceSequence>>()],
         ); // kcov-ignore
 
         // Spawn objects
         builder.add(
-            SpawnGameObjectSystem::new(),
+            SpawnGameObjectSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SpawnGameObjectSystem>(),
             &[any::type_name::<FrameComponentUpdateSystem<SpawnsSequence>>()],
         ); // kcov-ignore
         builder.add(
-            SpawnGameObjectRectifySystem::new(),
+            SpawnGameObjectRectifySystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SpawnGameObjectRectifySystem>(),
             &[any::type_name::<SpawnGameObjectSystem>()],
         ); // kcov-ignore
         builder.add(
-            MapSpawnOutOfBoundsDetectionSystem::new().pausable(StateId::GamePlay),
+            MapSpawnOutOfBoundsDetectionSystem::new()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
             any::type_name::<MapSpawnOutOfBoundsDetectionSystem>(),
             &[any::type_name::<SpawnGameObjectRectifySystem>()],
         ); // kcov-ignore
         builder.add(
-            GamePlayRemovalAugmentSystem::new(),
+            GamePlayRemovalAugmentSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<GamePlayRemovalAugmentSystem>(),
             &[any::type_name::<SpawnGameObjectSystem>()],
         ); // kcov-ignore
@@ -203,16 +223,24 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
 
         // === Component value update === //
 
+        // Held weapons replace their holder's attack `Interactions`, and are dropped when their
+        // holder is stunned.
+        builder.add(
+            WeaponHeldSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<WeaponHeldSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // transform.scale_mut().{x/y/z} = `Scale`
         builder.add(
-            SpriteScaleUpdateSystem::new(),
+            SpriteScaleUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SpriteScaleUpdateSystem>(),
             &[],
         ); // kcov-ignore
 
         // vel += `ObjectAcceleration` (from frame config).
         builder.add(
-            ObjectAccelerationSystem::new(),
+            ObjectAccelerationSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectAccelerationSystem>(),
             &[],
         ); // kcov-ignore
@@ -221,43 +249,58 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // This must be between the `FrameFreezeClockAugmentSystem` and `SequenceUpdateSystem`s
         // since it needs to wait for the `FrameFreezeClock` to tick.
         builder.add(
-            ObjectKinematicsUpdateSystem::new(),
+            ObjectKinematicsUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectKinematicsUpdateSystem>(),
             &[any::type_name::<ObjectAccelerationSystem>()],
         ); // kcov-ignore
 
+        // `Position` correction based on terrain.
+        builder.add(
+            ObjectTerrainCollisionSystem::new()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
+            any::type_name::<ObjectTerrainCollisionSystem>(),
+            &[any::type_name::<ObjectKinematicsUpdateSystem>()],
+        ); // kcov-ignore
+
         // `Position` correction based on margins.
         // vel += mass
         builder.add(
-            ObjectGravitySystem::new(),
+            ObjectGravitySystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectGravitySystem>(),
-            &[any::type_name::<ObjectKinematicsUpdateSystem>()],
+            &[any::type_name::<ObjectTerrainCollisionSystem>()],
         ); // kcov-ignore
         builder.add(
-            MapEnterExitDetectionSystem::new().pausable(StateId::GamePlay),
+            MapEnterExitDetectionSystem::new()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
             any::type_name::<MapEnterExitDetectionSystem>(),
             &[any::type_name::<ObjectGravitySystem>()],
         ); // kcov-ignore
         builder.add(
-            KeepWithinMapBoundsSystem::new().pausable(StateId::GamePlay),
+            KeepWithinMapBoundsSystem::new()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
             any::type_name::<KeepWithinMapBoundsSystem>(),
             &[any::type_name::<MapEnterExitDetectionSystem>()],
         ); // kcov-ignore
         builder.add(
-            ObjectGroundingSystem::new().pausable(StateId::GamePlay),
+            ObjectGroundingSystem::new()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectGroundingSystem>(),
             &[any::type_name::<MapEnterExitDetectionSystem>()],
         ); // kcov-ignore
 
         // Updates `Velocity<f32>` based on grounding.
         builder.add(
-            GroundingFrictionSystem::new(),
+            GroundingFrictionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<GroundingFrictionSystem>(),
             &[any::type_name::<ObjectGroundingSystem>()],
         ); // kcov-ignore
 
         builder.add(
-            MapOutOfBoundsDeletionSystem::new(),
+            MapOutOfBoundsDeletionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<MapOutOfBoundsDeletionSystem>(),
             &[
                 any::type_name::<MapEnterExitDetectionSystem>(),
@@ -265,13 +308,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             ],
         ); // kcov-ignore
         builder.add(
-            MapOutOfBoundsClockAugmentSystem::new(),
+            MapOutOfBoundsClockAugmentSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<MapOutOfBoundsClockAugmentSystem>(),
             &[any::type_name::<MapOutOfBoundsDeletionSystem>()],
         ); // kcov-ignore
 
         builder.add(
-            ObjectTransformUpdateSystem::new(),
+            ObjectTransformUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectTransformUpdateSystem>(),
             &[
                 any::type_name::<ObjectKinematicsUpdateSystem>(),
@@ -279,37 +322,47 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             ],
         ); // kcov-ignore
         builder.add(
-            InteractableObjectSyncSystem::new(),
+            InteractableObjectSyncSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<InteractableObjectSyncSystem>(),
             &[any::type_name::<ObjectTransformUpdateSystem>()],
         ); // kcov-ignore
         builder.add(
-            ObjectMirroringSystem::new(),
+            ObjectMirroringSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ObjectMirroringSystem>(),
-            &[any::type_name::<ObjectTransformUpdateSystem>()],
+            &[
+                any::type_name::<ObjectTransformUpdateSystem>(),
+                any::type_name::<WeaponHeldSystem>(),
+            ],
         ); // kcov-ignore
         builder.add(
-            StickToTargetObjectSystem::new(),
+            StickToTargetObjectSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<StickToTargetObjectSystem>(),
             &[any::type_name::<ObjectTransformUpdateSystem>()],
         ); // kcov-ignore
 
         // Reduces charge when not charging.
         builder.add(
-            ChargeRetentionSystem::new(),
+            ChargeRetentionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ChargeRetentionSystem>(),
             &[],
         ); // kcov-ignore
 
         // Reduces `StunPoints` each tick.
         builder.add(
-            StunPointsReductionSystem::new(),
+            StunPointsReductionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<StunPointsReductionSystem>(),
             &[],
         ); // kcov-ignore
 
+        // Recovers `GuardPoints` each tick.
         builder.add(
-            HitRepeatTrackersTickerSystem::new(),
+            GuardPointsRecoverySystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<GuardPointsRecoverySystem>(),
+            &[],
+        ); // kcov-ignore
+
+        builder.add(
+            HitRepeatTrackersTickerSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HitRepeatTrackersTickerSystem>(),
             &[any::type_name::<HitRepeatTrackersAugmentSystem>()],
         ); // kcov-ignore
@@ -319,20 +372,21 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // === Effect Detection === //
 
         builder.add(
-            CollisionDetectionSystem::new(),
+            CollisionDetectionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<CollisionDetectionSystem>(),
             &[
                 any::type_name::<StunPointsReductionSystem>(),
+                any::type_name::<GuardPointsRecoverySystem>(),
                 any::type_name::<HitRepeatTrackersTickerSystem>(),
             ],
         ); // kcov-ignore
         builder.add(
-            ContactDetectionSystem::new(),
+            ContactDetectionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ContactDetectionSystem>(),
             &[any::type_name::<CollisionDetectionSystem>()],
         ); // kcov-ignore
         builder.add(
-            HitDetectionSystem::new(),
+            HitDetectionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HitDetectionSystem>(),
             &[any::type_name::<ContactDetectionSystem>()],
         ); // kcov-ignore
@@ -342,13 +396,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // === Sequence ID Updates === //
 
         builder.add(
-            SequenceEndTransitionSystem::new(),
+            SequenceEndTransitionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<SequenceEndTransitionSystem>(),
             &[],
         ); // kcov-ignore
 
         builder.add(
-            InputReactionsTransitionSystem::<BasicIrr>::new(),
+            InputReactionsTransitionSystem::<BasicIrr>::new().pausable(GamePlayStepStatus::Ready),
             &any::type_name::<InputReactionsTransitionSystem<BasicIrr>>(),
             &[any::type_name::<SequenceEndTransitionSystem>()],
         ); // kcov-ignore
@@ -357,7 +411,8 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
                 'a,
                 'b,
                 ButtonInputReactionsTransitionSystem<BasicIrr>,
-            >>::build(ButtonInputReactionsTransitionSystemDesc::default(), world),
+            >>::build(ButtonInputReactionsTransitionSystemDesc::default(), world)
+            .pausable(GamePlayStepStatus::Ready),
             &any::type_name::<ButtonInputReactionsTransitionSystem<BasicIrr>>(),
             &[any::type_name::<SequenceEndTransitionSystem>()],
         ); // kcov-ignore
@@ -370,47 +425,84 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // `CharacterSequenceUpdater` transitions should overwrite the `SequenceEndTransition`
         // update.
         builder.add(
-            CharacterSequenceUpdateSystem::new(),
+            CharacterSequenceUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<CharacterSequenceUpdateSystem>(),
             &[any::type_name::<SequenceEndTransitionSystem>()],
         ); // kcov-ignore
+
+        // Records input commands before `CharacterIrr` requirements are checked, so that input
+        // sequences include the inputs for this tick.
         builder.add(
-            InputReactionsTransitionSystem::<CharacterIrr>::new(),
+            InputHistoryUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<InputHistoryUpdateSystem>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            InputReactionsTransitionSystem::<CharacterIrr>::new()
+                .pausable(GamePlayStepStatus::Ready),
             &any::type_name::<InputReactionsTransitionSystem<CharacterIrr>>(),
-            &[any::type_name::<CharacterSequenceUpdateSystem>()],
+            &[
+                any::type_name::<CharacterSequenceUpdateSystem>(),
+                any::type_name::<InputHistoryUpdateSystem>(),
+            ],
         ); // kcov-ignore
         builder.add(
-            CharacterHitEffectSystem::new(),
+            CharacterHitEffectSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<CharacterHitEffectSystem>(),
             &[&any::type_name::<
                 InputReactionsTransitionSystem<CharacterIrr>,
             >()],
         ); // kcov-ignore
 
+        // Runs after the `CharacterHitEffectSystem` so that characters who are hit this tick keep
+        // their combo.
+        builder.add(
+            ComboResetSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<ComboResetSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+
+        // Runs after the `CharacterHitEffectSystem` so that held characters who are hit, or whose
+        // holder is hit, are released.
+        builder.add(
+            CharacterGrabEffectSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<CharacterGrabEffectSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+
         // Charging
         builder.add(
-            ChargeInitializeDetectionSystem::new(),
+            ChargeInitializeDetectionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ChargeInitializeDetectionSystem>(),
             &[&any::type_name::<
                 InputReactionsTransitionSystem<CharacterIrr>,
             >()],
         ); // kcov-ignore
         builder.add(
-            ChargeInitializeDelaySystem::new(),
+            ChargeInitializeDelaySystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ChargeInitializeDelaySystem>(),
             &[any::type_name::<ChargeInitializeDetectionSystem>()],
         ); // kcov-ignore
         builder.add(
-            ChargeIncrementSystem::new(),
+            ChargeIncrementSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ChargeIncrementSystem>(),
             &[any::type_name::<ChargeInitializeDelaySystem>()],
         ); // kcov-ignore
         builder.add(
-            ChargeUsageSystem::new(),
+            ChargeUsageSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<ChargeUsageSystem>(),
             &[any::type_name::<ChargeIncrementSystem>()],
         ); // kcov-ignore
 
+        // Weapons
+        builder.add(
+            WeaponPickUpSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<WeaponPickUpSystem>(),
+            &[&any::type_name::<
+                InputReactionsTransitionSystem<CharacterIrr>,
+            >()],
+        ); // kcov-ignore
+
         // Hit / Hitting effects.
         //
         // There are only two currently, but if there is a timer system, perhaps that should go
@@ -418,12 +510,12 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // The `HitEffectSystem` depends on the `HittingEffectSystem` to ensure the
         // `Hit` sequence is deterministic and overwrites the `Hitting` sequence.
         builder.add(
-            HittingEffectSystem::new(),
+            HittingEffectSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HittingEffectSystem>(),
             &[],
         ); // kcov-ignore
         builder.add(
-            HitEffectSystem::new(),
+            HitEffectSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HitEffectSystem>(),
             &[any::type_name::<HittingEffectSystem>()],
         ); // kcov-ignore
@@ -432,54 +524,94 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         // so that the renderer will show the HP including the damage dealt this frame, instead of
         // one frame later.
         builder.add(
-            HpBarUpdateSystem::new(),
+            HpBarUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<HpBarUpdateSystem>(),
             &[any::type_name::<CharacterHitEffectSystem>()],
         ); // kcov-ignore
         builder.add(
-            CpBarUpdateSystem::new(),
+            CpBarUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<CpBarUpdateSystem>(),
             &[any::type_name::<CharacterHitEffectSystem>()],
         ); // kcov-ignore
+        builder.add(
+            ComboBarUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<ComboBarUpdateSystem>(),
+            &[any::type_name::<ComboResetSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            ComboCountUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<ComboCountUpdateSystem>(),
+            &[any::type_name::<ComboResetSystem>()],
+        ); // kcov-ignore
+
+        // Records damage, hits, and KOs using the health points after hits are applied.
+        builder.add(
+            GameStatsTrackingSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<GameStatsTrackingSystem>(),
+            &[any::type_name::<ComboResetSystem>()],
+        ); // kcov-ignore
 
         builder.add_barrier();
 
         // === Helper Systems === //
 
+        // Respawns characters that have stocks remaining.
+        builder.add(
+            CharacterRespawnSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<CharacterRespawnSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // Detects when the winning condition has been met.
         builder.add(
-            GamePlayEndDetectionSystem::new(),
+            GamePlayEndDetectionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<GamePlayEndDetectionSystem>(),
-            &[],
+            &[any::type_name::<CharacterRespawnSystem>()],
         ); // kcov-ignore
 
+        // Starts the next round after a round has ended.
         builder.add(
-            GamePlayStatusDisplaySystem::new(),
-            any::type_name::<GamePlayStatusDisplaySystem>(),
+            GamePlayRoundStartSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<GamePlayRoundStartSystem>(),
             &[any::type_name::<GamePlayEndDetectionSystem>()],
         ); // kcov-ignore
 
+        builder.add(
+            GamePlayStatusDisplaySystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<GamePlayStatusDisplaySystem>(),
+            &[any::type_name::<GamePlayRoundStartSystem>()],
+        ); // kcov-ignore
+
+        // Not pausable, so that the pause menu is shown and hidden while game play is paused.
+        builder.add(
+            GamePlayPauseMenuSystem::new(),
+            any::type_name::<GamePlayPauseMenuSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // Delay before game play end transition is accepted.
         builder.add(
-            GamePlayEndTransitionDelaySystem::new(),
+            GamePlayEndTransitionDelaySystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<GamePlayEndTransitionDelaySystem>(),
             &[any::type_name::<GamePlayEndDetectionSystem>()],
         ); // kcov-ignore
 
         // Sends a state transition when game play ends, and `Attack` is pressed.
         builder.add(
-            GamePlayEndTransitionSystem::new(),
+            GamePlayEndTransitionSystem::new().pausable(GamePlayStepStatus::Ready),
             any::type_name::<GamePlayEndTransitionSystem>(),
             &[any::type_name::<GamePlayEndTransitionDelaySystem>()],
         ); // kcov-ignore
 
         builder.add(
-            CameraTrackingSystem::default().pausable(StateId::GamePlay),
+            CameraTrackingSystem::default()
+                .pausable(StateId::GamePlay)
+                .pausable(GamePlayStepStatus::Ready),
             any::type_name::<CameraTrackingSystem>(),
             &[],
         ); // kcov-ignore
         builder.add(
-            CameraVelocitySystem::default(),
+            CameraVelocitySystem::default().pausable(GamePlayStepStatus::Ready),
             any::type_name::<CameraVelocitySystem>(),
             &[any::type_name::<CameraTrackingSystem>()],
         ); // kcov-ignore
@@ -487,17 +619,46 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         let position_tracker_system =
             LastTrackerSystem::<Position<f32>>::new(stringify!(Position<f32>));
         let position_tracker_system_name = position_tracker_system.system_name();
-        builder.add(position_tracker_system, &position_tracker_system_name, &[]); // kcov-ignore
+        builder.add(
+            position_tracker_system.pausable(GamePlayStepStatus::Ready),
+            &position_tracker_system_name,
+            &[],
+        ); // kcov-ignore
+
+        // Tracked after characters are respawned, so that damage on the next tick is measured from
+        // the respawned health points.
+        macro_rules! game_stats_last_tracker_system {
+            ($component:ty) => {
+                let last_tracker_system =
+                    LastTrackerSystem::<$component>::new(stringify!($component));
+                let last_tracker_system_name = last_tracker_system.system_name();
+                builder.add(
+                    last_tracker_system.pausable(GamePlayStepStatus::Ready),
+                    &last_tracker_system_name,
+                    &[any::type_name::<GamePlayRoundStartSystem>()],
+                ); // kcov-ignore
+            };
+        }
+        game_stats_last_tracker_system!(HealthPoints);
+        game_stats_last_tracker_system!(SkillPoints);
 
         let controller_input_tracker_system =
             LastTrackerSystem::<ControllerInput>::new(stringify!(game_input::ControllerInput));
         let controller_input_tracker_system_name = controller_input_tracker_system.system_name();
         builder.add(
-            controller_input_tracker_system,
+            controller_input_tracker_system.pausable(GamePlayStepStatus::Ready),
             &controller_input_tracker_system_name,
             &[any::type_name::<GamePlayEndTransitionSystem>()],
         ); // kcov-ignore
 
+        // Counts the frames that game play has stepped, so that replays are keyed on game play
+        // frames.
+        builder.add(
+            FrameNumberUpdateSystem::new().pausable(GamePlayStepStatus::Ready),
+            any::type_name::<FrameNumberUpdateSystem>(),
+            &[],
+        ); // kcov-ignore
+
         Ok(())
     }
 }
@@ -1,23 +1,124 @@
+use std::any;
+
+use ai_play::AiControlSystem;
 use amethyst::{
-    ecs::{Entity, World, WorldExt},
+    core::SystemBundle,
+    ecs::{Dispatcher, DispatcherBuilder, Entity, Read, World, WorldExt},
     input::{is_key_down, VirtualKeyCode},
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
+use control_settings::ControlSettingsState;
 use derivative::Derivative;
 use derive_new::new;
+use game_input::{ControllerInputUpdateSystem, SharedControllerInputUpdateSystem};
+use game_loading::GameEntitiesLoader;
 use game_model::play::GameEntities;
-use game_play_model::{GamePlayEntity, GamePlayEvent, GamePlayStatus};
-use log::debug;
+use game_play_model::{
+    config::MatchRules,
+    play::{RoundClock, RoundStartDelayClock},
+    GamePlayEntity, GamePlayEvent, GamePlayStatus,
+};
+use game_stats::GameStatsState;
+use game_stats_model::play::{GameStats, WinStatus};
+use log::{debug, warn};
+use network_input_model::play::FrameNumber;
+use network_session_model::play::SessionStatus;
+use rollback_play::GamePlayRollback;
 use state_registry::StateId;
 use state_support::StateEntityUtils;
 
+use crate::GamePlayBundle;
+
 /// `State` where game play takes place.
 #[derive(Derivative, Default, new)]
 #[derivative(Debug)]
-pub struct GamePlayState;
+pub struct GamePlayState<'a, 'b> {
+    /// Dispatcher for game play systems, used when game play is stepped by rollback.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    rollback_dispatcher: Option<Dispatcher<'a, 'b>>,
+    /// Steps and re-simulates game play during network play.
+    #[new(default)]
+    game_play_rollback: GamePlayRollback,
+}
+
+impl<'a, 'b> GamePlayState<'a, 'b> {
+    /// Sets up the rollback dispatcher if game play is stepped by rollback.
+    ///
+    /// The dispatcher is only created when needed, as its systems register readers on event
+    /// channels, which would otherwise grow without being read.
+    fn rollback_initialize(&mut self, world: &mut World) {
+        self.game_play_rollback = GamePlayRollback::new();
+
+        if GamePlayRollback::is_active(world) {
+            let mut dispatcher_builder = DispatcherBuilder::new();
+
+            // Computer players decide from the game play state of each simulated frame, so that
+            // their input is re-decided when a frame is re-simulated.
+            dispatcher_builder.add(
+                AiControlSystem::new(),
+                any::type_name::<AiControlSystem>(),
+                &[],
+            );
+            dispatcher_builder.add(
+                ControllerInputUpdateSystem::new(),
+                any::type_name::<ControllerInputUpdateSystem>(),
+                &[any::type_name::<AiControlSystem>()],
+            );
+            dispatcher_builder.add(
+                SharedControllerInputUpdateSystem::new(),
+                any::type_name::<SharedControllerInputUpdateSystem>(),
+                &[any::type_name::<ControllerInputUpdateSystem>()],
+            );
+            dispatcher_builder.add_barrier();
+
+            GamePlayBundle::new()
+                .build(world, &mut dispatcher_builder)
+                .expect("Failed to register `GamePlayBundle`.");
+
+            let mut dispatcher = dispatcher_builder.build();
+            dispatcher.setup(world);
+            self.rollback_dispatcher = Some(dispatcher);
+        } else {
+            self.rollback_dispatcher = None;
+        }
+    }
+
+    /// Resets the game play status, match progress, game statistics, and frame number.
+    fn game_play_status_initialize(world: &mut World) {
+        world.insert(GamePlayStatus::Playing);
+        world.insert(WinStatus::default());
+        world.insert(GameStats::default());
+
+        let match_rules = world
+            .try_fetch::<MatchRules>()
+            .map(|match_rules| *match_rules)
+            .unwrap_or_default();
+        world.insert(RoundClock::new(match_rules.time_limit.unwrap_or_default()));
+        world.insert(RoundStartDelayClock::default());
+        world.insert(FrameNumber::default());
+    }
+
+    /// Returns whether game play may be restarted.
+    ///
+    /// Restarting is not supported during a network session, as each session device steps game
+    /// play from its own frame numbers, input buffer, and checksums, which are not reset.
+    fn is_restart_allowed(world: &World) -> bool {
+        let session_status = world.system_data::<Read<'_, SessionStatus>>();
+        *session_status != SessionStatus::JoinEstablished
+            && *session_status != SessionStatus::HostEstablished
+    }
+
+    /// Despawns all game play entities, and spawns the same characters and map again.
+    fn restart(&mut self, world: &mut World) {
+        self.terminate_entities(world);
+        GameEntitiesLoader::load(world);
+        Self::game_play_status_initialize(world);
+
+        self.rollback_initialize(world);
+    }
 
-impl GamePlayState {
     fn terminate_entities(&mut self, world: &mut World) {
         // This `allow` is needed because rustc evaluates that `game_entities` does not live long
         // enough when entities is constructed, so we need to bind entities to a variable.
@@ -38,18 +139,22 @@ impl GamePlayState {
                 .delete_entity(entity)
                 .expect("Failed to delete game entity.");
         });
+        self.game_play_rollback.terminate(world);
 
         StateEntityUtils::clear::<GamePlayEntity>(world);
     }
 }
 
-impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
+impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState<'a, 'b> {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         data.world.insert(StateId::GamePlay);
-        data.world.insert(GamePlayStatus::Playing);
+        Self::game_play_status_initialize(data.world);
+
+        self.rollback_initialize(data.world);
     }
 
     fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
+        self.rollback_dispatcher = None;
         self.terminate_entities(&mut data.world);
     }
 
@@ -65,39 +170,67 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
         match event {
             AppEvent::Window(window_event) => {
                 if is_key_down(&window_event, VirtualKeyCode::Escape) {
-                    debug!("Returning from `GamePlayState`.");
-                    data.world.insert(GamePlayStatus::None);
-                    Trans::Pop
+                    let game_play_status = *data.world.read_resource::<GamePlayStatus>();
+                    match game_play_status {
+                        GamePlayStatus::Playing => {
+                            data.world.insert(GamePlayStatus::Paused);
+                            Trans::None
+                        }
+                        GamePlayStatus::Paused => {
+                            data.world.insert(GamePlayStatus::Playing);
+                            Trans::None
+                        }
+                        _ => {
+                            debug!("Returning from `GamePlayState`.");
+                            data.world.insert(GamePlayStatus::None);
+                            Trans::Pop
+                        }
+                    }
                 } else {
                     Trans::None
                 }
             }
-            AppEvent::GamePlay(game_play_event) => {
-                match game_play_event {
-                    GamePlayEvent::Return => {
-                        debug!("Returning from `GamePlayState`.");
-                        data.world.insert(GamePlayStatus::None);
-                        Trans::Pop
-                    }
-                    GamePlayEvent::Restart => {
-                        // TODO: Switch to `GameLoadingState`
-                        Trans::None
-                    }
-                    GamePlayEvent::Pause => {
-                        data.world.insert(GamePlayStatus::Paused);
-                        Trans::None
+            AppEvent::GamePlay(game_play_event) => match game_play_event {
+                GamePlayEvent::Return => {
+                    debug!("Returning from `GamePlayState`.");
+                    data.world.insert(GamePlayStatus::None);
+                    Trans::Pop
+                }
+                GamePlayEvent::Restart => {
+                    if Self::is_restart_allowed(data.world) {
+                        debug!("Restarting `GamePlayState`.");
+                        self.restart(data.world);
+                    } else {
+                        warn!("Game play cannot be restarted during a network session.");
                     }
-                    GamePlayEvent::Resume => {
-                        data.world.insert(GamePlayStatus::Playing);
-                        Trans::None
+                    Trans::None
+                }
+                GamePlayEvent::Pause => {
+                    let mut game_play_status = data.world.write_resource::<GamePlayStatus>();
+                    if *game_play_status == GamePlayStatus::Playing {
+                        *game_play_status = GamePlayStatus::Paused;
                     }
-                    GamePlayEvent::End => Trans::None,
-                    GamePlayEvent::EndStats => {
-                        // TODO: `GamePlayStats` state.
-                        Trans::Pop
+                    Trans::None
+                }
+                GamePlayEvent::Resume => {
+                    let mut game_play_status = data.world.write_resource::<GamePlayStatus>();
+                    if *game_play_status == GamePlayStatus::Paused {
+                        *game_play_status = GamePlayStatus::Playing;
                     }
+                    Trans::None
                 }
-            }
+                GamePlayEvent::ControlSettings => {
+                    debug!("Pushing `ControlSettingsState`.");
+                    Trans::Push(Box::new(ControlSettingsState::new()))
+                }
+                GamePlayEvent::RoundEnd | GamePlayEvent::RoundStart | GamePlayEvent::End => {
+                    Trans::None
+                }
+                GamePlayEvent::EndStats => {
+                    debug!("Switching to `GameStatsState`.");
+                    Trans::Switch(Box::new(GameStatsState::new()))
+                }
+            },
             _ => Trans::None,
         }
     }
@@ -106,9 +239,19 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
         &mut self,
         data: StateData<'_, GameData<'_, '_>>,
     ) -> Trans<GameData<'a, 'b>, AppEvent> {
-        // Note: The built-in dispatcher must be run before the state specific dispatcher as the
-        // `"input_system"` is registered in the main dispatcher, and is a dependency of the
-        // `ControllerInputUpdateSystem`.
+        // Game play is stepped before the main dispatcher is run, so that the rendered frame
+        // includes the latest game play state.
+        //
+        // The rollback dispatcher does not depend on the `"input_system"` in the main dispatcher,
+        // as input is read from the `NetworkInputBuffer` received in previous ticks.
+        //
+        // Game play is not stepped while paused.
+        let paused = *data.world.read_resource::<GamePlayStatus>() == GamePlayStatus::Paused;
+        if let Some(rollback_dispatcher) = self.rollback_dispatcher.as_mut().filter(|_| !paused) {
+            self.game_play_rollback
+                .update(data.world, rollback_dispatcher);
+        }
+
         data.data.update(&data.world);
         Trans::None
     }
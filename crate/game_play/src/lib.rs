@@ -8,21 +8,26 @@
 pub use crate::{
     game_play_bundle::GamePlayBundle,
     game_play_state::GamePlayState,
+    spawn_position_calculator::SpawnPositionCalculator,
     system::{
-        CharacterHitEffectSystem, CharacterHitEffectSystemData, CharacterSequenceUpdateSystem,
-        CharacterSequenceUpdateSystemData, FrameFreezeClockAugmentSystem,
-        FrameFreezeClockAugmentSystemData, GamePlayEndDetectionSystem,
+        CharacterGrabEffectSystem, CharacterGrabEffectSystemData, CharacterHitEffectSystem,
+        CharacterHitEffectSystemData, CharacterRespawnSystem, CharacterRespawnSystemData,
+        CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData, ComboResetSystem,
+        ComboResetSystemData, FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData,
+        FrameNumberUpdateSystem, FrameNumberUpdateSystemData, GamePlayEndDetectionSystem,
         GamePlayEndDetectionSystemData, GamePlayEndTransitionDelaySystem,
         GamePlayEndTransitionDelaySystemData, GamePlayEndTransitionSystem,
-        GamePlayEndTransitionSystemData, GamePlayRemovalAugmentSystem,
-        GamePlayRemovalAugmentSystemData, GamePlayStatusDisplaySystem,
-        GamePlayStatusDisplaySystemData, GroundingFrictionSystem, GroundingFrictionSystemData,
-        ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
-        ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
-        GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
+        GamePlayEndTransitionSystemData, GamePlayPauseMenuSystem, GamePlayPauseMenuSystemData,
+        GamePlayRemovalAugmentSystem, GamePlayRemovalAugmentSystemData, GamePlayRoundStartSystem,
+        GamePlayRoundStartSystemData, GamePlayStatusDisplaySystem, GamePlayStatusDisplaySystemData,
+        GameStatsTrackingSystem, GameStatsTrackingSystemData, GroundingFrictionSystem,
+        GroundingFrictionSystemData, ObjectKinematicsUpdateSystem,
+        ObjectKinematicsUpdateSystemData, ObjectTransformUpdateSystem,
+        ObjectTransformUpdateSystemData, GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
     },
 };
 
 mod game_play_bundle;
 mod game_play_state;
+mod spawn_position_calculator;
 mod system;
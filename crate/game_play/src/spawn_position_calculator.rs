@@ -0,0 +1,31 @@
+use kinematic_model::config::Position;
+use map_model::loaded::AssetMapBounds;
+use map_selection_model::MapSelection;
+
+/// Calculates where characters are placed when they respawn or a round starts.
+#[derive(Debug)]
+pub struct SpawnPositionCalculator;
+
+impl SpawnPositionCalculator {
+    /// Returns the position in the middle of the selected map, if a map is selected.
+    ///
+    /// # Parameters
+    ///
+    /// * `map_selection`: The selected map.
+    /// * `asset_map_bounds`: Bounds of each map.
+    pub fn calculate(
+        map_selection: MapSelection,
+        asset_map_bounds: &AssetMapBounds,
+    ) -> Option<Position<f32>> {
+        map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_map_bounds.get(asset_id))
+            .map(|bounds| {
+                Position::<f32>::new(
+                    bounds.width as f32 / 2.,
+                    bounds.height as f32 / 2.,
+                    bounds.depth as f32 / 2.,
+                )
+            })
+    }
+}
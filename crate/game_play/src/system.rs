@@ -1,8 +1,12 @@
 pub use self::{
+    character_grab_effect_system::{CharacterGrabEffectSystem, CharacterGrabEffectSystemData},
     character_hit_effect_system::{CharacterHitEffectSystem, CharacterHitEffectSystemData},
+    character_respawn_system::{CharacterRespawnSystem, CharacterRespawnSystemData},
     character_sequence_update_system::{
         CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData,
     },
+    combo_reset_system::{ComboResetSystem, ComboResetSystemData},
+    frame_number_update_system::{FrameNumberUpdateSystem, FrameNumberUpdateSystemData},
     game_play_end_detection_system::{GamePlayEndDetectionSystem, GamePlayEndDetectionSystemData},
     game_play_end_transition_delay_system::{
         GamePlayEndTransitionDelaySystem, GamePlayEndTransitionDelaySystemData,
@@ -11,12 +15,15 @@ pub use self::{
     game_play_end_transition_system::{
         GamePlayEndTransitionSystem, GamePlayEndTransitionSystemData,
     },
+    game_play_pause_menu_system::{GamePlayPauseMenuSystem, GamePlayPauseMenuSystemData},
     game_play_removal_augment_system::{
         GamePlayRemovalAugmentSystem, GamePlayRemovalAugmentSystemData,
     },
+    game_play_round_start_system::{GamePlayRoundStartSystem, GamePlayRoundStartSystemData},
     game_play_status_display_system::{
         GamePlayStatusDisplaySystem, GamePlayStatusDisplaySystemData,
     },
+    game_stats_tracking_system::{GameStatsTrackingSystem, GameStatsTrackingSystemData},
     grounding_friction_system::{GroundingFrictionSystem, GroundingFrictionSystemData},
     object_kinematics_update_system::{
         ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
@@ -27,13 +34,20 @@ pub use self::{
     sequence::{FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData},
 };
 
+mod character_grab_effect_system;
 mod character_hit_effect_system;
+mod character_respawn_system;
 mod character_sequence_update_system;
+mod combo_reset_system;
+mod frame_number_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
 mod game_play_end_transition_system;
+mod game_play_pause_menu_system;
 mod game_play_removal_augment_system;
+mod game_play_round_start_system;
 mod game_play_status_display_system;
+mod game_stats_tracking_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
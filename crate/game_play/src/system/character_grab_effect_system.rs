@@ -0,0 +1,390 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use character_model::loaded::{CharacterGrabTransitions, CharacterHitTransitions};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::{Grab, InteractionKind, Throw},
+    play::{ContactEvent, HeldBy, Holding},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{Position, Velocity};
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+
+/// Determines grab and throw effects for characters.
+///
+/// A `Grab` attaches the contacted character to the grabbing character, and a `Throw` releases the
+/// held character with the configured damage and acceleration. Held characters are also released
+/// when the grabbing character is stunned or dies, or when the held character is knocked out of
+/// its held sequence.
+#[derive(Debug, Default, new)]
+pub struct CharacterGrabEffectSystem {
+    /// Reader ID for the `ContactEvent` event channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+/// `CharacterGrabEffectSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterGrabEffectSystemData<'s> {
+    /// `ContactEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub contact_ec: Read<'s, EventChannel<ContactEvent>>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: ReadStorage<'s, CharacterGrabTransitions>,
+    /// `CharacterHitTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
+    /// `Holding` components.
+    #[derivative(Debug = "ignore")]
+    pub holdings: WriteStorage<'s, Holding>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl CharacterGrabEffectSystem {
+    /// Returns whether the held entity should be released without being thrown.
+    fn should_release(
+        CharacterGrabEffectSystemData {
+            entities,
+            character_grab_transitionses,
+            character_hit_transitionses,
+            health_pointses,
+            sequence_ids,
+            ..
+        }: &CharacterGrabEffectSystemData<'_>,
+        holder: Entity,
+        held: Entity,
+    ) -> bool {
+        let holder_incapacitated = !entities.is_alive(holder)
+            || health_pointses
+                .get(holder)
+                .map_or(true, |health_points| *health_points == 0)
+            || match (
+                character_hit_transitionses.get(holder),
+                sequence_ids.get(holder),
+            ) {
+                (Some(character_hit_transitions), Some(sequence_id)) => {
+                    character_hit_transitions.is_stunned(*sequence_id)
+                }
+                _ => false,
+            };
+
+        let held_escaped = match (
+            character_grab_transitionses.get(held),
+            sequence_ids.get(held),
+        ) {
+            (Some(character_grab_transitions), Some(sequence_id)) => {
+                character_grab_transitions.held != Some(*sequence_id)
+            }
+            _ => true,
+        };
+
+        holder_incapacitated || held_escaped
+    }
+
+    /// Removes the components that attach the held entity to the holder.
+    fn detach(
+        CharacterGrabEffectSystemData {
+            holdings,
+            held_bys,
+            target_objects,
+            chase_mode_sticks,
+            ..
+        }: &mut CharacterGrabEffectSystemData<'_>,
+        holder: Entity,
+        held: Entity,
+    ) {
+        if holdings.get(holder).map(|holding| holding.entity) == Some(held) {
+            holdings.remove(holder);
+        }
+        held_bys.remove(held);
+        target_objects.remove(held);
+        chase_mode_sticks.remove(held);
+    }
+
+    /// Releases the held entity, dropping it if it is still in its held sequence.
+    fn release(
+        character_grab_effect_system_data: &mut CharacterGrabEffectSystemData<'_>,
+        holder: Entity,
+        held: Entity,
+    ) {
+        Self::detach(character_grab_effect_system_data, holder, held);
+
+        let CharacterGrabEffectSystemData {
+            character_grab_transitionses,
+            velocities,
+            sequence_ids,
+            ..
+        } = character_grab_effect_system_data;
+
+        if let (Some(character_grab_transitions), Some(sequence_id)) = (
+            character_grab_transitionses.get(held),
+            sequence_ids.get_mut(held),
+        ) {
+            if character_grab_transitions.held == Some(*sequence_id) {
+                *sequence_id = character_grab_transitions.thrown;
+
+                if let Some(velocity) = velocities.get_mut(held) {
+                    *velocity = Velocity::default();
+                }
+            }
+        }
+    }
+
+    /// Attaches the contacted entity to the grabbing entity, if both are able to.
+    fn grab(
+        character_grab_effect_system_data: &mut CharacterGrabEffectSystemData<'_>,
+        grab: Grab,
+        holder: Entity,
+        held: Entity,
+    ) {
+        let CharacterGrabEffectSystemData {
+            character_grab_transitionses,
+            holdings,
+            held_bys,
+            target_objects,
+            chase_mode_sticks,
+            mirroreds,
+            health_pointses,
+            velocities,
+            sequence_ids,
+            ..
+        } = character_grab_effect_system_data;
+
+        // Objects may only hold, or be held by, one object at a time.
+        let is_attached = |entity| holdings.contains(entity) || held_bys.contains(entity);
+        if is_attached(holder) || is_attached(held) {
+            return;
+        }
+        let held_alive = health_pointses
+            .get(held)
+            .map_or(false, |health_points| *health_points > 0);
+        if !held_alive {
+            return;
+        }
+
+        let grabbing = character_grab_transitionses
+            .get(holder)
+            .and_then(|character_grab_transitions| character_grab_transitions.grabbing);
+        let held_sequence_id = character_grab_transitionses
+            .get(held)
+            .and_then(|character_grab_transitions| character_grab_transitions.held);
+
+        if let (Some(grabbing), Some(held_sequence_id)) = (grabbing, held_sequence_id) {
+            let holder_mirrored = mirroreds
+                .get(holder)
+                .map(|mirrored| **mirrored)
+                .unwrap_or(false);
+            let offset_x = if holder_mirrored {
+                -grab.offset.x
+            } else {
+                grab.offset.x
+            };
+            let offset =
+                Position::<f32>::new(offset_x as f32, grab.offset.y as f32, grab.offset.z as f32);
+
+            holdings
+                .insert(holder, Holding::new(held))
+                .expect("Failed to insert `Holding` component.");
+            held_bys
+                .insert(held, HeldBy::new(holder))
+                .expect("Failed to insert `HeldBy` component.");
+            target_objects
+                .insert(held, TargetObject::new(holder))
+                .expect("Failed to insert `TargetObject` component.");
+            chase_mode_sticks
+                .insert(held, ChaseModeStick::new(Some(offset)))
+                .expect("Failed to insert `ChaseModeStick` component.");
+
+            // Held characters face the character holding them.
+            if let Some(mirrored) = mirroreds.get_mut(held) {
+                *mirrored = Mirrored::new(!holder_mirrored);
+            }
+            if let Some(velocity) = velocities.get_mut(held) {
+                *velocity = Velocity::default();
+            }
+            if let Some(sequence_id) = sequence_ids.get_mut(holder) {
+                *sequence_id = grabbing;
+            }
+            if let Some(sequence_id) = sequence_ids.get_mut(held) {
+                *sequence_id = held_sequence_id;
+            }
+        }
+    }
+
+    /// Releases the held entity with the throw's damage and acceleration.
+    fn throw(
+        character_grab_effect_system_data: &mut CharacterGrabEffectSystemData<'_>,
+        throw: Throw,
+        holder: Entity,
+        held: Entity,
+    ) {
+        // Throws only affect the object that is held.
+        let is_holding = character_grab_effect_system_data
+            .holdings
+            .get(holder)
+            .map(|holding| holding.entity)
+            == Some(held);
+        if !is_holding {
+            return;
+        }
+
+        Self::detach(character_grab_effect_system_data, holder, held);
+
+        let CharacterGrabEffectSystemData {
+            character_grab_transitionses,
+            mirroreds,
+            health_pointses,
+            velocities,
+            sequence_ids,
+            ..
+        } = character_grab_effect_system_data;
+
+        let Throw {
+            hp_damage,
+            acceleration,
+        } = throw;
+
+        if let Some(health_points) = health_pointses.get_mut(held) {
+            if health_points.0 < hp_damage {
+                *health_points = HealthPoints(0);
+            } else {
+                (*health_points) -= hp_damage;
+            }
+        }
+
+        if let Some(velocity) = velocities.get_mut(held) {
+            let holder_mirrored = mirroreds
+                .get(holder)
+                .map(|mirrored| **mirrored)
+                .unwrap_or(false);
+            let acceleration_x = if holder_mirrored {
+                -acceleration.x
+            } else {
+                acceleration.x
+            };
+            *velocity = Velocity::new(
+                acceleration_x as f32,
+                acceleration.y as f32,
+                acceleration.z as f32,
+            );
+        }
+
+        if let (Some(character_grab_transitions), Some(sequence_id)) = (
+            character_grab_transitionses.get(held),
+            sequence_ids.get_mut(held),
+        ) {
+            *sequence_id = character_grab_transitions.thrown;
+        }
+    }
+}
+
+impl<'s> System<'s> for CharacterGrabEffectSystem {
+    type SystemData = CharacterGrabEffectSystemData<'s>;
+
+    fn run(&mut self, mut character_grab_effect_system_data: Self::SystemData) {
+        let contact_events = character_grab_effect_system_data
+            .contact_ec
+            .read(
+                self.contact_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for CharacterGrabEffectSystem."),
+            )
+            .filter(|ev| match ev.interaction.kind {
+                InteractionKind::Grab(_) | InteractionKind::Throw(_) => true,
+                InteractionKind::Hit(_) => false,
+            })
+            .cloned()
+            .collect::<Vec<ContactEvent>>();
+
+        // Release held characters before processing new grabs, so that a released character may
+        // be grabbed again on the same tick.
+        //
+        // Other held objects, such as weapons, are released by their own systems.
+        let releases = (
+            &character_grab_effect_system_data.entities,
+            &character_grab_effect_system_data.character_grab_transitionses,
+            &character_grab_effect_system_data.held_bys,
+        )
+            .join()
+            .map(|(held, _, held_by)| (held_by.entity, held))
+            .filter(|(holder, held)| {
+                Self::should_release(&character_grab_effect_system_data, *holder, *held)
+            })
+            .collect::<Vec<(Entity, Entity)>>();
+        releases.into_iter().for_each(|(holder, held)| {
+            Self::release(&mut character_grab_effect_system_data, holder, held);
+        });
+
+        // Held entities may be deleted, e.g. when they leave the map.
+        let holders_stale = (
+            &character_grab_effect_system_data.entities,
+            &character_grab_effect_system_data.holdings,
+        )
+            .join()
+            .filter(|(_, holding)| {
+                !character_grab_effect_system_data
+                    .entities
+                    .is_alive(holding.entity)
+            })
+            .map(|(holder, _)| holder)
+            .collect::<Vec<Entity>>();
+        holders_stale.into_iter().for_each(|holder| {
+            character_grab_effect_system_data.holdings.remove(holder);
+        });
+
+        contact_events
+            .into_iter()
+            .for_each(|ev| match ev.interaction.kind {
+                InteractionKind::Grab(grab) => {
+                    Self::grab(&mut character_grab_effect_system_data, grab, ev.from, ev.to)
+                }
+                InteractionKind::Throw(throw) => Self::throw(
+                    &mut character_grab_effect_system_data,
+                    throw,
+                    ev.from,
+                    ev.to,
+                ),
+                InteractionKind::Hit(_) => {}
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -1,26 +1,39 @@
 use amethyst::{
-    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    ecs::{Entity, Read, ReadStorage, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
 use character_model::loaded::CharacterHitTransitions;
 use collision_model::{
-    config::{Hit, Interaction, InteractionKind},
+    config::{Hit, InteractionKind},
     play::HitEvent,
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use game_play_model::{config::MatchRules, play::Combo};
+use kinematic_model::config::{Position, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::HealthPoints;
-use object_status_model::config::StunPoints;
+use object_status_model::config::{GuardPoints, StunPoints};
 use sequence_model::loaded::SequenceId;
+use spawn_model::play::SpawnParent;
 
 const STUN_THRESHOLD_LOW: StunPoints = StunPoints(40);
 const STUN_THRESHOLD_MID: StunPoints = StunPoints(80);
 const STUN_THRESHOLD_HIGH: StunPoints = StunPoints(120);
+/// HP damage is divided by this when a hit is blocked.
+const GUARD_HP_DAMAGE_DIVISOR: u32 = 4;
+/// Horizontal knockback is multiplied by this when a hit is blocked.
+const GUARD_KNOCKBACK_MULTIPLIER: f32 = 0.5;
 
 /// Determines collision effects for characters.
+///
+/// Characters that are defending block hits from the direction they are facing, taking reduced
+/// damage and knockback. Blocked hits reduce `GuardPoints` instead of inflicting stun, and the
+/// guard is broken when they run out.
+///
+/// Consecutive hits on a stunned character are tracked as a `Combo`, and each hit after the first
+/// has its damage and stun reduced by the `MatchRules`' `ComboScaling`.
 #[derive(Debug, Default, new)]
 pub struct CharacterHitEffectSystem {
     /// Reader ID for the `HitEvent` event channel.
@@ -35,9 +48,18 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `HitEvent` channel.
     #[derivative(Debug = "ignore")]
     pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `MatchRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub match_rules: Read<'s, MatchRules>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
@@ -47,12 +69,49 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `StunPoints` components.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `GuardPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub guard_pointses: WriteStorage<'s, GuardPoints>,
     /// `Velocity<f32>` components.
     #[derivative(Debug = "ignore")]
     pub velocities: WriteStorage<'s, Velocity<f32>>,
     /// `SequenceId` components.
     #[derivative(Debug = "ignore")]
     pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: WriteStorage<'s, Combo>,
+}
+
+impl CharacterHitEffectSystem {
+    /// Returns whether the hit comes from the direction the defending character is facing.
+    ///
+    /// Hits are treated as coming from the front when either position is unknown.
+    fn is_hit_from_front(
+        to_mirrored: bool,
+        to_position: Option<&Position<f32>>,
+        from_position: Option<&Position<f32>>,
+    ) -> bool {
+        if let (Some(to_position), Some(from_position)) = (to_position, from_position) {
+            if to_mirrored {
+                from_position.x <= to_position.x
+            } else {
+                from_position.x >= to_position.x
+            }
+        } else {
+            true
+        }
+    }
+
+    /// Returns the entity that a combo is attributed to.
+    ///
+    /// Hits from spawned objects are attributed to the entity that spawned them.
+    fn attacker(spawn_parents: &ReadStorage<'_, SpawnParent>, mut entity: Entity) -> Entity {
+        while let Some(spawn_parent) = spawn_parents.get(entity) {
+            entity = spawn_parent.entity;
+        }
+        entity
+    }
 }
 
 impl<'s> System<'s> for CharacterHitEffectSystem {
@@ -62,12 +121,17 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
         &mut self,
         CharacterHitEffectSystemData {
             hit_ec,
+            match_rules,
+            spawn_parents,
             mirroreds,
+            positions,
             character_hit_transitionses,
             mut health_pointses,
             mut stun_pointses,
+            mut guard_pointses,
             mut velocities,
             mut sequence_ids,
+            mut combos,
         }: Self::SystemData,
     ) {
         // Read from channel
@@ -95,50 +159,119 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     Some(stun_points),
                     Some(velocity),
                     Some(sequence_id),
+                    &InteractionKind::Hit(Hit {
+                        hp_damage,
+                        stun,
+                        acceleration,
+                        ..
+                    }),
                 ) = (
                     character_hit_transitions,
                     health_points,
                     stun_points,
                     velocity,
                     sequence_id,
+                    &ev.interaction.kind,
                 ) {
                     // TODO: Split this system with health check system.
-                    let Interaction {
-                        kind:
-                            InteractionKind::Hit(Hit {
-                                hp_damage,
-                                stun,
-                                acceleration,
-                                ..
-                            }),
-                        ..
-                    } = ev.interaction;
+                    let to_mirrored = mirroreds
+                        .get(ev.to)
+                        .map(|mirrored| **mirrored)
+                        .unwrap_or(false);
+                    let guard_points = guard_pointses.get_mut(ev.to);
+                    let guard_points = guard_points.filter(|_| {
+                        character_hit_transitions.is_defending(*sequence_id)
+                            && Self::is_hit_from_front(
+                                to_mirrored,
+                                positions.get(ev.to),
+                                positions.get(ev.from),
+                            )
+                    });
+
+                    // Blocked hits break the combo.
+                    let combo_count = if guard_points.is_some() {
+                        combos.remove(ev.to);
+                        1
+                    } else {
+                        let attacker = Self::attacker(&spawn_parents, ev.from);
+                        let combo_count = combos
+                            .get(ev.to)
+                            .filter(|combo| {
+                                combo.attacker == attacker
+                                    && character_hit_transitions.is_stunned(*sequence_id)
+                            })
+                            .map(|combo| combo.count + 1)
+                            .unwrap_or(1);
+                        combos
+                            .insert(ev.to, Combo::new(attacker, combo_count))
+                            .expect("Failed to insert `Combo` component.");
+                        combo_count
+                    };
+                    let combo_scaling = match_rules.combo_scaling;
+                    let hp_damage =
+                        (hp_damage as f32 * combo_scaling.hp_damage_multiplier(combo_count)) as u32;
+                    let stun = StunPoints(
+                        (stun.0 as f32 * combo_scaling.stun_multiplier(combo_count)) as u32,
+                    );
+
+                    let (hp_damage, knockback_multiplier) = if guard_points.is_some() {
+                        (
+                            hp_damage / GUARD_HP_DAMAGE_DIVISOR,
+                            GUARD_KNOCKBACK_MULTIPLIER,
+                        )
+                    } else {
+                        (hp_damage, 1.)
+                    };
+
                     if health_points.0 < hp_damage {
                         *health_points = HealthPoints(0);
                     } else {
                         (*health_points) -= hp_damage;
                     }
 
-                    *stun_points += stun;
-
+                    let knockback_x = (*acceleration).x as f32 * knockback_multiplier;
                     if mirrored {
-                        velocity.x -= (*acceleration).x as f32;
+                        velocity.x -= knockback_x;
                     } else {
-                        velocity.x += (*acceleration).x as f32;
+                        velocity.x += knockback_x;
                     }
-                    velocity.y += (*acceleration).y as f32;
-                    velocity.z += (*acceleration).z as f32;
-
-                    let next_sequence_id = if *health_points == 0 {
-                        character_hit_transitions.falling
-                    } else if *stun_points < STUN_THRESHOLD_LOW {
-                        character_hit_transitions.low_stun
-                    } else if *stun_points < STUN_THRESHOLD_MID {
-                        character_hit_transitions.mid_stun
-                    } else if *stun_points < STUN_THRESHOLD_HIGH {
-                        character_hit_transitions.high_stun
+
+                    let next_sequence_id = if let Some(guard_points) = guard_points {
+                        // Blocked hits do not launch the character.
+                        velocity.z += (*acceleration).z as f32 * knockback_multiplier;
+
+                        // Broken guards are recovered by the `GuardPointsRecoverySystem`.
+                        let guard_broken = guard_points.0 <= stun.0;
+                        if guard_broken {
+                            *guard_points = GuardPoints(0);
+                        } else {
+                            *guard_points -= stun.0;
+                        }
+
+                        if *health_points == 0 {
+                            character_hit_transitions.falling
+                        } else if guard_broken {
+                            character_hit_transitions.guard_break
+                        } else {
+                            character_hit_transitions.defend_hit
+                        }
                     } else {
-                        character_hit_transitions.falling
+                        *stun_points += stun;
+
+                        velocity.y += (*acceleration).y as f32;
+                        velocity.z += (*acceleration).z as f32;
+
+                        if *health_points == 0 {
+                            character_hit_transitions.falling
+                        } else if *stun_points < STUN_THRESHOLD_LOW {
+                            character_hit_transitions.low_stun
+                        } else if *stun_points < STUN_THRESHOLD_MID {
+                            character_hit_transitions.mid_stun
+                        } else if *stun_points < STUN_THRESHOLD_HIGH {
+                            character_hit_transitions.high_stun
+                        } else {
+                            character_hit_transitions.falling
+                        }
                     };
 
                     // Set sequence id
@@ -0,0 +1,90 @@
+use amethyst::{
+    ecs::{Join, Read, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{play::Stocks, GamePlayStatus};
+use kinematic_model::config::{Position, Velocity};
+use log::debug;
+use map_model::loaded::AssetMapBounds;
+use map_selection_model::MapSelection;
+use object_model::play::HealthPoints;
+
+use crate::SpawnPositionCalculator;
+
+/// Respawns objects that have run out of `HealthPoints` but still have `Stocks` remaining.
+#[derive(Debug, Default, new)]
+pub struct CharacterRespawnSystem;
+
+/// `CharacterRespawnSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterRespawnSystemData<'s> {
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl<'s> System<'s> for CharacterRespawnSystem {
+    type SystemData = CharacterRespawnSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterRespawnSystemData {
+            game_play_status,
+            map_selection,
+            asset_map_bounds,
+            mut stockses,
+            mut health_pointses,
+            mut positions,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        let spawn_position = SpawnPositionCalculator::calculate(*map_selection, &asset_map_bounds);
+
+        (
+            &mut stockses,
+            &mut health_pointses,
+            (&mut positions).maybe(),
+            (&mut velocities).maybe(),
+        )
+            .join()
+            .filter(|(stocks, health_points, _, _)| **health_points == 0 && **stocks > 1)
+            .for_each(|(stocks, health_points, position, velocity)| {
+                *stocks -= 1;
+                *health_points = HealthPoints::default();
+
+                if let (Some(position), Some(spawn_position)) = (position, spawn_position) {
+                    *position = spawn_position;
+                }
+                if let Some(velocity) = velocity {
+                    *velocity = Velocity::default();
+                }
+
+                debug!("Respawned object, stocks remaining: {}", stocks);
+            });
+    }
+}
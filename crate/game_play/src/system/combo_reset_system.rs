@@ -0,0 +1,71 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use character_model::loaded::CharacterHitTransitions;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::play::Combo;
+use sequence_model::loaded::SequenceId;
+
+/// Removes the `Combo` from characters that are no longer stunned.
+///
+/// This must run after the `CharacterHitEffectSystem`, so that characters who are hit this tick
+/// keep their combo.
+#[derive(Debug, Default, new)]
+pub struct ComboResetSystem;
+
+/// `ComboResetSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComboResetSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterHitTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: WriteStorage<'s, Combo>,
+}
+
+impl<'s> System<'s> for ComboResetSystem {
+    type SystemData = ComboResetSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ComboResetSystemData {
+            entities,
+            character_hit_transitionses,
+            sequence_ids,
+            mut combos,
+        }: Self::SystemData,
+    ) {
+        let entities_neutral = (&entities, &combos, (&sequence_ids).maybe())
+            .join()
+            .filter_map(|(entity, _, sequence_id)| {
+                let is_stunned = character_hit_transitionses
+                    .get(entity)
+                    .and_then(|character_hit_transitions| {
+                        sequence_id
+                            .map(|sequence_id| character_hit_transitions.is_stunned(*sequence_id))
+                    })
+                    .unwrap_or(false);
+
+                if is_stunned {
+                    None
+                } else {
+                    Some(entity)
+                }
+            })
+            .collect::<Vec<Entity>>();
+
+        entities_neutral.into_iter().for_each(|entity| {
+            combos.remove(entity);
+        });
+    }
+}
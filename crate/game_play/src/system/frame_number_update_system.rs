@@ -0,0 +1,31 @@
+use amethyst::{
+    ecs::{System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use network_input_model::play::FrameNumber;
+
+/// Increments the `FrameNumber` resource each time game play steps.
+///
+/// The `FrameNumber` is the number of frames game play has stepped since the match began, so
+/// systems that key information on game play frames should read it before this system runs.
+#[derive(Debug, Default, new)]
+pub struct FrameNumberUpdateSystem;
+
+/// `FrameNumberUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct FrameNumberUpdateSystemData<'s> {
+    /// `FrameNumber` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_number: Write<'s, FrameNumber>,
+}
+
+impl<'s> System<'s> for FrameNumberUpdateSystem {
+    type SystemData = FrameNumberUpdateSystemData<'s>;
+
+    fn run(&mut self, FrameNumberUpdateSystemData { mut frame_number }: Self::SystemData) {
+        *frame_number += 1;
+    }
+}
@@ -1,25 +1,32 @@
 use std::collections::HashMap;
 
 use amethyst::{
-    ecs::{Join, ReadStorage, System, World, Write},
+    ecs::{Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_play_model::{GamePlayEvent, GamePlayStatus};
+use game_play_model::{
+    config::MatchRules,
+    play::{RoundClock, RoundStartDelayClock, Stocks},
+    GamePlayEvent, GamePlayStatus,
+};
 use game_stats_model::play::{WinOutcome, WinStatus};
 use object_model::play::HealthPoints;
 use team_model::play::Team;
 
-/// Detects the end of a game play round, and fires a `GamePlayEvent::End`.
+/// Detects the end of a game play round, and fires a `GamePlayEvent::End` when the match is over.
 ///
-/// In the future this will be type parameterized to specify the detection function.
+/// If there are more rounds to play, a `GamePlayEvent::RoundEnd` is sent instead.
 #[derive(Debug, Default, new)]
 pub struct GamePlayEndDetectionSystem {
-    /// Pre-allocated `HashMap` to track number of alive players.
+    /// Pre-allocated `HashMap` to track remaining stocks and health points of alive teams.
+    #[new(default)]
+    team_alive_counter: HashMap<Team, (u32, u32)>,
+    /// Pre-allocated `HashMap` to track number of rounds won by each team.
     #[new(default)]
-    team_alive_counter: HashMap<Team, u32>,
+    team_round_wins: HashMap<Team, u32>,
 }
 
 /// `GamePlayEndDetectionSystemData`.
@@ -32,32 +39,45 @@ pub struct GamePlayEndDetectionSystemData<'s> {
     /// `GamePlayEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+    /// `MatchRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub match_rules: Read<'s, MatchRules>,
+    /// `RoundClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_clock: Write<'s, RoundClock>,
+    /// `RoundStartDelayClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_start_delay_clock: Write<'s, RoundStartDelayClock>,
     /// `Team` components.
     #[derivative(Debug = "ignore")]
     pub teams: ReadStorage<'s, Team>,
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: ReadStorage<'s, Stocks>,
     /// `WinStatus` resource.
     #[derivative(Debug = "ignore")]
     pub win_status: Write<'s, WinStatus>,
 }
 
 impl GamePlayEndDetectionSystem {
-    /// Computes and returns the `WinStatus` if the game has ended.
-    fn win_status(
+    /// Computes and returns the `WinOutcome` of the round if the round has ended.
+    fn round_outcome(
         &mut self,
         GamePlayEndDetectionSystemData {
+            match_rules,
+            round_clock,
             teams,
             health_pointses,
+            stockses,
             ..
-        }: &mut GamePlayEndDetectionSystemData,
-    ) -> Option<WinStatus> {
-        let team_alive_count = self.team_alive_count(&teams, &health_pointses);
+        }: &GamePlayEndDetectionSystemData,
+    ) -> Option<WinOutcome> {
+        let team_alive_count = self.team_alive_count(teams, health_pointses, stockses);
         if team_alive_count == 0 {
-            let win_outcome = WinOutcome::Draw;
-            let win_status = WinStatus::new(win_outcome);
-            Some(win_status)
+            Some(WinOutcome::Draw)
         } else if team_alive_count == 1 {
             let winning_team = self
                 .team_alive_counter
@@ -65,9 +85,9 @@ impl GamePlayEndDetectionSystem {
                 .next()
                 .copied()
                 .expect("Expected `Team` entry to exist.");
-            let win_outcome = WinOutcome::WinLoss { winning_team };
-            let win_status = WinStatus::new(win_outcome);
-            Some(win_status)
+            Some(WinOutcome::WinLoss { winning_team })
+        } else if match_rules.time_limit.is_some() && round_clock.is_complete() {
+            Some(self.time_up_outcome())
         } else {
             None
         }
@@ -78,21 +98,90 @@ impl GamePlayEndDetectionSystem {
         &mut self,
         teams: &ReadStorage<'_, Team>,
         health_pointses: &ReadStorage<'_, HealthPoints>,
+        stockses: &ReadStorage<'_, Stocks>,
     ) -> usize {
         self.team_alive_counter.clear();
 
-        // Game ends when there is one or less teams remaining
-        (teams, health_pointses)
-            .join()
-            .for_each(|(team, health_points)| {
+        // Round ends when there is one or less teams remaining
+        (teams, health_pointses, stockses.maybe()).join().for_each(
+            |(team, health_points, stocks)| {
                 if *health_points > 0 {
-                    let alive_count = self.team_alive_counter.entry(*team).or_insert(0);
-                    *alive_count += 1;
+                    let stocks = stocks.copied().unwrap_or_default();
+                    let (team_stocks, team_health_points) =
+                        self.team_alive_counter.entry(*team).or_insert((0, 0));
+                    *team_stocks += stocks.0;
+                    *team_health_points += health_points.0;
                 };
-            });
+            },
+        );
 
         self.team_alive_counter.len()
     }
+
+    /// Returns the outcome when time runs out.
+    ///
+    /// The team with the most remaining stocks wins, followed by the most remaining health points.
+    fn time_up_outcome(&self) -> WinOutcome {
+        let best = self
+            .team_alive_counter
+            .values()
+            .copied()
+            .max()
+            .expect("Expected `Team` entry to exist.");
+        let mut leading_teams = self
+            .team_alive_counter
+            .iter()
+            .filter(|(_, remaining)| **remaining == best)
+            .map(|(team, _)| *team);
+
+        match (leading_teams.next(), leading_teams.next()) {
+            (Some(winning_team), None) => WinOutcome::WinLoss { winning_team },
+            _ => WinOutcome::Draw,
+        }
+    }
+
+    /// Returns the `WinOutcome` of the match if the match has ended.
+    fn match_outcome(
+        &mut self,
+        match_rules: MatchRules,
+        round_outcomes: &[WinOutcome],
+    ) -> Option<WinOutcome> {
+        self.team_round_wins.clear();
+        round_outcomes
+            .iter()
+            .for_each(|round_outcome| match round_outcome {
+                WinOutcome::WinLoss { winning_team } => {
+                    *self.team_round_wins.entry(*winning_team).or_insert(0) += 1;
+                }
+                WinOutcome::None | WinOutcome::Draw => {}
+            });
+
+        let rounds_to_win = match_rules.rounds_to_win();
+        let match_winner = self
+            .team_round_wins
+            .iter()
+            .find(|(_, round_wins)| **round_wins >= rounds_to_win)
+            .map(|(team, _)| *team);
+
+        if let Some(winning_team) = match_winner {
+            Some(WinOutcome::WinLoss { winning_team })
+        } else if round_outcomes.len() >= match_rules.rounds as usize {
+            // All rounds are played, but no team has won a majority due to drawn rounds.
+            let most_wins = self.team_round_wins.values().copied().max();
+            let mut leading_teams = self
+                .team_round_wins
+                .iter()
+                .filter(|(_, round_wins)| Some(**round_wins) == most_wins)
+                .map(|(team, _)| *team);
+
+            match (leading_teams.next(), leading_teams.next()) {
+                (Some(winning_team), None) => Some(WinOutcome::WinLoss { winning_team }),
+                _ => Some(WinOutcome::Draw),
+            }
+        } else {
+            None
+        }
+    }
 }
 
 impl<'s> System<'s> for GamePlayEndDetectionSystem {
@@ -101,19 +190,35 @@ impl<'s> System<'s> for GamePlayEndDetectionSystem {
     fn run(&mut self, mut system_data: Self::SystemData) {
         match *system_data.game_play_status {
             GamePlayStatus::Playing => {
-                if let Some(win_status) = self.win_status(&mut system_data) {
-                    *system_data.win_status = win_status;
-                    *system_data.game_play_status = GamePlayStatus::Ended;
-                    system_data.game_play_ec.single_write(GamePlayEvent::End);
+                system_data.round_clock.tick();
+
+                if let Some(round_outcome) = self.round_outcome(&system_data) {
+                    let match_rules = *system_data.match_rules;
+                    system_data.win_status.round_outcomes.push(round_outcome);
+
+                    let match_outcome =
+                        self.match_outcome(match_rules, &system_data.win_status.round_outcomes);
+                    if let Some(match_outcome) = match_outcome {
+                        system_data.win_status.outcome = match_outcome;
+                        *system_data.game_play_status = GamePlayStatus::Ended;
+                        system_data.game_play_ec.single_write(GamePlayEvent::End);
+                    } else {
+                        *system_data.round_start_delay_clock =
+                            RoundStartDelayClock::new(match_rules.round_start_delay);
+                        *system_data.game_play_status = GamePlayStatus::RoundEnded;
+                        system_data
+                            .game_play_ec
+                            .single_write(GamePlayEvent::RoundEnd);
+                    }
                 }
             }
             GamePlayStatus::Ended => {
-                if self.win_status(&mut system_data).is_none() {
+                if self.round_outcome(&system_data).is_none() {
                     *system_data.win_status = WinStatus::default();
                     *system_data.game_play_status = GamePlayStatus::Playing;
                 }
             }
-            GamePlayStatus::None | GamePlayStatus::Paused => {}
+            GamePlayStatus::None | GamePlayStatus::Paused | GamePlayStatus::RoundEnded => {}
         }
     }
 }
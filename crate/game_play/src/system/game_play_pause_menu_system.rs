@@ -0,0 +1,102 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::{AssetId, AssetIdMappings, AssetItemIds, ItemId};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{play::GamePlayPauseEntity, GamePlayEntity, GamePlayStatus};
+use state_registry::StateId;
+use state_support::StateAssetUtils;
+
+/// Spawns the pause menu when game play is paused, and deletes it when game play is resumed.
+///
+/// The pause menu is spawned from the items of the `StateId::GamePlayPause` UI asset.
+///
+/// This system must not be paused, as it deletes the pause menu when another state is pushed.
+#[derive(Debug, Default, new)]
+pub struct GamePlayPauseMenuSystem;
+
+/// `GamePlayPauseMenuSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayPauseMenuSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `AssetItemIds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_ids: Read<'s, AssetItemIds>,
+    /// `GamePlayPauseEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_pause_entities: WriteStorage<'s, GamePlayPauseEntity>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: WriteStorage<'s, AssetId>,
+    /// `ItemId` components.
+    #[derivative(Debug = "ignore")]
+    pub item_ids: WriteStorage<'s, ItemId>,
+}
+
+impl<'s> System<'s> for GamePlayPauseMenuSystem {
+    type SystemData = GamePlayPauseMenuSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayPauseMenuSystemData {
+            entities,
+            state_id,
+            game_play_status,
+            asset_id_mappings,
+            asset_item_ids,
+            mut game_play_pause_entities,
+            mut game_play_entities,
+            mut asset_ids,
+            mut item_ids,
+        }: Self::SystemData,
+    ) {
+        let menu_shown = (&entities, &game_play_pause_entities)
+            .join()
+            .next()
+            .is_some();
+        let menu_wanted =
+            *state_id == StateId::GamePlay && *game_play_status == GamePlayStatus::Paused;
+
+        if menu_wanted && !menu_shown {
+            // Don't panic if there is no pause menu asset.
+            let asset_id = StateAssetUtils::asset_id(&asset_id_mappings, StateId::GamePlayPause);
+            let items = asset_id.and_then(|asset_id| asset_item_ids.get(asset_id));
+            if let (Some(asset_id), Some(items)) = (asset_id, items) {
+                items.iter().copied().for_each(|item_id| {
+                    entities
+                        .build_entity()
+                        .with(GamePlayPauseEntity, &mut game_play_pause_entities)
+                        .with(GamePlayEntity, &mut game_play_entities)
+                        .with(asset_id, &mut asset_ids)
+                        .with(item_id, &mut item_ids)
+                        .build();
+                });
+            }
+        } else if !menu_wanted && menu_shown {
+            (&entities, &game_play_pause_entities)
+                .join()
+                .for_each(|(entity, _)| {
+                    entities
+                        .delete(entity)
+                        .expect("Failed to delete `GamePlayPauseEntity` entity.");
+                });
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use amethyst::{
+    ecs::{Join, Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::{
+    config::MatchRules,
+    play::{RoundClock, RoundStartDelayClock, Stocks},
+    GamePlayEvent, GamePlayStatus,
+};
+use kinematic_model::config::{Position, Velocity};
+use map_model::loaded::AssetMapBounds;
+use map_selection_model::MapSelection;
+use object_model::play::HealthPoints;
+
+use crate::SpawnPositionCalculator;
+
+/// Starts the next round once the round start delay has passed, and fires a
+/// `GamePlayEvent::RoundStart`.
+///
+/// Objects with `Stocks` have their stocks, health points, and position reset.
+#[derive(Debug, Default, new)]
+pub struct GamePlayRoundStartSystem;
+
+/// `GamePlayRoundStartSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayRoundStartSystemData<'s> {
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Write<'s, GamePlayStatus>,
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+    /// `MatchRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub match_rules: Read<'s, MatchRules>,
+    /// `RoundClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_clock: Write<'s, RoundClock>,
+    /// `RoundStartDelayClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_start_delay_clock: Write<'s, RoundStartDelayClock>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl<'s> System<'s> for GamePlayRoundStartSystem {
+    type SystemData = GamePlayRoundStartSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayRoundStartSystemData {
+            mut game_play_status,
+            mut game_play_ec,
+            match_rules,
+            mut round_clock,
+            mut round_start_delay_clock,
+            map_selection,
+            asset_map_bounds,
+            mut stockses,
+            mut health_pointses,
+            mut positions,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::RoundEnded {
+            return;
+        }
+
+        round_start_delay_clock.tick();
+        if !round_start_delay_clock.is_complete() {
+            return;
+        }
+
+        let spawn_position = SpawnPositionCalculator::calculate(*map_selection, &asset_map_bounds);
+        (
+            &mut stockses,
+            &mut health_pointses,
+            (&mut positions).maybe(),
+            (&mut velocities).maybe(),
+        )
+            .join()
+            .for_each(|(stocks, health_points, position, velocity)| {
+                *stocks = Stocks::new(match_rules.stocks);
+                *health_points = HealthPoints::default();
+
+                if let (Some(position), Some(spawn_position)) = (position, spawn_position) {
+                    *position = spawn_position;
+                }
+                if let Some(velocity) = velocity {
+                    *velocity = Velocity::default();
+                }
+            });
+
+        *round_clock = RoundClock::new(match_rules.time_limit.unwrap_or_default());
+        *game_play_status = GamePlayStatus::Playing;
+        game_play_ec.single_write(GamePlayEvent::RoundStart);
+    }
+}
@@ -1,5 +1,6 @@
 use std::convert::TryInto;
 
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Join, Read, ReadExpect, ReadStorage, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -25,7 +26,8 @@ const LABEL_HEIGHT: f32 = 100.;
 
 /// Displays the status of game play.
 ///
-/// Intended for displaying when the game is paused, or when the game ends (winning team).
+/// Intended for displaying when the game is paused, or when a round or the game ends (winning
+/// team).
 #[derive(Debug, Default, new)]
 pub struct GamePlayStatusDisplaySystem {
     /// Reader ID for the `GamePlayEvent` event channel.
@@ -58,6 +60,9 @@ pub struct GamePlayStatusDisplaySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Read<'s, PlayerControllers>,
@@ -89,22 +94,33 @@ impl GamePlayStatusDisplaySystem {
             });
     }
 
-    fn win_status_text(
-        win_status: WinStatus,
+    fn win_outcome_text(
+        win_outcome: WinOutcome,
+        entities: &Entities<'_>,
         teams: &ReadStorage<'_, Team>,
         input_controlleds: &ReadStorage<'_, InputControlled>,
+        ai_controlleds: &ReadStorage<'_, AiControlled>,
         player_controllers: &PlayerControllers,
     ) -> String {
-        match win_status.outcome {
+        match win_outcome {
             WinOutcome::None => String::from("Ongoing Match"),
             WinOutcome::WinLoss { winning_team } => {
-                let winner = (teams, input_controlleds)
+                let winner = (entities, teams)
                     .join()
-                    .find(|(team, _)| **team == winning_team)
-                    .map(|(team, input_controlled)| match team {
+                    .filter(|(_, team)| **team == winning_team)
+                    .find_map(|(entity, team)| {
+                        input_controlleds
+                            .get(entity)
+                            .map(|input_controlled| (team, input_controlled.controller_id, false))
+                            .or_else(|| {
+                                ai_controlleds
+                                    .get(entity)
+                                    .map(|ai_controlled| (team, ai_controlled.controller_id, true))
+                            })
+                    })
+                    .map(|(team, controller_id, is_cpu)| match team {
                         Team::Independent(..) => {
-                            let controller_id = input_controlled.controller_id;
-                            player_controllers
+                            let name = player_controllers
                                 .get(
                                     TryInto::<usize>::try_into(controller_id)
                                         .expect("Failed to convert `u32` into `usize`"),
@@ -120,7 +136,13 @@ impl GamePlayStatusDisplaySystem {
                                     );
 
                                     String::from("")
-                                })
+                                });
+
+                            if is_cpu {
+                                format!("{} (CPU)", name)
+                            } else {
+                                name
+                            }
                         }
                         Team::Number(team_counter) => format!("Team {}", team_counter),
                     })
@@ -143,6 +165,7 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
             win_status,
             teams,
             input_controlleds,
+            ai_controlleds,
             player_controllers,
             mut game_play_status_entities,
             mut game_play_entities,
@@ -162,7 +185,36 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
             .for_each(|ev| {
                 Self::delete_existing(&entities, &mut game_play_status_entities);
 
-                if ev == GamePlayEvent::End {
+                let status_text = match ev {
+                    GamePlayEvent::End => Some(Self::win_outcome_text(
+                        win_status.outcome,
+                        &entities,
+                        &teams,
+                        &input_controlleds,
+                        &ai_controlleds,
+                        &player_controllers,
+                    )),
+                    GamePlayEvent::RoundEnd => {
+                        win_status.round_outcomes.last().map(|round_outcome| {
+                            let round_outcome_text = Self::win_outcome_text(
+                                *round_outcome,
+                                &entities,
+                                &teams,
+                                &input_controlleds,
+                                &ai_controlleds,
+                                &player_controllers,
+                            );
+                            format!(
+                                "Round {}: {}",
+                                win_status.round_outcomes.len(),
+                                round_outcome_text
+                            )
+                        })
+                    }
+                    _ => None,
+                };
+
+                if let Some(status_text) = status_text {
                     let font = theme
                         .fonts
                         .get(&FontVariant::Bold)
@@ -183,16 +235,9 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
                         LABEL_HEIGHT,
                     );
 
-                    let win_status_text = Self::win_status_text(
-                        *win_status,
-                        &teams,
-                        &input_controlleds,
-                        &player_controllers,
-                    );
-
                     let ui_text = UiText::new(
                         font.clone(),
-                        win_status_text,
+                        status_text,
                         FONT_COLOUR_NEUTRAL,
                         FONT_SIZE_WIDGET,
                     );
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use ai_model::play::AiControlled;
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::{Hit, InteractionKind},
+    play::HitEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{config::ControllerId, play::InputControlled};
+use game_play_model::{play::Combo, GamePlayStatus};
+use game_stats_model::play::{GameStats, PlayerStats};
+use object_model::play::{HealthPoints, SkillPoints};
+use spawn_model::play::SpawnParent;
+use tracker::Last;
+
+/// Tracks each player's statistics during a match.
+///
+/// Damage is attributed from `HitEvent`s, bounded by the health points the hit object actually
+/// lost this tick. This system must run after the `ComboResetSystem`, and before the
+/// `LastTrackerSystem`s for `HealthPoints` and `SkillPoints`.
+///
+/// Hits from spawned objects are attributed to the player who spawned them.
+#[derive(Debug, Default, new)]
+pub struct GameStatsTrackingSystem {
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+    /// Pre-allocated `HashMap` to track health points lost by each hit object this tick.
+    #[new(default)]
+    hp_losses: HashMap<Entity, u32>,
+}
+
+/// `GameStatsTrackingSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameStatsTrackingSystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Last<HealthPoints>` components.
+    #[derivative(Debug = "ignore")]
+    pub last_health_pointses: ReadStorage<'s, Last<HealthPoints>>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: ReadStorage<'s, SkillPoints>,
+    /// `Last<SkillPoints>` components.
+    #[derivative(Debug = "ignore")]
+    pub last_skill_pointses: ReadStorage<'s, Last<SkillPoints>>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: ReadStorage<'s, Combo>,
+    /// `GameStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_stats: Write<'s, GameStats>,
+}
+
+impl GameStatsTrackingSystem {
+    /// Returns the `ControllerId` of the player who controls the entity or its spawner.
+    fn controller_id(
+        input_controlleds: &ReadStorage<'_, InputControlled>,
+        ai_controlleds: &ReadStorage<'_, AiControlled>,
+        spawn_parents: &ReadStorage<'_, SpawnParent>,
+        mut entity: Entity,
+    ) -> Option<ControllerId> {
+        loop {
+            if let Some(input_controlled) = input_controlleds.get(entity) {
+                return Some(input_controlled.controller_id);
+            }
+            if let Some(ai_controlled) = ai_controlleds.get(entity) {
+                return Some(ai_controlled.controller_id);
+            }
+            entity = spawn_parents.get(entity)?.entity;
+        }
+    }
+}
+
+impl<'s> System<'s> for GameStatsTrackingSystem {
+    type SystemData = GameStatsTrackingSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameStatsTrackingSystemData {
+            hit_ec,
+            game_play_status,
+            entities,
+            input_controlleds,
+            ai_controlleds,
+            spawn_parents,
+            health_pointses,
+            last_health_pointses,
+            skill_pointses,
+            last_skill_pointses,
+            combos,
+            mut game_stats,
+        }: Self::SystemData,
+    ) {
+        let GameStatsTrackingSystem {
+            hit_event_rid,
+            hp_losses,
+        } = self;
+
+        let hit_events = hit_ec.read(
+            hit_event_rid
+                .as_mut()
+                .expect("Expected reader ID to exist for GameStatsTrackingSystem."),
+        );
+
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        (&entities, &input_controlleds)
+            .join()
+            .map(|(entity, input_controlled)| (entity, input_controlled.controller_id, false))
+            .chain(
+                (&entities, &ai_controlleds)
+                    .join()
+                    .map(|(entity, ai_controlled)| (entity, ai_controlled.controller_id, true)),
+            )
+            .for_each(|(entity, controller_id, is_cpu)| {
+                let player_stats = game_stats
+                    .entry(controller_id)
+                    .or_insert_with(|| PlayerStats::new(is_cpu));
+
+                let skill_points = skill_pointses.get(entity).copied();
+                let last_skill_points = last_skill_pointses.get(entity).map(|last| last.0);
+                if let (Some(skill_points), Some(last_skill_points)) =
+                    (skill_points, last_skill_points)
+                {
+                    player_stats.sp_used += last_skill_points.0.saturating_sub(skill_points.0);
+                }
+            });
+
+        hp_losses.clear();
+        hit_events.for_each(|ev| {
+            let hp_damage = if let InteractionKind::Hit(Hit { hp_damage, .. }) = ev.interaction.kind
+            {
+                hp_damage
+            } else {
+                return;
+            };
+
+            let health_points = health_pointses.get(ev.to).copied();
+            let last_health_points = last_health_pointses.get(ev.to).map(|last| last.0);
+            let hp_loss = hp_losses.entry(ev.to).or_insert_with(|| {
+                if let (Some(health_points), Some(last_health_points)) =
+                    (health_points, last_health_points)
+                {
+                    last_health_points.0.saturating_sub(health_points.0)
+                } else {
+                    0
+                }
+            });
+            let damage = hp_damage.min(*hp_loss);
+            *hp_loss -= damage;
+            let is_ko = damage > 0 && *hp_loss == 0 && health_points == Some(HealthPoints(0));
+
+            let controller_id_from =
+                Self::controller_id(&input_controlleds, &ai_controlleds, &spawn_parents, ev.from);
+            let controller_id_to =
+                Self::controller_id(&input_controlleds, &ai_controlleds, &spawn_parents, ev.to);
+
+            if let Some(controller_id_from) = controller_id_from {
+                // Objects that are not comboed, such as those without hit transitions, count
+                // each hit as a combo of one.
+                let combo_count = combos.get(ev.to).map(|combo| combo.count).unwrap_or(1);
+
+                if let Some(player_stats) = game_stats.get_mut(&controller_id_from) {
+                    player_stats.hits_landed += 1;
+                    player_stats.damage_dealt += damage;
+                    player_stats.max_combo = player_stats.max_combo.max(combo_count);
+                    if is_ko {
+                        player_stats.kos += 1;
+                    }
+                }
+            }
+
+            if let Some(player_stats) =
+                controller_id_to.and_then(|controller_id_to| game_stats.get_mut(&controller_id_to))
+            {
+                player_stats.damage_taken += damage;
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
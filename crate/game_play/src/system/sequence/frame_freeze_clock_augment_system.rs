@@ -3,10 +3,7 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use collision_model::{
-    config::{Interaction, InteractionKind},
-    play::HitEvent,
-};
+use collision_model::{config::InteractionKind, play::HitEvent};
 use derivative::Derivative;
 use derive_new::new;
 use sequence_model::play::FrameFreezeClock;
@@ -51,15 +48,12 @@ impl<'s> System<'s> for FrameFreezeClockAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add `FrameFreezeClock` for `Hit` interactions.
-                let Interaction {
-                    kind: InteractionKind::Hit(_),
-                    ..
-                } = ev.interaction;
-
-                let frame_freeze_clock = FrameFreezeClock::new(3);
-                frame_freeze_clocks
-                    .insert(ev.from, frame_freeze_clock)
-                    .expect("Failed to insert `FrameFreezeClock`.");
+                if let InteractionKind::Hit(_) = ev.interaction.kind {
+                    let frame_freeze_clock = FrameFreezeClock::new(3);
+                    frame_freeze_clocks
+                        .insert(ev.from, frame_freeze_clock)
+                        .expect("Failed to insert `FrameFreezeClock`.");
+                }
             });
     }
 
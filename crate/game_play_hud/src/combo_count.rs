@@ -0,0 +1,6 @@
+use amethyst::ecs::{storage::NullStorage, Component};
+
+/// Tag component for combo counter text.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+#[storage(NullStorage)]
+pub struct ComboCount;
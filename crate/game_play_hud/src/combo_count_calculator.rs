@@ -0,0 +1,30 @@
+use amethyst::ecs::{Entity, Join, ReadStorage};
+use game_play_model::play::Combo;
+
+use crate::COMBO_COUNT_DISPLAY_MIN;
+
+/// Calculates the number of hits in the combo that an entity is landing.
+#[derive(Debug)]
+pub struct ComboCountCalculator;
+
+impl ComboCountCalculator {
+    /// Returns the number of hits in the longest combo the attacker is landing.
+    ///
+    /// `Combo`s are attached to the entities being hit, so this finds the longest combo whose
+    /// attacker is the given entity. Returns `0` if the combo has fewer than
+    /// `COMBO_COUNT_DISPLAY_MIN` hits.
+    ///
+    /// # Parameters
+    ///
+    /// * `combos`: `Combo` components.
+    /// * `attacker`: Entity landing the combo.
+    pub fn combo_count(combos: &ReadStorage<'_, Combo>, attacker: Entity) -> u32 {
+        combos
+            .join()
+            .filter(|combo| combo.attacker == attacker)
+            .map(|combo| combo.count)
+            .max()
+            .filter(|combo_count| *combo_count >= COMBO_COUNT_DISPLAY_MIN)
+            .unwrap_or(0)
+    }
+}
@@ -11,3 +11,19 @@ pub const CP_BAR_LENGTH: f32 = 100.;
 pub const CP_BAR_HEIGHT: f32 = 3.;
 /// Number of sprites on the `CpBar` sprite sheet.
 pub const CP_BAR_SPRITE_COUNT: usize = 10;
+
+/// Maximum length to render the `ComboBar`.
+pub const COMBO_BAR_LENGTH: f32 = 100.;
+/// Length of the `ComboBar` for each hit in a combo.
+pub const COMBO_BAR_PIP_LENGTH: f32 = 5.;
+/// Height to render the `ComboBar`.
+pub const COMBO_BAR_HEIGHT: f32 = 3.;
+/// Number of sprites on the `ComboBar` sprite sheet.
+pub const COMBO_BAR_SPRITE_COUNT: usize = 10;
+
+/// Minimum number of hits before the combo is displayed.
+pub const COMBO_COUNT_DISPLAY_MIN: u32 = 2;
+/// Width of the `ComboCount` text.
+pub const COMBO_COUNT_WIDTH: f32 = 100.;
+/// Height of the `ComboCount` text.
+pub const COMBO_COUNT_HEIGHT: f32 = 20.;
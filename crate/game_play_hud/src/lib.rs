@@ -3,16 +3,23 @@
 //! Heads-up-display (HUD) types to provide information in game.
 
 pub use crate::{
+    combo_bar::ComboBar,
+    combo_count::ComboCount,
+    combo_count_calculator::ComboCountCalculator,
     constants::{
-        CP_BAR_HEIGHT, CP_BAR_LENGTH, CP_BAR_SPRITE_COUNT, HP_BAR_HEIGHT, HP_BAR_LENGTH,
-        HP_BAR_SPRITE_COUNT,
+        COMBO_BAR_HEIGHT, COMBO_BAR_LENGTH, COMBO_BAR_PIP_LENGTH, COMBO_BAR_SPRITE_COUNT,
+        COMBO_COUNT_DISPLAY_MIN, COMBO_COUNT_HEIGHT, COMBO_COUNT_WIDTH, CP_BAR_HEIGHT,
+        CP_BAR_LENGTH, CP_BAR_SPRITE_COUNT, HP_BAR_HEIGHT, HP_BAR_LENGTH, HP_BAR_SPRITE_COUNT,
     },
     cp_bar::CpBar,
     hp_bar::HpBar,
-    prefab::{CpBarPrefab, HpBarPrefab},
-    system::{CpBarUpdateSystem, HpBarUpdateSystem},
+    prefab::{ComboBarPrefab, ComboCountPrefab, CpBarPrefab, HpBarPrefab},
+    system::{ComboBarUpdateSystem, ComboCountUpdateSystem, CpBarUpdateSystem, HpBarUpdateSystem},
 };
 
+mod combo_bar;
+mod combo_count;
+mod combo_count_calculator;
 mod constants;
 mod cp_bar;
 mod hp_bar;
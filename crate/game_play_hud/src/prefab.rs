@@ -1,4 +1,9 @@
-pub use self::{cp_bar_prefab::CpBarPrefab, hp_bar_prefab::HpBarPrefab};
+pub use self::{
+    combo_bar_prefab::ComboBarPrefab, combo_count_prefab::ComboCountPrefab,
+    cp_bar_prefab::CpBarPrefab, hp_bar_prefab::HpBarPrefab,
+};
 
+mod combo_bar_prefab;
+mod combo_count_prefab;
 mod cp_bar_prefab;
 mod hp_bar_prefab;
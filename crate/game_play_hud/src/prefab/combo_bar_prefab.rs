@@ -0,0 +1,118 @@
+use amethyst::{
+    assets::PrefabData,
+    core::{math::Vector3, Transform},
+    ecs::{Entity, World, WriteStorage},
+    renderer::{transparent::Transparent, SpriteRender},
+    shred::{ResourceId, SystemData},
+    Error,
+};
+use asset_gfx_gen::{ColourSpriteSheetGen, ColourSpriteSheetGenData};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use parent_model::play::ParentEntity;
+
+use crate::{ComboBar, COMBO_BAR_HEIGHT, COMBO_BAR_SPRITE_COUNT};
+
+const COLOUR_COMBO_LOW: [f32; 4] = [1., 0.9, 0.2, 0.8];
+const COLOUR_COMBO_HIGH: [f32; 4] = [1., 0.3, 0.1, 0.8];
+
+/// Prefab to attach all components of a combo bar.
+///
+/// These include:
+///
+/// * `ComboBar`: Tag component.
+/// * `Transform`: Coordinates of the combo bar to draw.
+/// * `Parent`: Link to the parent entity whose `Combo` count the `ComboBar` entity will display.
+/// * `SpriteRender`: Indicates which "sprite" (colour) of the `ComboBar` to draw.
+/// * `Transparent`: Tags the `ComboBar` for sorting when rendering.
+///
+/// Ideally, the `Parent` component will be inserted by the `PrefabLoaderSystem`, so the (game
+/// object) entity whose `Combo` count should displayed is specified as the `parent` of the
+/// `ComboBar` entity. However this is not currently possible ergonomically, see
+/// <https://community.amethyst-engine.org/t/prefabs-with-special-cases-at-runtime/589> for
+/// discussion.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct ComboBarPrefab {
+    /// Entity whose `Combo` count to display.
+    pub game_object_entity: Entity,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComboBarPrefabSystemData<'s> {
+    /// `ComboBar` components.
+    #[derivative(Debug = "ignore")]
+    pub combo_bars: WriteStorage<'s, ComboBar>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// System data needed to load colour sprites.
+    #[derivative(Debug = "ignore")]
+    pub colour_sprite_sheet_gen_data: ColourSpriteSheetGenData<'s>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: WriteStorage<'s, SpriteRender>,
+    /// `Transparent` components.
+    #[derivative(Debug = "ignore")]
+    pub transparents: WriteStorage<'s, Transparent>,
+}
+
+impl<'s> PrefabData<'s> for ComboBarPrefab {
+    type SystemData = ComboBarPrefabSystemData<'s>;
+    type Result = ();
+
+    fn add_to_entity(
+        &self,
+        entity: Entity,
+        ComboBarPrefabSystemData {
+            combo_bars,
+            transforms,
+            parent_entities,
+            target_objects,
+            chase_mode_sticks,
+            colour_sprite_sheet_gen_data,
+            sprite_renders,
+            transparents,
+        }: &mut Self::SystemData,
+        _entities: &[Entity],
+        _children: &[Entity],
+    ) -> Result<(), Error> {
+        let parent_translation = transforms
+            .get(self.game_object_entity)
+            .map(Transform::translation)
+            .copied();
+
+        combo_bars.insert(entity, ComboBar::default())?;
+        let mut transform = Transform::default();
+        if let Some(translation) = parent_translation {
+            *transform.translation_mut() = translation;
+        }
+        // Hidden until the entity lands a combo.
+        transform.set_scale(Vector3::new(0., COMBO_BAR_HEIGHT, 1.));
+        transforms.insert(entity, transform)?;
+        parent_entities.insert(entity, ParentEntity::new(self.game_object_entity))?;
+        target_objects.insert(entity, TargetObject::new(self.game_object_entity))?;
+        chase_mode_sticks.insert(entity, Default::default())?;
+
+        let sprite_render = ColourSpriteSheetGen::gradient(
+            colour_sprite_sheet_gen_data,
+            COLOUR_COMBO_LOW,
+            COLOUR_COMBO_HIGH,
+            COMBO_BAR_SPRITE_COUNT,
+        );
+        sprite_renders.insert(entity, sprite_render)?;
+        transparents.insert(entity, Transparent)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use amethyst::{
+    assets::PrefabData,
+    ecs::{Entity, ReadExpect, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::{Anchor, UiText, UiTransform},
+    Error,
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use parent_model::play::ParentEntity;
+
+use crate::{ComboCount, COMBO_COUNT_HEIGHT, COMBO_COUNT_WIDTH};
+
+const FONT_COLOUR_COMBO: [f32; 4] = [1., 0.6, 0.1, 1.];
+const FONT_SIZE_COMBO: f32 = 18.;
+
+/// Prefab to attach all components of a combo counter.
+///
+/// These include:
+///
+/// * `ComboCount`: Tag component.
+/// * `Parent`: Link to the parent entity whose `Combo` count the `ComboCount` entity will display.
+/// * `UiTransform`: Screen coordinates of the combo count to draw.
+/// * `UiText`: Text of the combo count, empty until the entity lands a combo.
+///
+/// See `ComboBarPrefab` for why the `Parent` component is inserted here.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct ComboCountPrefab {
+    /// Entity whose `Combo` count to display.
+    pub game_object_entity: Entity,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComboCountPrefabSystemData<'s> {
+    /// `ComboCount` components.
+    #[derivative(Debug = "ignore")]
+    pub combo_counts: WriteStorage<'s, ComboCount>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl<'s> PrefabData<'s> for ComboCountPrefab {
+    type SystemData = ComboCountPrefabSystemData<'s>;
+    type Result = ();
+
+    fn add_to_entity(
+        &self,
+        entity: Entity,
+        ComboCountPrefabSystemData {
+            combo_counts,
+            parent_entities,
+            theme,
+            ui_transforms,
+            ui_texts,
+        }: &mut Self::SystemData,
+        _entities: &[Entity],
+        _children: &[Entity],
+    ) -> Result<(), Error> {
+        combo_counts.insert(entity, ComboCount::default())?;
+        parent_entities.insert(entity, ParentEntity::new(self.game_object_entity))?;
+
+        // Positioned next to the parent entity by the `ComboCountUpdateSystem`.
+        let ui_transform = UiTransform::new(
+            format!("combo_count_{:?}", entity),
+            Anchor::BottomLeft,
+            Anchor::Middle,
+            0.,
+            0.,
+            1.,
+            COMBO_COUNT_WIDTH,
+            COMBO_COUNT_HEIGHT,
+        );
+        ui_transforms.insert(entity, ui_transform)?;
+
+        let font = theme
+            .fonts
+            .get(&FontVariant::Bold)
+            .expect("Failed to get bold font handle.");
+        let ui_text = UiText::new(
+            font.clone(),
+            String::new(),
+            FONT_COLOUR_COMBO,
+            FONT_SIZE_COMBO,
+        );
+        ui_texts.insert(entity, ui_text)?;
+
+        Ok(())
+    }
+}
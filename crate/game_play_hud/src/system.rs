@@ -1,4 +1,10 @@
-pub use self::{cp_bar_update_system::CpBarUpdateSystem, hp_bar_update_system::HpBarUpdateSystem};
+pub use self::{
+    combo_bar_update_system::ComboBarUpdateSystem,
+    combo_count_update_system::ComboCountUpdateSystem, cp_bar_update_system::CpBarUpdateSystem,
+    hp_bar_update_system::HpBarUpdateSystem,
+};
 
+mod combo_bar_update_system;
+mod combo_count_update_system;
 mod cp_bar_update_system;
 mod hp_bar_update_system;
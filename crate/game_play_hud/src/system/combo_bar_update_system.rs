@@ -0,0 +1,91 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Join, ReadStorage, System, World, WriteStorage},
+    renderer::SpriteRender,
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::play::Combo;
+use parent_model::play::ParentEntity;
+
+use crate::{
+    ComboBar, ComboCountCalculator, COMBO_BAR_LENGTH, COMBO_BAR_PIP_LENGTH, COMBO_BAR_SPRITE_COUNT,
+};
+
+/// Move ComboBar below character.
+const Y_OFFSET: f32 = -18.;
+/// Move ComboBar in front of object.
+const Z_OFFSET: f32 = 1.;
+
+/// Updates `ComboBar` length based on the `Combo` that its parent entity is landing.
+///
+/// Each hit in the combo lengthens the bar by `COMBO_BAR_PIP_LENGTH`.
+#[derive(Debug, Default, new)]
+pub struct ComboBarUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComboBarUpdateSystemData<'s> {
+    /// `ComboBar` components.
+    #[derivative(Debug = "ignore")]
+    pub combo_bars: ReadStorage<'s, ComboBar>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: ReadStorage<'s, ParentEntity>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: ReadStorage<'s, Combo>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: WriteStorage<'s, SpriteRender>,
+}
+
+impl<'s> System<'s> for ComboBarUpdateSystem {
+    type SystemData = ComboBarUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ComboBarUpdateSystemData {
+            combo_bars,
+            parent_entities,
+            combos,
+            mut transforms,
+            mut sprite_renders,
+        }: Self::SystemData,
+    ) {
+        (
+            &combo_bars,
+            &parent_entities,
+            &mut transforms,
+            &mut sprite_renders,
+        )
+            .join()
+            .for_each(|(_, parent_entity, transform, sprite_render)| {
+                let combo_count = ComboCountCalculator::combo_count(&combos, parent_entity.0);
+                let combo_length =
+                    (combo_count as f32 * COMBO_BAR_PIP_LENGTH).min(COMBO_BAR_LENGTH);
+
+                // This is here because the `DrawFlat2D` pass renders sprites centered -- i.e. the
+                // sprite is shifted left by half its width, and down by half its height.
+                //
+                // Since the `ComboBar` is drawn centered, and we want it to be on the left in a
+                // fixed position, we calculate how far it should be.
+                let half_combo_missing = (COMBO_BAR_LENGTH - combo_length) / 2.;
+                let translation = transform.translation_mut();
+                translation.x += -half_combo_missing;
+                translation.y += Y_OFFSET;
+                translation.z += Z_OFFSET;
+
+                let scale = transform.scale_mut();
+                scale[0] = combo_length;
+
+                sprite_render.sprite_number = (COMBO_BAR_SPRITE_COUNT - 1)
+                    * (combo_length as usize)
+                    / COMBO_BAR_LENGTH as usize;
+            });
+    }
+}
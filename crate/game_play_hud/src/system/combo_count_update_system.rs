@@ -0,0 +1,115 @@
+use amethyst::{
+    core::Transform,
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::Camera,
+    shred::{ResourceId, SystemData},
+    ui::{UiText, UiTransform},
+};
+use camera_model::play::CameraZoomDimensions;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::play::Combo;
+use parent_model::play::ParentEntity;
+use ui_model::play::UiFovScaleTransform;
+
+use crate::{ComboCount, ComboCountCalculator, COMBO_COUNT_HEIGHT, COMBO_COUNT_WIDTH};
+
+/// Move ComboCount below the ComboBar.
+const Y_OFFSET: f32 = -32.;
+
+/// Updates `ComboCount` text and position based on the `Combo` that its parent entity is landing.
+///
+/// The text is positioned on screen below the parent entity, relative to the camera's view.
+#[derive(Debug, Default, new)]
+pub struct ComboCountUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComboCountUpdateSystemData<'s> {
+    /// `ComboCount` components.
+    #[derivative(Debug = "ignore")]
+    pub combo_counts: ReadStorage<'s, ComboCount>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: ReadStorage<'s, ParentEntity>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: ReadStorage<'s, Combo>,
+    /// `Camera` components.
+    #[derivative(Debug = "ignore")]
+    pub cameras: ReadStorage<'s, Camera>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: ReadStorage<'s, Transform>,
+    /// `CameraZoomDimensions` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_zoom_dimensions: Read<'s, CameraZoomDimensions>,
+    /// `UiFovScaleTransform` resource.
+    #[derivative(Debug = "ignore")]
+    pub ui_fov_scale_transform: Read<'s, UiFovScaleTransform>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl<'s> System<'s> for ComboCountUpdateSystem {
+    type SystemData = ComboCountUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ComboCountUpdateSystemData {
+            combo_counts,
+            parent_entities,
+            combos,
+            cameras,
+            transforms,
+            camera_zoom_dimensions,
+            ui_fov_scale_transform,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        // The camera's translation is the center of the view, so we shift by half the zoom
+        // dimensions to get the bottom left corner of the screen in world coordinates.
+        let (camera_left, camera_bottom) = (&cameras, &transforms)
+            .join()
+            .next()
+            .map(|(_, transform)| {
+                let translation = transform.translation();
+                (
+                    translation.x - camera_zoom_dimensions.width / 2.,
+                    translation.y - camera_zoom_dimensions.height / 2.,
+                )
+            })
+            .unwrap_or((0., 0.));
+
+        (
+            &combo_counts,
+            &parent_entities,
+            &mut ui_transforms,
+            &mut ui_texts,
+        )
+            .join()
+            .for_each(|(_, parent_entity, ui_transform, ui_text)| {
+                let combo_count = ComboCountCalculator::combo_count(&combos, parent_entity.0);
+                ui_text.text = if combo_count > 0 {
+                    format!("{} hits", combo_count)
+                } else {
+                    String::new()
+                };
+
+                if let Some(transform) = transforms.get(parent_entity.0) {
+                    let translation = transform.translation();
+                    ui_transform.local_x = translation.x - camera_left;
+                    ui_transform.local_y = translation.y + Y_OFFSET - camera_bottom;
+                    ui_transform.width = COMBO_COUNT_WIDTH;
+                    ui_transform.height = COMBO_COUNT_HEIGHT;
+
+                    ui_fov_scale_transform.apply(ui_transform);
+                }
+            });
+    }
+}
@@ -0,0 +1,6 @@
+//! Contains data types used for configuration.
+
+pub use self::{combo_scaling::ComboScaling, match_rules::MatchRules};
+
+mod combo_scaling;
+mod match_rules;
@@ -0,0 +1,39 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Reduces the damage and stun of each consecutive hit in a combo.
+///
+/// For the `n`th hit of a combo, values are multiplied by `multiplier ^ (n - 1)`, bounded below by
+/// `min`.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComboScaling {
+    /// Multiplier applied to HP damage for each hit after the first.
+    #[derivative(Default(value = "0.9"))]
+    pub hp_damage: f32,
+    /// Multiplier applied to stun for each hit after the first.
+    #[derivative(Default(value = "0.9"))]
+    pub stun: f32,
+    /// Lowest multiplier that may be applied.
+    #[derivative(Default(value = "0.3"))]
+    pub min: f32,
+}
+
+impl ComboScaling {
+    /// Returns the HP damage multiplier for the given hit of a combo.
+    pub fn hp_damage_multiplier(self, combo_count: u32) -> f32 {
+        self.multiplier(self.hp_damage, combo_count)
+    }
+
+    /// Returns the stun multiplier for the given hit of a combo.
+    pub fn stun_multiplier(self, combo_count: u32) -> f32 {
+        self.multiplier(self.stun, combo_count)
+    }
+
+    fn multiplier(self, multiplier: f32, combo_count: u32) -> f32 {
+        let exponent = combo_count.saturating_sub(1) as i32;
+        multiplier.powi(exponent).max(self.min).min(1.)
+    }
+}
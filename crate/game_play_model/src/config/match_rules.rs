@@ -0,0 +1,38 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ComboScaling;
+
+/// Rules that determine how a match is won.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct MatchRules {
+    /// Number of ticks before a round ends, if any.
+    ///
+    /// When time runs out, the team with the most remaining stocks wins the round, followed by
+    /// the most remaining health points.
+    pub time_limit: Option<usize>,
+    /// Number of lives each character has per round.
+    #[derivative(Default(value = "1"))]
+    pub stocks: u32,
+    /// Maximum number of rounds in the match.
+    ///
+    /// The first team to win more than half of the rounds wins the match.
+    #[derivative(Default(value = "1"))]
+    pub rounds: u32,
+    /// Number of ticks between the end of a round and the start of the next round.
+    #[derivative(Default(value = "120"))]
+    pub round_start_delay: usize,
+    /// Reduction of damage and stun for consecutive hits in a combo.
+    #[new(default)]
+    pub combo_scaling: ComboScaling,
+}
+
+impl MatchRules {
+    /// Returns the number of round wins needed to win the match.
+    pub fn rounds_to_win(self) -> u32 {
+        self.rounds / 2 + 1
+    }
+}
@@ -9,7 +9,13 @@ pub enum GamePlayEvent {
     Pause,
     /// Resumes the round.
     Resume,
-    /// Signals the end of the round.
+    /// Opens control settings.
+    ControlSettings,
+    /// Signals the end of a round, when there are more rounds to play.
+    RoundEnd,
+    /// Signals the start of the next round.
+    RoundStart,
+    /// Signals the end of the match.
     End,
     /// Signals to go to the round statistics.
     EndStats,
@@ -9,6 +9,7 @@ use structopt_derive::StructOpt;
 /// * `game_play restart`
 /// * `game_play pause`
 /// * `game_play resume`
+/// * `game_play control_settings`
 /// * `game_play end`
 /// * `game_play end_stats`
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
@@ -23,6 +24,8 @@ pub enum GamePlayEventArgs {
     Pause,
     /// Resumes the round.
     Resume,
+    /// Opens control settings.
+    ControlSettings,
     /// Signals the end of the round.
     End,
     /// Signals to go to the round statistics.
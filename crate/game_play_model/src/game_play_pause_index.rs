@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Game play pause menu indicies.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GamePlayPauseIndex {
+    /// Resumes the round.
+    Resume,
+    /// Restarts the round.
+    Restart,
+    /// Opens control settings.
+    Settings,
+    /// Returns to the menu.
+    ReturnToMenu,
+}
@@ -11,6 +11,8 @@ pub enum GamePlayStatus {
     Playing,
     /// Round is paused.
     Paused,
-    /// Round has ended.
+    /// Round has ended, and the next round is about to start.
+    RoundEnded,
+    /// Match has ended.
     Ended,
 }
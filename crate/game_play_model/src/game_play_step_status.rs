@@ -0,0 +1,21 @@
+use derivative::Derivative;
+
+/// Whether game play systems may step this tick.
+///
+/// During network play, game play waits until input for the current frame has been received from
+/// every session device.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub enum GamePlayStepStatus {
+    /// Game play systems run this tick.
+    #[derivative(Default)]
+    Ready,
+    /// Game play systems are waiting for input.
+    Waiting,
+    /// Game play is paused.
+    Paused,
+    /// Game play is stepped by the `GamePlayState`'s rollback dispatcher.
+    ///
+    /// The game play systems in the main dispatcher do not run.
+    Rollback,
+}
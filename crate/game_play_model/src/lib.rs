@@ -4,12 +4,16 @@
 
 pub use crate::{
     game_play_entity::GamePlayEntity, game_play_event::GamePlayEvent,
-    game_play_event_args::GamePlayEventArgs, game_play_status::GamePlayStatus,
+    game_play_event_args::GamePlayEventArgs, game_play_pause_index::GamePlayPauseIndex,
+    game_play_status::GamePlayStatus, game_play_step_status::GamePlayStepStatus,
 };
 
+pub mod config;
 pub mod play;
 
 mod game_play_entity;
 mod game_play_event;
 mod game_play_event_args;
+mod game_play_pause_index;
 mod game_play_status;
+mod game_play_step_status;
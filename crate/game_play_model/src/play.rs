@@ -1,9 +1,15 @@
 //! Contains data types used at runtime.
 
 pub use self::{
-    game_play_end_transition_delay_clock::GamePlayEndTransitionDelayClock,
-    game_play_status_entity::GamePlayStatusEntity,
+    combo::Combo, game_play_end_transition_delay_clock::GamePlayEndTransitionDelayClock,
+    game_play_pause_entity::GamePlayPauseEntity, game_play_status_entity::GamePlayStatusEntity,
+    round_clock::RoundClock, round_start_delay_clock::RoundStartDelayClock, stocks::Stocks,
 };
 
+mod combo;
 mod game_play_end_transition_delay_clock;
+mod game_play_pause_entity;
 mod game_play_status_entity;
+mod round_clock;
+mod round_start_delay_clock;
+mod stocks;
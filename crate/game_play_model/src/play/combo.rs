@@ -0,0 +1,17 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Consecutive hits landed on the object while it is stunned.
+///
+/// This component is attached to the object that is hit, and is removed when the object returns
+/// to a sequence where it is not stunned.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct Combo {
+    /// Entity that started the combo.
+    ///
+    /// For spawned objects, this is the entity that spawned them.
+    pub attacker: Entity,
+    /// Number of hits in the combo.
+    pub count: u32,
+}
@@ -0,0 +1,6 @@
+use amethyst::ecs::{storage::NullStorage, Component};
+
+/// Marker for entities that display the game play pause menu.
+#[derive(Component, Debug, Default)]
+#[storage(NullStorage)]
+pub struct GamePlayPauseEntity;
@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_more::From;
+use logic_clock::logic_clock;
+use serde::{Deserialize, Serialize};
+
+/// Logic clock to track the time elapsed in a round.
+#[logic_clock]
+pub struct RoundClock;
@@ -0,0 +1,12 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Number of lives an object has remaining, including its current life.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[storage(VecStorage)]
+#[derivative(Default)]
+pub struct Stocks(#[derivative(Default(value = "1"))] pub u32);
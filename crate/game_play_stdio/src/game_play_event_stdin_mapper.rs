@@ -17,6 +17,7 @@ impl StdinMapper for GamePlayEventStdinMapper {
             GamePlayEventArgs::Restart => Ok(GamePlayEvent::Restart),
             GamePlayEventArgs::Pause => Ok(GamePlayEvent::Pause),
             GamePlayEventArgs::Resume => Ok(GamePlayEvent::Resume),
+            GamePlayEventArgs::ControlSettings => Ok(GamePlayEvent::ControlSettings),
             GamePlayEventArgs::End => Ok(GamePlayEvent::End),
             GamePlayEventArgs::EndStats => Ok(GamePlayEvent::EndStats),
         }
@@ -0,0 +1,79 @@
+use amethyst::{
+    ecs::WorldExt,
+    input::{is_key_down, VirtualKeyCode},
+    GameData, State, StateData, Trans,
+};
+use application_event::AppEvent;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControlAction,
+    play::{ControlActionEventData, ControlInputEvent},
+};
+use game_stats_model::GameStatsEntity;
+use log::debug;
+use state_registry::StateId;
+use state_support::StateEntityUtils;
+
+/// `State` where the statistics of each player in the match are displayed.
+///
+/// Pressing `Attack` or `Jump` returns to the previous state.
+#[derive(Derivative, Default, new)]
+#[derivative(Debug)]
+pub struct GameStatsState;
+
+impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GameStatsState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.register::<GameStatsEntity>();
+        data.world.insert(StateId::GameStats);
+    }
+
+    fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
+        StateEntityUtils::clear::<GameStatsEntity>(&mut data.world);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::GameStats);
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'_, '_>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        match event {
+            AppEvent::Window(window_event) => {
+                if is_key_down(&window_event, VirtualKeyCode::Escape) {
+                    debug!("Returning from `GameStatsState`.");
+                    Trans::Pop
+                } else {
+                    Trans::None
+                }
+            }
+            AppEvent::ControlInput(ControlInputEvent::ControlActionPress(
+                ControlActionEventData {
+                    control_action: ControlAction::Attack,
+                    ..
+                },
+            ))
+            | AppEvent::ControlInput(ControlInputEvent::ControlActionPress(
+                ControlActionEventData {
+                    control_action: ControlAction::Jump,
+                    ..
+                },
+            )) => {
+                debug!("Returning from `GameStatsState`.");
+                Trans::Pop
+            }
+            _ => Trans::None,
+        }
+    }
+
+    fn update(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        data.data.update(&data.world);
+        Trans::None
+    }
+}
@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! State where the statistics of a match are displayed.
+
+pub use crate::game_stats_state::GameStatsState;
+
+mod game_stats_state;
@@ -0,0 +1,6 @@
+use amethyst::ecs::{storage::NullStorage, Component};
+
+/// ID tag for entities created in the `GameStatsState`.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+#[storage(NullStorage)]
+pub struct GameStatsEntity;
@@ -2,4 +2,8 @@
 
 //! Types to track game statistics.
 
+pub use crate::game_stats_entity::GameStatsEntity;
+
+mod game_stats_entity;
+
 pub mod play;
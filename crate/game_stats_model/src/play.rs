@@ -1,6 +1,11 @@
 //! Contains data types used at runtime.
 
-pub use self::{win_outcome::WinOutcome, win_status::WinStatus};
+pub use self::{
+    game_stats::GameStats, player_stats::PlayerStats, win_outcome::WinOutcome,
+    win_status::WinStatus,
+};
 
+mod game_stats;
+mod player_stats;
 mod win_outcome;
 mod win_status;
@@ -0,0 +1,13 @@
+use std::collections::BTreeMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::play::PlayerStats;
+
+/// Statistics of each player in a match (`BTreeMap<ControllerId, PlayerStats>` newtype).
+///
+/// Players are ordered by their `ControllerId`.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct GameStats(pub BTreeMap<ControllerId, PlayerStats>);
@@ -0,0 +1,26 @@
+use derive_new::new;
+
+/// Statistics of a player over a match.
+#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+pub struct PlayerStats {
+    /// Whether the player is controlled by the computer.
+    pub is_cpu: bool,
+    /// Health points of damage dealt to other objects.
+    #[new(default)]
+    pub damage_dealt: u32,
+    /// Health points of damage taken from other objects.
+    #[new(default)]
+    pub damage_taken: u32,
+    /// Number of hits landed on other objects.
+    #[new(default)]
+    pub hits_landed: u32,
+    /// Number of objects knocked out.
+    #[new(default)]
+    pub kos: u32,
+    /// Highest number of consecutive hits landed on a stunned object.
+    #[new(default)]
+    pub max_combo: u32,
+    /// Skill points spent.
+    #[new(default)]
+    pub sp_used: u32,
+}
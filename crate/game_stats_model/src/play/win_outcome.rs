@@ -1,18 +1,18 @@
 use derivative::Derivative;
 use team_model::play::Team;
 
-/// Outcome of a round.
+/// Outcome of a round or match.
 #[derive(Clone, Copy, Debug, Derivative, PartialEq)]
 #[derivative(Default)]
 pub enum WinOutcome {
     /// There is currently no outcome.
     #[derivative(Default)]
     None,
-    /// A team has won the round.
+    /// A team has won.
     WinLoss {
-        /// Team that won the round.
+        /// Team that won.
         winning_team: Team,
     },
-    /// The round ended in a draw.
+    /// Ended in a draw.
     Draw,
 }
@@ -0,0 +1,10 @@
+//! User defined configuration types for the game statistics UI.
+
+pub use self::{
+    game_stats_ui::GameStatsUi, player_stats_widget_template::PlayerStatsWidgetTemplate,
+    player_stats_widgets::PlayerStatsWidgets,
+};
+
+mod game_stats_ui;
+mod player_stats_widget_template;
+mod player_stats_widgets;
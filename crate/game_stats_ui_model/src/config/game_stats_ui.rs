@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::PlayerStatsWidgets;
+
+/// Configuration for initializing the game statistics UI.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GameStatsUi {
+    /// List of player statistics.
+    pub player_stats: PlayerStatsWidgets,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use ui_label_model::config::UiLabel;
+use ui_model_spi::config::Dimensions;
+
+/// Configuration for displaying the statistics of a particular player.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PlayerStatsWidgetTemplate {
+    /// Dimensions of the widget.
+    ///
+    /// Each player stats widget will be placed below the previous one. The width is currently not
+    /// used.
+    pub dimensions: Dimensions,
+    /// Label attributes for the player name.
+    pub player_name: UiLabel,
+    /// Label attributes for the player's statistics.
+    pub stats: UiLabel,
+}
@@ -0,0 +1,5 @@
+//! Types representing loaded configuration.
+
+pub use self::player_stats_widgets::PlayerStatsWidgets;
+
+mod player_stats_widgets;
@@ -0,0 +1,51 @@
+use amethyst::{
+    ecs::{storage::DenseVecStorage, Component, Entity, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::{loaded::ItemId, ItemComponent};
+use derivative::Derivative;
+use derive_new::new;
+
+use crate::play::PlayerStatsEntities;
+
+/// Marks the `PlayerStatsWidgets` entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct PlayerStatsWidgets {
+    /// `ItemId` for entities that display a player name.
+    pub item_id_player_name: ItemId,
+    /// `ItemId` for entities that display a player's statistics.
+    pub item_id_stats: ItemId,
+}
+
+/// `PlayerStatsWidgetsSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsWidgetsSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Write<'s, PlayerStatsEntities>,
+    /// `PlayerStatsWidgets` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgetses: WriteStorage<'s, PlayerStatsWidgets>,
+}
+
+impl<'s> ItemComponent<'s> for PlayerStatsWidgets {
+    type SystemData = PlayerStatsWidgetsSystemData<'s>;
+
+    fn augment(&self, system_data: &mut Self::SystemData, entity: Entity) {
+        let PlayerStatsWidgetsSystemData {
+            player_stats_entities,
+            player_stats_widgetses,
+        } = system_data;
+
+        player_stats_entities.player_stats_widgets_entity = Some(entity);
+        player_stats_entities.player_stats_widget_entities.clear();
+
+        if !player_stats_widgetses.contains(entity) {
+            player_stats_widgetses
+                .insert(entity, *self)
+                .expect("Failed to insert `PlayerStatsWidgets` component.");
+        }
+    }
+}
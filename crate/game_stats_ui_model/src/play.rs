@@ -0,0 +1,8 @@
+//! Data types used at runtime.
+
+pub use self::{
+    player_stats_entities::PlayerStatsEntities, player_stats_widget::PlayerStatsWidget,
+};
+
+mod player_stats_entities;
+mod player_stats_widget;
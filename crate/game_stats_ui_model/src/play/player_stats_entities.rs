@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+
+/// Entities of the `PlayerStatsWidgets`.
+///
+/// This is used to track the main widget entity, as well as each `PlayerStatsWidget` entity.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct PlayerStatsEntities {
+    /// Main `PlayerStatsWidgets` entity.
+    pub player_stats_widgets_entity: Option<Entity>,
+    /// Entities for each player stats widget.
+    pub player_stats_widget_entities: Vec<Entity>,
+}
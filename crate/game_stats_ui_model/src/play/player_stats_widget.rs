@@ -0,0 +1,11 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Entities that make up a `PlayerStatsWidget`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct PlayerStatsWidget {
+    /// Entity for the player name.
+    pub entity_player_name: Entity,
+    /// Entity for the player's statistics.
+    pub entity_stats: Entity,
+}
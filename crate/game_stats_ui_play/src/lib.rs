@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to update the game statistics UI.
+
+pub use crate::system::{PlayerStatsEntityCreateDeleteSystem, PlayerStatsWidgetUpdateSystem};
+
+mod system;
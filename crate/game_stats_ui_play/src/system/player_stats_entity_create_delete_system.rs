@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+
+use amethyst::{
+    ecs::{Entities, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::ItemId;
+use derivative::Derivative;
+use derive_new::new;
+use game_stats_model::play::GameStats;
+use game_stats_ui_model::{
+    loaded::PlayerStatsWidgets,
+    play::{PlayerStatsEntities, PlayerStatsWidget},
+};
+use kinematic_model::{
+    config::Position,
+    play::{PositionInitOffset, PositionInitParent},
+};
+use log::debug;
+use parent_model::play::ParentEntity;
+use ui_model_spi::config::Dimensions;
+
+/// Updates `PlayerStatsEntities` to have the right number of entities for each player's stats.
+#[derive(Debug, new)]
+pub struct PlayerStatsEntityCreateDeleteSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsEntityCreateDeleteSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Write<'s, PlayerStatsEntities>,
+    /// `PlayerStatsWidgets` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgetses: ReadStorage<'s, PlayerStatsWidgets>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `Dimensions` components.
+    #[derivative(Debug = "ignore")]
+    pub dimensionses: ReadStorage<'s, Dimensions>,
+    /// `GameStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_stats: Read<'s, GameStats>,
+    /// `ItemId` components.
+    #[derivative(Debug = "ignore")]
+    pub item_ids: WriteStorage<'s, ItemId>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `PositionInitParent` components.
+    #[derivative(Debug = "ignore")]
+    pub position_init_parents: WriteStorage<'s, PositionInitParent>,
+    /// `PositionInitOffset` components.
+    #[derivative(Debug = "ignore")]
+    pub position_init_offsets: WriteStorage<'s, PositionInitOffset>,
+    /// `PlayerStatsWidget` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgets: WriteStorage<'s, PlayerStatsWidget>,
+}
+
+impl<'s> System<'s> for PlayerStatsEntityCreateDeleteSystem {
+    type SystemData = PlayerStatsEntityCreateDeleteSystemData<'s>;
+
+    fn run(
+        &mut self,
+        PlayerStatsEntityCreateDeleteSystemData {
+            mut player_stats_entities,
+            player_stats_widgetses,
+            entities,
+            dimensionses,
+            game_stats,
+            mut item_ids,
+            mut parent_entities,
+            mut position_init_parents,
+            mut position_init_offsets,
+            mut player_stats_widgets,
+        }: Self::SystemData,
+    ) {
+        let PlayerStatsEntities {
+            player_stats_widgets_entity,
+            player_stats_widget_entities,
+        } = &mut *player_stats_entities;
+
+        let player_stats_widgets_entity =
+            player_stats_widgets_entity.and_then(|player_stats_widgets_entity| {
+                if entities.is_alive(player_stats_widgets_entity) {
+                    Some(player_stats_widgets_entity)
+                } else {
+                    None
+                }
+            });
+        if let Some(player_stats_widgets_entity) = player_stats_widgets_entity {
+            match game_stats.len().cmp(&player_stats_widget_entities.len()) {
+                Ordering::Equal => {}
+                Ordering::Less => {
+                    debug!(
+                        "Removing extra player stats entities. Required: {}, Actual: {}",
+                        game_stats.len(),
+                        player_stats_widget_entities.len()
+                    );
+
+                    // Remove extra entities.
+                    player_stats_widget_entities
+                        .drain(game_stats.len()..)
+                        .for_each(|player_stats_widget_entity| {
+                            let player_stats_widget = player_stats_widgets
+                                .get(player_stats_widget_entity)
+                                .copied();
+
+                            if let Some(player_stats_widget) = player_stats_widget {
+                                let PlayerStatsWidget {
+                                    entity_player_name,
+                                    entity_stats,
+                                } = player_stats_widget;
+
+                                entities
+                                    .delete(entity_player_name)
+                                    .expect("Failed to delete `entity_player_name`.");
+                                entities
+                                    .delete(entity_stats)
+                                    .expect("Failed to delete `entity_stats`.");
+                            }
+
+                            entities
+                                .delete(player_stats_widget_entity)
+                                .expect("Failed to delete `player_stats_widget_entity`.");
+                        });
+                }
+                Ordering::Greater => {
+                    debug!(
+                        "Creating additional player stats entities. Required: {}, Actual: {}",
+                        game_stats.len(),
+                        player_stats_widget_entities.len()
+                    );
+
+                    // Create additional entities.
+                    let player_stats_widgets_component = player_stats_widgetses
+                        .get(player_stats_widgets_entity)
+                        .expect(
+                            "Expected `PlayerStatsWidgets` to exist for \
+                             `player_stats_widgets_entity`.",
+                        );
+                    let dimensions = dimensionses.get(player_stats_widgets_entity).expect(
+                        "Expected `Dimensions` to exist for `player_stats_widgets_entity`.",
+                    );
+
+                    let player_stats_widget_entities_new = (0..game_stats.len())
+                        .skip(player_stats_widget_entities.len())
+                        .map(|n| {
+                            let parent_entity = ParentEntity::new(player_stats_widgets_entity);
+                            let position_init_parent =
+                                PositionInitParent::new(player_stats_widgets_entity);
+                            let y_offset = -((n * dimensions.h as usize) as f32);
+                            let position_init_offset =
+                                PositionInitOffset::new(Position::new(0., y_offset, 0.));
+
+                            let item_id_player_name =
+                                player_stats_widgets_component.item_id_player_name;
+                            let item_id_stats = player_stats_widgets_component.item_id_stats;
+
+                            let entity_player_name = entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(item_id_player_name, &mut item_ids)
+                                .build();
+                            let entity_stats = entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(item_id_stats, &mut item_ids)
+                                .build();
+
+                            let player_stats_widget =
+                                PlayerStatsWidget::new(entity_player_name, entity_stats);
+
+                            entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(player_stats_widget, &mut player_stats_widgets)
+                                .build()
+                        });
+
+                    player_stats_widget_entities.extend(player_stats_widget_entities_new);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::UiText,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::loaded::PlayerControllers;
+use game_stats_model::play::{GameStats, PlayerStats};
+use game_stats_ui_model::play::{PlayerStatsEntities, PlayerStatsWidget};
+
+/// Updates the text in each `PlayerStatsWidget` with the player name and statistics.
+#[derive(Debug, new)]
+pub struct PlayerStatsWidgetUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsWidgetUpdateSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Read<'s, PlayerStatsEntities>,
+    /// `GameStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_stats: Read<'s, GameStats>,
+    /// `PlayerControllers` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_controllers: Read<'s, PlayerControllers>,
+    /// `PlayerStatsWidget` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgets: ReadStorage<'s, PlayerStatsWidget>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl PlayerStatsWidgetUpdateSystem {
+    fn stats_text(player_stats: PlayerStats) -> String {
+        let PlayerStats {
+            damage_dealt,
+            damage_taken,
+            hits_landed,
+            kos,
+            max_combo,
+            sp_used,
+            ..
+        } = player_stats;
+
+        format!(
+            "Damage: {} dealt, {} taken | Hits: {} | KOs: {} | Max Combo: {} | SP Used: {}",
+            damage_dealt, damage_taken, hits_landed, kos, max_combo, sp_used
+        )
+    }
+}
+
+impl<'s> System<'s> for PlayerStatsWidgetUpdateSystem {
+    type SystemData = PlayerStatsWidgetUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        PlayerStatsWidgetUpdateSystemData {
+            player_stats_entities,
+            game_stats,
+            player_controllers,
+            player_stats_widgets,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let PlayerStatsEntities {
+            player_stats_widget_entities,
+            ..
+        } = &*player_stats_entities;
+
+        if game_stats.len() == player_stats_widget_entities.len() {
+            game_stats
+                .iter()
+                .zip(player_stats_widget_entities.iter().copied().map(
+                    |player_stats_widget_entity| {
+                        player_stats_widgets
+                            .get(player_stats_widget_entity)
+                            .copied()
+                    },
+                ))
+                .for_each(|((controller_id, player_stats), player_stats_widget)| {
+                    if let Some(player_stats_widget) = player_stats_widget {
+                        let PlayerStatsWidget {
+                            entity_player_name,
+                            entity_stats,
+                        } = player_stats_widget;
+
+                        if let Some(ui_text_player_name) = ui_texts.get_mut(entity_player_name) {
+                            let name = player_controllers
+                                .get(*controller_id)
+                                .map(|player_controller| player_controller.name.as_str())
+                                .unwrap_or("");
+                            ui_text_player_name.text = if player_stats.is_cpu {
+                                format!("{} (CPU)", name)
+                            } else {
+                                name.to_string()
+                            };
+                        }
+                        if let Some(ui_text_stats) = ui_texts.get_mut(entity_stats) {
+                            ui_text_stats.text = Self::stats_text(*player_stats);
+                        }
+                    }
+                });
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use asset_selection_model::config::AssetSelectionEventCommand;
+use audio_settings_model::AudioSettingsEvent;
 use control_settings_model::ControlSettingsEvent;
 use derive_more::From;
 use game_mode_selection_model::GameModeSelectionEventArgs;
@@ -20,6 +21,8 @@ use session_lobby_model::config::SessionLobbyEventCommand;
 pub enum InputReactionAppEvent {
     /// `asset_selection` events.
     AssetSelection(AssetSelectionEventCommand),
+    /// `audio_settings` events.
+    AudioSettings(AudioSettingsEvent),
     /// `control_settings` events.
     ControlSettings(ControlSettingsEvent),
     /// `game_mode_selection` events.
@@ -1,5 +1,6 @@
 use amethyst::ecs::{Entity, ReadStorage};
 use asset_model::loaded::{AssetId, AssetIdMappings};
+use audio_settings_model::AudioSettingsEvent;
 use control_settings_model::ControlSettingsEvent;
 use game_input_model::config::ControllerId;
 use game_mode_selection_model::{GameModeSelectionEvent, GameModeSelectionEventArgs};
@@ -56,6 +57,12 @@ impl IrAppEventSender {
                     error!("Expected `controller_id` to be set to send `AssetSelection` event.");
                 }
             }
+            InputReactionAppEvent::AudioSettings(audio_settings_event) => {
+                Self::handle_audio_settings_event(
+                    ir_app_event_sender_system_data,
+                    audio_settings_event,
+                );
+            }
             InputReactionAppEvent::ControlSettings(control_settings_event) => {
                 Self::handle_control_settings_event(
                     ir_app_event_sender_system_data,
@@ -100,6 +107,15 @@ impl IrAppEventSender {
         }
     }
 
+    fn handle_audio_settings_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        audio_settings_event: AudioSettingsEvent,
+    ) {
+        ir_app_event_sender_system_data
+            .audio_settings_ec
+            .single_write(audio_settings_event);
+    }
+
     fn handle_control_settings_event(
         ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
         control_settings_event: ControlSettingsEvent,
@@ -132,6 +148,7 @@ impl IrAppEventSender {
             GamePlayEventArgs::Restart => GamePlayEvent::Restart,
             GamePlayEventArgs::Pause => GamePlayEvent::Pause,
             GamePlayEventArgs::Resume => GamePlayEvent::Resume,
+            GamePlayEventArgs::ControlSettings => GamePlayEvent::ControlSettings,
             GamePlayEventArgs::End => GamePlayEvent::End,
             GamePlayEventArgs::EndStats => GamePlayEvent::EndStats,
         };
@@ -109,6 +109,14 @@ impl IrAssetSelectionEventSender {
                     controller_id,
                 })
             }
+            AssetSelectionEventCommand::Cpu => Some(AssetSelectionEvent::Cpu {
+                entity: Some(ash_entity),
+                controller_id,
+            }),
+            AssetSelectionEventCommand::Team => Some(AssetSelectionEvent::Team {
+                entity: Some(ash_entity),
+                controller_id,
+            }),
             AssetSelectionEventCommand::Confirm => {
                 if Self::asset_selection_confirm_preconditions_met(
                     ir_app_event_sender_system_data,
@@ -7,6 +7,7 @@ use amethyst::{
 use asset_model::loaded::{AssetId, AssetIdMappings, AssetTypeMappings};
 use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
 use asset_ui_model::play::{AssetSelectionHighlightMain, AssetSelectionStatus};
+use audio_settings_model::AudioSettingsEvent;
 use chase_model::play::TargetObject;
 use control_settings_model::ControlSettingsEvent;
 use derivative::Derivative;
@@ -73,6 +74,9 @@ pub struct IrAppEventSenderSystemData<'s> {
     #[derivative(Debug = "ignore")]
     pub asset_selections: ReadStorage<'s, AssetSelection>,
 
+    /// `AudioSettingsEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings_ec: Write<'s, EventChannel<AudioSettingsEvent>>,
     /// `ControlSettingsEvent` channel.
     #[derivative(Debug = "ignore")]
     pub control_settings_ec: Write<'s, EventChannel<ControlSettingsEvent>>,
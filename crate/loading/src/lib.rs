@@ -12,10 +12,10 @@ pub use crate::{
         AssetPartLoadingSystem, AssetSequenceComponentLoader,
         AssetSequenceComponentLoaderUiCharacterSelection, AssetSequenceComponentLoaderUiComponents,
         AssetSequenceComponentLoaderUiControlSettings, AssetSequenceComponentLoaderUiForm,
-        AssetSequenceComponentLoaderUiMapSelection, AssetSequenceComponentLoaderUiMenu,
-        AssetSequenceComponentLoaderUiSessionLobby, AssetSequenceComponentLoadingSystem,
-        AssetSpritesDefinitionLoader, AssetSpritesDefinitionLoadingSystem, AssetTextureLoader,
-        AssetTextureLoadingSystem,
+        AssetSequenceComponentLoaderUiGameStats, AssetSequenceComponentLoaderUiMapSelection,
+        AssetSequenceComponentLoaderUiMenu, AssetSequenceComponentLoaderUiSessionLobby,
+        AssetSequenceComponentLoadingSystem, AssetSpritesDefinitionLoader,
+        AssetSpritesDefinitionLoadingSystem, AssetTextureLoader, AssetTextureLoadingSystem,
     },
     system_data::{
         AssetLoadingResources, DefinitionLoadingResources, DefinitionLoadingResourcesRead,
@@ -161,6 +161,7 @@ where
                          * `SpriteLoadingBundle`\n\
                          * `CharacterLoadingBundle`\n\
                          * `EnergyLoadingBundle`\n\
+                         * `WeaponLoadingBundle`\n\
                          * `BackgroundLoadingBundle`\n\
                          * `MapLoadingBundle`\n\
                          * `amethyst::audio::AudioBundle`\n\
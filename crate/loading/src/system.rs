@@ -10,9 +10,9 @@ pub use self::{
     asset_sequence_component_loading_system::{
         AssetSequenceComponentLoader, AssetSequenceComponentLoaderUiCharacterSelection,
         AssetSequenceComponentLoaderUiComponents, AssetSequenceComponentLoaderUiControlSettings,
-        AssetSequenceComponentLoaderUiForm, AssetSequenceComponentLoaderUiMapSelection,
-        AssetSequenceComponentLoaderUiMenu, AssetSequenceComponentLoaderUiSessionLobby,
-        AssetSequenceComponentLoadingSystem,
+        AssetSequenceComponentLoaderUiForm, AssetSequenceComponentLoaderUiGameStats,
+        AssetSequenceComponentLoaderUiMapSelection, AssetSequenceComponentLoaderUiMenu,
+        AssetSequenceComponentLoaderUiSessionLobby, AssetSequenceComponentLoadingSystem,
     },
     asset_sprites_definition_loading_system::{
         AssetSpritesDefinitionLoader, AssetSpritesDefinitionLoadingSystem,
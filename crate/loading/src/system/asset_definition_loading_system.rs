@@ -31,11 +31,13 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            weapon_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_weapon_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
@@ -93,6 +95,16 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
 
                         asset_energy_definition_handle.insert(asset_id, energy_definition_handle);
                     }
+                    ObjectType::Weapon => {
+                        let weapon_definition_handle = loader.load(
+                            object_definition_path,
+                            YamlFormat,
+                            &mut *progress_counter,
+                            weapon_definition_assets,
+                        );
+
+                        asset_weapon_definition_handle.insert(asset_id, weapon_definition_handle);
+                    }
                     ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
                 }
             }
@@ -152,11 +164,13 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
         DefinitionLoadingResources {
             character_definition_assets,
             energy_definition_assets,
+            weapon_definition_assets,
             map_definition_assets,
             background_definition_assets,
             ui_definition_assets,
             asset_character_definition_handle,
             asset_energy_definition_handle,
+            asset_weapon_definition_handle,
             asset_map_definition_handle,
             asset_background_definition_handle,
             asset_ui_definition_handle,
@@ -181,6 +195,12 @@ impl<'s> AssetPartLoader<'s> for AssetDefinitionLoader {
                         energy_definition_assets.get(character_definition_handle)
                     })
                     .is_some(),
+                ObjectType::Weapon => asset_weapon_definition_handle
+                    .get(asset_id)
+                    .and_then(|weapon_definition_handle| {
+                        weapon_definition_assets.get(weapon_definition_handle)
+                    })
+                    .is_some(),
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => asset_map_definition_handle
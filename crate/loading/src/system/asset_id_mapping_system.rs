@@ -34,12 +34,14 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
         IdMappingResources {
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
             ..
         }: &mut IdMappingResources<'_>,
     ) {
         let capacity = asset_id_mappings.capacity();
         asset_sequence_id_mappings_character.set_capacity(capacity);
         asset_sequence_id_mappings_energy.set_capacity(capacity);
+        asset_sequence_id_mappings_weapon.set_capacity(capacity);
     }
 
     /// Map's an asset's sequence IDs.
@@ -55,10 +57,12 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    weapon_definition_assets,
                     map_definition_assets,
                     ui_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_weapon_definition_handle,
                     asset_map_definition_handle,
                     asset_ui_definition_handle,
                     ..
@@ -66,6 +70,7 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
             asset_sequence_id_mappings_sprite,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
         }: &mut IdMappingResources<'_>,
         asset_id: AssetId,
     ) {
@@ -111,6 +116,19 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                     );
                     asset_sequence_id_mappings_energy.insert(asset_id, sequence_id_mappings);
                 }
+                ObjectType::Weapon => {
+                    let weapon_definition = asset_weapon_definition_handle
+                        .get(asset_id)
+                        .and_then(|weapon_definition_handle| {
+                            weapon_definition_assets.get(weapon_definition_handle)
+                        })
+                        .expect("Expected `WeaponDefinition` to be loaded.");
+
+                    let sequence_id_mappings = SequenceIdMappings::from_iter(
+                        weapon_definition.object_definition.sequences.keys(),
+                    );
+                    asset_sequence_id_mappings_weapon.insert(asset_id, sequence_id_mappings);
+                }
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => {
@@ -190,14 +208,17 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    weapon_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_weapon_definition_handle,
                     asset_ui_definition_handle,
                     ..
                 },
             asset_sequence_id_mappings_sprite,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
         } = id_mapping_resources;
 
         let asset_type = asset_type_mappings
@@ -246,6 +267,26 @@ impl<'s> AssetPartLoader<'s> for AssetIdMapper {
 
                     id_mappings_self && spawn_id_mappings_exist
                 }
+                ObjectType::Weapon => {
+                    let id_mappings_self =
+                        asset_sequence_id_mappings_weapon.get(asset_id).is_some();
+                    let spawn_id_mappings_exist = {
+                        let weapon_definition = asset_weapon_definition_handle
+                            .get(asset_id)
+                            .and_then(|weapon_definition_handle| {
+                                weapon_definition_assets.get(weapon_definition_handle)
+                            })
+                            .expect("Expected `WeaponDefinition` to be loaded.");
+
+                        Self::spawn_object_sequence_id_mappings_loaded(
+                            asset_loading_resources,
+                            id_mapping_resources,
+                            &weapon_definition.object_definition,
+                        )
+                    };
+
+                    id_mappings_self && spawn_id_mappings_exist
+                }
                 ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
             },
             AssetType::Map => asset_sequence_id_mappings_sprite.get(asset_id).is_some(),
@@ -269,6 +310,7 @@ impl AssetIdMapper {
         IdMappingResources {
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
             ..
         }: &IdMappingResources<'_>,
         object_definition: &ObjectDefinition<ObjSeq>,
@@ -301,6 +343,9 @@ impl AssetIdMapper {
                         ObjectType::Energy => asset_sequence_id_mappings_energy
                             .get(spawn_asset_id)
                             .is_some(),
+                        ObjectType::Weapon => asset_sequence_id_mappings_weapon
+                            .get(spawn_asset_id)
+                            .is_some(),
                         ObjectType::TestObject => {
                             panic!("Spawning `TestObject`s is not supported.")
                         }
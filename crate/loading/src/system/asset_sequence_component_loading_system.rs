@@ -5,6 +5,7 @@ mod asset_sequence_component_loader_ui_character_selection;
 mod asset_sequence_component_loader_ui_components;
 mod asset_sequence_component_loader_ui_control_settings;
 mod asset_sequence_component_loader_ui_form;
+mod asset_sequence_component_loader_ui_game_stats;
 mod asset_sequence_component_loader_ui_map_selection;
 mod asset_sequence_component_loader_ui_menu;
 mod asset_sequence_component_loader_ui_session_lobby;
@@ -36,6 +37,7 @@ pub use self::{
     asset_sequence_component_loader_ui_components::AssetSequenceComponentLoaderUiComponents,
     asset_sequence_component_loader_ui_control_settings::AssetSequenceComponentLoaderUiControlSettings,
     asset_sequence_component_loader_ui_form::AssetSequenceComponentLoaderUiForm,
+    asset_sequence_component_loader_ui_game_stats::AssetSequenceComponentLoaderUiGameStats,
     asset_sequence_component_loader_ui_map_selection::AssetSequenceComponentLoaderUiMapSelection,
     asset_sequence_component_loader_ui_menu::AssetSequenceComponentLoaderUiMenu,
     asset_sequence_component_loader_ui_session_lobby::AssetSequenceComponentLoaderUiSessionLobby,
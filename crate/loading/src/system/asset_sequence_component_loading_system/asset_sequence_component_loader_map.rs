@@ -1,10 +1,12 @@
 use amethyst::ecs::{Builder, WorldExt};
 use asset_model::loaded::{AssetId, ItemId, ItemIds};
 use kinematic_loading::PositionInitsLoader;
-use map_model::loaded::Margins;
+use log::error;
+use map_model::loaded::{Margins, Terrain};
 use sequence_loading::{
     SequenceEndTransitionsLoader, SequenceIdMapper, WaitSequenceHandlesLoader, WaitSequenceLoader,
 };
+use shape_model::VolumeBounds;
 use sprite_loading::{
     ScaleSequenceHandlesLoader, ScaleSequenceLoader, SpriteRenderSequenceHandlesLoader,
     SpriteRenderSequenceLoader, TintSequenceHandlesLoader, TintSequenceLoader,
@@ -49,6 +51,8 @@ impl AssetSequenceComponentLoaderMap {
             scale_sequence_assets,
             asset_map_bounds,
             asset_margins,
+            asset_music_id,
+            asset_terrain,
             ..
         }: &mut SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -180,5 +184,31 @@ impl AssetSequenceComponentLoaderMap {
 
         let margins = Margins::from(map_bounds);
         asset_margins.insert(asset_id, margins);
+
+        // Terrain coordinates are relative to the map's left, bottom, and back boundaries.
+        let terrain_origin = [margins.left, margins.bottom, margins.back];
+        let terrain = map_definition
+            .terrain
+            .iter()
+            .enumerate()
+            .filter(|(index, volume)| match volume.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    error!(
+                        "Invalid terrain volume at index `{}`, it will be ignored. Error: `{}`.",
+                        index, e
+                    );
+                    false
+                }
+            })
+            .map(|(_, volume)| {
+                VolumeBounds::from_volume(volume, [0., 0.], false).translate(terrain_origin)
+            })
+            .collect::<Vec<VolumeBounds>>();
+        asset_terrain.insert(asset_id, Terrain::new(terrain));
+
+        if let Some(music_id) = map_definition.music.clone() {
+            asset_music_id.insert(asset_id, music_id);
+        }
     }
 }
@@ -1,10 +1,12 @@
 use amethyst::ecs::{Builder, WorldExt};
 use asset_model::loaded::{AssetId, ItemId, ItemIds};
+use audio_loading::AudioLoader;
 use character_loading::CHARACTER_INPUT_REACTIONS_DEFAULT;
 use character_model::{
     config::{CharacterSequence, CharacterSequenceName},
     loaded::{CharacterIrsHandle, CharacterIrsHandles},
 };
+use collision_audio_model::loaded::CollisionSfxMap;
 use energy_model::config::{EnergySequence, EnergySequenceName};
 use input_reaction_loading::{IrsLoader, IrsLoaderParams};
 use kinematic_model::{
@@ -17,6 +19,7 @@ use object_loading::{ObjectLoader, ObjectLoaderParams};
 use object_model::{loaded::Object, play::Grounding};
 use object_type::ObjectType;
 use sequence_model::loaded::SequenceId;
+use weapon_model::config::{WeaponSequence, WeaponSequenceName};
 
 use crate::{
     AssetLoadingResources, DefinitionLoadingResourcesRead, IdMappingResourcesRead,
@@ -36,14 +39,17 @@ impl AssetSequenceComponentLoaderObject {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    weapon_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_weapon_definition_handle,
                     ..
                 },
             id_mapping_resources_read:
                 IdMappingResourcesRead {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_weapon,
                     ..
                 },
             texture_loading_resources_read:
@@ -66,6 +72,7 @@ impl AssetSequenceComponentLoaderObject {
             spawns_sequence_assets,
             character_input_reactions_assets,
             character_irs_assets,
+            asset_collision_sfx_map,
             ..
         }: &mut SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -93,6 +100,7 @@ impl AssetSequenceComponentLoaderObject {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -107,7 +115,7 @@ impl AssetSequenceComponentLoaderObject {
             sprite_sheet_handles,
         };
 
-        let (sequence_id_init, object) = match object_type {
+        let (sequence_id_init, object, collision_sfx_paths) = match object_type {
             ObjectType::Character => {
                 let character_definition = asset_character_definition_handle
                     .get(asset_id)
@@ -169,8 +177,9 @@ impl AssetSequenceComponentLoaderObject {
                     object_loader_params,
                     &character_definition.object_definition,
                 );
+                let collision_sfx_paths = &character_definition.object_definition.collision_sfx;
 
-                (sequence_id_init, object)
+                (sequence_id_init, object, collision_sfx_paths)
             }
             ObjectType::Energy => {
                 let energy_definition = asset_energy_definition_handle
@@ -203,11 +212,59 @@ impl AssetSequenceComponentLoaderObject {
                     object_loader_params,
                     &energy_definition.object_definition,
                 );
+                let collision_sfx_paths = &energy_definition.object_definition.collision_sfx;
 
-                (sequence_id_init, object)
+                (sequence_id_init, object, collision_sfx_paths)
+            }
+            ObjectType::Weapon => {
+                let weapon_definition = asset_weapon_definition_handle
+                    .get(asset_id)
+                    .and_then(|weapon_definition_handle| {
+                        weapon_definition_assets.get(weapon_definition_handle)
+                    })
+                    .expect("Expected `WeaponDefinition` to be loaded.");
+
+                let sequence_id_mappings = asset_sequence_id_mappings_weapon
+                    .get(asset_id)
+                    .expect("Expected `SequenceIdMapping` to be loaded.");
+                let sequence_id_init = {
+                    let sequence_name_default = WeaponSequenceName::default();
+                    sequence_id_mappings
+                        .id_by_name(sequence_name_default)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            warn!(
+                                "`{}` sequence ID not found for asset: `{}`. \
+                                 Falling back to first declared sequence.",
+                                sequence_name_default, asset_slug
+                            );
+
+                            SequenceId::new(0)
+                        })
+                };
+
+                let object = ObjectLoader::load::<WeaponSequence>(
+                    object_loader_params,
+                    &weapon_definition.object_definition,
+                );
+                let collision_sfx_paths = &weapon_definition.object_definition.collision_sfx;
+
+                (sequence_id_init, object, collision_sfx_paths)
             }
             ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
         };
+
+        if !collision_sfx_paths.is_empty() {
+            let collision_sfx_map = collision_sfx_paths
+                .iter()
+                .map(|(collision_sfx_id, path)| {
+                    let source_handle = AudioLoader::load(loader, source_assets, (), path);
+                    (collision_sfx_id.clone(), source_handle)
+                })
+                .collect();
+            asset_collision_sfx_map.insert(asset_id, CollisionSfxMap::new(collision_sfx_map));
+        }
+
         let Object {
             sequence_end_transitions,
             wait_sequence_handles,
@@ -6,7 +6,7 @@ use asset_model::{
     config::AssetSlugBuilder,
     loaded::{AssetId, ItemId, ItemIds},
 };
-use control_settings_loading::KeyboardUiGen;
+use control_settings_loading::{GamepadUiGen, KeyboardUiGen};
 use game_input_model::play::{ButtonInputControlled, NormalInputControlled};
 use input_reaction_loading::{IrsLoader, IrsLoaderParams};
 use input_reaction_model::loaded::{
@@ -35,10 +35,10 @@ use ui_model::config::{UiDefinition, UiType};
 use crate::{
     AssetLoadingResources, AssetSequenceComponentLoaderUiCharacterSelection,
     AssetSequenceComponentLoaderUiComponents, AssetSequenceComponentLoaderUiControlSettings,
-    AssetSequenceComponentLoaderUiForm, AssetSequenceComponentLoaderUiMapSelection,
-    AssetSequenceComponentLoaderUiMenu, AssetSequenceComponentLoaderUiSessionLobby,
-    DefinitionLoadingResourcesRead, IdMappingResourcesRead, SequenceComponentLoadingResources,
-    TextureLoadingResourcesRead,
+    AssetSequenceComponentLoaderUiForm, AssetSequenceComponentLoaderUiGameStats,
+    AssetSequenceComponentLoaderUiMapSelection, AssetSequenceComponentLoaderUiMenu,
+    AssetSequenceComponentLoaderUiSessionLobby, DefinitionLoadingResourcesRead,
+    IdMappingResourcesRead, SequenceComponentLoadingResources, TextureLoadingResourcesRead,
 };
 
 /// Loads sequence components for UI assets.
@@ -134,18 +134,30 @@ impl AssetSequenceComponentLoaderUi {
             .and_then(|ui_definition_handle| ui_definition_assets.get(ui_definition_handle))
             .cloned(); // Clone so that we don't mutate the actual read data.
 
-        // Keyboard button labels for `ControlSettings` UI
+        // Keyboard and gamepad button labels for `ControlSettings` UI
         let keyboard_button_labels = if let Some(UiDefinition {
             ui_type: UiType::ControlSettings(control_settings),
             sequences,
             ..
         }) = ui_definition.as_mut()
         {
-            Some(KeyboardUiGen::generate_full(
+            let player_input_configs = &sequence_component_loading_resources.player_input_configs;
+            let mut control_button_labels = KeyboardUiGen::generate_full(
                 &control_settings.keyboard,
-                &sequence_component_loading_resources.player_input_configs,
+                player_input_configs,
                 sequences,
-            ))
+            );
+            if let Some(gamepad_settings) = control_settings.gamepad.as_ref() {
+                let gamepad_button_labels = GamepadUiGen::generate_full(
+                    gamepad_settings,
+                    &control_settings.keyboard.controller_tints,
+                    player_input_configs,
+                    sequences,
+                );
+                control_button_labels.extend(gamepad_button_labels.0);
+            }
+
+            Some(control_button_labels)
         } else {
             None
         };
@@ -227,6 +239,10 @@ impl AssetSequenceComponentLoaderUi {
             item_ids_all.append(&mut item_ids)
         }
 
+        let music_id = ui_definition
+            .as_ref()
+            .and_then(|ui_definition| ui_definition.music.clone());
+
         // `UiDefinition`.
         if let Some(ui_definition) = ui_definition {
             let UiDefinition {
@@ -380,6 +396,13 @@ impl AssetSequenceComponentLoaderUi {
                         map_selection_ui,
                     );
                 }
+                UiType::GameStats(game_stats_ui) => {
+                    AssetSequenceComponentLoaderUiGameStats::load(
+                        &mut sequence_component_loading_resources.asset_world,
+                        &mut item_ids_all,
+                        game_stats_ui,
+                    );
+                }
                 UiType::SessionLobby(session_lobby_ui) => {
                     AssetSequenceComponentLoaderUiSessionLobby::load(
                         &mut sequence_component_loading_resources.asset_world,
@@ -416,6 +439,12 @@ impl AssetSequenceComponentLoaderUi {
         sequence_component_loading_resources
             .asset_item_ids
             .insert(asset_id, item_ids_all);
+
+        if let Some(music_id) = music_id {
+            sequence_component_loading_resources
+                .asset_music_id
+                .insert(asset_id, music_id);
+        }
     }
 
     fn sequence_components<Seq, Frame>(
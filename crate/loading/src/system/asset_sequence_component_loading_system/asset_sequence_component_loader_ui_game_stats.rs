@@ -0,0 +1,62 @@
+use amethyst::ecs::{Builder, WorldExt};
+use asset_model::{loaded::ItemId, play::AssetWorld};
+use game_stats_ui_model::{
+    config::{GameStatsUi, PlayerStatsWidgetTemplate},
+    loaded::PlayerStatsWidgets,
+};
+
+/// Loads asset items for a `GameStatsUi`.
+#[derive(Debug)]
+pub struct AssetSequenceComponentLoaderUiGameStats;
+
+impl AssetSequenceComponentLoaderUiGameStats {
+    /// Loads asset items for a `GameStatsUi`.
+    pub fn load(
+        asset_world: &mut AssetWorld,
+        item_ids_all: &mut Vec<ItemId>,
+        game_stats_ui: &GameStatsUi,
+    ) {
+        let item_id_player_stats_widgets =
+            Self::load_item_entity_player_stats_widgets(asset_world, game_stats_ui);
+
+        item_ids_all.push(item_id_player_stats_widgets);
+    }
+
+    fn load_item_entity_player_stats_widgets(
+        asset_world: &mut AssetWorld,
+        game_stats_ui: &GameStatsUi,
+    ) -> ItemId {
+        let game_stats_ui_model::config::PlayerStatsWidgets {
+            position: position_init,
+            player_stats_widget_template:
+                PlayerStatsWidgetTemplate {
+                    dimensions,
+                    player_name,
+                    stats,
+                },
+        } = game_stats_ui.player_stats.clone();
+
+        let item_id_player_name = ItemId::new(
+            asset_world
+                .create_entity()
+                .with(player_name.position)
+                .with(player_name)
+                .build(),
+        );
+        let item_id_stats = ItemId::new(
+            asset_world
+                .create_entity()
+                .with(stats.position)
+                .with(stats)
+                .build(),
+        );
+
+        let item_entity_player_stats_widgets = asset_world
+            .create_entity()
+            .with(position_init)
+            .with(dimensions)
+            .with(PlayerStatsWidgets::new(item_id_player_name, item_id_stats))
+            .build();
+        ItemId::new(item_entity_player_stats_widgets)
+    }
+}
@@ -9,6 +9,7 @@ use derivative::Derivative;
 use energy_model::{config::EnergyDefinition, loaded::AssetEnergyDefinitionHandle};
 use map_model::{config::MapDefinition, loaded::AssetMapDefinitionHandle};
 use ui_model::{config::UiDefinition, loaded::AssetUiDefinitionHandle};
+use weapon_model::{config::WeaponDefinition, loaded::AssetWeaponDefinitionHandle};
 
 /// `DefinitionLoadingResources`.
 #[derive(Derivative, SystemData)]
@@ -20,6 +21,9 @@ pub struct DefinitionLoadingResources<'s> {
     /// `EnergyDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub energy_definition_assets: Read<'s, AssetStorage<EnergyDefinition>>,
+    /// `WeaponDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub weapon_definition_assets: Read<'s, AssetStorage<WeaponDefinition>>,
     /// `MapDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub map_definition_assets: Read<'s, AssetStorage<MapDefinition>>,
@@ -35,6 +39,9 @@ pub struct DefinitionLoadingResources<'s> {
     /// `AssetEnergyDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_energy_definition_handle: Write<'s, AssetEnergyDefinitionHandle>,
+    /// `AssetWeaponDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_weapon_definition_handle: Write<'s, AssetWeaponDefinitionHandle>,
     /// `AssetMapDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_map_definition_handle: Write<'s, AssetMapDefinitionHandle>,
@@ -56,6 +63,9 @@ pub struct DefinitionLoadingResourcesRead<'s> {
     /// `EnergyDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub energy_definition_assets: Read<'s, AssetStorage<EnergyDefinition>>,
+    /// `WeaponDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub weapon_definition_assets: Read<'s, AssetStorage<WeaponDefinition>>,
     /// `MapDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub map_definition_assets: Read<'s, AssetStorage<MapDefinition>>,
@@ -71,6 +81,9 @@ pub struct DefinitionLoadingResourcesRead<'s> {
     /// `AssetEnergyDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_energy_definition_handle: Read<'s, AssetEnergyDefinitionHandle>,
+    /// `AssetWeaponDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_weapon_definition_handle: Read<'s, AssetWeaponDefinitionHandle>,
     /// `AssetMapDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_map_definition_handle: Read<'s, AssetMapDefinitionHandle>,
@@ -7,6 +7,7 @@ use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
 use sequence_model::loaded::AssetSequenceIdMappings;
 use sprite_model::config::SpriteSequenceName;
+use weapon_model::config::WeaponSequenceName;
 
 use crate::DefinitionLoadingResourcesRead;
 
@@ -26,6 +27,9 @@ pub struct IdMappingResources<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Write<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<WeaponSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_weapon: Write<'s, AssetSequenceIdMappings<WeaponSequenceName>>,
 }
 
 /// `IdMappingResourcesRead`.
@@ -42,4 +46,7 @@ pub struct IdMappingResourcesRead<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<WeaponSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_weapon: Read<'s, AssetSequenceIdMappings<WeaponSequenceName>>,
 }
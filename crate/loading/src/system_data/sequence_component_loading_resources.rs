@@ -8,6 +8,7 @@ use asset_model::{loaded::AssetItemIds, play::AssetWorld};
 use audio_model::loaded::SourceSequence;
 use camera_model::play::CameraZoomDimensions;
 use character_model::loaded::{CharacterInputReactions, CharacterIrs};
+use collision_audio_model::loaded::AssetCollisionSfxMap;
 use collision_model::{
     config::{Body, Interactions},
     loaded::{BodySequence, InteractionsSequence},
@@ -16,7 +17,8 @@ use derivative::Derivative;
 use game_input_model::{config::PlayerInputConfigs, loaded::PlayerControllers};
 use input_reaction_model::loaded::{InputReaction, InputReactions, InputReactionsSequence};
 use kinematic_model::loaded::ObjectAccelerationSequence;
-use map_model::loaded::{AssetMapBounds, AssetMargins};
+use map_model::loaded::{AssetMapBounds, AssetMargins, AssetTerrain};
+use music_model::loaded::AssetMusicId;
 use sequence_model::loaded::WaitSequence;
 use spawn_model::loaded::{Spawns, SpawnsSequence};
 use sprite_model::loaded::{ScaleSequence, SpriteRenderSequence, TintSequence};
@@ -112,6 +114,15 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Write<'s, AssetMargins>,
+    /// `AssetTerrain` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_terrain: Write<'s, AssetTerrain>,
+    /// `AssetMusicId` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_music_id: Write<'s, AssetMusicId>,
+    /// `AssetCollisionSfxMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_collision_sfx_map: Write<'s, AssetCollisionSfxMap>,
 }
 
 /// `SequenceComponentLoadingResourcesRead`.
@@ -180,4 +191,13 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
+    /// `AssetTerrain` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_terrain: Read<'s, AssetTerrain>,
+    /// `AssetMusicId` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_music_id: Read<'s, AssetMusicId>,
+    /// `AssetCollisionSfxMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_collision_sfx_map: Read<'s, AssetCollisionSfxMap>,
 }
@@ -1,7 +1,9 @@
 use asset_derive::Asset;
 use background_model::config::BackgroundDefinition;
 use derive_new::new;
+use music_model::config::MusicId;
 use serde::{Deserialize, Serialize};
+use shape_model::Volume;
 
 use crate::config::MapHeader;
 
@@ -13,4 +15,14 @@ pub struct MapDefinition {
     /// Background to draw.
     #[serde(flatten)]
     pub background: BackgroundDefinition,
+    /// Music track to play on this map.
+    #[serde(default)]
+    #[new(default)]
+    pub music: Option<MusicId>,
+    /// Solid volumes that objects stand on and cannot move through.
+    ///
+    /// Coordinates are relative to the map's left, bottom, and back boundaries.
+    #[serde(default)]
+    #[new(default)]
+    pub terrain: Vec<Volume>,
 }
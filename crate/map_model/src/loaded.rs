@@ -2,10 +2,12 @@
 
 pub use self::{
     asset_map_bounds::AssetMapBounds, asset_map_definition_handle::AssetMapDefinitionHandle,
-    asset_margins::AssetMargins, margins::Margins,
+    asset_margins::AssetMargins, asset_terrain::AssetTerrain, margins::Margins, terrain::Terrain,
 };
 
 mod asset_map_bounds;
 mod asset_map_definition_handle;
 mod asset_margins;
+mod asset_terrain;
 mod margins;
+mod terrain;
@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SparseSecondaryMap;
+
+use crate::loaded::Terrain;
+
+/// `Terrain` for an asset.
+pub type AssetTerrain = SparseSecondaryMap<AssetId, Terrain>;
@@ -0,0 +1,7 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use shape_model::VolumeBounds;
+
+/// Bounds of a map's solid volumes, in world coordinates.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct Terrain(pub Vec<VolumeBounds>);
@@ -1,9 +1,10 @@
 use amethyst::{
-    ecs::{Entities, Join, System, World, WriteStorage},
+    ecs::{Entities, Join, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_model::play::GameEntityDeletions;
 use map_model::play::OutOfBoundsDeleteClock;
 
 /// Ticks each `HitRepeatTracker`'s clock.
@@ -16,6 +17,9 @@ pub struct MapOutOfBoundsDeletionSystemData<'s> {
     /// `Entities`.
     #[derivative(Debug = "ignore")]
     pub entities: Entities<'s>,
+    /// `GameEntityDeletions` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_deletions: Write<'s, GameEntityDeletions>,
     /// `OutOfBoundsDeleteClock` components.
     #[derivative(Debug = "ignore")]
     pub out_of_bounds_delete_clocks: WriteStorage<'s, OutOfBoundsDeleteClock>,
@@ -28,6 +32,7 @@ impl<'s> System<'s> for MapOutOfBoundsDeletionSystem {
         &mut self,
         MapOutOfBoundsDeletionSystemData {
             entities,
+            mut game_entity_deletions,
             mut out_of_bounds_delete_clocks,
         }: Self::SystemData,
     ) {
@@ -36,7 +41,9 @@ impl<'s> System<'s> for MapOutOfBoundsDeletionSystem {
             .for_each(|(entity, out_of_bounds_delete_clock)| {
                 out_of_bounds_delete_clock.tick();
                 if out_of_bounds_delete_clock.is_complete() {
-                    entities.delete(entity).expect("Failed to delete entity.");
+                    game_entity_deletions
+                        .delete(&entities, entity)
+                        .expect("Failed to delete entity.");
                 }
             });
     } // kcov-ignore
@@ -85,7 +85,10 @@ impl<'s> System<'s> for MapSelectionSystem {
                 AssetSelectionEvent::Confirm => {
                     *map_selection_status = MapSelectionStatus::Confirmed;
                 }
-                AssetSelectionEvent::Join { .. } | AssetSelectionEvent::Leave { .. } => {
+                AssetSelectionEvent::Join { .. }
+                | AssetSelectionEvent::Leave { .. }
+                | AssetSelectionEvent::Cpu { .. }
+                | AssetSelectionEvent::Team { .. } => {
                     warn!("Received `{:?}` in `MapSelectionSystem`.", ev);
                 }
             });
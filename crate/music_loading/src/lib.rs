@@ -0,0 +1,8 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes music configuration into the loaded music model.
+
+pub use crate::{music_loading_bundle::MusicLoadingBundle, system::MusicLoadingSystem};
+
+mod music_loading_bundle;
+mod system;
@@ -0,0 +1,42 @@
+use std::{any, path::PathBuf};
+
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use derive_new::new;
+use music_model::config::MusicTracks;
+
+use crate::MusicLoadingSystem;
+
+/// Adds the following systems to the `World`:
+///
+/// * `Processor<MusicTracks>`
+/// * `MusicLoadingSystem`
+#[derive(Debug, new)]
+pub struct MusicLoadingBundle {
+    /// Path to the assets directory.
+    assets_dir: PathBuf,
+}
+
+impl<'a, 'b> SystemBundle<'a, 'b> for MusicLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<MusicTracks>::new(),
+            "music_tracks_processor",
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            MusicLoadingSystem::new(self.assets_dir),
+            any::type_name::<MusicLoadingSystem>(),
+            &["music_tracks_processor"],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::music_loading_system::MusicLoadingSystem;
+
+mod music_loading_system;
@@ -0,0 +1,159 @@
+use std::{path::PathBuf, time::Duration};
+
+use amethyst::{
+    assets::{AssetStorage, Handle, Loader, ProgressCounter},
+    audio::Source,
+    ecs::{Read, ReadExpect, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use asset_loading::YamlFormat;
+use audio_loading::AudioLoader;
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error};
+use music_model::{
+    config::{MusicId, MusicTrack, MusicTracks},
+    loaded::{MusicMap, MusicSource},
+    MusicLoadingStatus,
+};
+
+/// File name of the music configuration.
+const MUSIC_YAML: &str = "music.yaml";
+
+/// Loads music assets.
+///
+/// Music is optional, so the `LoadingState` does not wait for music to be loaded.
+#[derive(Default, Derivative, new)]
+#[derivative(Debug)]
+pub struct MusicLoadingSystem {
+    /// Path to the assets directory.
+    assets_dir: PathBuf,
+    /// `Handle` to the `MusicTracks`.
+    #[new(default)]
+    music_tracks_handle: Option<Handle<MusicTracks>>,
+    /// Tracks loaded assets.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    progress_counter: ProgressCounter,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct MusicLoadingSystemData<'s> {
+    /// `Loader` to load assets.
+    #[derivative(Debug = "ignore")]
+    loader: ReadExpect<'s, Loader>,
+    /// `MusicTracks` assets.
+    #[derivative(Debug = "ignore")]
+    music_tracks_assets: Read<'s, AssetStorage<MusicTracks>>,
+    /// `Source` assets.
+    #[derivative(Debug = "ignore")]
+    source_assets: Read<'s, AssetStorage<Source>>,
+    /// `MusicMap` resource.
+    #[derivative(Debug = "ignore")]
+    music_map: Write<'s, MusicMap>,
+    /// `MusicLoadingStatus` resource.
+    #[derivative(Debug = "ignore")]
+    music_loading_status: Write<'s, MusicLoadingStatus>,
+}
+
+impl MusicLoadingSystem {
+    /// Returns the loop points of a music track.
+    ///
+    /// Invalid loop points are logged, and fall back to looping the whole track.
+    fn loop_points(music_id: &MusicId, music_track: &MusicTrack) -> (Duration, Option<Duration>) {
+        let loop_start = Duration::from_secs_f32(music_track.loop_start.max(0.));
+        let loop_end = music_track.loop_end.and_then(|loop_end| {
+            let loop_end = Duration::from_secs_f32(loop_end.max(0.));
+            if loop_end > loop_start {
+                Some(loop_end)
+            } else {
+                error!(
+                    "Music track `{}` has `loop_end` before `loop_start`, \
+                     looping from the end of the track.",
+                    music_id
+                );
+                None
+            }
+        });
+
+        (loop_start, loop_end)
+    }
+}
+
+impl<'s> System<'s> for MusicLoadingSystem {
+    type SystemData = MusicLoadingSystemData<'s>;
+
+    fn run(
+        &mut self,
+        MusicLoadingSystemData {
+            loader,
+            music_tracks_assets,
+            source_assets,
+            mut music_map,
+            mut music_loading_status,
+        }: Self::SystemData,
+    ) {
+        if *music_loading_status == MusicLoadingStatus::NotStarted {
+            *music_loading_status = MusicLoadingStatus::InProgress;
+
+            let music_yaml_path = self.assets_dir.join(MUSIC_YAML);
+
+            if music_yaml_path.exists() {
+                // Borrow self piecewise.
+                let progress_counter = &mut self.progress_counter;
+                let music_tracks_handle = &mut self.music_tracks_handle;
+                let handle = loader.load(
+                    MUSIC_YAML,
+                    YamlFormat,
+                    progress_counter,
+                    &music_tracks_assets,
+                );
+                *music_tracks_handle = Some(handle);
+            } else {
+                debug!(
+                    "`{}` does not exist in `assets` directory, no music will be played.",
+                    MUSIC_YAML
+                );
+                *music_loading_status = MusicLoadingStatus::Complete;
+            }
+        }
+
+        if *music_loading_status == MusicLoadingStatus::InProgress {
+            if let Some(music_tracks_handle) = self.music_tracks_handle.as_ref() {
+                if let Some(music_tracks) = music_tracks_assets.get(music_tracks_handle) {
+                    debug!("Music tracks: {:?}", &*music_tracks);
+
+                    let music_to_load = music_tracks
+                        .iter()
+                        .filter(|(music_id, _)| music_map.get(music_id).is_none())
+                        .collect::<Vec<(&MusicId, &MusicTrack)>>();
+
+                    music_to_load
+                        .into_iter()
+                        .for_each(|(music_id, music_track)| {
+                            let source_handle = AudioLoader::load(
+                                &loader,
+                                &source_assets,
+                                &mut self.progress_counter,
+                                &music_track.path,
+                            );
+
+                            let (loop_start, loop_end) = Self::loop_points(music_id, music_track);
+                            let music_source =
+                                MusicSource::new(source_handle, loop_start, loop_end);
+                            music_map.insert(music_id.clone(), music_source);
+                        });
+
+                    let all_loaded = music_map.iter().all(|(_, music_source)| {
+                        source_assets.get(&music_source.source_handle).is_some()
+                    });
+                    if all_loaded {
+                        debug!("Music assets loaded: {:?}", &*music_map);
+                        *music_loading_status = MusicLoadingStatus::Complete;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Contains the types that represent the configuration on disk.
+
+pub use self::{
+    music_id::MusicId,
+    music_track::MusicTrack,
+    music_tracks::{MusicTracks, MusicTracksHandle},
+};
+
+mod music_id;
+mod music_track;
+mod music_tracks;
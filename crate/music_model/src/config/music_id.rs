@@ -0,0 +1,35 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Name of a music track (`String` newtype).
+#[derive(
+    Clone, Debug, Default, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, new,
+)]
+#[serde(transparent)]
+pub struct MusicId(pub String);
+
+impl Display for MusicId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl From<String> for MusicId {
+    fn from(s: String) -> MusicId {
+        MusicId(s)
+    }
+}
+
+impl FromStr for MusicId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(MusicId::new(String::from(s)))
+    }
+}
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Path and loop points of a music track.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct MusicTrack {
+    /// Path to the music file, relative to the assets directory.
+    pub path: PathBuf,
+    /// Time in seconds that the track loops back to.
+    #[serde(default)]
+    pub loop_start: f32,
+    /// Time in seconds that the track loops from, defaults to the end of the track.
+    #[serde(default)]
+    pub loop_end: Option<f32>,
+}
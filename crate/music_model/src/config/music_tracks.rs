@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use asset_derive::Asset;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{MusicId, MusicTrack};
+
+/// Map of `MusicId` to the music track.
+#[derive(Asset, Debug, Default, Deref, DerefMut, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields, transparent)]
+pub struct MusicTracks(HashMap<MusicId, MusicTrack>);
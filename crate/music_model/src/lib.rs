@@ -0,0 +1,16 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types for background music.
+
+pub use crate::{
+    music_event::MusicEvent, music_event_args::MusicEventArgs,
+    music_loading_status::MusicLoadingStatus,
+};
+
+pub mod config;
+pub mod loaded;
+pub mod play;
+
+mod music_event;
+mod music_event_args;
+mod music_loading_status;
@@ -0,0 +1,7 @@
+//! Types representing loaded music.
+
+pub use self::{asset_music_id::AssetMusicId, music_map::MusicMap, music_source::MusicSource};
+
+mod asset_music_id;
+mod music_map;
+mod music_source;
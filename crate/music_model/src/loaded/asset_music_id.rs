@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SparseSecondaryMap;
+
+use crate::config::MusicId;
+
+/// `MusicId` named by an asset.
+pub type AssetMusicId = SparseSecondaryMap<AssetId, MusicId>;
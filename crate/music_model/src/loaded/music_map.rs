@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::{config::MusicId, loaded::MusicSource};
+
+/// Map of `MusicId` to the loaded music data.
+#[derive(Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct MusicMap(HashMap<MusicId, MusicSource>);
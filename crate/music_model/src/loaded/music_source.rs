@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+use amethyst::audio::SourceHandle;
+use derive_new::new;
+
+/// Loaded music data and loop points of a music track.
+#[derive(Clone, Debug, PartialEq, Eq, new)]
+pub struct MusicSource {
+    /// Handle to the audio `Source`.
+    pub source_handle: SourceHandle,
+    /// Time that the track loops back to.
+    pub loop_start: Duration,
+    /// Time that the track loops from, `None` for the end of the track.
+    pub loop_end: Option<Duration>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Event to query or control the background music.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum MusicEvent {
+    /// Logs the music track that is currently playing.
+    Query,
+    /// Crossfades to the next music track.
+    Skip,
+}
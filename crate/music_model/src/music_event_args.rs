@@ -0,0 +1,9 @@
+use crate::MusicEvent;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `music query`
+/// * `music skip`
+pub type MusicEventArgs = MusicEvent;
@@ -0,0 +1,14 @@
+use derivative::Derivative;
+
+/// Status of music asset loading.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum MusicLoadingStatus {
+    /// Music asset loading has not started.
+    #[derivative(Default)]
+    NotStarted,
+    /// Music asset loading is in progress.
+    InProgress,
+    /// Music asset loading is complete.
+    Complete,
+}
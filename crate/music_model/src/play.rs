@@ -0,0 +1,5 @@
+//! Types used at runtime.
+
+pub use self::music_playback::MusicPlayback;
+
+mod music_playback;
@@ -0,0 +1,10 @@
+use derive_new::new;
+
+use crate::config::MusicId;
+
+/// Music track that is selected to play.
+#[derive(Clone, Debug, Default, PartialEq, Eq, new)]
+pub struct MusicPlayback {
+    /// ID of the selected track, `None` if no track has been selected.
+    pub music_id: Option<MusicId>,
+}
@@ -0,0 +1,8 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Plays background music.
+
+pub use crate::system::{MusicPlaySystem, MusicPlaySystemData, MUSIC_CROSSFADE_TICKS};
+
+mod music_sink;
+mod system;
@@ -0,0 +1,114 @@
+use std::io::Cursor;
+
+use amethyst::audio::Source;
+use derivative::Derivative;
+use music_model::{config::MusicId, loaded::MusicSource};
+use rodio::{decoder::DecoderError, Decoder, Device, Sink, Source as _};
+
+use crate::MUSIC_CROSSFADE_TICKS;
+
+/// Plays a music track, looping between its loop points.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub(crate) struct MusicSink {
+    /// ID of the music track.
+    pub(crate) music_id: MusicId,
+    /// Sink that the track is played through.
+    #[derivative(Debug = "ignore")]
+    sink: Sink,
+    /// Number of ticks the track has faded in, up to `MUSIC_CROSSFADE_TICKS`.
+    fade_ticks: u32,
+}
+
+impl MusicSink {
+    /// Returns a `MusicSink` that begins playing the track silently.
+    ///
+    /// The track plays from the beginning to its loop end, then repeats the section between its
+    /// loop points.
+    ///
+    /// # Parameters
+    ///
+    /// * `device`: Device to play the track through.
+    /// * `music_id`: ID of the music track.
+    /// * `source`: Audio data of the track.
+    /// * `music_source`: Loop points of the track.
+    pub(crate) fn new(
+        device: &Device,
+        music_id: MusicId,
+        source: &Source,
+        music_source: &MusicSource,
+    ) -> Result<Self, DecoderError> {
+        let sink = Sink::new(device);
+        sink.set_volume(0.);
+
+        let intro = Decoder::new(Cursor::new(source.clone()))?;
+        let mut body = Decoder::new(Cursor::new(source.clone()))?;
+
+        // Decode and discard samples up to the loop start.
+        let samples_per_second = u64::from(body.sample_rate()) * u64::from(body.channels());
+        let samples_to_skip =
+            music_source.loop_start.as_millis() as u64 * samples_per_second / 1000;
+        body.by_ref().take(samples_to_skip as usize).for_each(drop);
+
+        let loop_duration = music_source
+            .loop_end
+            .and_then(|loop_end| loop_end.checked_sub(music_source.loop_start));
+        match (music_source.loop_end, loop_duration) {
+            (Some(loop_end), Some(loop_duration)) => {
+                sink.append(intro.take_duration(loop_end));
+                sink.append(body.take_duration(loop_duration).repeat_infinite());
+            }
+            _ => {
+                sink.append(intro);
+                sink.append(body.repeat_infinite());
+            }
+        }
+
+        Ok(MusicSink {
+            music_id,
+            sink,
+            fade_ticks: 0,
+        })
+    }
+
+    /// Pauses or resumes playing the track.
+    pub(crate) fn pause(&self, paused: bool) {
+        if paused {
+            self.sink.pause();
+        } else {
+            self.sink.play();
+        }
+    }
+
+    /// Increases the volume of the track by one tick of the crossfade.
+    ///
+    /// # Parameters
+    ///
+    /// * `volume`: Volume of the track once it has faded in completely.
+    pub(crate) fn fade_in(&mut self, volume: f32) {
+        if self.fade_ticks < MUSIC_CROSSFADE_TICKS {
+            self.fade_ticks += 1;
+        }
+        self.volume_update(volume);
+    }
+
+    /// Decreases the volume of the track by one tick of the crossfade.
+    ///
+    /// # Parameters
+    ///
+    /// * `volume`: Volume of the track before it began fading out.
+    pub(crate) fn fade_out(&mut self, volume: f32) {
+        self.fade_ticks = self.fade_ticks.saturating_sub(1);
+        self.volume_update(volume);
+    }
+
+    /// Returns whether the track has faded out completely.
+    pub(crate) fn is_silent(&self) -> bool {
+        self.fade_ticks == 0
+    }
+
+    fn volume_update(&self, volume: f32) {
+        let fade = self.fade_ticks as f32 / MUSIC_CROSSFADE_TICKS as f32;
+        self.sink.set_volume(volume * fade);
+    }
+}
@@ -0,0 +1,3 @@
+pub use self::music_play_system::{MusicPlaySystem, MusicPlaySystemData, MUSIC_CROSSFADE_TICKS};
+
+mod music_play_system;
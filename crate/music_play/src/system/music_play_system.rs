@@ -0,0 +1,274 @@
+use amethyst::{
+    assets::AssetStorage,
+    audio::{output::Output, Source},
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetIdMappings;
+use audio_model::config::{AudioChannel, AudioSettings};
+use audio_play::OutputDevice;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayStatus;
+use log::{error, info};
+use map_selection_model::MapSelection;
+use music_model::{
+    config::MusicId,
+    loaded::{AssetMusicId, MusicMap},
+    play::MusicPlayback,
+    MusicEvent,
+};
+use rodio::Device;
+use state_registry::{StateId, StateIdUpdateEvent};
+use state_support::StateAssetUtils;
+
+use crate::music_sink::MusicSink;
+
+/// Number of ticks to crossfade between music tracks.
+pub const MUSIC_CROSSFADE_TICKS: u32 = 60;
+
+/// Plays background music, crossfading between tracks when the `StateId` changes.
+///
+/// During game play, the track is named by the selected map, otherwise it is named by the state's
+/// UI asset. When neither names a track, the current track continues to play.
+///
+/// Music is played at the `AudioChannel::Music` volume.
+#[derive(Derivative, Default, new)]
+#[derivative(Debug)]
+pub struct MusicPlaySystem {
+    /// Reader ID for the `StateIdUpdateEvent` channel.
+    #[new(default)]
+    state_id_update_event_rid: Option<ReaderId<StateIdUpdateEvent>>,
+    /// Reader ID for the `MusicEvent` channel.
+    #[new(default)]
+    music_event_rid: Option<ReaderId<MusicEvent>>,
+    /// Device of the audio `Output` that music is played through.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    device: Option<Device>,
+    /// Track that is playing.
+    #[new(default)]
+    music_sink: Option<MusicSink>,
+    /// Tracks that are fading out.
+    #[new(default)]
+    music_sinks_fading: Vec<MusicSink>,
+}
+
+/// `MusicPlaySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct MusicPlaySystemData<'s> {
+    /// `StateIdUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub state_id_update_ec: Read<'s, EventChannel<StateIdUpdateEvent>>,
+    /// `MusicEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub music_ec: Read<'s, EventChannel<MusicEvent>>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `AssetMusicId` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_music_id: Read<'s, AssetMusicId>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `MusicMap` resource.
+    #[derivative(Debug = "ignore")]
+    pub music_map: Read<'s, MusicMap>,
+    /// `MusicPlayback` resource.
+    #[derivative(Debug = "ignore")]
+    pub music_playback: Write<'s, MusicPlayback>,
+    /// `Source` assets.
+    #[derivative(Debug = "ignore")]
+    pub source_assets: Read<'s, AssetStorage<Source>>,
+    /// `AudioSettings` resource.
+    #[derivative(Debug = "ignore")]
+    pub audio_settings: Read<'s, AudioSettings>,
+    /// `Output` resource.
+    #[derivative(Debug = "ignore")]
+    pub output: Option<Read<'s, Output>>,
+}
+
+impl MusicPlaySystem {
+    /// Returns the music track named for the state, if any.
+    fn state_music_id(
+        asset_id_mappings: &AssetIdMappings,
+        asset_music_id: &AssetMusicId,
+        map_selection: MapSelection,
+        state_id: StateId,
+    ) -> Option<MusicId> {
+        let asset_id = if state_id == StateId::GamePlay {
+            map_selection.asset_id()
+        } else {
+            StateAssetUtils::asset_id(asset_id_mappings, state_id)
+        };
+
+        asset_id
+            .and_then(|asset_id| asset_music_id.get(asset_id))
+            .cloned()
+    }
+
+    /// Returns the track after the current one, ordered by `MusicId`.
+    fn music_id_next(music_map: &MusicMap, music_id: Option<&MusicId>) -> Option<MusicId> {
+        music_map
+            .keys()
+            .filter(|music_id_candidate| Some(*music_id_candidate) > music_id)
+            .min()
+            .or_else(|| music_map.keys().min())
+            .cloned()
+    }
+
+    /// Begins playing the selected track if it is not already playing.
+    ///
+    /// The track that is playing is faded out.
+    fn music_sink_update(
+        &mut self,
+        music_map: &MusicMap,
+        source_assets: &AssetStorage<Source>,
+        music_playback: &mut MusicPlayback,
+    ) {
+        let music_id_playing = self
+            .music_sink
+            .as_ref()
+            .map(|music_sink| &music_sink.music_id);
+        if music_playback.music_id.as_ref() == music_id_playing {
+            return;
+        }
+
+        let device = if let Some(device) = self.device.as_ref() {
+            device
+        } else {
+            return;
+        };
+
+        let music_sink_next = if let Some(music_id) = music_playback.music_id.as_ref() {
+            let music_source = music_map.get(music_id);
+            let source = music_source
+                .and_then(|music_source| source_assets.get(&music_source.source_handle));
+            if let (Some(music_source), Some(source)) = (music_source, source) {
+                match MusicSink::new(device, music_id.clone(), source, music_source) {
+                    Ok(music_sink) => Some(music_sink),
+                    Err(e) => {
+                        error!("Failed to decode music track `{}`: {}", music_id, e);
+
+                        // Don't attempt to play the track again.
+                        music_playback.music_id = music_id_playing.cloned();
+                        return;
+                    }
+                }
+            } else {
+                // Track is not loaded yet.
+                return;
+            }
+        } else {
+            None
+        };
+
+        if let Some(music_sink) = self.music_sink.take() {
+            self.music_sinks_fading.push(music_sink);
+        }
+        self.music_sink = music_sink_next;
+    }
+}
+
+impl<'s> System<'s> for MusicPlaySystem {
+    type SystemData = MusicPlaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        MusicPlaySystemData {
+            state_id_update_ec,
+            music_ec,
+            asset_id_mappings,
+            asset_music_id,
+            map_selection,
+            game_play_status,
+            music_map,
+            mut music_playback,
+            source_assets,
+            audio_settings,
+            output,
+        }: Self::SystemData,
+    ) {
+        let state_id_update_event_rid = self
+            .state_id_update_event_rid
+            .as_mut()
+            .expect("Expected `state_id_update_event_rid` field to be set.");
+        let music_event_rid = self
+            .music_event_rid
+            .as_mut()
+            .expect("Expected `music_event_rid` field to be set.");
+
+        state_id_update_ec
+            .read(state_id_update_event_rid)
+            .filter_map(|ev| {
+                Self::state_music_id(
+                    &asset_id_mappings,
+                    &asset_music_id,
+                    *map_selection,
+                    ev.state_id,
+                )
+            })
+            .for_each(|music_id| music_playback.music_id = Some(music_id));
+
+        music_ec.read(music_event_rid).for_each(|ev| match ev {
+            MusicEvent::Query => match music_playback.music_id.as_ref() {
+                Some(music_id) => info!("Music track: `{}`.", music_id),
+                None => info!("No music track is selected."),
+            },
+            MusicEvent::Skip => {
+                music_playback.music_id =
+                    Self::music_id_next(&music_map, music_playback.music_id.as_ref());
+            }
+        });
+
+        // Music is not played when there is no audio output device.
+        let output = if let Some(output) = output {
+            output
+        } else {
+            return;
+        };
+        if self.device.is_none() {
+            self.device = OutputDevice::find(&output);
+        }
+
+        self.music_sink_update(&music_map, &source_assets, &mut music_playback);
+
+        let paused = *game_play_status == GamePlayStatus::Paused;
+        let music_sinks = self.music_sink.iter().chain(self.music_sinks_fading.iter());
+        music_sinks.for_each(|music_sink| music_sink.pause(paused));
+        if paused {
+            return;
+        }
+
+        let volume = audio_settings.volume(AudioChannel::Music);
+        if let Some(music_sink) = self.music_sink.as_mut() {
+            music_sink.fade_in(volume);
+        }
+        self.music_sinks_fading
+            .iter_mut()
+            .for_each(|music_sink| music_sink.fade_out(volume));
+        self.music_sinks_fading
+            .retain(|music_sink| !music_sink.is_silent());
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.state_id_update_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<StateIdUpdateEvent>>()
+                .register_reader(),
+        );
+        self.music_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<MusicEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable background music to be controlled by stdio.
+
+pub use crate::{
+    music_event_stdin_mapper::MusicEventStdinMapper, music_stdio_bundle::MusicStdioBundle,
+};
+
+mod music_event_stdin_mapper;
+mod music_stdio_bundle;
@@ -0,0 +1,17 @@
+use amethyst::Error;
+use music_model::{MusicEvent, MusicEventArgs};
+use stdio_spi::StdinMapper;
+
+/// Builds a `MusicEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct MusicEventStdinMapper;
+
+impl StdinMapper for MusicEventStdinMapper {
+    type SystemData = ();
+    type Event = MusicEvent;
+    type Args = MusicEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        Ok(args)
+    }
+}
@@ -0,0 +1,31 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::MusicEventStdinMapper;
+
+/// Adds a `MapperSystem<MusicEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct MusicStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for MusicStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<MusicEventStdinMapper>::new(AppEventVariant::Music),
+            any::type_name::<MapperSystem<MusicEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
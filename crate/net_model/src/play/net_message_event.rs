@@ -1,5 +1,7 @@
 use derive_more::From;
+use desync_model::play::GameChecksum;
 use game_input_model::GameInputEvent;
+use network_input_model::play::NetworkInputFrame;
 use network_session_model::SessionMessageEvent;
 use serde::{Deserialize, Serialize};
 use session_host_model::SessionHostEvent;
@@ -9,8 +11,12 @@ use session_lobby_model::SessionLobbyEvent;
 /// All variants of messages that can be sent over the network.
 #[derive(Clone, Debug, Deserialize, From, PartialEq, Serialize)]
 pub enum NetMessageEvent {
+    /// `GameChecksum` messages.
+    GameChecksum(GameChecksum),
     /// `GameInputEvent` messages.
     GameInputEvent(GameInputEvent),
+    /// `NetworkInputFrame` messages.
+    NetworkInputFrame(NetworkInputFrame),
     /// `SessionHostEvent` messages.
     SessionHostEvent(SessionHostEvent),
     /// `SessionJoinEvent` messages.
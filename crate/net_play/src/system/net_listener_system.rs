@@ -7,9 +7,11 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
+use desync_model::play::GameChecksum;
 use game_input_model::GameInputEvent;
 use log::{debug, error};
 use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use network_input_model::play::NetworkInputFrame;
 use network_session_model::SessionMessageEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
@@ -30,9 +32,15 @@ pub struct NetListenerSystemData<'s> {
     /// `NetworkSimulationEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// Net `GameChecksum` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_checksum_nec: Write<'s, NetEventChannel<GameChecksum>>,
     /// Net `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_nec: Write<'s, NetEventChannel<GameInputEvent>>,
+    /// Net `NetworkInputFrame` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_input_frame_nec: Write<'s, NetEventChannel<NetworkInputFrame>>,
     /// Net `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_host_nec: Write<'s, NetEventChannel<SessionHostEvent>>,
@@ -54,7 +62,9 @@ impl<'s> System<'s> for NetListenerSystem {
         &mut self,
         NetListenerSystemData {
             network_simulation_ec,
+            mut game_checksum_nec,
             mut game_input_nec,
+            mut network_input_frame_nec,
             mut session_host_nec,
             mut session_join_nec,
             mut session_lobby_nec,
@@ -71,10 +81,20 @@ impl<'s> System<'s> for NetListenerSystem {
                         Ok(net_message_event) => {
                             debug!("{:?}", net_message_event);
                             match net_message_event {
+                                NetMessageEvent::GameChecksum(game_checksum) => {
+                                    game_checksum_nec
+                                        .single_write(NetData::new(*socket_addr, game_checksum));
+                                }
                                 NetMessageEvent::GameInputEvent(game_input_event) => {
                                     game_input_nec
                                         .single_write(NetData::new(*socket_addr, game_input_event));
                                 }
+                                NetMessageEvent::NetworkInputFrame(network_input_frame) => {
+                                    network_input_frame_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        network_input_frame,
+                                    ));
+                                }
                                 NetMessageEvent::SessionHostEvent(session_host_event) => {
                                     session_host_nec.single_write(NetData::new(
                                         *socket_addr,
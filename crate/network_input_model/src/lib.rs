@@ -0,0 +1,5 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to synchronize game input across a network session.
+
+pub mod play;
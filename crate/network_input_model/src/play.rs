@@ -0,0 +1,11 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    frame_number::FrameNumber, lockstep_frame_numbers::LockstepFrameNumbers,
+    network_input_buffer::NetworkInputBuffer, network_input_frame::NetworkInputFrame,
+};
+
+mod frame_number;
+mod lockstep_frame_numbers;
+mod network_input_buffer;
+mod network_input_frame;
@@ -0,0 +1,8 @@
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Logical frame that game input is applied on.
+#[numeric_newtype]
+#[derive(Debug, Default, Deserialize, Hash, Serialize)]
+pub struct FrameNumber(pub u64);
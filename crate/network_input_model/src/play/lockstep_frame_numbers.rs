@@ -0,0 +1,19 @@
+use derive_new::new;
+
+use crate::play::FrameNumber;
+
+/// Frame counters for lockstep game input synchronization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+pub struct LockstepFrameNumbers {
+    /// Next frame that local input will be sent for.
+    pub send: FrameNumber,
+    /// Next frame that game play will be stepped with.
+    pub step: FrameNumber,
+}
+
+impl LockstepFrameNumbers {
+    /// Resets both counters to the first frame.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use network_session_model::play::{SessionDeviceId, SessionDevices};
+
+use crate::play::{FrameNumber, NetworkInputFrame};
+
+/// Buffers `NetworkInputFrame`s until input for a frame has been received from every device.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct NetworkInputBuffer {
+    /// Game input events for each frame, by session device.
+    #[new(default)]
+    frames: BTreeMap<FrameNumber, BTreeMap<SessionDeviceId, Vec<GameInputEvent>>>,
+}
+
+impl NetworkInputBuffer {
+    /// Buffers the game input events for a device's frame.
+    ///
+    /// If input has already been buffered for the device and frame, it is replaced.
+    pub fn insert(&mut self, network_input_frame: NetworkInputFrame) {
+        let NetworkInputFrame {
+            session_device_id,
+            frame_number,
+            game_input_events,
+        } = network_input_frame;
+
+        self.frames
+            .entry(frame_number)
+            .or_insert_with(BTreeMap::new)
+            .insert(session_device_id, game_input_events);
+    }
+
+    /// Returns whether input for the frame has been received from every session device.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Frame to check.
+    /// * `session_devices`: Devices in the session.
+    pub fn is_complete(&self, frame_number: FrameNumber, session_devices: &SessionDevices) -> bool {
+        self.frames
+            .get(&frame_number)
+            .map(|device_events| {
+                session_devices
+                    .iter()
+                    .all(|session_device| device_events.contains_key(&session_device.id))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the game input events for a device's frame, if it has been received.
+    pub fn device_events(
+        &self,
+        frame_number: FrameNumber,
+        session_device_id: SessionDeviceId,
+    ) -> Option<&[GameInputEvent]> {
+        self.frames
+            .get(&frame_number)
+            .and_then(|device_events| device_events.get(&session_device_id))
+            .map(Vec::as_slice)
+    }
+
+    /// Returns the session devices whose input for the frame has not been received.
+    pub fn missing_devices(
+        &self,
+        frame_number: FrameNumber,
+        session_devices: &SessionDevices,
+    ) -> Vec<SessionDeviceId> {
+        let device_events = self.frames.get(&frame_number);
+        session_devices
+            .iter()
+            .map(|session_device| session_device.id)
+            .filter(|session_device_id| {
+                device_events
+                    .map(|device_events| !device_events.contains_key(session_device_id))
+                    .unwrap_or(true)
+            })
+            .collect::<Vec<SessionDeviceId>>()
+    }
+
+    /// Returns the received game input events for a frame, ordered by `SessionDeviceId`.
+    pub fn game_input_events(&self, frame_number: FrameNumber) -> Vec<GameInputEvent> {
+        self.frames
+            .get(&frame_number)
+            .map(|device_events| {
+                device_events
+                    .values()
+                    .flat_map(|evs| evs.iter().copied())
+                    .collect::<Vec<GameInputEvent>>()
+            })
+            .unwrap_or_else(Vec::new)
+    }
+
+    /// Removes and returns the game input events for a frame, ordered by `SessionDeviceId`.
+    ///
+    /// Events for earlier frames are discarded.
+    pub fn take(&mut self, frame_number: FrameNumber) -> Vec<GameInputEvent> {
+        let game_input_events = self.game_input_events(frame_number);
+        self.discard_before(frame_number + 1);
+
+        game_input_events
+    }
+
+    /// Discards input for frames before the given frame.
+    pub fn discard_before(&mut self, frame_number: FrameNumber) {
+        self.frames = self.frames.split_off(&frame_number);
+    }
+
+    /// Clears all buffered input.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Returns whether no input is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
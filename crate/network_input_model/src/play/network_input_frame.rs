@@ -0,0 +1,20 @@
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use network_session_model::play::SessionDeviceId;
+use serde::{Deserialize, Serialize};
+
+use crate::play::FrameNumber;
+
+/// Game input from a session device for a particular frame.
+///
+/// A frame is sent every tick, even when there are no input events, so that peers know the device
+/// has no further input for that frame.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct NetworkInputFrame {
+    /// ID of the session device that the input originates from.
+    pub session_device_id: SessionDeviceId,
+    /// Frame that the input should be applied on.
+    pub frame_number: FrameNumber,
+    /// Game input events for the frame.
+    pub game_input_events: Vec<GameInputEvent>,
+}
@@ -1,7 +1,9 @@
 pub use self::{
+    network_input_lockstep_system::{NetworkInputLockstepSystem, NetworkInputLockstepSystemDesc},
     network_input_request_system::{NetworkInputRequestSystem, NetworkInputRequestSystemDesc},
     network_input_response_system::{NetworkInputResponseSystem, NetworkInputResponseSystemDesc},
 };
 
+mod network_input_lockstep_system;
 mod network_input_request_system;
 mod network_input_response_system;
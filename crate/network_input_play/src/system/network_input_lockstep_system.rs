@@ -0,0 +1,130 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::GameInputEvent;
+use game_play_model::{GamePlayStatus, GamePlayStepStatus};
+use log::debug;
+use network_input_model::play::{LockstepFrameNumbers, NetworkInputBuffer};
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{SessionDevices, SessionStatus},
+};
+use state_registry::{StateId, StateIdUpdateEvent};
+
+/// Steps game play once input for the current frame is received from all session devices.
+///
+/// The buffered input is written to the `GameInputEvent` channel in `SessionDeviceId` order, so
+/// that every device applies the same input in the same order.
+///
+/// When rollback is enabled, game play is stepped by the `GamePlayState` instead.
+///
+/// Game play systems do not step while game play is paused.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(NetworkInputLockstepSystemDesc))]
+pub struct NetworkInputLockstepSystem {
+    /// Reader ID for the `StateIdUpdateEvent` channel.
+    #[system_desc(event_channel_reader)]
+    state_id_update_event_rid: ReaderId<StateIdUpdateEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct NetworkInputLockstepSystemData<'s> {
+    /// `StateIdUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub state_id_update_ec: Read<'s, EventChannel<StateIdUpdateEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: Read<'s, SessionServerConfig>,
+    /// `SessionDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_devices: Read<'s, SessionDevices>,
+    /// `LockstepFrameNumbers` resource.
+    #[derivative(Debug = "ignore")]
+    pub lockstep_frame_numbers: Write<'s, LockstepFrameNumbers>,
+    /// `NetworkInputBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub network_input_buffer: Write<'s, NetworkInputBuffer>,
+    /// `GamePlayStepStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_step_status: Write<'s, GamePlayStepStatus>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+}
+
+impl<'s> System<'s> for NetworkInputLockstepSystem {
+    type SystemData = NetworkInputLockstepSystemData<'s>;
+
+    fn run(
+        &mut self,
+        NetworkInputLockstepSystemData {
+            state_id_update_ec,
+            session_status,
+            state_id,
+            game_play_status,
+            session_server_config,
+            session_devices,
+            mut lockstep_frame_numbers,
+            mut network_input_buffer,
+            mut game_play_step_status,
+            mut game_input_ec,
+        }: Self::SystemData,
+    ) {
+        // Input for a new round starts from the first frame.
+        //
+        // We only reset when leaving game play, as other devices may begin sending input before
+        // this device has entered game play.
+        let game_play_exited = state_id_update_ec
+            .read(&mut self.state_id_update_event_rid)
+            .fold(false, |game_play_exited, ev| {
+                game_play_exited || ev.state_id_prev == Some(StateId::GamePlay)
+            });
+        if game_play_exited {
+            lockstep_frame_numbers.reset();
+            network_input_buffer.clear();
+        }
+
+        if *state_id == StateId::GamePlay && *game_play_status == GamePlayStatus::Paused {
+            *game_play_step_status = GamePlayStepStatus::Paused;
+            return;
+        }
+
+        let in_session = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if !in_session || *state_id != StateId::GamePlay {
+            *game_play_step_status = GamePlayStepStatus::Ready;
+            return;
+        }
+        if session_server_config.rollback_frames > 0 {
+            return;
+        }
+
+        let frame_number = lockstep_frame_numbers.step;
+        if network_input_buffer.is_complete(frame_number, &session_devices) {
+            let game_input_events = network_input_buffer.take(frame_number);
+            game_input_ec.iter_write(game_input_events);
+
+            lockstep_frame_numbers.step += 1;
+            *game_play_step_status = GamePlayStepStatus::Ready;
+        } else {
+            debug!("Waiting for network input for frame: {}.", frame_number);
+
+            *game_play_step_status = GamePlayStepStatus::Waiting;
+        }
+    }
+}
@@ -9,17 +9,37 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::{config::ControlBindings, GameInputEvent};
+use game_input_model::{
+    config::{ControlBindings, PlayerInputConfigs},
+    play::{AxisDigitizer, ControllerIdOffset, GamepadAssignments},
+    GameInputEvent,
+};
 use net_model::play::NetMessageEvent;
-use network_session_model::play::SessionStatus;
+use network_input_model::play::{LockstepFrameNumbers, NetworkInputBuffer, NetworkInputFrame};
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{SessionDeviceId, SessionStatus},
+};
+use state_registry::StateId;
 
 /// Sends network input to a session server.
+///
+/// During game play, input is batched into a `NetworkInputFrame` per tick, tagged with the frame
+/// it should be applied on. Outside of game play, input events are sent as they are received.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputRequestSystemDesc))]
 pub struct NetworkInputRequestSystem {
     /// Reader ID for the `InputEvent<ControlBindings>` channel.
     #[system_desc(event_channel_reader)]
     input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Local `GameInputEvent`s that have not yet been sent in a `NetworkInputFrame`.
+    #[new(default)]
+    #[system_desc(skip)]
+    game_input_events: Vec<GameInputEvent>,
+    /// Rounds analog gamepad axis values.
+    #[new(default)]
+    #[system_desc(skip)]
+    axis_digitizer: AxisDigitizer,
 }
 
 #[derive(Derivative, SystemData)]
@@ -31,6 +51,30 @@ pub struct NetworkInputRequestSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `PlayerInputConfigs` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_input_configs: Read<'s, PlayerInputConfigs>,
+    /// `ControllerIdOffset` resource.
+    #[derivative(Debug = "ignore")]
+    pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Read<'s, GamepadAssignments>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: Read<'s, SessionServerConfig>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `LockstepFrameNumbers` resource.
+    #[derivative(Debug = "ignore")]
+    pub lockstep_frame_numbers: Write<'s, LockstepFrameNumbers>,
+    /// `NetworkInputBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub network_input_buffer: Write<'s, NetworkInputBuffer>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
@@ -44,6 +88,14 @@ impl<'s> System<'s> for NetworkInputRequestSystem {
         NetworkInputRequestSystemData {
             input_ec,
             session_status,
+            state_id,
+            player_input_configs,
+            controller_id_offset,
+            gamepad_assignments,
+            session_server_config,
+            session_device_id,
+            mut lockstep_frame_numbers,
+            mut network_input_buffer,
             mut net_message_ec,
         }: Self::SystemData,
     ) {
@@ -53,11 +105,42 @@ impl<'s> System<'s> for NetworkInputRequestSystem {
         if *session_status == SessionStatus::JoinEstablished
             || *session_status == SessionStatus::HostEstablished
         {
-            input_events
+            let axis_digitizer = &mut self.axis_digitizer;
+            let game_input_events = input_events
                 .filter_map(|ev| GameInputEvent::try_from(ev).ok())
-                .for_each(|ev| {
+                .filter_map(|ev| {
+                    axis_digitizer.digitize(
+                        &player_input_configs,
+                        *controller_id_offset,
+                        &gamepad_assignments,
+                        ev,
+                    )
+                });
+
+            if *state_id == StateId::GamePlay {
+                self.game_input_events.extend(game_input_events);
+
+                // Send input for each frame up to `input_delay` frames ahead of the current step.
+                let input_delay = u64::from(session_server_config.input_delay);
+                while lockstep_frame_numbers.send <= lockstep_frame_numbers.step + input_delay {
+                    let network_input_frame = NetworkInputFrame::new(
+                        *session_device_id,
+                        lockstep_frame_numbers.send,
+                        self.game_input_events.drain(..).collect(),
+                    );
+
+                    network_input_buffer.insert(network_input_frame.clone());
+                    net_message_ec.single_write(NetMessageEvent::from(network_input_frame));
+
+                    lockstep_frame_numbers.send += 1;
+                }
+            } else {
+                self.game_input_events.clear();
+
+                game_input_events.for_each(|ev| {
                     net_message_ec.single_write(NetMessageEvent::GameInputEvent(ev));
                 });
+            }
         }
     }
 }
@@ -9,15 +9,21 @@ use derive_new::new;
 use game_input_model::GameInputEvent;
 use log::debug;
 use net_model::play::{NetData, NetEventChannel};
+use network_input_model::play::{NetworkInputBuffer, NetworkInputFrame};
 use network_session_model::play::SessionStatus;
 
 /// Writes received `GameInputEvent`s from the net channel to the regular event channel.
+///
+/// Received `NetworkInputFrame`s are buffered until game play steps with them.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputResponseSystemDesc))]
 pub struct NetworkInputResponseSystem {
     /// Reader ID for the `GameInputEvent` channel.
     #[system_desc(event_channel_reader)]
     game_input_event_rid: ReaderId<NetData<GameInputEvent>>,
+    /// Reader ID for the `NetworkInputFrame` channel.
+    #[system_desc(event_channel_reader)]
+    network_input_frame_rid: ReaderId<NetData<NetworkInputFrame>>,
 }
 
 #[derive(Derivative, SystemData)]
@@ -26,9 +32,15 @@ pub struct NetworkInputResponseSystemData<'s> {
     /// Net `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_nec: Read<'s, NetEventChannel<GameInputEvent>>,
+    /// Net `NetworkInputFrame` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_input_frame_nec: Read<'s, NetEventChannel<NetworkInputFrame>>,
     /// `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `NetworkInputBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub network_input_buffer: Write<'s, NetworkInputBuffer>,
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
@@ -41,11 +53,14 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
         &mut self,
         NetworkInputResponseSystemData {
             game_input_nec,
+            network_input_frame_nec,
             mut game_input_ec,
+            mut network_input_buffer,
             session_status,
         }: Self::SystemData,
     ) {
         let game_input_events = game_input_nec.read(&mut self.game_input_event_rid);
+        let network_input_frames = network_input_frame_nec.read(&mut self.network_input_frame_rid);
         let session_status = &*session_status;
 
         if session_status == &SessionStatus::JoinEstablished
@@ -64,6 +79,20 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
 
                 game_input_ec.single_write(*game_input_event);
             });
+
+            network_input_frames.for_each(|ev| {
+                let NetData {
+                    data: network_input_frame,
+                    ..
+                } = ev;
+
+                debug!(
+                    "`NetData<NetworkInputFrame>` received: {:?}.",
+                    network_input_frame
+                );
+
+                network_input_buffer.insert(network_input_frame.clone());
+            });
         }
     }
 }
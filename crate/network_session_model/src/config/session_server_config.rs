@@ -12,6 +12,40 @@ pub struct SessionServerConfig {
     pub address: IpAddr,
     /// Port that the server is listening on.
     pub port: u16,
+    /// Number of frames between when local input is sent and when it is applied.
+    ///
+    /// Larger values tolerate more network latency, at the cost of input responsiveness.
+    #[serde(default = "SessionServerConfig::input_delay_default")]
+    pub input_delay: u32,
+    /// Maximum number of frames that game play may run ahead of confirmed input.
+    ///
+    /// When non-zero, input from other devices is predicted so game play does not wait, and game
+    /// play is re-simulated when the actual input differs. `0` disables rollback, and game play
+    /// waits for input from every device.
+    #[serde(default = "SessionServerConfig::rollback_frames_default")]
+    pub rollback_frames: u32,
+    /// Number of frames between game play checksums, `0` disables desync detection.
+    ///
+    /// Checksums are exchanged between session devices to detect when game play has diverged.
+    #[serde(default = "SessionServerConfig::checksum_interval_default")]
+    pub checksum_interval: u32,
+}
+
+impl SessionServerConfig {
+    /// Returns the default input delay.
+    pub fn input_delay_default() -> u32 {
+        2
+    }
+
+    /// Returns the default number of rollback frames.
+    pub fn rollback_frames_default() -> u32 {
+        8
+    }
+
+    /// Returns the default number of frames between game play checksums.
+    pub fn checksum_interval_default() -> u32 {
+        60
+    }
 }
 
 impl Default for SessionServerConfig {
@@ -19,6 +53,9 @@ impl Default for SessionServerConfig {
         SessionServerConfig {
             address: IpAddr::V4(Ipv4Addr::LOCALHOST),
             port: 1234,
+            input_delay: SessionServerConfig::input_delay_default(),
+            rollback_frames: SessionServerConfig::rollback_frames_default(),
+            checksum_interval: SessionServerConfig::checksum_interval_default(),
         }
     }
 }
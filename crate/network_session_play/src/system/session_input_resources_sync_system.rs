@@ -10,7 +10,7 @@ use derive_new::new;
 use game_input_model::{
     config::{ControlBindings, PlayerInputConfigs},
     loaded::PlayerControllers,
-    play::ControllerIdOffset,
+    play::{ControllerIdOffset, GamepadAssignments},
 };
 use log::error;
 use network_session_model::{play::SessionStatus, SessionStatusEvent};
@@ -39,6 +39,9 @@ pub struct SessionInputResourcesSyncSystemData<'s> {
     /// `ControllerIdOffset` resource.
     #[derivative(Debug = "ignore")]
     pub controller_id_offset: Read<'s, ControllerIdOffset>,
+    /// `GamepadAssignments` resource.
+    #[derivative(Debug = "ignore")]
+    pub gamepad_assignments: Read<'s, GamepadAssignments>,
     /// `InputHandler<ControlBindings>` resource.
     #[derivative(Debug = "ignore")]
     pub input_handler: Write<'s, InputHandler<ControlBindings>>,
@@ -52,9 +55,10 @@ impl SessionInputResourcesSyncSystem {
         player_input_configs: &PlayerInputConfigs,
         input_handler: &mut InputHandler<ControlBindings>,
         controller_id_offset: ControllerIdOffset,
+        gamepad_assignments: &GamepadAssignments,
     ) {
         // Update `PlayerAxisControl`s and `PlayerActionControl`s in `Bindings`
-        match player_input_configs.generate_bindings(controller_id_offset) {
+        match player_input_configs.generate_bindings(controller_id_offset, gamepad_assignments) {
             Ok(bindings) => input_handler.bindings = bindings,
             Err(e) => {
                 error!(
@@ -77,6 +81,7 @@ impl<'s> System<'s> for SessionInputResourcesSyncSystem {
             session_status,
             player_input_configs,
             controller_id_offset,
+            gamepad_assignments,
             mut input_handler,
             mut player_controllers,
         }: Self::SystemData,
@@ -92,6 +97,7 @@ impl<'s> System<'s> for SessionInputResourcesSyncSystem {
                         &player_input_configs,
                         &mut input_handler,
                         *controller_id_offset,
+                        &gamepad_assignments,
                     );
 
                     // Reload `PlayerControllers` from configuration.
@@ -102,6 +108,7 @@ impl<'s> System<'s> for SessionInputResourcesSyncSystem {
                         &player_input_configs,
                         &mut input_handler,
                         *controller_id_offset,
+                        &gamepad_assignments,
                     );
                 }
                 _ => {}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 use amethyst::{assets::Handle, renderer::SpriteRender};
 use asset_model::config::AssetType;
@@ -6,7 +6,7 @@ use audio_loading::AudioLoader;
 use audio_model::loaded::{SourceHandleOpt, SourceSequence, SourceSequenceHandles};
 use character_model::config::CharacterSequenceName;
 use collision_model::{
-    config::{Body, Interactions},
+    config::{Body, Interaction, Interactions},
     loaded::{
         BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
     },
@@ -30,8 +30,10 @@ use sequence_model::{
     },
 };
 use serde::{Deserialize, Serialize};
+use shape_model::Volume;
 use spawn_model::loaded::{Spawn, Spawns, SpawnsSequence, SpawnsSequenceHandles};
 use sprite_model::loaded::{SpriteRenderSequence, SpriteRenderSequenceHandles};
+use weapon_model::config::WeaponSequenceName;
 
 use crate::ObjectLoaderParams;
 
@@ -53,6 +55,7 @@ impl ObjectLoader {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -126,7 +129,7 @@ impl ObjectLoader {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
-        ) = object_definition.sequences.values().fold(
+        ) = object_definition.sequences.iter().fold(
             sequences_handles,
             |(
                 mut wait_sequence_handles,
@@ -137,7 +140,7 @@ impl ObjectLoader {
                 mut interactions_sequence_handles,
                 mut spawns_sequence_handles,
             ),
-             sequence| {
+             (sequence_name, sequence)| {
                 let object_sequence = sequence.object_sequence();
 
                 let wait_sequence = WaitSequence::new(
@@ -197,12 +200,14 @@ impl ObjectLoader {
                         .sequence
                         .frames
                         .iter()
-                        .map(|frame| {
-                            loader.load_from_data(
-                                frame.object_frame().body.clone(),
-                                (),
-                                body_assets,
-                            )
+                        .enumerate()
+                        .map(|(frame_index, frame)| {
+                            let body = Body::new(Self::volumes_validate(
+                                &frame.object_frame().body,
+                                sequence_name,
+                                frame_index,
+                            ));
+                            loader.load_from_data(body, (), body_assets)
                         })
                         .collect::<Vec<Handle<Body>>>(),
                 );
@@ -211,9 +216,26 @@ impl ObjectLoader {
                         .sequence
                         .frames
                         .iter()
-                        .map(|frame| {
+                        .enumerate()
+                        .map(|(frame_index, frame)| {
+                            let interactions = frame
+                                .object_frame()
+                                .interactions
+                                .iter()
+                                .map(|interaction| {
+                                    let bounds = Self::volumes_validate(
+                                        &interaction.bounds,
+                                        sequence_name,
+                                        frame_index,
+                                    );
+                                    Interaction {
+                                        bounds,
+                                        ..interaction.clone()
+                                    }
+                                })
+                                .collect::<Vec<Interaction>>();
                             loader.load_from_data(
-                                frame.object_frame().interactions.clone(),
+                                Interactions::new(interactions),
                                 (),
                                 interactions_assets,
                             )
@@ -290,6 +312,26 @@ impl ObjectLoader {
                                                             .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
                                                 }
                                             }
+                                            ObjectType::Weapon => {
+                                                let spawn_sequence_id_mappings = asset_sequence_id_mappings_weapon.get(spawn_asset_id)
+                                                    .unwrap_or_else(|| panic!("`SequenceIdMappings<Weapon>` not found for `{}`.", spawn_asset_slug));
+
+                                                if let Some(sequence_string) = spawn_config.sequence.as_ref() {
+                                                    let sequence_name_string = SequenceNameString::from_str(sequence_string).expect("Expected `SequenceNameString::from_str` to succeed.");
+                                                    spawn_sequence_id_mappings.id(&sequence_name_string).copied().unwrap_or_else(|| {
+                                                        let message = format!("Sequence ID not found for string: `{}` in `{}`. Falling back to default.", sequence_string, spawn_asset_slug);
+                                                        error!("{}", message);
+
+                                                        let sequence_default = WeaponSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                    })
+                                                } else {
+                                                    let sequence_default = WeaponSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                }
+                                            }
                                             ObjectType::TestObject => {
                                                 panic!("Spawning `TestObject`s is not supported.")
                                             }
@@ -364,4 +406,32 @@ impl ObjectLoader {
             SequenceEndTransitions::new(sequence_end_transitions),
         )
     }
+
+    /// Returns the valid volumes, logging an error for each invalid volume.
+    ///
+    /// Invalid volumes are excluded, as they cannot be intersected with.
+    fn volumes_validate<SeqName>(
+        volumes: &[Volume],
+        sequence_name: &SeqName,
+        frame_index: usize,
+    ) -> Vec<Volume>
+    where
+        SeqName: Display,
+    {
+        volumes
+            .iter()
+            .filter(|volume| match volume.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    error!(
+                        "Invalid volume in sequence `{}`, frame `{}`, it will be ignored. \
+                        Error: `{}`.",
+                        sequence_name, frame_index, e
+                    );
+                    false
+                }
+            })
+            .copied()
+            .collect::<Vec<Volume>>()
+    }
 }
@@ -16,6 +16,7 @@ use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
 use sprite_model::loaded::SpriteRenderSequence;
+use weapon_model::config::WeaponSequenceName;
 
 use crate::ObjectLoaderSystemData;
 
@@ -38,6 +39,9 @@ pub struct ObjectLoaderParams<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: &'s AssetSequenceIdMappings<EnergySequenceName>,
+    /// `AssetSequenceIdMappings<WeaponSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_weapon: &'s AssetSequenceIdMappings<WeaponSequenceName>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: &'s AssetStorage<WaitSequence>,
@@ -90,6 +94,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             ref asset_type_mappings,
             ref asset_sequence_id_mappings_character,
             ref asset_sequence_id_mappings_energy,
+            ref asset_sequence_id_mappings_weapon,
             ref wait_sequence_assets,
             ref source_assets,
             ref source_sequence_assets,
@@ -109,6 +114,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_weapon,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
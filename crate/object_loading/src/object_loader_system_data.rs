@@ -17,6 +17,7 @@ use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
 use sprite_model::loaded::SpriteRenderSequence;
+use weapon_model::config::WeaponSequenceName;
 
 /// Resources needed to load an object.
 #[derive(Derivative, SystemData)]
@@ -38,6 +39,9 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<WeaponSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_weapon: Read<'s, AssetSequenceIdMappings<WeaponSequenceName>>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: Read<'s, AssetStorage<WaitSequence>>,
@@ -1,3 +1,4 @@
+use collision_audio_model::config::CollisionSfxPaths;
 use derivative::Derivative;
 use derive_new::new;
 use indexmap::IndexMap;
@@ -22,4 +23,8 @@ where
 {
     /// Sequences of actions this object can perform.
     pub sequences: IndexMap<SequenceNameString<ObjSeq::SequenceName>, ObjSeq>,
+    /// Collision sounds that override the default sounds when this object hits another.
+    #[serde(default)]
+    #[new(default)]
+    pub collision_sfx: CollisionSfxPaths,
 }
@@ -4,6 +4,10 @@
 
 pub use crate::system::{
     ObjectAccelerationSystem, ObjectGravitySystem, ObjectGroundingSystem, ObjectMirroringSystem,
+    ObjectTerrainCollisionSystem, TERRAIN_GROUND_TOLERANCE,
 };
 
+pub(crate) use crate::object_body_bounds::ObjectBodyBounds;
+
+mod object_body_bounds;
 mod system;
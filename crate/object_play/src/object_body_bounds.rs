@@ -0,0 +1,71 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    renderer::{SpriteRender, SpriteSheet},
+};
+use collision_model::config::Body;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use shape_model::VolumeBounds;
+
+/// Calculates the bounds of an object's `Body`.
+#[derive(Debug)]
+pub(crate) struct ObjectBodyBounds;
+
+impl ObjectBodyBounds {
+    /// Returns the bounds of the object's `Body` in world coordinates.
+    ///
+    /// Objects without a loaded `Body`, or whose `Body` has no volumes, are treated as a point at
+    /// their position.
+    ///
+    /// # Parameters
+    ///
+    /// * `body_assets`: `Body` assets.
+    /// * `sprite_sheet_assets`: `SpriteSheet` assets.
+    /// * `position`: Position of the object.
+    /// * `body_handle`: `Handle<Body>` of the object.
+    /// * `sprite_render`: `SpriteRender` of the object, used to offset the volumes.
+    /// * `mirrored`: Whether the object is mirrored.
+    pub(crate) fn calculate(
+        body_assets: &AssetStorage<Body>,
+        sprite_sheet_assets: &AssetStorage<SpriteSheet>,
+        position: &Position<f32>,
+        body_handle: Option<&Handle<Body>>,
+        sprite_render: Option<&SpriteRender>,
+        mirrored: Option<&Mirrored>,
+    ) -> VolumeBounds {
+        let offsets = sprite_render
+            .and_then(|sprite_render| Self::sprite_offsets(sprite_sheet_assets, sprite_render))
+            .unwrap_or([0., 0.]);
+        let mirrored = mirrored.map(|mirrored| mirrored.0).unwrap_or(false);
+
+        body_handle
+            .and_then(|body_handle| body_assets.get(body_handle))
+            .and_then(|body| {
+                body.iter()
+                    .map(|volume| VolumeBounds::from_volume(volume, offsets, mirrored))
+                    .fold(None, |bounds_union, bounds| match bounds_union {
+                        Some(bounds_union) => Some(bounds_union.union(bounds)),
+                        None => Some(bounds),
+                    })
+            })
+            .unwrap_or_else(|| VolumeBounds::new([0., 0., 0.], [0., 0., 0.]))
+            .translate([position[0], position[1], position[2]])
+    }
+
+    /// Returns the offsets of the sprite, accounting for the half width and height shift from
+    /// Amethyst.
+    fn sprite_offsets(
+        sprite_sheet_assets: &AssetStorage<SpriteSheet>,
+        sprite_render: &SpriteRender,
+    ) -> Option<[f32; 2]> {
+        sprite_sheet_assets
+            .get(&sprite_render.sprite_sheet)
+            .and_then(|sprite_sheet| sprite_sheet.sprites.get(sprite_render.sprite_number))
+            .map(|sprite| {
+                [
+                    sprite.offsets[0] + sprite.width / 2.,
+                    sprite.offsets[1] + sprite.height / 2.,
+                ]
+            })
+    }
+}
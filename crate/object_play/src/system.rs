@@ -1,10 +1,13 @@
 pub use self::{
     object_acceleration_system::ObjectAccelerationSystem,
-    object_gravity_system::ObjectGravitySystem, object_grounding_system::ObjectGroundingSystem,
+    object_gravity_system::ObjectGravitySystem,
+    object_grounding_system::{ObjectGroundingSystem, TERRAIN_GROUND_TOLERANCE},
     object_mirroring_system::ObjectMirroringSystem,
+    object_terrain_collision_system::ObjectTerrainCollisionSystem,
 };
 
 mod object_acceleration_system;
 mod object_gravity_system;
 mod object_grounding_system;
 mod object_mirroring_system;
+mod object_terrain_collision_system;
@@ -1,15 +1,27 @@
 use amethyst::{
+    assets::{AssetStorage, Handle},
     ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{SpriteRender, SpriteSheet},
     shred::{ResourceId, SystemData},
 };
+use collision_model::config::Body;
 use derivative::Derivative;
 use derive_new::new;
 use kinematic_model::config::Position;
-use map_model::loaded::AssetMargins;
+use map_model::loaded::{AssetMargins, AssetTerrain};
 use map_selection_model::MapSelection;
+use mirrored_model::play::Mirrored;
 use object_model::play::Grounding;
+use shape_model::VolumeBounds;
+
+use crate::ObjectBodyBounds;
+
+/// Distance above terrain that an object's `Body` may be while still standing on it.
+pub const TERRAIN_GROUND_TOLERANCE: f32 = 0.01;
 
 /// Updates `Grounding` to `Airborne` for objects above the map bottom boundary.
+///
+/// Objects whose `Body` is standing on the map's terrain are `OnGround`.
 #[derive(Debug, Default, new)]
 pub struct ObjectGroundingSystem;
 
@@ -22,14 +34,41 @@ pub struct ObjectGroundingSystemData<'s> {
     /// `AssetMargins` resource.
     #[derivative(Debug = "ignore")]
     pub asset_margins: Read<'s, AssetMargins>,
+    /// `AssetTerrain` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_terrain: Read<'s, AssetTerrain>,
     /// `Position<f32>` components.
     #[derivative(Debug = "ignore")]
     pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Handle<Body>` components.
+    #[derivative(Debug = "ignore")]
+    pub body_handles: ReadStorage<'s, Handle<Body>>,
+    /// `Body` assets.
+    #[derivative(Debug = "ignore")]
+    pub body_assets: Read<'s, AssetStorage<Body>>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: ReadStorage<'s, SpriteRender>,
+    /// `SpriteSheet` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_sheet_assets: Read<'s, AssetStorage<SpriteSheet>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
     /// `Grounding` components.
     #[derivative(Debug = "ignore")]
     pub groundings: WriteStorage<'s, Grounding>,
 }
 
+impl ObjectGroundingSystem {
+    /// Returns whether the bounds rest on top of the terrain volume.
+    fn stands_on(bounds: &VolumeBounds, terrain_bounds: &VolumeBounds) -> bool {
+        (bounds.min[1] - terrain_bounds.max[1]).abs() <= TERRAIN_GROUND_TOLERANCE
+            && bounds.overlaps_on(terrain_bounds, 0)
+            && bounds.overlaps_on(terrain_bounds, 2)
+    }
+}
+
 impl<'s> System<'s> for ObjectGroundingSystem {
     type SystemData = ObjectGroundingSystemData<'s>;
 
@@ -38,28 +77,62 @@ impl<'s> System<'s> for ObjectGroundingSystem {
         ObjectGroundingSystemData {
             map_selection,
             asset_margins,
+            asset_terrain,
             positions,
+            body_handles,
+            body_assets,
+            sprite_renders,
+            sprite_sheet_assets,
+            mirroreds,
             mut groundings,
         }: Self::SystemData,
     ) {
+        let map_asset_id = map_selection
+            .asset_id()
+            .expect("Expected `MapSelection` asset ID to exist.");
         let map_margins = asset_margins
-            .get(
-                map_selection
-                    .asset_id()
-                    .expect("Expected `MapSelection` asset ID to exist."),
-            )
+            .get(map_asset_id)
             .expect("Expected `Margins` to be loaded.");
+        let terrain = asset_terrain.get(map_asset_id);
 
-        (&positions, &mut groundings)
+        (
+            &positions,
+            body_handles.maybe(),
+            sprite_renders.maybe(),
+            mirroreds.maybe(),
+            &mut groundings,
+        )
             .join()
-            .for_each(|(position, grounding)| {
-                if position[1] > map_margins.bottom {
-                    *grounding = Grounding::Airborne;
-                } else if position[1] < map_margins.bottom {
-                    *grounding = Grounding::Underground;
-                } else {
-                    *grounding = Grounding::OnGround;
-                }
-            });
+            .for_each(
+                |(position, body_handle, sprite_render, mirrored, grounding)| {
+                    if position[1] > map_margins.bottom {
+                        let on_terrain = terrain
+                            .map(|terrain| {
+                                let bounds = ObjectBodyBounds::calculate(
+                                    &body_assets,
+                                    &sprite_sheet_assets,
+                                    position,
+                                    body_handle,
+                                    sprite_render,
+                                    mirrored,
+                                );
+                                terrain
+                                    .iter()
+                                    .any(|terrain_bounds| Self::stands_on(&bounds, terrain_bounds))
+                            })
+                            .unwrap_or(false);
+
+                        *grounding = if on_terrain {
+                            Grounding::OnGround
+                        } else {
+                            Grounding::Airborne
+                        };
+                    } else if position[1] < map_margins.bottom {
+                        *grounding = Grounding::Underground;
+                    } else {
+                        *grounding = Grounding::OnGround;
+                    }
+                },
+            );
     }
 }
@@ -0,0 +1,172 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{SpriteRender, SpriteSheet},
+    shred::{ResourceId, SystemData},
+};
+use collision_model::config::Body;
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{Position, Velocity};
+use map_model::{loaded::AssetTerrain, play::MapBounded};
+use map_selection_model::MapSelection;
+use mirrored_model::play::Mirrored;
+use shape_model::VolumeBounds;
+
+use crate::ObjectBodyBounds;
+
+/// Moves `MapBounded` objects out of the map's terrain.
+///
+/// Objects that fall onto terrain are placed on top of it, and objects that move into the side of
+/// terrain are stopped against it. Velocity towards the terrain is removed.
+#[derive(Debug, Default, new)]
+pub struct ObjectTerrainCollisionSystem;
+
+/// `ObjectTerrainCollisionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ObjectTerrainCollisionSystemData<'s> {
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetTerrain` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_terrain: Read<'s, AssetTerrain>,
+    /// `MapBounded` components.
+    #[derivative(Debug = "ignore")]
+    pub map_boundeds: ReadStorage<'s, MapBounded>,
+    /// `Handle<Body>` components.
+    #[derivative(Debug = "ignore")]
+    pub body_handles: ReadStorage<'s, Handle<Body>>,
+    /// `Body` assets.
+    #[derivative(Debug = "ignore")]
+    pub body_assets: Read<'s, AssetStorage<Body>>,
+    /// `SpriteRender` components.
+    #[derivative(Debug = "ignore")]
+    pub sprite_renders: ReadStorage<'s, SpriteRender>,
+    /// `SpriteSheet` assets.
+    #[derivative(Debug = "ignore")]
+    pub sprite_sheet_assets: Read<'s, AssetStorage<SpriteSheet>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl ObjectTerrainCollisionSystem {
+    /// Returns the axis and distance to move the object by so that it is outside the terrain.
+    ///
+    /// The object is moved back to the side of the terrain it came from, preferring the top and
+    /// bottom sides. If the object was already inside the terrain, it is moved the shortest
+    /// distance out of it.
+    ///
+    /// # Parameters
+    ///
+    /// * `bounds`: Bounds of the object.
+    /// * `bounds_previous`: Bounds of the object before it moved this tick.
+    /// * `terrain_bounds`: Bounds of the terrain volume.
+    fn resolution(
+        bounds: VolumeBounds,
+        bounds_previous: VolumeBounds,
+        terrain_bounds: VolumeBounds,
+    ) -> (usize, f32) {
+        [1, 0, 2]
+            .iter()
+            .copied()
+            .find_map(|axis| {
+                if bounds_previous.min[axis] >= terrain_bounds.max[axis] {
+                    Some((axis, terrain_bounds.max[axis] - bounds.min[axis]))
+                } else if bounds_previous.max[axis] <= terrain_bounds.min[axis] {
+                    Some((axis, terrain_bounds.min[axis] - bounds.max[axis]))
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| {
+                (0..3)
+                    .flat_map(|axis| {
+                        vec![
+                            (axis, terrain_bounds.max[axis] - bounds.min[axis]),
+                            (axis, terrain_bounds.min[axis] - bounds.max[axis]),
+                        ]
+                    })
+                    .min_by(|(_, distance_a), (_, distance_b)| {
+                        distance_a
+                            .abs()
+                            .partial_cmp(&distance_b.abs())
+                            .expect("Expected distance to be comparable.")
+                    })
+                    .expect("Expected resolution to exist.")
+            })
+    }
+}
+
+impl<'s> System<'s> for ObjectTerrainCollisionSystem {
+    type SystemData = ObjectTerrainCollisionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ObjectTerrainCollisionSystemData {
+            map_selection,
+            asset_terrain,
+            map_boundeds,
+            body_handles,
+            body_assets,
+            sprite_renders,
+            sprite_sheet_assets,
+            mirroreds,
+            mut positions,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        let terrain = map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_terrain.get(asset_id));
+        let terrain = match terrain {
+            Some(terrain) if !terrain.is_empty() => terrain,
+            _ => return,
+        };
+
+        (
+            &map_boundeds,
+            body_handles.maybe(),
+            sprite_renders.maybe(),
+            mirroreds.maybe(),
+            &mut positions,
+            &mut velocities,
+        )
+            .join()
+            .for_each(
+                |(_, body_handle, sprite_render, mirrored, position, velocity)| {
+                    terrain.iter().for_each(|terrain_bounds| {
+                        let bounds = ObjectBodyBounds::calculate(
+                            &body_assets,
+                            &sprite_sheet_assets,
+                            position,
+                            body_handle,
+                            sprite_render,
+                            mirrored,
+                        );
+                        if !bounds.intersects(terrain_bounds) {
+                            return;
+                        }
+
+                        let bounds_previous =
+                            bounds.translate([-velocity[0], -velocity[1], -velocity[2]]);
+                        let (axis, distance) =
+                            Self::resolution(bounds, bounds_previous, *terrain_bounds);
+
+                        position[axis] += distance;
+                        if velocity[axis] * distance < 0. {
+                            velocity[axis] = 0.;
+                        }
+                    });
+                },
+            );
+    }
+}
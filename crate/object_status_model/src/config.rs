@@ -1,5 +1,9 @@
 //! Contains the types that represent the configuration on disk.
 
-pub use self::stun_points::{StunPoints, STUN_POINTS_DEFAULT};
+pub use self::{
+    guard_points::{GuardPoints, GUARD_POINTS_DEFAULT},
+    stun_points::{StunPoints, STUN_POINTS_DEFAULT},
+};
 
+mod guard_points;
 mod stun_points;
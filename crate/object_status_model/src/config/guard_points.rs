@@ -0,0 +1,17 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Default guard points of an object.
+pub const GUARD_POINTS_DEFAULT: u32 = 100;
+
+/// Guard points of an object.
+///
+/// Blocked hits reduce guard points by the hit's stun. When they run out, the guard is broken.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[derivative(Default)]
+#[storage(VecStorage)]
+pub struct GuardPoints(#[derivative(Default(value = "GUARD_POINTS_DEFAULT"))] pub u32);
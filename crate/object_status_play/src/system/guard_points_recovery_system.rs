@@ -0,0 +1,21 @@
+use amethyst::ecs::{Join, System, WriteStorage};
+use derive_new::new;
+use object_status_model::config::{GuardPoints, GUARD_POINTS_DEFAULT};
+
+/// Increments `GuardPoints` up to the default.
+#[derive(Debug, Default, new)]
+pub struct GuardPointsRecoverySystem;
+
+type GuardPointsRecoverySystemData<'s> = WriteStorage<'s, GuardPoints>;
+
+impl<'s> System<'s> for GuardPointsRecoverySystem {
+    type SystemData = GuardPointsRecoverySystemData<'s>;
+
+    fn run(&mut self, mut guard_pointses: Self::SystemData) {
+        (&mut guard_pointses).join().for_each(|guard_points| {
+            if *guard_points < GUARD_POINTS_DEFAULT {
+                *guard_points += 1;
+            }
+        });
+    } // kcov-ignore
+}
@@ -18,6 +18,9 @@ pub enum ObjectType {
     /// Energy / aura / spark effects.
     #[evt(skip)]
     Energy,
+    /// Items that may be picked up and used by characters.
+    #[evt(skip)]
+    Weapon,
     /// Used in tests.
     #[evt(skip)]
     TestObject,
@@ -0,0 +1,6 @@
+//! Contains data types used for replay files.
+
+pub use self::{replay::Replay, replay_frame::ReplayFrame};
+
+mod replay;
+mod replay_frame;
@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ReplayFrame;
+
+/// Recorded match, used to reproduce game play.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct Replay {
+    /// Version of the replay format, see `Replay::VERSION`.
+    pub version: u32,
+    /// Seed for random number generation during game play.
+    ///
+    /// Game play does not use random numbers yet -- random selections resolve to the first
+    /// asset -- so this is always `0`.
+    pub rng_seed: u64,
+    /// Character selected by each controller.
+    pub character_selections: BTreeMap<ControllerId, AssetSlug>,
+    /// Selected map.
+    pub map_selection: AssetSlug,
+    /// Input for each frame that has input, in frame order.
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl Replay {
+    /// Version of the replay format written by this application.
+    ///
+    /// This should be incremented whenever the format, or the game play logic, changes such that
+    /// existing replays no longer reproduce the same match.
+    pub const VERSION: u32 = 1;
+}
@@ -0,0 +1,19 @@
+use derive_new::new;
+use game_input_model::{config::ControlInputEventArgs, GameInputEvent};
+use network_input_model::play::FrameNumber;
+use serde::{Deserialize, Serialize};
+
+/// Input events for a frame of game play.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayFrame {
+    /// Frame of game play that the input is applied on, starting from `0`.
+    pub frame_number: FrameNumber,
+    /// Game input events for the frame.
+    pub game_input_events: Vec<GameInputEvent>,
+    /// Control input events for the frame that were not sent from game input, such as stdin input.
+    ///
+    /// These are recorded as their stdin arguments, as the entities they apply to differ between
+    /// matches.
+    pub control_input_events: Vec<ControlInputEventArgs>,
+}
@@ -0,0 +1,5 @@
+//! Contains data types used at runtime.
+
+pub use self::replay_mode::ReplayMode;
+
+mod replay_mode;
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use derivative::Derivative;
+
+use crate::config::Replay;
+
+/// Whether matches are being recorded or played back.
+#[derive(Clone, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub enum ReplayMode {
+    /// Matches are not recorded.
+    #[derivative(Default)]
+    None,
+    /// Each match is recorded to the replay file at the given path.
+    Record(PathBuf),
+    /// The replay is played back, replacing device input.
+    Playback(Replay),
+}
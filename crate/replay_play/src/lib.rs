@@ -0,0 +1,14 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to record and play back matches.
+//!
+//! Game input is recorded per frame of game play, along with the character and map selections.
+//! During playback, the selection states are driven by `AssetSelectionEvent`s, and the recorded
+//! game input replaces device input.
+
+pub use crate::system::{
+    ReplayPlaybackSystem, ReplayPlaybackSystemDesc, ReplayRecordSystem, ReplayRecordSystemDesc,
+};
+
+mod replay_state_id_utils;
+mod system;
@@ -0,0 +1,24 @@
+use state_registry::{StateId, StateIdUpdateEvent};
+
+/// Functions to query `StateIdUpdateEvent`s during replay recording and playback.
+#[derive(Debug)]
+pub(crate) struct ReplayStateIdUtils;
+
+impl ReplayStateIdUtils {
+    /// Returns whether the event is for `ControlSettingsState` being pushed on top of, or popped
+    /// back to the `GamePlayState`.
+    ///
+    /// Game play does not end when control settings are opened from the pause menu, so these
+    /// events should not begin or end a replay.
+    ///
+    /// # Parameters
+    ///
+    /// * `ev`: The `StateIdUpdateEvent` to check.
+    pub(crate) fn is_control_settings_transition(ev: &StateIdUpdateEvent) -> bool {
+        match (ev.state_id_prev, ev.state_id) {
+            (Some(StateId::GamePlay), StateId::ControlSettings)
+            | (Some(StateId::ControlSettings), StateId::GamePlay) => true,
+            _ => false,
+        }
+    }
+}
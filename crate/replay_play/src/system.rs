@@ -0,0 +1,7 @@
+pub use self::{
+    replay_playback_system::{ReplayPlaybackSystem, ReplayPlaybackSystemDesc},
+    replay_record_system::{ReplayRecordSystem, ReplayRecordSystemDesc},
+};
+
+mod replay_playback_system;
+mod replay_record_system;
@@ -0,0 +1,314 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Entities, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::{
+    config::AssetSlug,
+    loaded::{AssetId, AssetIdMappings},
+};
+use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    play::{ControlInputEvent, InputControlled},
+    GameInputEvent,
+};
+use game_input_stdio::ControlInputEventStdinMapper;
+use game_mode_selection_model::{GameModeIndex, GameModeSelectionEvent};
+use game_play_model::GamePlayStatus;
+use log::{error, info};
+use map_selection_model::MapSelection;
+use menu_model::MenuEvent;
+use network_input_model::play::FrameNumber;
+use replay_model::{config::Replay, play::ReplayMode};
+use state_registry::{StateId, StateIdUpdateEvent};
+use stdio_spi::StdinMapper;
+
+use crate::replay_state_id_utils::ReplayStateIdUtils;
+
+/// Plays back a `Replay`, driving the selection states and sending the recorded game input.
+///
+/// Selections are sent as `AssetSelectionEvent`s, and confirmed on a later tick once the selection
+/// systems have processed them. The playback ends when game play ends.
+///
+/// Recorded input is sent once game play reaches the `FrameNumber` it was recorded on. This must
+/// run before `GameInputEvent`s are mapped to `ControlInputEvent`s, before `ControlInputEvent`s
+/// update `ControllerInput`s, and before game play steps the `FrameNumber`, so that recorded input
+/// is applied on the frame it was recorded.
+///
+/// Recorded `ControlInputEvent`s are sent to the entity controlled by the recorded controller, the
+/// same way as stdin input.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayPlaybackSystemDesc))]
+pub struct ReplayPlaybackSystem {
+    /// Reader ID for the `StateIdUpdateEvent` channel.
+    #[system_desc(event_channel_reader)]
+    state_id_update_event_rid: ReaderId<StateIdUpdateEvent>,
+    /// Whether the selections for the current state have been sent.
+    #[new(default)]
+    #[system_desc(skip)]
+    selections_sent: bool,
+    /// Whether the selections for the current state have been confirmed.
+    #[new(default)]
+    #[system_desc(skip)]
+    selections_confirmed: bool,
+    /// Index of the next `ReplayFrame` to send.
+    #[new(default)]
+    #[system_desc(skip)]
+    frame_index: usize,
+    /// Whether playback has completed.
+    #[new(default)]
+    #[system_desc(skip)]
+    complete: bool,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayPlaybackSystemData<'s> {
+    /// `StateIdUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub state_id_update_ec: Read<'s, EventChannel<StateIdUpdateEvent>>,
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `FrameNumber` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_number: Read<'s, FrameNumber>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `GameModeSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_mode_selection_ec: Write<'s, EventChannel<GameModeSelectionEvent>>,
+    /// `AssetSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_ec: Write<'s, EventChannel<AssetSelectionEvent>>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+impl ReplayPlaybackSystem {
+    fn asset_id_find(
+        asset_id_mappings: &AssetIdMappings,
+        asset_slug: &AssetSlug,
+    ) -> Option<AssetId> {
+        let asset_id = asset_id_mappings.id(asset_slug).copied();
+        if asset_id.is_none() {
+            error!("Replay asset `{}` is not loaded.", asset_slug);
+        }
+        asset_id
+    }
+
+    fn character_selection_play(
+        &mut self,
+        replay: &Replay,
+        asset_id_mappings: &AssetIdMappings,
+        character_selections: &CharacterSelections,
+        asset_selection_ec: &mut EventChannel<AssetSelectionEvent>,
+    ) {
+        if !self.selections_sent {
+            replay
+                .character_selections
+                .iter()
+                .for_each(|(controller_id, asset_slug)| {
+                    if let Some(asset_id) = Self::asset_id_find(asset_id_mappings, asset_slug) {
+                        asset_selection_ec.iter_write(vec![
+                            AssetSelectionEvent::Join {
+                                entity: None,
+                                controller_id: *controller_id,
+                            },
+                            AssetSelectionEvent::Select {
+                                entity: None,
+                                controller_id: *controller_id,
+                                asset_selection: AssetSelection::Id(asset_id),
+                            },
+                        ]);
+                    }
+                });
+            self.selections_sent = true;
+        } else if !self.selections_confirmed {
+            let selections_applied =
+                replay
+                    .character_selections
+                    .iter()
+                    .all(|(controller_id, asset_slug)| {
+                        character_selections
+                            .selections
+                            .get(controller_id)
+                            .and_then(|asset_id| asset_id_mappings.slug(*asset_id))
+                            == Some(asset_slug)
+                    });
+            if selections_applied {
+                asset_selection_ec.single_write(AssetSelectionEvent::Confirm);
+                self.selections_confirmed = true;
+            }
+        }
+    }
+
+    fn map_selection_play(
+        &mut self,
+        replay: &Replay,
+        asset_id_mappings: &AssetIdMappings,
+        map_selection: MapSelection,
+        asset_selection_ec: &mut EventChannel<AssetSelectionEvent>,
+    ) {
+        if !self.selections_sent {
+            if let Some(asset_id) = Self::asset_id_find(asset_id_mappings, &replay.map_selection) {
+                asset_selection_ec.single_write(AssetSelectionEvent::Select {
+                    entity: None,
+                    controller_id: 0,
+                    asset_selection: AssetSelection::Id(asset_id),
+                });
+            }
+            self.selections_sent = true;
+        } else if !self.selections_confirmed {
+            let selection_applied = map_selection
+                .asset_id()
+                .and_then(|asset_id| asset_id_mappings.slug(asset_id))
+                == Some(&replay.map_selection);
+            if selection_applied {
+                asset_selection_ec.single_write(AssetSelectionEvent::Confirm);
+                self.selections_confirmed = true;
+            }
+        }
+    }
+
+    fn game_play_play(
+        &mut self,
+        replay: &Replay,
+        frame_number: FrameNumber,
+        control_input_mapper_data: &(Entities<'_>, ReadStorage<'_, InputControlled>),
+        game_input_ec: &mut EventChannel<GameInputEvent>,
+        control_input_ec: &mut EventChannel<ControlInputEvent>,
+    ) {
+        while let Some(replay_frame) = replay.frames.get(self.frame_index) {
+            if replay_frame.frame_number > frame_number {
+                break;
+            }
+
+            game_input_ec.iter_write(replay_frame.game_input_events.iter().copied());
+            replay_frame
+                .control_input_events
+                .iter()
+                .for_each(|control_input_event_args| {
+                    match ControlInputEventStdinMapper::map(
+                        control_input_mapper_data,
+                        *control_input_event_args,
+                    ) {
+                        Ok(control_input_event) => {
+                            control_input_ec.single_write(control_input_event)
+                        }
+                        Err(e) => error!("Failed to play back control input. Error: `{}`.", e),
+                    }
+                });
+            self.frame_index += 1;
+        }
+    }
+}
+
+impl<'s> System<'s> for ReplayPlaybackSystem {
+    type SystemData = ReplayPlaybackSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayPlaybackSystemData {
+            state_id_update_ec,
+            replay_mode,
+            state_id,
+            game_play_status,
+            frame_number,
+            asset_id_mappings,
+            character_selections,
+            map_selection,
+            entities,
+            input_controlleds,
+            mut game_mode_selection_ec,
+            mut asset_selection_ec,
+            mut game_input_ec,
+            mut control_input_ec,
+        }: Self::SystemData,
+    ) {
+        let state_id_update_events = state_id_update_ec.read(&mut self.state_id_update_event_rid);
+
+        let replay = if let ReplayMode::Playback(replay) = &*replay_mode {
+            replay
+        } else {
+            return;
+        };
+
+        state_id_update_events
+            .filter(|ev| !ReplayStateIdUtils::is_control_settings_transition(ev))
+            .for_each(|ev| {
+                self.selections_sent = false;
+                self.selections_confirmed = false;
+
+                if ev.state_id_prev == Some(StateId::GamePlay) {
+                    info!("Replay playback complete.");
+                    self.complete = true;
+                }
+                if ev.state_id == StateId::GamePlay {
+                    self.frame_index = 0;
+                }
+            });
+
+        if self.complete {
+            return;
+        }
+
+        match *state_id {
+            StateId::GameModeSelection => {
+                if !self.selections_sent {
+                    game_mode_selection_ec
+                        .single_write(MenuEvent::Select(GameModeIndex::StartGame));
+                    self.selections_sent = true;
+                }
+            }
+            StateId::CharacterSelection => self.character_selection_play(
+                replay,
+                &asset_id_mappings,
+                &character_selections,
+                &mut asset_selection_ec,
+            ),
+            StateId::MapSelection => self.map_selection_play(
+                replay,
+                &asset_id_mappings,
+                *map_selection,
+                &mut asset_selection_ec,
+            ),
+            StateId::GamePlay if *game_play_status != GamePlayStatus::Paused => self
+                .game_play_play(
+                    replay,
+                    *frame_number,
+                    &(entities, input_controlleds),
+                    &mut game_input_ec,
+                    &mut control_input_ec,
+                ),
+            _ => {}
+        }
+    }
+}
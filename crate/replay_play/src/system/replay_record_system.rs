@@ -0,0 +1,286 @@
+use std::{fs, path::Path};
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Entities, Read, ReadStorage, System, World},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetIdMappings;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input::GameInputToControlInputSystem;
+use game_input_model::{
+    config::ControlInputEventArgs,
+    play::{ControlInputEvent, InputControlled, SharedInputControlled},
+    GameInputEvent,
+};
+use log::{error, info};
+use map_selection_model::MapSelection;
+use network_input_model::play::FrameNumber;
+use replay_model::{
+    config::{Replay, ReplayFrame},
+    play::ReplayMode,
+};
+use state_registry::{StateId, StateIdUpdateEvent};
+
+use crate::replay_state_id_utils::ReplayStateIdUtils;
+
+/// Records `GameInputEvent`s and `ControlInputEvent`s for each frame of game play, and writes the
+/// replay when game play ends.
+///
+/// `ControlInputEvent`s that are sent from `GameInputEvent`s are not recorded, as they are sent
+/// again when the `GameInputEvent`s are played back. Events for computer controlled characters are
+/// not recorded either, as those characters decide their input during playback.
+///
+/// Input is recorded on the game play `FrameNumber` that it is applied on. This must run after
+/// `GameInputEvent`s and stdin `ControlInputEvent`s for the tick have been sent, and before game
+/// play steps the `FrameNumber`.
+/// Input sent while game play is paused is applied when game play next steps, so it is recorded on
+/// the same frame.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayRecordSystemDesc))]
+pub struct ReplayRecordSystem {
+    /// Reader ID for the `StateIdUpdateEvent` channel.
+    #[system_desc(event_channel_reader)]
+    state_id_update_event_rid: ReaderId<StateIdUpdateEvent>,
+    /// Reader ID for the `GameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_input_event_rid: ReaderId<GameInputEvent>,
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    control_input_event_rid: ReaderId<ControlInputEvent>,
+    /// Replay of the current match.
+    #[new(default)]
+    #[system_desc(skip)]
+    replay: Option<Replay>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayRecordSystemData<'s> {
+    /// `StateIdUpdateEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub state_id_update_ec: Read<'s, EventChannel<StateIdUpdateEvent>>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Read<'s, EventChannel<GameInputEvent>>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `SharedInputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub shared_input_controlleds: ReadStorage<'s, SharedInputControlled>,
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `FrameNumber` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_number: Read<'s, FrameNumber>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+}
+
+impl ReplayRecordSystem {
+    /// Returns a new `Replay` with the current character and map selections.
+    fn replay_begin(
+        asset_id_mappings: &AssetIdMappings,
+        character_selections: &CharacterSelections,
+        map_selection: MapSelection,
+    ) -> Option<Replay> {
+        let character_selections = character_selections
+            .selections
+            .iter()
+            .map(|(controller_id, asset_id)| {
+                asset_id_mappings
+                    .slug(*asset_id)
+                    .cloned()
+                    .map(|asset_slug| (*controller_id, asset_slug))
+            })
+            .collect::<Option<_>>();
+        let map_selection = map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_id_mappings.slug(asset_id))
+            .cloned();
+
+        if let (Some(character_selections), Some(map_selection)) =
+            (character_selections, map_selection)
+        {
+            Some(Replay::new(
+                Replay::VERSION,
+                0,
+                character_selections,
+                map_selection,
+                Vec::new(),
+            ))
+        } else {
+            error!("Failed to look up selected asset slugs, match will not be recorded.");
+            None
+        }
+    }
+
+    /// Returns the `ControlInputEvent`s for input controlled entities that were not sent from the
+    /// `GameInputEvent`s.
+    fn control_input_events_filter(
+        entities: &Entities<'_>,
+        input_controlleds: &ReadStorage<'_, InputControlled>,
+        shared_input_controlleds: &ReadStorage<'_, SharedInputControlled>,
+        game_input_events: &[GameInputEvent],
+        control_input_events: impl Iterator<Item = ControlInputEvent>,
+    ) -> Vec<ControlInputEventArgs> {
+        let mut control_input_events_from_game_input = Vec::new();
+        game_input_events.iter().for_each(|game_input_event| {
+            GameInputToControlInputSystem::control_input_events(
+                entities,
+                input_controlleds,
+                shared_input_controlleds,
+                *game_input_event,
+                &mut control_input_events_from_game_input,
+            );
+        });
+
+        control_input_events
+            .filter(|control_input_event| {
+                let entity = match control_input_event {
+                    ControlInputEvent::AxisMoved(axis_move_event_data) => {
+                        axis_move_event_data.entity
+                    }
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                    | ControlInputEvent::ControlActionRelease(control_action_event_data) => {
+                        control_action_event_data.entity
+                    }
+                };
+                input_controlleds.contains(entity)
+            })
+            .filter(|control_input_event| {
+                let position = control_input_events_from_game_input.iter().position(
+                    |control_input_event_from_game_input| {
+                        control_input_event_from_game_input == control_input_event
+                    },
+                );
+                if let Some(position) = position {
+                    control_input_events_from_game_input.remove(position);
+                    false
+                } else {
+                    true
+                }
+            })
+            .map(ControlInputEventArgs::from)
+            .collect::<Vec<ControlInputEventArgs>>()
+    }
+
+    fn replay_write(replay_path: &Path, replay: &Replay) {
+        let result = serde_yaml::to_string(replay)
+            .map_err(|e| e.to_string())
+            .and_then(|replay_yaml| fs::write(replay_path, replay_yaml).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(()) => info!("Replay written to: `{}`.", replay_path.display()),
+            Err(e) => error!(
+                "Failed to write replay to `{}`. Error: `{}`.",
+                replay_path.display(),
+                e
+            ),
+        }
+    }
+}
+
+impl<'s> System<'s> for ReplayRecordSystem {
+    type SystemData = ReplayRecordSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayRecordSystemData {
+            state_id_update_ec,
+            game_input_ec,
+            control_input_ec,
+            entities,
+            input_controlleds,
+            shared_input_controlleds,
+            replay_mode,
+            state_id,
+            frame_number,
+            asset_id_mappings,
+            character_selections,
+            map_selection,
+        }: Self::SystemData,
+    ) {
+        let state_id_update_events = state_id_update_ec.read(&mut self.state_id_update_event_rid);
+        let game_input_events = game_input_ec.read(&mut self.game_input_event_rid);
+        let control_input_events = control_input_ec.read(&mut self.control_input_event_rid);
+
+        let replay_path = if let ReplayMode::Record(replay_path) = &*replay_mode {
+            replay_path
+        } else {
+            return;
+        };
+
+        state_id_update_events
+            .filter(|ev| !ReplayStateIdUtils::is_control_settings_transition(ev))
+            .for_each(|ev| {
+                if ev.state_id_prev == Some(StateId::GamePlay) {
+                    if let Some(replay) = self.replay.take() {
+                        Self::replay_write(replay_path, &replay);
+                    }
+                }
+                if ev.state_id == StateId::GamePlay {
+                    self.replay = Self::replay_begin(
+                        &asset_id_mappings,
+                        &character_selections,
+                        *map_selection,
+                    );
+                }
+            });
+
+        if *state_id != StateId::GamePlay {
+            return;
+        }
+
+        if let Some(replay) = self.replay.as_mut() {
+            let mut game_input_events = game_input_events.copied().collect::<Vec<GameInputEvent>>();
+            let mut control_input_events = Self::control_input_events_filter(
+                &entities,
+                &input_controlleds,
+                &shared_input_controlleds,
+                &game_input_events,
+                control_input_events.copied(),
+            );
+            if game_input_events.is_empty() && control_input_events.is_empty() {
+                return;
+            }
+
+            match replay.frames.last_mut() {
+                Some(replay_frame) if replay_frame.frame_number == *frame_number => {
+                    replay_frame
+                        .game_input_events
+                        .append(&mut game_input_events);
+                    replay_frame
+                        .control_input_events
+                        .append(&mut control_input_events);
+                }
+                _ => replay.frames.push(ReplayFrame::new(
+                    *frame_number,
+                    game_input_events,
+                    control_input_events,
+                )),
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to roll back and re-simulate game play.
+
+pub mod play;
@@ -0,0 +1,11 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    component_snapshot::ComponentSnapshot, game_play_snapshot::GamePlaySnapshot,
+    game_play_snapshots::GamePlaySnapshots, rollback_predictions::RollbackPredictions,
+};
+
+mod component_snapshot;
+mod game_play_snapshot;
+mod game_play_snapshots;
+mod rollback_predictions;
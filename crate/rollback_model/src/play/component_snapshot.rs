@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Values of a component type for entities at a point in time.
+#[derive(Clone, Debug, Deref, DerefMut, new)]
+pub struct ComponentSnapshot<C>(pub Vec<(Entity, C)>);
+
+impl<C> Default for ComponentSnapshot<C> {
+    fn default() -> Self {
+        ComponentSnapshot(Vec::new())
+    }
+}
@@ -0,0 +1,117 @@
+use ai_model::play::AiReactionClock;
+use amethyst::{assets::Handle, core::Transform, ecs::Entity};
+use charge_model::{
+    config::ChargePoints,
+    play::{ChargeStatus, ChargeTrackerClock},
+};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::Interactions,
+    play::{HeldBy, HitRepeatTrackers, Holding},
+};
+use game_input_model::play::{ControllerInput, InputHistory};
+use game_model::play::{GameEntityId, GameEntityIdAllocator};
+use game_play_model::{
+    play::{Combo, RoundClock, RoundStartDelayClock, Stocks},
+    GamePlayStatus,
+};
+use game_stats_model::play::{GameStats, WinStatus};
+use kinematic_model::config::{Position, Velocity};
+use map_model::play::OutOfBoundsDeleteClock;
+use mirrored_model::play::Mirrored;
+use network_input_model::play::FrameNumber;
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
+use object_status_model::config::{GuardPoints, StunPoints};
+use sequence_model::{
+    loaded::SequenceId,
+    play::{FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus},
+};
+
+use crate::play::ComponentSnapshot;
+
+/// Simulation relevant values of game play entities and resources at the start of a frame.
+#[derive(Clone, Debug, Default)]
+pub struct GamePlaySnapshot {
+    /// Game play entities that exist at the time of the snapshot.
+    pub entities: Vec<Entity>,
+    /// `Transform` components.
+    pub transforms: ComponentSnapshot<Transform>,
+    /// `Position<f32>` components.
+    pub positions: ComponentSnapshot<Position<f32>>,
+    /// `Velocity<f32>` components.
+    pub velocities: ComponentSnapshot<Velocity<f32>>,
+    /// `Mirrored` components.
+    pub mirroreds: ComponentSnapshot<Mirrored>,
+    /// `Grounding` components.
+    pub groundings: ComponentSnapshot<Grounding>,
+    /// `SequenceId` components.
+    pub sequence_ids: ComponentSnapshot<SequenceId>,
+    /// `SequenceStatus` components.
+    pub sequence_statuses: ComponentSnapshot<SequenceStatus>,
+    /// `FrameIndexClock` components.
+    pub frame_index_clocks: ComponentSnapshot<FrameIndexClock>,
+    /// `FrameWaitClock` components.
+    pub frame_wait_clocks: ComponentSnapshot<FrameWaitClock>,
+    /// `FrameFreezeClock` components.
+    pub frame_freeze_clocks: ComponentSnapshot<FrameFreezeClock>,
+    /// `HealthPoints` components.
+    pub health_pointses: ComponentSnapshot<HealthPoints>,
+    /// `SkillPoints` components.
+    pub skill_pointses: ComponentSnapshot<SkillPoints>,
+    /// `StunPoints` components.
+    pub stun_pointses: ComponentSnapshot<StunPoints>,
+    /// `GuardPoints` components.
+    pub guard_pointses: ComponentSnapshot<GuardPoints>,
+    /// `ChargePoints` components.
+    pub charge_pointses: ComponentSnapshot<ChargePoints>,
+    /// `ChargeStatus` components.
+    pub charge_statuses: ComponentSnapshot<ChargeStatus>,
+    /// `ChargeTrackerClock` components.
+    pub charge_tracker_clocks: ComponentSnapshot<ChargeTrackerClock>,
+    /// `HitRepeatTrackers` components.
+    pub hit_repeat_trackerses: ComponentSnapshot<HitRepeatTrackers>,
+    /// `Combo` components.
+    pub combos: ComponentSnapshot<Combo>,
+    /// `Handle<Interactions>` components.
+    ///
+    /// Held weapons replace their holder's `Interactions` when the holder attacks.
+    pub interactions_handles: ComponentSnapshot<Handle<Interactions>>,
+    /// `Holding` components.
+    pub holdings: ComponentSnapshot<Holding>,
+    /// `HeldBy` components.
+    pub held_bys: ComponentSnapshot<HeldBy>,
+    /// `TargetObject` components.
+    pub target_objects: ComponentSnapshot<TargetObject>,
+    /// `ChaseModeStick` components.
+    pub chase_mode_sticks: ComponentSnapshot<ChaseModeStick>,
+    /// `ControllerInput` components.
+    pub controller_inputs: ComponentSnapshot<ControllerInput>,
+    /// Values of `Last<ControllerInput>` components.
+    pub last_controller_inputs: ComponentSnapshot<ControllerInput>,
+    /// `InputHistory` components.
+    pub input_histories: ComponentSnapshot<InputHistory>,
+    /// `AiReactionClock` components.
+    pub ai_reaction_clocks: ComponentSnapshot<AiReactionClock>,
+    /// `OutOfBoundsDeleteClock` components.
+    pub out_of_bounds_delete_clocks: ComponentSnapshot<OutOfBoundsDeleteClock>,
+    /// `Stocks` components.
+    pub stockses: ComponentSnapshot<Stocks>,
+    /// `GameEntityId` components.
+    pub game_entity_ids: ComponentSnapshot<GameEntityId>,
+    /// `GamePlayStatus` resource.
+    pub game_play_status: GamePlayStatus,
+    /// `RoundClock` resource.
+    pub round_clock: RoundClock,
+    /// `RoundStartDelayClock` resource.
+    pub round_start_delay_clock: RoundStartDelayClock,
+    /// `WinStatus` resource.
+    pub win_status: WinStatus,
+    /// `GameStats` resource.
+    pub game_stats: GameStats,
+    /// `FrameNumber` resource, the number of frames game play has stepped.
+    pub frame_number: FrameNumber,
+    /// `GameEntityIdAllocator` resource.
+    ///
+    /// Objects spawned when re-simulating frames are allocated the same IDs they were originally.
+    pub game_entity_id_allocator: GameEntityIdAllocator,
+}
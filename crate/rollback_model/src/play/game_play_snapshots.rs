@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+
+use derive_new::new;
+use network_input_model::play::FrameNumber;
+
+use crate::play::GamePlaySnapshot;
+
+/// `GamePlaySnapshot`s for frames that may be rolled back to.
+#[derive(Clone, Debug, Default, new)]
+pub struct GamePlaySnapshots {
+    /// Snapshot of game play at the start of each frame.
+    #[new(default)]
+    snapshots: BTreeMap<FrameNumber, GamePlaySnapshot>,
+}
+
+impl GamePlaySnapshots {
+    /// Stores the snapshot taken at the start of a frame, replacing any existing snapshot.
+    pub fn insert(&mut self, frame_number: FrameNumber, game_play_snapshot: GamePlaySnapshot) {
+        self.snapshots.insert(frame_number, game_play_snapshot);
+    }
+
+    /// Returns the snapshot taken at the start of a frame.
+    pub fn get(&self, frame_number: FrameNumber) -> Option<&GamePlaySnapshot> {
+        self.snapshots.get(&frame_number)
+    }
+
+    /// Discards snapshots for frames before the given frame.
+    pub fn discard_before(&mut self, frame_number: FrameNumber) {
+        self.snapshots = self.snapshots.split_off(&frame_number);
+    }
+
+    /// Returns the number of stored snapshots.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns whether there are no stored snapshots.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use derive_new::new;
+use network_input_model::play::{FrameNumber, NetworkInputBuffer};
+use network_session_model::play::SessionDeviceId;
+
+/// Tracks which devices' input was predicted for each frame.
+///
+/// Input is predicted to have no new `GameInputEvent`s, which means each device's
+/// `ControllerInput` is held from the previous frame.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct RollbackPredictions {
+    /// Devices whose input was predicted, by frame.
+    #[new(default)]
+    predictions: BTreeMap<FrameNumber, BTreeSet<SessionDeviceId>>,
+}
+
+impl RollbackPredictions {
+    /// Records that input was predicted for the given devices in a frame.
+    pub fn insert<I>(&mut self, frame_number: FrameNumber, session_device_ids: I)
+    where
+        I: IntoIterator<Item = SessionDeviceId>,
+    {
+        let mut session_device_ids = session_device_ids.into_iter().peekable();
+        if session_device_ids.peek().is_some() {
+            self.predictions
+                .entry(frame_number)
+                .or_insert_with(BTreeSet::new)
+                .extend(session_device_ids);
+        }
+    }
+
+    /// Returns whether input was predicted for any device in the frame.
+    pub fn contains(&self, frame_number: FrameNumber) -> bool {
+        self.predictions.contains_key(&frame_number)
+    }
+
+    /// Checks predictions against received input, and returns the earliest mispredicted frame.
+    ///
+    /// Predictions that have been confirmed by received input are removed.
+    pub fn mispredicted_frame(
+        &mut self,
+        network_input_buffer: &NetworkInputBuffer,
+    ) -> Option<FrameNumber> {
+        let mut mispredicted_frame = None;
+
+        self.predictions
+            .iter_mut()
+            .for_each(|(frame_number, session_device_ids)| {
+                session_device_ids.retain(|session_device_id| {
+                    match network_input_buffer.device_events(*frame_number, *session_device_id) {
+                        Some(game_input_events) => {
+                            if !game_input_events.is_empty() && mispredicted_frame.is_none() {
+                                mispredicted_frame = Some(*frame_number);
+                            }
+                            false
+                        }
+                        None => true,
+                    }
+                });
+            });
+        let confirmed_frames = self
+            .predictions
+            .iter()
+            .filter_map(|(frame_number, session_device_ids)| {
+                if session_device_ids.is_empty() {
+                    Some(*frame_number)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<FrameNumber>>();
+        confirmed_frames.iter().for_each(|frame_number| {
+            self.predictions.remove(frame_number);
+        });
+
+        mispredicted_frame
+    }
+
+    /// Discards predictions for frames from the given frame onwards.
+    pub fn discard_from(&mut self, frame_number: FrameNumber) {
+        self.predictions.split_off(&frame_number);
+    }
+
+    /// Discards predictions for frames before the given frame.
+    pub fn discard_before(&mut self, frame_number: FrameNumber) {
+        self.predictions = self.predictions.split_off(&frame_number);
+    }
+}
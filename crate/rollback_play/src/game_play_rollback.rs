@@ -0,0 +1,301 @@
+use std::{collections::BTreeMap, mem};
+
+use amethyst::{
+    audio::output::Output,
+    ecs::{Dispatcher, Entities, Entity, Read, ReadStorage, World, WorldExt, Write},
+    shrev::EventChannel,
+};
+use derive_new::new;
+use desync_model::play::EntityChecksum;
+use desync_play::{GameChecksumRecordResources, GameChecksumRecorder, GameChecksumResources};
+use game_input::GameInputToControlInputSystem;
+use game_input_model::play::{ControlInputEvent, InputControlled, SharedInputControlled};
+use game_model::play::GameEntityDeletions;
+use game_play_model::GamePlayStepStatus;
+use log::{debug, error};
+use network_input_model::play::{FrameNumber, LockstepFrameNumbers, NetworkInputBuffer};
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{SessionDevices, SessionStatus},
+};
+use rollback_model::play::{GamePlaySnapshots, RollbackPredictions};
+use state_registry::StateId;
+
+use crate::{GamePlaySnapshotResources, GamePlaySnapshotter};
+
+/// Steps game play during network play, re-simulating frames when input was mispredicted.
+///
+/// Game play for each frame is run through a separate dispatcher, so that game play systems may be
+/// run multiple times in a single tick.
+#[derive(Debug, new)]
+pub struct GamePlayRollback {
+    /// First frame that has not had input confirmed from every session device.
+    #[new(default)]
+    frame_confirmed: FrameNumber,
+    /// Snapshots of game play for unconfirmed frames.
+    #[new(default)]
+    game_play_snapshots: GamePlaySnapshots,
+    /// Devices whose input was predicted for unconfirmed frames.
+    #[new(default)]
+    rollback_predictions: RollbackPredictions,
+    /// Game play checksums for unconfirmed frames.
+    #[new(default)]
+    game_checksums_pending: BTreeMap<FrameNumber, Vec<EntityChecksum>>,
+    /// Entities deleted in unconfirmed frames, kept alive in case the frame is re-simulated.
+    #[new(default)]
+    entities_withheld: BTreeMap<FrameNumber, Vec<Entity>>,
+    /// Pre-allocated vector.
+    #[new(value = "Vec::with_capacity(64)")]
+    control_input_events: Vec<ControlInputEvent>,
+}
+
+impl GamePlayRollback {
+    /// Returns whether game play is stepped by rollback instead of by the main dispatcher.
+    pub fn is_active(world: &World) -> bool {
+        let (session_status, state_id, session_server_config) = world.system_data::<(
+            Read<'_, SessionStatus>,
+            Read<'_, StateId>,
+            Read<'_, SessionServerConfig>,
+        )>();
+
+        (*session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished)
+            && *state_id == StateId::GamePlay
+            && session_server_config.rollback_frames > 0
+    }
+
+    /// Steps game play for the next frame, first re-simulating any mispredicted frames.
+    ///
+    /// # Parameters
+    ///
+    /// * `world`: `World` to operate on.
+    /// * `dispatcher`: `Dispatcher` with the game play systems.
+    pub fn update(&mut self, world: &mut World, dispatcher: &mut Dispatcher<'_, '_>) {
+        if !Self::is_active(world) {
+            return;
+        }
+
+        let rollback_frames = world.read_resource::<SessionServerConfig>().rollback_frames;
+        let frame_step = world.read_resource::<LockstepFrameNumbers>().step;
+
+        let mispredicted_frame = {
+            let network_input_buffer = world.read_resource::<NetworkInputBuffer>();
+            self.rollback_predictions
+                .mispredicted_frame(&network_input_buffer)
+        };
+        let frame_begin = mispredicted_frame
+            .and_then(|frame_number| {
+                if let Some(game_play_snapshot) = self.game_play_snapshots.get(frame_number) {
+                    debug!("Rolling back to frame: {}.", frame_number);
+
+                    let entities_withheld = self
+                        .entities_withheld
+                        .split_off(&frame_number)
+                        .into_iter()
+                        .flat_map(|(_, entities)| entities)
+                        .collect::<Vec<Entity>>();
+                    GamePlaySnapshotter::restore(
+                        &mut world.system_data(),
+                        game_play_snapshot,
+                        &entities_withheld,
+                    );
+                    world.maintain();
+                    self.rollback_predictions.discard_from(frame_number);
+
+                    Some(frame_number)
+                } else {
+                    error!("No snapshot to roll back to for frame: {}.", frame_number);
+                    None
+                }
+            })
+            .unwrap_or(frame_step);
+
+        // Only run ahead of confirmed input by `rollback_frames`.
+        let frame_end = if frame_step < self.frame_confirmed + u64::from(rollback_frames) {
+            frame_step + 1
+        } else {
+            debug!(
+                "Waiting for network input for frame: {}.",
+                self.frame_confirmed
+            );
+            frame_step
+        };
+
+        (*frame_begin..*frame_end)
+            .map(FrameNumber::new)
+            .for_each(|frame_number| {
+                let is_resimulation = frame_number < frame_step;
+                self.frame_simulate(world, dispatcher, frame_number, is_resimulation);
+            });
+        world.write_resource::<LockstepFrameNumbers>().step = frame_end;
+
+        self.frame_confirmed_update(world, frame_end);
+
+        // Game play systems in the main dispatcher must not run.
+        world.insert(GamePlayStepStatus::Rollback);
+    }
+
+    fn frame_simulate(
+        &mut self,
+        world: &mut World,
+        dispatcher: &mut Dispatcher<'_, '_>,
+        frame_number: FrameNumber,
+        is_resimulation: bool,
+    ) {
+        let game_play_snapshot =
+            GamePlaySnapshotter::capture(&world.system_data::<GamePlaySnapshotResources<'_>>());
+        self.game_play_snapshots
+            .insert(frame_number, game_play_snapshot);
+
+        let checksum_interval = world
+            .read_resource::<SessionServerConfig>()
+            .checksum_interval;
+        if GameChecksumRecorder::is_checksum_frame(frame_number, checksum_interval) {
+            let entity_checksums =
+                GameChecksumRecorder::calculate(&world.system_data::<GameChecksumResources<'_>>());
+            self.game_checksums_pending
+                .insert(frame_number, entity_checksums);
+        }
+
+        let (game_input_events, session_device_ids_missing) = {
+            let (network_input_buffer, session_devices) =
+                world.system_data::<(Read<'_, NetworkInputBuffer>, Read<'_, SessionDevices>)>();
+
+            (
+                network_input_buffer.game_input_events(frame_number),
+                network_input_buffer.missing_devices(frame_number, &session_devices),
+            )
+        };
+        self.rollback_predictions
+            .insert(frame_number, session_device_ids_missing);
+
+        {
+            let (entities, input_controlleds, shared_input_controlleds, mut control_input_ec) =
+                world.system_data::<(
+                    Entities<'_>,
+                    ReadStorage<'_, InputControlled>,
+                    ReadStorage<'_, SharedInputControlled>,
+                    Write<'_, EventChannel<ControlInputEvent>>,
+                )>();
+
+            game_input_events.into_iter().for_each(|game_input_event| {
+                GameInputToControlInputSystem::control_input_events(
+                    &entities,
+                    &input_controlleds,
+                    &shared_input_controlleds,
+                    game_input_event,
+                    &mut self.control_input_events,
+                );
+            });
+            control_input_ec.drain_vec_write(&mut self.control_input_events);
+        }
+
+        // Sounds have already been played when the frame was first simulated.
+        let output = if is_resimulation {
+            world.remove::<Output>()
+        } else {
+            None
+        };
+
+        world.insert(GamePlayStepStatus::Ready);
+        world.write_resource::<GameEntityDeletions>().deferred = true;
+        dispatcher.dispatch(world);
+
+        let entities_deleted = {
+            let mut game_entity_deletions = world.write_resource::<GameEntityDeletions>();
+            game_entity_deletions.deferred = false;
+            mem::take(&mut game_entity_deletions.entities)
+        };
+        if !entities_deleted.is_empty() {
+            GamePlaySnapshotter::withhold(&mut world.system_data(), &entities_deleted);
+            self.entities_withheld
+                .insert(frame_number, entities_deleted);
+        }
+        world.maintain();
+
+        if let Some(output) = output {
+            world.insert(output);
+        }
+    }
+
+    fn frame_confirmed_update(&mut self, world: &mut World, frame_end: FrameNumber) {
+        {
+            let (network_input_buffer, session_devices) =
+                world.system_data::<(Read<'_, NetworkInputBuffer>, Read<'_, SessionDevices>)>();
+
+            while self.frame_confirmed < frame_end
+                && network_input_buffer.is_complete(self.frame_confirmed, &session_devices)
+                && !self.rollback_predictions.contains(self.frame_confirmed)
+            {
+                self.frame_confirmed += 1;
+            }
+        }
+
+        // Game play state at the beginning of a frame is final once input for all previous frames
+        // is confirmed.
+        let frame_numbers_checksum = self
+            .game_checksums_pending
+            .keys()
+            .copied()
+            .take_while(|frame_number| *frame_number <= self.frame_confirmed)
+            .collect::<Vec<FrameNumber>>();
+        if !frame_numbers_checksum.is_empty() {
+            let mut game_checksum_record_resources =
+                world.system_data::<GameChecksumRecordResources<'_>>();
+            frame_numbers_checksum.into_iter().for_each(|frame_number| {
+                if let Some(entity_checksums) = self.game_checksums_pending.remove(&frame_number) {
+                    GameChecksumRecorder::record(
+                        &mut game_checksum_record_resources,
+                        frame_number,
+                        entity_checksums,
+                    );
+                }
+            });
+        }
+
+        // Entities deleted in a confirmed frame will not be restored.
+        let entities_withheld = self.entities_withheld.split_off(&self.frame_confirmed);
+        let entities_deleted = mem::replace(&mut self.entities_withheld, entities_withheld);
+        Self::entities_delete(world, entities_deleted);
+
+        self.game_play_snapshots
+            .discard_before(self.frame_confirmed);
+        self.rollback_predictions
+            .discard_before(self.frame_confirmed);
+        world
+            .write_resource::<NetworkInputBuffer>()
+            .discard_before(self.frame_confirmed);
+    }
+
+    /// Deletes entities that are withheld from game play.
+    ///
+    /// This should be called when game play ends, as the entities would otherwise not be deleted.
+    ///
+    /// # Parameters
+    ///
+    /// * `world`: `World` to operate on.
+    pub fn terminate(&mut self, world: &mut World) {
+        let entities_withheld = mem::take(&mut self.entities_withheld);
+        Self::entities_delete(world, entities_withheld);
+        world.maintain();
+    }
+
+    fn entities_delete(world: &World, entities_withheld: BTreeMap<FrameNumber, Vec<Entity>>) {
+        let entities = world.entities();
+        entities_withheld
+            .into_iter()
+            .flat_map(|(_, entities_withheld)| entities_withheld)
+            .filter(|entity| entities.is_alive(*entity))
+            .for_each(|entity| {
+                if let Err(e) = entities.delete(entity) {
+                    error!("Failed to delete withheld entity. Error: `{}`.", e);
+                }
+            });
+    }
+}
+
+impl Default for GamePlayRollback {
+    fn default() -> Self {
+        GamePlayRollback::new()
+    }
+}
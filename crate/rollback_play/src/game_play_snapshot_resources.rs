@@ -0,0 +1,158 @@
+use ai_model::play::AiReactionClock;
+use amethyst::{
+    assets::Handle,
+    core::Transform,
+    ecs::{Entities, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use charge_model::{
+    config::ChargePoints,
+    play::{ChargeStatus, ChargeTrackerClock},
+};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::Interactions,
+    play::{HeldBy, HitRepeatTrackers, Holding},
+};
+use derivative::Derivative;
+use game_input_model::play::{ControllerInput, InputHistory};
+use game_model::play::{GameEntityId, GameEntityIdAllocator};
+use game_play_model::{
+    play::{Combo, RoundClock, RoundStartDelayClock, Stocks},
+    GamePlayStatus,
+};
+use game_stats_model::play::{GameStats, WinStatus};
+use kinematic_model::config::{Position, Velocity};
+use map_model::play::OutOfBoundsDeleteClock;
+use mirrored_model::play::Mirrored;
+use network_input_model::play::FrameNumber;
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
+use object_status_model::config::{GuardPoints, StunPoints};
+use sequence_model::{
+    loaded::SequenceId,
+    play::{FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus},
+};
+use tracker::Last;
+
+/// Resources needed to capture and restore `GamePlaySnapshot`s.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlaySnapshotResources<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `Transform` components.
+    #[derivative(Debug = "ignore")]
+    pub transforms: WriteStorage<'s, Transform>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `Grounding` components.
+    #[derivative(Debug = "ignore")]
+    pub groundings: WriteStorage<'s, Grounding>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+    /// `SequenceStatus` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_statuses: WriteStorage<'s, SequenceStatus>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: WriteStorage<'s, FrameIndexClock>,
+    /// `FrameWaitClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_wait_clocks: WriteStorage<'s, FrameWaitClock>,
+    /// `FrameFreezeClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_freeze_clocks: WriteStorage<'s, FrameFreezeClock>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: WriteStorage<'s, SkillPoints>,
+    /// `StunPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `GuardPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub guard_pointses: WriteStorage<'s, GuardPoints>,
+    /// `ChargePoints` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_pointses: WriteStorage<'s, ChargePoints>,
+    /// `ChargeStatus` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_statuses: WriteStorage<'s, ChargeStatus>,
+    /// `ChargeTrackerClock` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tracker_clocks: WriteStorage<'s, ChargeTrackerClock>,
+    /// `HitRepeatTrackers` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_repeat_trackerses: WriteStorage<'s, HitRepeatTrackers>,
+    /// `Combo` components.
+    #[derivative(Debug = "ignore")]
+    pub combos: WriteStorage<'s, Combo>,
+    /// `Handle<Interactions>` components.
+    #[derivative(Debug = "ignore")]
+    pub interactions_handles: WriteStorage<'s, Handle<Interactions>>,
+    /// `Holding` components.
+    #[derivative(Debug = "ignore")]
+    pub holdings: WriteStorage<'s, Holding>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: WriteStorage<'s, ControllerInput>,
+    /// `Last<ControllerInput>` components.
+    #[derivative(Debug = "ignore")]
+    pub last_controller_inputs: WriteStorage<'s, Last<ControllerInput>>,
+    /// `InputHistory` components.
+    #[derivative(Debug = "ignore")]
+    pub input_histories: WriteStorage<'s, InputHistory>,
+    /// `AiReactionClock` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_reaction_clocks: WriteStorage<'s, AiReactionClock>,
+    /// `OutOfBoundsDeleteClock` components.
+    #[derivative(Debug = "ignore")]
+    pub out_of_bounds_delete_clocks: WriteStorage<'s, OutOfBoundsDeleteClock>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
+    /// `GameEntityId` components.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_ids: WriteStorage<'s, GameEntityId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Write<'s, GamePlayStatus>,
+    /// `RoundClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_clock: Write<'s, RoundClock>,
+    /// `RoundStartDelayClock` resource.
+    #[derivative(Debug = "ignore")]
+    pub round_start_delay_clock: Write<'s, RoundStartDelayClock>,
+    /// `WinStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub win_status: Write<'s, WinStatus>,
+    /// `GameStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_stats: Write<'s, GameStats>,
+    /// `FrameNumber` resource.
+    #[derivative(Debug = "ignore")]
+    pub frame_number: Write<'s, FrameNumber>,
+    /// `GameEntityIdAllocator` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_id_allocator: Write<'s, GameEntityIdAllocator>,
+}
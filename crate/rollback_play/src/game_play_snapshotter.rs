@@ -0,0 +1,370 @@
+use amethyst::ecs::{Component, Entity, Join, WriteStorage};
+use log::{debug, error};
+use rollback_model::play::{ComponentSnapshot, GamePlaySnapshot};
+use tracker::Last;
+
+use crate::GamePlaySnapshotResources;
+
+/// Captures and restores `GamePlaySnapshot`s.
+///
+/// Game play entities are entities with a `SequenceId` component.
+#[derive(Debug)]
+pub struct GamePlaySnapshotter;
+
+impl GamePlaySnapshotter {
+    /// Returns a snapshot of the simulation relevant components of game play entities, and game
+    /// play resources.
+    pub fn capture(resources: &GamePlaySnapshotResources<'_>) -> GamePlaySnapshot {
+        let entities = (&resources.entities, &resources.sequence_ids)
+            .join()
+            .map(|(entity, _)| entity)
+            .collect::<Vec<Entity>>();
+
+        let last_controller_inputs = entities
+            .iter()
+            .filter_map(|entity| {
+                resources
+                    .last_controller_inputs
+                    .get(*entity)
+                    .map(|last_controller_input| (*entity, last_controller_input.0))
+            })
+            .collect::<Vec<_>>();
+
+        GamePlaySnapshot {
+            transforms: Self::component_capture(&entities, &resources.transforms),
+            positions: Self::component_capture(&entities, &resources.positions),
+            velocities: Self::component_capture(&entities, &resources.velocities),
+            mirroreds: Self::component_capture(&entities, &resources.mirroreds),
+            groundings: Self::component_capture(&entities, &resources.groundings),
+            sequence_ids: Self::component_capture(&entities, &resources.sequence_ids),
+            sequence_statuses: Self::component_capture(&entities, &resources.sequence_statuses),
+            frame_index_clocks: Self::component_capture(&entities, &resources.frame_index_clocks),
+            frame_wait_clocks: Self::component_capture(&entities, &resources.frame_wait_clocks),
+            frame_freeze_clocks: Self::component_capture(&entities, &resources.frame_freeze_clocks),
+            health_pointses: Self::component_capture(&entities, &resources.health_pointses),
+            skill_pointses: Self::component_capture(&entities, &resources.skill_pointses),
+            stun_pointses: Self::component_capture(&entities, &resources.stun_pointses),
+            guard_pointses: Self::component_capture(&entities, &resources.guard_pointses),
+            charge_pointses: Self::component_capture(&entities, &resources.charge_pointses),
+            charge_statuses: Self::component_capture(&entities, &resources.charge_statuses),
+            charge_tracker_clocks: Self::component_capture(
+                &entities,
+                &resources.charge_tracker_clocks,
+            ),
+            hit_repeat_trackerses: Self::component_capture(
+                &entities,
+                &resources.hit_repeat_trackerses,
+            ),
+            combos: Self::component_capture(&entities, &resources.combos),
+            interactions_handles: Self::component_capture(
+                &entities,
+                &resources.interactions_handles,
+            ),
+            holdings: Self::component_capture(&entities, &resources.holdings),
+            held_bys: Self::component_capture(&entities, &resources.held_bys),
+            target_objects: Self::component_capture(&entities, &resources.target_objects),
+            chase_mode_sticks: Self::component_capture(&entities, &resources.chase_mode_sticks),
+            controller_inputs: Self::component_capture(&entities, &resources.controller_inputs),
+            last_controller_inputs: ComponentSnapshot::new(last_controller_inputs),
+            input_histories: Self::component_capture(&entities, &resources.input_histories),
+            ai_reaction_clocks: Self::component_capture(&entities, &resources.ai_reaction_clocks),
+            out_of_bounds_delete_clocks: Self::component_capture(
+                &entities,
+                &resources.out_of_bounds_delete_clocks,
+            ),
+            stockses: Self::component_capture(&entities, &resources.stockses),
+            game_entity_ids: Self::component_capture(&entities, &resources.game_entity_ids),
+            game_play_status: *resources.game_play_status,
+            round_clock: *resources.round_clock,
+            round_start_delay_clock: *resources.round_start_delay_clock,
+            win_status: resources.win_status.clone(),
+            game_stats: resources.game_stats.clone(),
+            frame_number: *resources.frame_number,
+            game_entity_id_allocator: *resources.game_entity_id_allocator,
+            entities,
+        }
+    }
+
+    /// Removes the simulation relevant components of game play entities that were deleted.
+    ///
+    /// The entities themselves are kept alive until the frame they were deleted in is confirmed,
+    /// so that they may be restored if the frame is re-simulated.
+    ///
+    /// # Parameters
+    ///
+    /// * `resources`: Resources needed to capture and restore snapshots.
+    /// * `entities_withheld`: Entities to withhold from game play.
+    pub fn withhold(resources: &mut GamePlaySnapshotResources<'_>, entities_withheld: &[Entity]) {
+        entities_withheld.iter().copied().for_each(|entity| {
+            resources.transforms.remove(entity);
+            resources.positions.remove(entity);
+            resources.velocities.remove(entity);
+            resources.mirroreds.remove(entity);
+            resources.groundings.remove(entity);
+            resources.sequence_ids.remove(entity);
+            resources.sequence_statuses.remove(entity);
+            resources.frame_index_clocks.remove(entity);
+            resources.frame_wait_clocks.remove(entity);
+            resources.frame_freeze_clocks.remove(entity);
+            resources.health_pointses.remove(entity);
+            resources.skill_pointses.remove(entity);
+            resources.stun_pointses.remove(entity);
+            resources.guard_pointses.remove(entity);
+            resources.charge_pointses.remove(entity);
+            resources.charge_statuses.remove(entity);
+            resources.charge_tracker_clocks.remove(entity);
+            resources.hit_repeat_trackerses.remove(entity);
+            resources.combos.remove(entity);
+            resources.interactions_handles.remove(entity);
+            resources.holdings.remove(entity);
+            resources.held_bys.remove(entity);
+            resources.target_objects.remove(entity);
+            resources.chase_mode_sticks.remove(entity);
+            resources.controller_inputs.remove(entity);
+            resources.last_controller_inputs.remove(entity);
+            resources.input_histories.remove(entity);
+            resources.ai_reaction_clocks.remove(entity);
+            resources.out_of_bounds_delete_clocks.remove(entity);
+            resources.stockses.remove(entity);
+            resources.game_entity_ids.remove(entity);
+        });
+    }
+
+    /// Restores game play entities' components and game play resources to the values in the
+    /// snapshot.
+    ///
+    /// Game play entities that were spawned after the snapshot was taken are deleted, and entities
+    /// that were withheld after the snapshot was taken regain their components.
+    ///
+    /// # Parameters
+    ///
+    /// * `resources`: Resources needed to capture and restore snapshots.
+    /// * `game_play_snapshot`: Snapshot to restore.
+    /// * `entities_withheld`: Entities withheld since the snapshot was taken.
+    pub fn restore(
+        resources: &mut GamePlaySnapshotResources<'_>,
+        game_play_snapshot: &GamePlaySnapshot,
+        entities_withheld: &[Entity],
+    ) {
+        let entities = &game_play_snapshot.entities;
+
+        let entities_spawned = (&resources.entities, &resources.sequence_ids)
+            .join()
+            .map(|(entity, _)| entity)
+            .chain(entities_withheld.iter().copied())
+            .filter(|entity| !entities.contains(entity))
+            .collect::<Vec<Entity>>();
+        entities_spawned.into_iter().for_each(|entity| {
+            if let Err(e) = resources.entities.delete(entity) {
+                error!("Failed to delete entity during rollback. Error: `{}`.", e);
+            }
+        });
+
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.transforms,
+            &mut resources.transforms,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.positions,
+            &mut resources.positions,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.velocities,
+            &mut resources.velocities,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.mirroreds,
+            &mut resources.mirroreds,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.groundings,
+            &mut resources.groundings,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.sequence_ids,
+            &mut resources.sequence_ids,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.sequence_statuses,
+            &mut resources.sequence_statuses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.frame_index_clocks,
+            &mut resources.frame_index_clocks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.frame_wait_clocks,
+            &mut resources.frame_wait_clocks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.frame_freeze_clocks,
+            &mut resources.frame_freeze_clocks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.health_pointses,
+            &mut resources.health_pointses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.skill_pointses,
+            &mut resources.skill_pointses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.stun_pointses,
+            &mut resources.stun_pointses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.guard_pointses,
+            &mut resources.guard_pointses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.charge_pointses,
+            &mut resources.charge_pointses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.charge_statuses,
+            &mut resources.charge_statuses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.charge_tracker_clocks,
+            &mut resources.charge_tracker_clocks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.hit_repeat_trackerses,
+            &mut resources.hit_repeat_trackerses,
+        );
+        Self::component_restore(entities, &game_play_snapshot.combos, &mut resources.combos);
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.interactions_handles,
+            &mut resources.interactions_handles,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.holdings,
+            &mut resources.holdings,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.held_bys,
+            &mut resources.held_bys,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.target_objects,
+            &mut resources.target_objects,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.chase_mode_sticks,
+            &mut resources.chase_mode_sticks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.controller_inputs,
+            &mut resources.controller_inputs,
+        );
+
+        entities.iter().for_each(|entity| {
+            resources.last_controller_inputs.remove(*entity);
+        });
+        game_play_snapshot
+            .last_controller_inputs
+            .iter()
+            .for_each(|(entity, controller_input)| {
+                if let Err(e) = resources
+                    .last_controller_inputs
+                    .insert(*entity, Last::new(*controller_input))
+                {
+                    debug!("Unable to restore `Last<ControllerInput>`: `{}`.", e);
+                }
+            });
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.input_histories,
+            &mut resources.input_histories,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.ai_reaction_clocks,
+            &mut resources.ai_reaction_clocks,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.out_of_bounds_delete_clocks,
+            &mut resources.out_of_bounds_delete_clocks,
+        );
+
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.stockses,
+            &mut resources.stockses,
+        );
+        Self::component_restore(
+            entities,
+            &game_play_snapshot.game_entity_ids,
+            &mut resources.game_entity_ids,
+        );
+
+        *resources.game_play_status = game_play_snapshot.game_play_status;
+        *resources.round_clock = game_play_snapshot.round_clock;
+        *resources.round_start_delay_clock = game_play_snapshot.round_start_delay_clock;
+        *resources.win_status = game_play_snapshot.win_status.clone();
+        *resources.game_stats = game_play_snapshot.game_stats.clone();
+        *resources.frame_number = game_play_snapshot.frame_number;
+        *resources.game_entity_id_allocator = game_play_snapshot.game_entity_id_allocator;
+    }
+
+    fn component_capture<C>(
+        entities: &[Entity],
+        storage: &WriteStorage<'_, C>,
+    ) -> ComponentSnapshot<C>
+    where
+        C: Component + Clone,
+    {
+        let component_values = entities
+            .iter()
+            .filter_map(|entity| {
+                storage
+                    .get(*entity)
+                    .map(|component| (*entity, component.clone()))
+            })
+            .collect::<Vec<(Entity, C)>>();
+
+        ComponentSnapshot::new(component_values)
+    }
+
+    fn component_restore<C>(
+        entities: &[Entity],
+        component_snapshot: &ComponentSnapshot<C>,
+        storage: &mut WriteStorage<'_, C>,
+    ) where
+        C: Component + Clone,
+    {
+        entities.iter().for_each(|entity| {
+            storage.remove(*entity);
+        });
+        component_snapshot.iter().for_each(|(entity, component)| {
+            if let Err(e) = storage.insert(*entity, component.clone()) {
+                debug!("Unable to restore component: `{}`.", e);
+            }
+        });
+    }
+}
@@ -0,0 +1,16 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to roll back and re-simulate game play during network play.
+//!
+//! Input from other session devices is predicted so that game play does not wait for the network.
+//! When input arrives that differs from the prediction, game play is restored to the snapshot
+//! taken before the mispredicted frame, and re-simulated up to the current frame.
+
+pub use crate::{
+    game_play_rollback::GamePlayRollback, game_play_snapshot_resources::GamePlaySnapshotResources,
+    game_play_snapshotter::GamePlaySnapshotter,
+};
+
+mod game_play_rollback;
+mod game_play_snapshot_resources;
+mod game_play_snapshotter;
@@ -1,10 +1,11 @@
 use amethyst::{
-    ecs::{Entities, Read, ReadStorage, System, World, WriteStorage},
+    ecs::{Entities, Read, ReadStorage, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_model::play::GameEntityDeletions;
 use sequence_model::{
     loaded::{SequenceEndTransition, SequenceId},
     play::SequenceUpdateEvent,
@@ -25,6 +26,9 @@ pub struct SequenceEndTransitionSystemData<'s> {
     /// `Entities` resource.
     #[derivative(Debug = "ignore")]
     pub entities: Entities<'s>,
+    /// `GameEntityDeletions` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_deletions: Write<'s, GameEntityDeletions>,
     /// Event channel for `SequenceUpdateEvent`s.
     #[derivative(Debug = "ignore")]
     pub sequence_update_ec: Read<'s, EventChannel<SequenceUpdateEvent>>,
@@ -43,6 +47,7 @@ impl<'s> System<'s> for SequenceEndTransitionSystem {
         &mut self,
         SequenceEndTransitionSystemData {
             entities,
+            mut game_entity_deletions,
             sequence_update_ec,
             sequence_end_transitions,
             mut sequence_ids,
@@ -79,8 +84,8 @@ impl<'s> System<'s> for SequenceEndTransitionSystem {
                                 .expect("Failed to insert `SequenceId` component.");
                         }
                         SequenceEndTransition::Delete => {
-                            entities
-                                .delete(entity)
+                            game_entity_deletions
+                                .delete(&entities, entity)
                                 .expect("Failed to delete entity on `SequenceEndTransition`.");
                         }
                         SequenceEndTransition::SequenceId(sequence_id) => {
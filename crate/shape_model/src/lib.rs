@@ -2,7 +2,11 @@
 
 //! Types used to represent shapes.
 
-pub use crate::{axis::Axis, volume::Volume};
+pub use crate::{
+    axis::Axis, volume::Volume, volume_bounds::VolumeBounds, volume_error::VolumeError,
+};
 
 mod axis;
 mod volume;
+mod volume_bounds;
+mod volume_error;
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Axis;
+use crate::{Axis, VolumeError};
 
 /// Represents a volume
 #[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize)]
@@ -55,6 +55,33 @@ pub enum Volume {
 }
 
 impl Volume {
+    /// Returns an error if this volume cannot be intersected with, such as when it has no size.
+    pub fn validate(&self) -> Result<(), VolumeError> {
+        match *self {
+            Volume::Box { w, h, d, .. } => {
+                if w == 0 || h == 0 || d == 0 {
+                    Err(VolumeError::BoxEmpty { w, h, d })
+                } else {
+                    Ok(())
+                }
+            }
+            Volume::Cylinder { r, l, .. } => {
+                if r == 0 || l == 0 {
+                    Err(VolumeError::CylinderEmpty { r, l })
+                } else {
+                    Ok(())
+                }
+            }
+            Volume::Sphere { r, .. } => {
+                if r == 0 {
+                    Err(VolumeError::SphereEmpty)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
     /// Default depth for `Box` volumes.
     fn box_default_depth() -> u32 {
         26
@@ -0,0 +1,108 @@
+use crate::{Axis, Volume};
+
+/// Axis aligned box that encloses one or more `Volume`s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolumeBounds {
+    /// Minimum coordinate on each axis.
+    pub min: [f32; 3],
+    /// Maximum coordinate on each axis.
+    pub max: [f32; 3],
+}
+
+impl VolumeBounds {
+    /// Returns a new `VolumeBounds`.
+    pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+        VolumeBounds { min, max }
+    }
+
+    /// Returns the bounds that enclose a volume.
+    ///
+    /// Volume coordinates are pixel coordinates on the object's sprite, so the sprite offsets are
+    /// subtracted to place the volume relative to the object's position. When the object is
+    /// mirrored, the X coordinates are negated.
+    ///
+    /// # Parameters
+    ///
+    /// * `volume`: Volume to enclose.
+    /// * `offsets`: X and Y offsets of the object's sprite.
+    /// * `mirrored`: Whether the object is mirrored.
+    pub fn from_volume(volume: &Volume, offsets: [f32; 2], mirrored: bool) -> Self {
+        let (min, max) = match *volume {
+            Volume::Box { x, y, z, w, h, d } => {
+                let x_min = x as f32 - offsets[0];
+                let y_min = y as f32 - offsets[1];
+                let z_min = z as f32;
+                (
+                    [x_min, y_min, z_min],
+                    [x_min + w as f32, y_min + h as f32, z_min + d as f32],
+                )
+            }
+            Volume::Cylinder { axis, center, r, l } => {
+                let r = r as f32;
+                let half_length = l as f32 / 2.;
+                let (axis, center) = match axis {
+                    Axis::X => (0, center as f32 - offsets[0]),
+                    Axis::Y => (1, center as f32 - offsets[1]),
+                    Axis::Z => (2, center as f32),
+                };
+                let mut min = [-r, -r, -r];
+                let mut max = [r, r, r];
+                min[axis] = center - half_length;
+                max[axis] = center + half_length;
+                (min, max)
+            }
+            Volume::Sphere { x, y, z, r } => {
+                let center = [x as f32 - offsets[0], y as f32 - offsets[1], z as f32];
+                let r = r as f32;
+                (
+                    [center[0] - r, center[1] - r, center[2] - r],
+                    [center[0] + r, center[1] + r, center[2] + r],
+                )
+            }
+        };
+
+        if mirrored {
+            VolumeBounds::new([-max[0], min[1], min[2]], [-min[0], max[1], max[2]])
+        } else {
+            VolumeBounds::new(min, max)
+        }
+    }
+
+    /// Returns the bounds that enclose both bounds.
+    pub fn union(self, other: Self) -> Self {
+        let mut min = self.min;
+        let mut max = self.max;
+        (0..3).for_each(|i| {
+            min[i] = min[i].min(other.min[i]);
+            max[i] = max[i].max(other.max[i]);
+        });
+
+        VolumeBounds::new(min, max)
+    }
+
+    /// Returns the bounds moved by the given translation.
+    pub fn translate(self, translation: [f32; 3]) -> Self {
+        let mut min = self.min;
+        let mut max = self.max;
+        (0..3).for_each(|i| {
+            min[i] += translation[i];
+            max[i] += translation[i];
+        });
+
+        VolumeBounds::new(min, max)
+    }
+
+    /// Returns whether the bounds overlap along an axis.
+    ///
+    /// Bounds that only touch do not overlap.
+    pub fn overlaps_on(&self, other: &Self, axis: usize) -> bool {
+        self.min[axis] < other.max[axis] && other.min[axis] < self.max[axis]
+    }
+
+    /// Returns whether the bounds overlap.
+    ///
+    /// Bounds that only touch do not intersect, so objects may rest on top of each other.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..3).all(|axis| self.overlaps_on(other, axis))
+    }
+}
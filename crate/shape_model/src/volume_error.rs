@@ -0,0 +1,47 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when validating a `Volume`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeError {
+    /// `Box` has a zero width, height, or depth.
+    BoxEmpty {
+        /// Width of the box.
+        w: u32,
+        /// Height of the box.
+        h: u32,
+        /// Depth of the box.
+        d: u32,
+    },
+    /// `Cylinder` has a zero radius or length.
+    CylinderEmpty {
+        /// Radius of the cylinder.
+        r: u32,
+        /// Length of the cylinder.
+        l: u32,
+    },
+    /// `Sphere` has a zero radius.
+    SphereEmpty,
+}
+
+impl Display for VolumeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BoxEmpty { w, h, d } => write!(
+                f,
+                "Box must have a non-zero `w`, `h`, and `d`, but was: `w: {}, h: {}, d: {}`.",
+                w, h, d
+            ),
+            Self::CylinderEmpty { r, l } => write!(
+                f,
+                "Cylinder must have a non-zero `r` and `l`, but was: `r: {}, l: {}`.",
+                r, l
+            ),
+            Self::SphereEmpty => write!(f, "Sphere must have a non-zero `r`."),
+        }
+    }
+}
+
+impl Error for VolumeError {}
@@ -5,6 +5,7 @@ use energy_prefab::EnergyEntityAugmenter;
 use log::{debug, error};
 use object_type::ObjectType;
 use spawn_model::{loaded::Spawn, play::SpawnEvent};
+use weapon_prefab::WeaponEntityAugmenter;
 
 use crate::SpawnGameObjectResources;
 
@@ -25,9 +26,13 @@ impl GameObjectSpawner {
             asset_item_ids,
             asset_ids,
             item_ids,
+            game_entity_id_allocator,
+            game_entity_ids,
             character_spawning_resources,
             character_component_storages,
             energy_component_storages,
+            weapon_spawning_resources,
+            weapon_component_storages,
             spawn_ec,
         }: &mut SpawnGameObjectResources<'_>,
         entity_parent: Entity,
@@ -65,6 +70,13 @@ impl GameObjectSpawner {
         item_ids
             .insert(entity_spawned, item_id)
             .expect("Failed to insert `ItemId`.");
+        // Only objects spawned by game play entities are game play entities, objects spawned by UI
+        // previews are not.
+        if game_entity_ids.contains(entity_parent) {
+            game_entity_ids
+                .insert(entity_spawned, game_entity_id_allocator.allocate())
+                .expect("Failed to insert `GameEntityId`.");
+        }
 
         match asset_type {
             AssetType::Object(ObjectType::Character) => {
@@ -78,6 +90,14 @@ impl GameObjectSpawner {
             AssetType::Object(ObjectType::Energy) => {
                 EnergyEntityAugmenter::augment(entity_spawned, energy_component_storages);
             }
+            AssetType::Object(ObjectType::Weapon) => {
+                WeaponEntityAugmenter::augment(
+                    weapon_spawning_resources,
+                    weapon_component_storages,
+                    asset_id,
+                    entity_spawned,
+                );
+            }
             _ => {
                 let asset_slug = asset_id_mappings
                     .slug(asset_id)
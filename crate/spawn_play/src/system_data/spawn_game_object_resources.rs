@@ -7,7 +7,9 @@ use asset_model::loaded::{AssetId, AssetIdMappings, AssetItemIds, AssetTypeMappi
 use character_prefab::{CharacterComponentStorages, CharacterSpawningResources};
 use derivative::Derivative;
 use energy_prefab::EnergyComponentStorages;
+use game_model::play::{GameEntityId, GameEntityIdAllocator};
 use spawn_model::play::SpawnEvent;
+use weapon_prefab::{WeaponComponentStorages, WeaponSpawningResources};
 
 /// `SpawnGameObjectResources`.
 #[derive(Derivative, SystemData)]
@@ -31,6 +33,12 @@ pub struct SpawnGameObjectResources<'s> {
     /// `ItemId` components.
     #[derivative(Debug = "ignore")]
     pub item_ids: WriteStorage<'s, ItemId>,
+    /// `GameEntityIdAllocator` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_id_allocator: Write<'s, GameEntityIdAllocator>,
+    /// `GameEntityId` components.
+    #[derivative(Debug = "ignore")]
+    pub game_entity_ids: WriteStorage<'s, GameEntityId>,
     /// `CharacterSpawningResources`.
     #[derivative(Debug = "ignore")]
     pub character_spawning_resources: CharacterSpawningResources<'s>,
@@ -40,6 +48,12 @@ pub struct SpawnGameObjectResources<'s> {
     /// `EnergyComponentStorages`.
     #[derivative(Debug = "ignore")]
     pub energy_component_storages: EnergyComponentStorages<'s>,
+    /// `WeaponSpawningResources`.
+    #[derivative(Debug = "ignore")]
+    pub weapon_spawning_resources: WeaponSpawningResources<'s>,
+    /// `WeaponComponentStorages`.
+    #[derivative(Debug = "ignore")]
+    pub weapon_component_storages: WeaponComponentStorages<'s>,
     /// `SpawnEvent` channel.
     #[derivative(Debug = "ignore")]
     pub spawn_ec: Write<'s, EventChannel<SpawnEvent>>,
@@ -24,6 +24,8 @@ pub enum StateId {
     #[derivative(Default)]
     #[serde(skip)]
     None,
+    /// `AudioSettingsState` ID.
+    AudioSettings,
     /// `CharacterSelectionState` ID.
     CharacterSelection,
     /// `ControlSettingsState` ID.
@@ -34,6 +36,12 @@ pub enum StateId {
     GameLoading,
     /// `GamePlayState` ID.
     GamePlay,
+    /// ID for the pause menu shown in the `GamePlayState`.
+    ///
+    /// This is not inserted as the current `StateId`, but identifies the pause menu UI asset.
+    GamePlayPause,
+    /// `GameStatsState` ID.
+    GameStats,
     /// `LoadingState` ID.
     Loading,
     /// `MapSelectionState` ID.
@@ -1,7 +1,11 @@
 //! Contains data types used during game play.
 
-pub use self::{independent_counter::IndependentCounter, team::Team, team_counter::TeamCounter};
+pub use self::{
+    friendly_fire::FriendlyFire, independent_counter::IndependentCounter, team::Team,
+    team_counter::TeamCounter,
+};
 
+mod friendly_fire;
 mod independent_counter;
 mod team;
 mod team_counter;
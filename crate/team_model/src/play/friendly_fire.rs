@@ -0,0 +1,8 @@
+use derive_more::From;
+
+/// Whether objects on the same team may hit each other.
+///
+/// Defaults to `false`, so objects only hit objects on other teams. This only applies to `Hit`
+/// interactions -- objects never grab or throw objects on the same team.
+#[derive(Clone, Copy, Debug, Default, From, PartialEq, Eq)]
+pub struct FriendlyFire(pub bool);
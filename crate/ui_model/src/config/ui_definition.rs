@@ -1,5 +1,6 @@
 use asset_derive::Asset;
 use derive_new::new;
+use music_model::config::MusicId;
 use serde::{Deserialize, Serialize};
 use ui_button_model::config::UiButtons;
 
@@ -21,4 +22,8 @@ pub struct UiDefinition {
     /// Sequences used by the UI type.
     #[serde(default)]
     pub sequences: UiSequences,
+    /// Music track to play in this state.
+    #[serde(default)]
+    #[new(default)]
+    pub music: Option<MusicId>,
 }
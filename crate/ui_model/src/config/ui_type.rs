@@ -1,6 +1,7 @@
 use application_menu::MenuIndex;
 use character_selection_ui_model::config::CharacterSelectionUi;
 use control_settings_model::config::ControlSettings;
+use game_stats_ui_model::config::GameStatsUi;
 use map_selection_ui_model::config::MapSelectionUi;
 use serde::{Deserialize, Serialize};
 use session_lobby_ui_model::config::SessionLobbyUi;
@@ -19,6 +20,8 @@ pub enum UiType {
     Menu(UiMenuItems<MenuIndex>),
     /// Generic form UI.
     Form(UiFormItems),
+    /// Game statistics UI.
+    GameStats(GameStatsUi),
     /// Map selection UI.
     MapSelection(MapSelectionUi),
     /// Session Lobby UI.
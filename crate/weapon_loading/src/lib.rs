@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes weapon configuration into the loaded weapon model.
+
+pub use crate::weapon_loading_bundle::WeaponLoadingBundle;
+
+mod weapon_loading_bundle;
@@ -0,0 +1,29 @@
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use derive_new::new;
+use weapon_model::config::WeaponDefinition;
+
+/// Adds the following processor `System`s to the world:
+///
+/// * `Processor::<WeaponDefinition>`
+#[derive(Debug, new)]
+pub struct WeaponLoadingBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for WeaponLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<WeaponDefinition>::new(),
+            "weapon_definition_processor",
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+//! Contains the types that represent the configuration on disk.
+
+pub use self::{
+    weapon_definition::{WeaponDefinition, WeaponDefinitionHandle},
+    weapon_frame::WeaponFrame,
+    weapon_sequence::WeaponSequence,
+    weapon_sequence_name::WeaponSequenceName,
+};
+
+mod weapon_definition;
+mod weapon_frame;
+mod weapon_sequence;
+mod weapon_sequence_name;
@@ -0,0 +1,14 @@
+use asset_derive::Asset;
+use derive_new::new;
+use object_model::config::ObjectDefinition;
+use serde::{Deserialize, Serialize};
+
+use crate::config::WeaponSequence;
+
+/// Contains all of the sequences for a `Weapon`.
+#[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+pub struct WeaponDefinition {
+    /// Sequences of actions this object can perform.
+    #[serde(flatten)]
+    pub object_definition: ObjectDefinition<WeaponSequence>,
+}
@@ -0,0 +1,30 @@
+use derive_new::new;
+use object_model::config::{GameObjectSequence, ObjectSequence};
+use sequence_model::config::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{WeaponFrame, WeaponSequenceName};
+
+/// Represents an independent action sequence of a `Weapon`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+// #[serde(deny_unknown_fields)] // See <https://github.com/serde-rs/serde/issues/1547>
+pub struct WeaponSequence {
+    /// Object sequence for common object fields.
+    #[serde(flatten)]
+    pub object_sequence: ObjectSequence<WeaponSequenceName, WeaponFrame>,
+}
+
+impl AsRef<Sequence<WeaponSequenceName, WeaponFrame>> for WeaponSequence {
+    fn as_ref(&self) -> &Sequence<WeaponSequenceName, WeaponFrame> {
+        &self.object_sequence.sequence
+    }
+}
+
+impl GameObjectSequence for WeaponSequence {
+    type SequenceName = WeaponSequenceName;
+    type GameObjectFrame = WeaponFrame;
+
+    fn object_sequence(&self) -> &ObjectSequence<Self::SequenceName, Self::GameObjectFrame> {
+        &self.object_sequence
+    }
+}
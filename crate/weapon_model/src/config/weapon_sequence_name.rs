@@ -0,0 +1,36 @@
+use derivative::Derivative;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString, IntoStaticStr};
+
+/// `Weapon` sequence names.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumString,
+    IntoStaticStr,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WeaponSequenceName {
+    /// Default sequence for weapons, lying on the ground ready to be picked up.
+    #[derivative(Default)]
+    OnGround,
+    /// Sequence while held by a character.
+    ///
+    /// Interactions in this sequence are used in place of the holder's attack interactions.
+    Held,
+    /// Sequence to switch to when thrown or dropped.
+    Thrown,
+}
+
+impl SequenceName for WeaponSequenceName {}
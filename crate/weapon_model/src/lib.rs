@@ -0,0 +1,6 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types that represent weapon objects.
+
+pub mod config;
+pub mod loaded;
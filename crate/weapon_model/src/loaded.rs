@@ -0,0 +1,12 @@
+//! Contains the types that represent processed configuration.
+//!
+//! This differs from the plain configuration types as they would have been processed into the form
+//! that will be used in game.
+
+pub use self::{
+    asset_weapon_definition_handle::AssetWeaponDefinitionHandle,
+    weapon_transitions::WeaponTransitions,
+};
+
+mod asset_weapon_definition_handle;
+mod weapon_transitions;
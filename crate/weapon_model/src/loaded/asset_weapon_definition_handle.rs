@@ -0,0 +1,7 @@
+use asset_model::loaded::AssetId;
+use slotmap::SecondaryMap;
+
+use crate::config::WeaponDefinitionHandle;
+
+/// `WeaponDefinitionHandle` for an asset.
+pub type AssetWeaponDefinitionHandle = SecondaryMap<AssetId, WeaponDefinitionHandle>;
@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when a weapon is picked up, or dropped.
+///
+/// This is also used to identify weapon entities.
+// TODO: Commonize Transition systems <https://gitlab.com/azriel91/autexousious/issues/157>
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct WeaponTransitions {
+    /// Sequence ID of the weapon when it may be picked up.
+    pub on_ground: SequenceId,
+    /// Sequence ID to transition to when picked up.
+    pub held: SequenceId,
+    /// Sequence ID to transition to when thrown or dropped.
+    pub thrown: SequenceId,
+}
@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for weapon objects used during game play.
+
+pub use crate::system::{WeaponHeldSystem, WeaponPickUpSystem, WEAPON_PICK_UP_DISTANCE};
+
+mod system;
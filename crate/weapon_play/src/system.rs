@@ -0,0 +1,7 @@
+pub use self::{
+    weapon_held_system::WeaponHeldSystem,
+    weapon_pick_up_system::{WeaponPickUpSystem, WEAPON_PICK_UP_DISTANCE},
+};
+
+mod weapon_held_system;
+mod weapon_pick_up_system;
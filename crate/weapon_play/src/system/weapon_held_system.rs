@@ -0,0 +1,211 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use character_model::loaded::CharacterHitTransitions;
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::{InteractionKind, Interactions},
+    play::{HeldBy, Holding},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+use weapon_model::loaded::WeaponTransitions;
+
+/// Updates held weapons, and the attacks of the objects holding them.
+///
+/// When the holder attacks, the weapon's `Interactions` are used in place of the holder's. Weapons
+/// are dropped when the holder is stunned or dies.
+///
+/// This must run after the `Interactions` for the tick have been updated, and before collision
+/// detection.
+#[derive(Debug, Default, new)]
+pub struct WeaponHeldSystem;
+
+/// `WeaponHeldSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct WeaponHeldSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `WeaponTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub weapon_transitionses: ReadStorage<'s, WeaponTransitions>,
+    /// `CharacterHitTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Interactions` assets.
+    #[derivative(Debug = "ignore")]
+    pub interactions_assets: Read<'s, AssetStorage<Interactions>>,
+    /// `Handle<Interactions>` components.
+    #[derivative(Debug = "ignore")]
+    pub interactions_handles: WriteStorage<'s, Handle<Interactions>>,
+    /// `Holding` components.
+    #[derivative(Debug = "ignore")]
+    pub holdings: WriteStorage<'s, Holding>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl WeaponHeldSystem {
+    /// Returns whether the holder is no longer able to hold the weapon.
+    fn holder_incapacitated(
+        WeaponHeldSystemData {
+            entities,
+            character_hit_transitionses,
+            health_pointses,
+            sequence_ids,
+            ..
+        }: &WeaponHeldSystemData<'_>,
+        holder: Entity,
+    ) -> bool {
+        !entities.is_alive(holder)
+            || health_pointses
+                .get(holder)
+                .map_or(false, |health_points| *health_points == 0)
+            || match (
+                character_hit_transitionses.get(holder),
+                sequence_ids.get(holder),
+            ) {
+                (Some(character_hit_transitions), Some(sequence_id)) => {
+                    character_hit_transitions.is_stunned(*sequence_id)
+                }
+                _ => false,
+            }
+    }
+
+    /// Detaches the weapon from its holder, and transitions it to its thrown sequence.
+    fn release(
+        WeaponHeldSystemData {
+            weapon_transitionses,
+            holdings,
+            held_bys,
+            target_objects,
+            chase_mode_sticks,
+            velocities,
+            sequence_ids,
+            ..
+        }: &mut WeaponHeldSystemData<'_>,
+        holder: Entity,
+        weapon: Entity,
+    ) {
+        if holdings.get(holder).map(|holding| holding.entity) == Some(weapon) {
+            holdings.remove(holder);
+        }
+        held_bys.remove(weapon);
+        target_objects.remove(weapon);
+        chase_mode_sticks.remove(weapon);
+
+        if let Some(velocity) = velocities.get_mut(weapon) {
+            *velocity = Velocity::default();
+        }
+        if let (Some(weapon_transitions), Some(sequence_id)) = (
+            weapon_transitionses.get(weapon),
+            sequence_ids.get_mut(weapon),
+        ) {
+            *sequence_id = weapon_transitions.thrown;
+        }
+    }
+
+    /// Returns whether the `Interactions` contain a `Hit`.
+    fn is_attack(interactions: &Interactions) -> bool {
+        interactions
+            .iter()
+            .any(|interaction| match interaction.kind {
+                InteractionKind::Hit(_) => true,
+                _ => false,
+            })
+    }
+}
+
+impl<'s> System<'s> for WeaponHeldSystem {
+    type SystemData = WeaponHeldSystemData<'s>;
+
+    fn run(&mut self, mut weapon_held_system_data: Self::SystemData) {
+        let drops = (
+            &weapon_held_system_data.entities,
+            &weapon_held_system_data.weapon_transitionses,
+            &weapon_held_system_data.held_bys,
+        )
+            .join()
+            .map(|(weapon, _, held_by)| (held_by.entity, weapon))
+            .filter(|(holder, _)| Self::holder_incapacitated(&weapon_held_system_data, *holder))
+            .collect::<Vec<(Entity, Entity)>>();
+        drops.into_iter().for_each(|(holder, weapon)| {
+            Self::release(&mut weapon_held_system_data, holder, weapon);
+        });
+
+        let WeaponHeldSystemData {
+            entities,
+            weapon_transitionses,
+            interactions_assets,
+            interactions_handles,
+            held_bys,
+            mirroreds,
+            ..
+        } = &mut weapon_held_system_data;
+
+        (&*entities, &*weapon_transitionses, &*held_bys)
+            .join()
+            .for_each(|(weapon, _, held_by)| {
+                let holder = held_by.entity;
+
+                // Weapons face the same direction as their holder.
+                let holder_mirrored = mirroreds.get(holder).copied();
+                if let (Some(holder_mirrored), Some(mirrored)) =
+                    (holder_mirrored, mirroreds.get_mut(weapon))
+                {
+                    *mirrored = holder_mirrored;
+                }
+
+                let holder_attacking = interactions_handles
+                    .get(holder)
+                    .and_then(|interactions_handle| interactions_assets.get(interactions_handle))
+                    .map_or(false, Self::is_attack);
+                if !holder_attacking {
+                    return;
+                }
+
+                // Weapons without interactions in the current frame leave the holder's attack as
+                // is.
+                let weapon_interactions_handle = interactions_handles
+                    .get(weapon)
+                    .filter(|interactions_handle| {
+                        interactions_assets
+                            .get(interactions_handle)
+                            .map_or(false, |interactions| !interactions.is_empty())
+                    })
+                    .cloned();
+                if let Some(weapon_interactions_handle) = weapon_interactions_handle {
+                    interactions_handles
+                        .insert(holder, weapon_interactions_handle)
+                        .expect("Failed to insert `Handle<Interactions>` component.");
+                }
+            });
+    }
+}
@@ -0,0 +1,220 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::play::{HeldBy, Holding};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::ControlAction,
+    play::{ControlActionEventData, ControlInputEvent},
+};
+use kinematic_model::config::{Position, Velocity};
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+use weapon_model::loaded::WeaponTransitions;
+
+/// Maximum distance between an object and a weapon for the weapon to be picked up.
+pub const WEAPON_PICK_UP_DISTANCE: f32 = 30.;
+
+/// Picks up the nearest weapon on the ground when `Attack` is pressed.
+///
+/// The `Attack` press is not consumed. Input reactions to the same press still run, so a character
+/// that picks up a weapon also begins its attack, which is made with the weapon.
+#[derive(Debug, Default, new)]
+pub struct WeaponPickUpSystem {
+    /// Reader ID for the `ControlInputEvent` channel.
+    #[new(default)]
+    control_input_event_rid: Option<ReaderId<ControlInputEvent>>,
+}
+
+/// `WeaponPickUpSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct WeaponPickUpSystemData<'s> {
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Read<'s, EventChannel<ControlInputEvent>>,
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `WeaponTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub weapon_transitionses: ReadStorage<'s, WeaponTransitions>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Holding` components.
+    #[derivative(Debug = "ignore")]
+    pub holdings: WriteStorage<'s, Holding>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl WeaponPickUpSystem {
+    /// Returns the nearest weapon on the ground within reach of the entity, if any.
+    fn weapon_in_reach(
+        WeaponPickUpSystemData {
+            entities,
+            weapon_transitionses,
+            positions,
+            held_bys,
+            sequence_ids,
+            ..
+        }: &WeaponPickUpSystemData<'_>,
+        position: Position<f32>,
+    ) -> Option<Entity> {
+        (
+            entities,
+            weapon_transitionses,
+            positions,
+            sequence_ids,
+            !held_bys,
+        )
+            .join()
+            .filter(|(_, weapon_transitions, _, sequence_id, _)| {
+                weapon_transitions.on_ground == **sequence_id
+            })
+            .filter_map(|(weapon, _, weapon_position, _, _)| {
+                let distance_x = weapon_position.x - position.x;
+                let distance_y = weapon_position.y - position.y;
+                let distance_z = weapon_position.z - position.z;
+                let distance_sq = distance_x * distance_x + distance_z * distance_z;
+
+                if distance_sq <= WEAPON_PICK_UP_DISTANCE * WEAPON_PICK_UP_DISTANCE
+                    && distance_y.abs() <= WEAPON_PICK_UP_DISTANCE
+                {
+                    Some((weapon, distance_sq))
+                } else {
+                    None
+                }
+            })
+            .fold(
+                None,
+                |nearest: Option<(Entity, f32)>, (weapon, distance_sq)| match nearest {
+                    Some((_, distance_sq_nearest)) if distance_sq_nearest <= distance_sq => nearest,
+                    _ => Some((weapon, distance_sq)),
+                },
+            )
+            .map(|(weapon, _)| weapon)
+    }
+
+    /// Picks up the nearest weapon, if the entity is able to.
+    fn pick_up(weapon_pick_up_system_data: &mut WeaponPickUpSystemData<'_>, holder: Entity) {
+        let can_pick_up = {
+            let WeaponPickUpSystemData {
+                health_pointses,
+                holdings,
+                held_bys,
+                ..
+            } = &*weapon_pick_up_system_data;
+
+            let holder_alive = health_pointses
+                .get(holder)
+                .map_or(true, |health_points| *health_points > 0);
+
+            holder_alive && !holdings.contains(holder) && !held_bys.contains(holder)
+        };
+        if !can_pick_up {
+            return;
+        }
+
+        let weapon = weapon_pick_up_system_data
+            .positions
+            .get(holder)
+            .copied()
+            .and_then(|position| Self::weapon_in_reach(weapon_pick_up_system_data, position));
+
+        if let Some(weapon) = weapon {
+            let WeaponPickUpSystemData {
+                weapon_transitionses,
+                holdings,
+                held_bys,
+                target_objects,
+                chase_mode_sticks,
+                velocities,
+                sequence_ids,
+                ..
+            } = weapon_pick_up_system_data;
+
+            holdings
+                .insert(holder, Holding::new(weapon))
+                .expect("Failed to insert `Holding` component.");
+            held_bys
+                .insert(weapon, HeldBy::new(holder))
+                .expect("Failed to insert `HeldBy` component.");
+            target_objects
+                .insert(weapon, TargetObject::new(holder))
+                .expect("Failed to insert `TargetObject` component.");
+            chase_mode_sticks
+                .insert(weapon, ChaseModeStick::new(None))
+                .expect("Failed to insert `ChaseModeStick` component.");
+
+            if let Some(velocity) = velocities.get_mut(weapon) {
+                *velocity = Velocity::default();
+            }
+            if let (Some(weapon_transitions), Some(sequence_id)) = (
+                weapon_transitionses.get(weapon),
+                sequence_ids.get_mut(weapon),
+            ) {
+                *sequence_id = weapon_transitions.held;
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for WeaponPickUpSystem {
+    type SystemData = WeaponPickUpSystemData<'s>;
+
+    fn run(&mut self, mut weapon_pick_up_system_data: Self::SystemData) {
+        let control_input_event_rid = self
+            .control_input_event_rid
+            .as_mut()
+            .expect("Expected `control_input_event_rid` field to be set.");
+
+        let holders = weapon_pick_up_system_data
+            .control_input_ec
+            .read(control_input_event_rid)
+            .filter_map(|ev| match ev {
+                ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    entity,
+                    control_action: ControlAction::Attack,
+                    ..
+                }) => Some(*entity),
+                _ => None,
+            })
+            .collect::<Vec<Entity>>();
+
+        holders.into_iter().for_each(|holder| {
+            Self::pick_up(&mut weapon_pick_up_system_data, holder);
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.control_input_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ControlInputEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides the prefab types and processing logic for weapons.
+
+pub use crate::{
+    system_data::{WeaponComponentStorages, WeaponSpawningResources},
+    weapon_entity_augmenter::WeaponEntityAugmenter,
+};
+
+mod system_data;
+mod weapon_entity_augmenter;
@@ -0,0 +1,7 @@
+pub use self::{
+    weapon_component_storages::WeaponComponentStorages,
+    weapon_spawning_resources::WeaponSpawningResources,
+};
+
+mod weapon_component_storages;
+mod weapon_spawning_resources;
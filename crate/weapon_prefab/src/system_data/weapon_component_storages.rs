@@ -0,0 +1,26 @@
+use amethyst::{
+    ecs::{World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use map_model::play::MapBounded;
+use object_model::config::Mass;
+use weapon_model::loaded::WeaponTransitions;
+
+/// Weapon specific `Component` storages.
+///
+/// These are the storages for the components specific to weapon objects. See also
+/// `ObjectComponentStorages`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct WeaponComponentStorages<'s> {
+    /// `Mass` component storage.
+    #[derivative(Debug = "ignore")]
+    pub masses: WriteStorage<'s, Mass>,
+    /// `MapBounded` component storage.
+    #[derivative(Debug = "ignore")]
+    pub map_boundeds: WriteStorage<'s, MapBounded>,
+    /// `WeaponTransitions` component storage.
+    #[derivative(Debug = "ignore")]
+    pub weapon_transitionses: WriteStorage<'s, WeaponTransitions>,
+}
@@ -0,0 +1,16 @@
+use amethyst::{
+    ecs::{Read, World},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use sequence_model::loaded::AssetSequenceIdMappings;
+use weapon_model::config::WeaponSequenceName;
+
+/// Resources used to spawn weapon entities.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct WeaponSpawningResources<'s> {
+    /// `AssetSequenceIdMappings<WeaponSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_weapon: Read<'s, AssetSequenceIdMappings<WeaponSequenceName>>,
+}
@@ -0,0 +1,69 @@
+use amethyst::ecs::Entity;
+use asset_model::loaded::AssetId;
+use map_model::play::MapBounded;
+use object_model::config::Mass;
+use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+use weapon_model::{config::WeaponSequenceName, loaded::WeaponTransitions};
+
+use crate::{WeaponComponentStorages, WeaponSpawningResources};
+
+/// Default `Weapon` `Mass`.
+const WEAPON_MASS_DEFAULT: Mass = Mass(0.7);
+
+/// Augments an entity with `Weapon` components.
+#[derive(Debug)]
+pub struct WeaponEntityAugmenter;
+
+impl WeaponEntityAugmenter {
+    /// Augments an entity with `Weapon` components.
+    ///
+    /// # Parameters
+    ///
+    /// * `weapon_spawning_resources`: Resources needed to spawn the weapon.
+    /// * `weapon_component_storages`: Weapon specific `Component` storages.
+    /// * `asset_id`: Asset ID of the weapon.
+    /// * `entity`: The entity to augment.
+    pub fn augment<'s>(
+        WeaponSpawningResources {
+            asset_sequence_id_mappings_weapon,
+        }: &WeaponSpawningResources<'s>,
+        WeaponComponentStorages {
+            masses,
+            map_boundeds,
+            weapon_transitionses,
+        }: &mut WeaponComponentStorages<'s>,
+        asset_id: AssetId,
+        entity: Entity,
+    ) {
+        let sequence_id_mappings = asset_sequence_id_mappings_weapon
+            .get(asset_id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Expected `SequenceIdMappings<WeaponSequenceName>` to exist for `{:?}`.",
+                    asset_id
+                )
+            });
+        let on_ground = sequence_id_mappings
+            .id(&SequenceNameString::Name(WeaponSequenceName::OnGround))
+            .copied()
+            .unwrap_or(SequenceId(0));
+        let held = sequence_id_mappings
+            .id(&SequenceNameString::Name(WeaponSequenceName::Held))
+            .copied()
+            .unwrap_or(on_ground);
+        let thrown = sequence_id_mappings
+            .id(&SequenceNameString::Name(WeaponSequenceName::Thrown))
+            .copied()
+            .unwrap_or(on_ground);
+
+        masses
+            .insert(entity, WEAPON_MASS_DEFAULT)
+            .expect("Failed to insert `Mass` component.");
+        map_boundeds
+            .insert(entity, MapBounded::default())
+            .expect("Failed to insert `MapBounded` component.");
+        weapon_transitionses
+            .insert(entity, WeaponTransitions::new(on_ground, held, thrown))
+            .expect("Failed to insert `WeaponTransitions` component.");
+    }
+}
@@ -0,0 +1,2 @@
+mod ai_input_decider;
+mod system;
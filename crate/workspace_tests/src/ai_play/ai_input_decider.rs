@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::config::AiProfile;
+    use character_model::config::CharacterSequenceName;
+    use game_input_model::play::ControllerInput;
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+
+    use ai_play::{AiInputDecider, AiObservation};
+
+    #[test]
+    fn no_input_when_there_is_no_target() {
+        let own = observation(0., 0., false, None);
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, None);
+
+        assert_eq!(ControllerInput::default(), controller_input);
+    }
+
+    #[test]
+    fn moves_towards_target_out_of_range() {
+        let own = observation(0., 0., false, None);
+        let target = observation(-200., 100., false, None);
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(-1., 1., false, false, false, false),
+            controller_input
+        );
+    }
+
+    #[test]
+    fn attacks_target_in_range() {
+        let own = observation(0., 0., false, None);
+        let target = observation(30., 5., false, None);
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(0., 0., false, false, true, false),
+            controller_input
+        );
+    }
+
+    #[test]
+    fn releases_attack_when_attack_pressed() {
+        let mut own = observation(0., 0., false, None);
+        own.controller_input.attack = true;
+        let target = observation(30., 5., false, None);
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, Some(&target));
+
+        assert_eq!(ControllerInput::default(), controller_input);
+    }
+
+    #[test]
+    fn turns_to_face_target_in_range() {
+        let own = observation(0., 0., true, None);
+        let target = observation(30., 5., false, None);
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(1., 0., false, false, false, false),
+            controller_input
+        );
+    }
+
+    #[test]
+    fn defends_when_target_attacks_in_range() {
+        let own = observation(0., 0., false, None);
+        let target = observation(30., 5., true, Some(CharacterSequenceName::StandAttack0));
+
+        let controller_input = AiInputDecider::decide(&AiProfile::default(), &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(0., 0., true, false, false, false),
+            controller_input
+        );
+    }
+
+    #[test]
+    fn attacks_when_target_attacks_and_profile_does_not_defend() {
+        let ai_profile = AiProfile {
+            defends: false,
+            ..Default::default()
+        };
+        let own = observation(0., 0., false, None);
+        let target = observation(30., 5., true, Some(CharacterSequenceName::StandAttack0));
+
+        let controller_input = AiInputDecider::decide(&ai_profile, &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(0., 0., false, false, true, false),
+            controller_input
+        );
+    }
+
+    #[test]
+    fn retreats_from_target_when_health_is_low() {
+        let ai_profile = AiProfile {
+            retreat_health: 30,
+            ..Default::default()
+        };
+        let mut own = observation(0., 0., false, None);
+        own.health_points = HealthPoints(20);
+        let target = observation(30., 5., false, None);
+
+        let controller_input = AiInputDecider::decide(&ai_profile, &own, Some(&target));
+
+        assert_eq!(
+            ControllerInput::new(-1., -1., false, false, false, false),
+            controller_input
+        );
+    }
+
+    fn observation(
+        x: f32,
+        z: f32,
+        mirrored: bool,
+        character_sequence_name: Option<CharacterSequenceName>,
+    ) -> AiObservation {
+        AiObservation::new(
+            Position::new(x, 0., z),
+            HealthPoints(100),
+            Mirrored(mirrored),
+            character_sequence_name,
+            ControllerInput::default(),
+        )
+    }
+}
@@ -0,0 +1 @@
+mod ai_control_system;
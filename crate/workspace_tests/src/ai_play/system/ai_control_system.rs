@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use ai_model::{
+        config::{AiDifficulty, AiProfile, AiProfiles},
+        play::AiControlled,
+    };
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, ControlAction},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent},
+    };
+    use game_play_model::GamePlayStatus;
+    use kinematic_model::config::Position;
+    use object_model::play::HealthPoints;
+    use team_model::play::{IndependentCounter, Team};
+
+    use ai_play::AiControlSystem;
+
+    #[test]
+    fn sends_axis_events_towards_opponent() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                target_position: Position::new(-200., 0., 100.),
+                target_team: Team::Independent(IndependentCounter(1)),
+            },
+            |entity| {
+                vec![
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity,
+                        axis: Axis::X,
+                        value: -1.,
+                    }),
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: 1,
+                        entity,
+                        axis: Axis::Z,
+                        value: 1.,
+                    }),
+                ]
+            },
+        )
+    }
+
+    #[test]
+    fn sends_attack_event_when_opponent_in_range() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                target_position: Position::new(30., 0., 0.),
+                target_team: Team::Independent(IndependentCounter(1)),
+            },
+            |entity| {
+                vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: 1,
+                        entity,
+                        control_action: ControlAction::Attack,
+                    },
+                )]
+            },
+        )
+    }
+
+    #[test]
+    fn sends_no_events_when_there_are_no_opponents() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                target_position: Position::new(30., 0., 0.),
+                target_team: Team::Independent(IndependentCounter(0)),
+            },
+            |_| vec![],
+        )
+    }
+
+    #[test]
+    fn sends_no_events_when_game_play_is_not_playing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Paused,
+                target_position: Position::new(30., 0., 0.),
+                target_team: Team::Independent(IndependentCounter(1)),
+            },
+            |_| vec![],
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            game_play_status,
+            target_position,
+            target_team,
+        }: SetupParams,
+        control_input_events_expected_fn: fn(Entity) -> Vec<ControlInputEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(move |world| {
+                let mut ai_profiles = AiProfiles::default();
+                ai_profiles.insert(
+                    AiDifficulty::Normal,
+                    AiProfile {
+                        reaction_delay: 0,
+                        ..Default::default()
+                    },
+                );
+                world.insert(ai_profiles);
+                world.insert(game_play_status);
+            })
+            .with_system(
+                AiControlSystem::new(),
+                any::type_name::<AiControlSystem>(),
+                &[],
+            )
+            .with_effect(setup_event_reader)
+            .with_effect(move |world| {
+                let entity = world
+                    .create_entity()
+                    .with(AiControlled::new(1, AiDifficulty::Normal))
+                    .with(Position::<f32>::new(0., 0., 0.))
+                    .with(HealthPoints(100))
+                    .with(Team::Independent(IndependentCounter(0)))
+                    .build();
+                world
+                    .create_entity()
+                    .with(target_position)
+                    .with(HealthPoints(100))
+                    .with(target_team)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let control_input_events_expected = control_input_events_expected_fn(entity);
+
+                let control_input_events = {
+                    let control_input_ec = world.read_resource::<EventChannel<ControlInputEvent>>();
+                    let mut control_input_event_rid =
+                        world.write_resource::<ReaderId<ControlInputEvent>>();
+                    control_input_ec
+                        .read(&mut *control_input_event_rid)
+                        .copied()
+                        .collect::<Vec<ControlInputEvent>>()
+                };
+
+                assert_eq!(control_input_events_expected, control_input_events);
+            })
+            .run()
+    }
+
+    fn setup_event_reader(world: &mut World) {
+        let control_input_event_rid = world
+            .write_resource::<EventChannel<ControlInputEvent>>()
+            .register_reader(); // kcov-ignore
+
+        world.insert(control_input_event_rid);
+    }
+
+    struct SetupParams {
+        game_play_status: GamePlayStatus,
+        target_position: Position<f32>,
+        target_team: Team,
+    }
+}
@@ -63,6 +63,7 @@ mod test {
                 ObjectType::iter()
                     .filter(|object_type| *object_type != ObjectType::TestObject)
                     .filter(|object_type| *object_type != ObjectType::Energy)
+                    .filter(|object_type| *object_type != ObjectType::Weapon)
                     .for_each(|object_type| {
                         let objects = game_entities.objects.get(&object_type);
                         let object_entities = objects.unwrap_or_else(|| {
@@ -44,6 +44,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn returns_asset_record_for_each_weapon() -> io::Result<()> {
+        let objects_tempdir = tempdir()?;
+        let objects_dir = objects_tempdir.path();
+
+        let weapon_dir = objects_dir.join("weapon");
+        let weapon_0_dir = weapon_dir.join("weapon_0");
+        [&weapon_dir, &weapon_0_dir]
+            .iter()
+            .fold(Ok(()), |result, dir| {
+                result.and_then(|_| fs::create_dir(&dir))
+            })?;
+
+        let object_assets_records = ObjectIndexer::index("rara", &objects_dir);
+
+        // kcov-ignore-start
+        assert_that!(
+            // kcov-ignore-end
+            object_assets_records
+                .get(&AssetType::Object(ObjectType::Weapon))
+                .expect(
+                    "Expected `Vec<AssetRecord>` to exist for \
+                     `AssetType::Object(ObjectType::Weapon)`."
+                ),
+            contains(vec![asset_record("rara", "weapon_0", weapon_0_dir)]).exactly()
+        );
+
+        Ok(())
+    }
+
     fn asset_record(namespace: &str, name: &str, path: PathBuf) -> AssetRecord {
         AssetRecord {
             asset_slug: AssetSlugBuilder::default()
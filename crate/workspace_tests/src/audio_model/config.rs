@@ -0,0 +1 @@
+mod audio_settings;
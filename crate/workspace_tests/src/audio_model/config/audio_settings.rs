@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use audio_model::config::{AudioChannel, AudioSettings};
+
+    #[test]
+    fn volume_applies_master_level() {
+        let audio_settings = AudioSettings::new(0.5, 0.8, 0.4, 1.);
+
+        assert_eq!(0.5, audio_settings.volume(AudioChannel::Master));
+        assert_eq!(0.4, audio_settings.volume(AudioChannel::Sfx));
+        assert_eq!(0.2, audio_settings.volume(AudioChannel::Ui));
+        assert_eq!(0.5, audio_settings.volume(AudioChannel::Music));
+    }
+
+    #[test]
+    fn level_set_clamps_level() {
+        let mut audio_settings = AudioSettings::default();
+
+        audio_settings.level_set(AudioChannel::Sfx, 1.1);
+        audio_settings.level_set(AudioChannel::Music, -0.1);
+
+        assert_eq!(1., audio_settings.level(AudioChannel::Sfx));
+        assert_eq!(0., audio_settings.level(AudioChannel::Music));
+    }
+
+    #[test]
+    fn level_set_rounds_level_to_nearest_percent() {
+        let mut audio_settings = AudioSettings::default();
+
+        (0..3).for_each(|_| {
+            let level = audio_settings.level(AudioChannel::Ui) - AudioSettings::LEVEL_STEP;
+            audio_settings.level_set(AudioChannel::Ui, level);
+        });
+
+        assert_eq!(0.7, audio_settings.level(AudioChannel::Ui));
+    }
+
+    #[test]
+    fn deserialize_defaults_missing_levels_to_full_volume() {
+        let audio_settings = serde_yaml::from_str::<AudioSettings>("music: 0.5")
+            .expect("Failed to deserialize audio settings.");
+
+        assert_eq!(AudioSettings::new(1., 1., 1., 0.5), audio_settings);
+    }
+}
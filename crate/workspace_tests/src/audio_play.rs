@@ -1 +1,2 @@
+mod channel_volumes;
 mod system;
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::core::{math::Vector3, Transform};
+    use approx::assert_relative_eq;
+    use camera_model::play::CameraZoomDimensions;
+
+    use audio_play::{ChannelVolumes, ATTENUATION_MIN, PAN_STRENGTH};
+
+    #[test]
+    fn centred_sound_plays_at_full_volume() {
+        let channel_volumes = positional(400., 300.);
+
+        assert_eq!(ChannelVolumes::new(1., 1.), channel_volumes);
+    }
+
+    #[test]
+    fn sound_at_left_edge_pans_left() {
+        let channel_volumes = positional(0., 300.);
+
+        assert_relative_eq!(1., channel_volumes.left);
+        assert_relative_eq!(1. - PAN_STRENGTH, channel_volumes.right);
+    }
+
+    #[test]
+    fn sound_at_right_edge_pans_right() {
+        let channel_volumes = positional(800., 300.);
+
+        assert_relative_eq!(1. - PAN_STRENGTH, channel_volumes.left);
+        assert_relative_eq!(1., channel_volumes.right);
+    }
+
+    #[test]
+    fn sound_outside_view_is_attenuated() {
+        // Half way between the edge of the view and one view's length away.
+        let channel_volumes = positional(400., 750.);
+
+        let attenuation = 1. - (1. - ATTENUATION_MIN) / 2.;
+        assert_relative_eq!(attenuation, channel_volumes.left);
+        assert_relative_eq!(attenuation, channel_volumes.right);
+    }
+
+    #[test]
+    fn sound_far_outside_view_is_attenuated_to_minimum() {
+        let channel_volumes = positional(5000., 300.);
+
+        assert_relative_eq!((1. - PAN_STRENGTH) * ATTENUATION_MIN, channel_volumes.left);
+        assert_relative_eq!(ATTENUATION_MIN, channel_volumes.right);
+    }
+
+    #[test]
+    fn emitter_without_camera_plays_centred() {
+        let transform = Transform::from(Vector3::new(0., 300., 0.));
+        let channel_volumes =
+            ChannelVolumes::emitter(None, CameraZoomDimensions::default(), Some(&transform));
+
+        assert_eq!(ChannelVolumes::default(), channel_volumes);
+    }
+
+    #[test]
+    fn emitter_without_transform_plays_centred() {
+        let channel_volumes = ChannelVolumes::emitter(
+            Some(Vector3::new(400., 300., 1000.)),
+            CameraZoomDimensions::default(),
+            None,
+        );
+
+        assert_eq!(ChannelVolumes::default(), channel_volumes);
+    }
+
+    fn positional(x: f32, y: f32) -> ChannelVolumes {
+        ChannelVolumes::positional(
+            Vector3::new(400., 300., 1000.),
+            CameraZoomDimensions::default(),
+            Vector3::new(x, y, 0.),
+        )
+    }
+}
@@ -0,0 +1 @@
+mod audio_settings_event_stdin_mapper;
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use audio_model::config::AudioChannel;
+    use audio_settings_model::{AudioSettingsEvent, AudioSettingsEventArgs};
+    use stdio_spi::StdinMapper;
+
+    use audio_settings_stdio::AudioSettingsEventStdinMapper;
+
+    #[test]
+    fn maps_return_event() {
+        let args = AudioSettingsEventArgs::Return;
+
+        let result = AudioSettingsEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(AudioSettingsEvent::Return, result.unwrap())
+    }
+
+    #[test]
+    fn maps_volume_up_event() {
+        let args = AudioSettingsEventArgs::VolumeUp {
+            channel: AudioChannel::Music,
+        };
+
+        let result = AudioSettingsEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            AudioSettingsEvent::VolumeUp {
+                channel: AudioChannel::Music
+            },
+            result.unwrap()
+        )
+    }
+}
@@ -3,7 +3,10 @@ mod character_sequence;
 
 #[cfg(test)]
 mod test {
+    use std::{collections::HashMap, path::PathBuf};
+
     use charge_model::config::ChargePoints;
+    use collision_audio_model::config::{CollisionSfxId, CollisionSfxPaths};
     use collision_model::config::Body;
     use indexmap::IndexMap;
     use input_reaction_model::config::{
@@ -66,6 +69,12 @@ sequences:
     frames: []
 ";
 
+    const COLLISION_SFX_YAML: &str = "\
+sequences: {}
+collision_sfx:
+  hit_slash: 'default/sfx/slash.wav'
+";
+
     #[test]
     fn deserialize_character_definition() {
         let char_definition = serde_yaml::from_str::<CharacterDefinition>(OBJECT_YAML)
@@ -104,6 +113,27 @@ sequences:
         assert_eq!(expected, char_definition);
     }
 
+    #[test]
+    fn deserialize_character_definition_with_collision_sfx() {
+        let char_definition = serde_yaml::from_str::<CharacterDefinition>(COLLISION_SFX_YAML)
+            .expect("Failed to deserialize character definition.");
+
+        let mut collision_sfx = HashMap::new();
+        collision_sfx.insert(
+            CollisionSfxId::new(String::from("hit_slash")),
+            PathBuf::from("default/sfx/slash.wav"),
+        );
+        let object_definition = ObjectDefinition {
+            collision_sfx: CollisionSfxPaths::new(collision_sfx),
+            ..Default::default()
+        };
+        let expected = CharacterDefinition {
+            object_definition,
+            ..Default::default()
+        };
+        assert_eq!(expected, char_definition);
+    }
+
     fn stand_sequence() -> CharacterSequence {
         let frames = vec![CharacterFrame::new(
             ObjectFrame {
@@ -5,8 +5,8 @@ mod tests {
         play::ChargeTrackerClock,
     };
     use game_input_model::{
-        config::{InputDirection, InputDirectionZ},
-        play::ControllerInput,
+        config::{InputCommand, InputCommandSequence, InputDirection, InputDirectionZ},
+        play::{ControllerInput, InputHistory},
     };
     use mirrored_model::play::Mirrored;
     use object_model::play::{HealthPoints, SkillPoints};
@@ -100,6 +100,54 @@ mod tests {
         assert!(!requirement.is_met(params));
     }
 
+    #[test]
+    fn input_sequence_requirement_met_when_input_history_matches() {
+        let requirement = CharacterIrrPart::InputSequence(defend_forward_attack());
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Defend);
+        input_history.push(InputCommand::Forward);
+        input_history.push(InputCommand::Attack);
+        let params = InputReactionRequirementParams {
+            input_history: Some(&input_history),
+            ..Default::default()
+        };
+
+        assert!(requirement.is_met(params));
+    }
+
+    #[test]
+    fn input_sequence_requirement_not_met_when_input_history_does_not_match() {
+        let requirement = CharacterIrrPart::InputSequence(defend_forward_attack());
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Defend);
+        input_history.push(InputCommand::Back);
+        input_history.push(InputCommand::Attack);
+        let params = InputReactionRequirementParams {
+            input_history: Some(&input_history),
+            ..Default::default()
+        };
+
+        assert!(!requirement.is_met(params));
+    }
+
+    #[test]
+    fn input_sequence_requirement_not_met_when_no_input_history() {
+        let requirement = CharacterIrrPart::InputSequence(defend_forward_attack());
+
+        assert!(!requirement.is_met(InputReactionRequirementParams::default()));
+    }
+
+    fn defend_forward_attack() -> InputCommandSequence {
+        InputCommandSequence::new(
+            vec![
+                InputCommand::Defend,
+                InputCommand::Forward,
+                InputCommand::Attack,
+            ],
+            20,
+        )
+    }
+
     macro_rules! input_x_test {
         ($test_name:ident, $variant:ident, $controller_input:expr, $mirrored:expr, true $(,)?) => {
             #[test]
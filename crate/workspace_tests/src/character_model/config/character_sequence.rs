@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use game_input_model::config::{InputCommand, InputCommandSequence};
     use input_reaction_model::config::{
         InputReaction, InputReactionAppEvents, InputReactionSingle,
     };
@@ -9,7 +10,7 @@ mod tests {
     use sprite_model::config::SpriteRef;
 
     use character_model::config::{
-        CharacterFrame, CharacterInputReactions, CharacterIrr, CharacterSequence,
+        CharacterFrame, CharacterInputReactions, CharacterIrr, CharacterIrrPart, CharacterSequence,
         CharacterSequenceName,
     };
 
@@ -24,6 +25,15 @@ frames:
     input_reactions:
       press_attack: "stand_attack_0"
       hold_jump: { next: "jump" }
+"#;
+    const SEQUENCE_WITH_INPUT_SEQUENCE_REQUIREMENT: &str = r#"---
+input_reactions:
+  press_attack:
+    next: "stand_attack_1"
+    requirement:
+      - input_sequence: { commands: [defend, forward, attack], window: 15 }
+
+frames: []
 "#;
 
     #[test]
@@ -77,4 +87,34 @@ frames:
 
         assert_eq!(expected, sequence);
     }
+
+    #[test]
+    fn sequence_with_input_sequence_requirement() {
+        let sequence =
+            serde_yaml::from_str::<CharacterSequence>(SEQUENCE_WITH_INPUT_SEQUENCE_REQUIREMENT)
+                .expect("Failed to deserialize sequence.");
+
+        let requirement = CharacterIrr::new(vec![CharacterIrrPart::InputSequence(
+            InputCommandSequence::new(
+                vec![
+                    InputCommand::Defend,
+                    InputCommand::Forward,
+                    InputCommand::Attack,
+                ],
+                15,
+            ),
+        )]);
+        let character_input_reactions = CharacterInputReactions {
+            press_attack: Some(InputReaction::Single(InputReactionSingle {
+                next: SequenceNameString::Name(CharacterSequenceName::StandAttack1),
+                events: InputReactionAppEvents::default(),
+                requirement,
+            })),
+            ..Default::default()
+        };
+        let expected =
+            CharacterSequence::new(ObjectSequence::default(), Some(character_input_reactions));
+
+        assert_eq!(expected, sequence);
+    }
 }
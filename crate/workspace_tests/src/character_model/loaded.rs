@@ -0,0 +1 @@
+mod character_hit_transitions;
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use character_model::loaded::CharacterHitTransitions;
+    use sequence_model::loaded::SequenceId;
+
+    #[test]
+    fn is_defending_when_sequence_is_defend_or_defend_hit() {
+        let character_hit_transitions = character_hit_transitions(Some(SequenceId::new(5)));
+
+        assert!(character_hit_transitions.is_defending(SequenceId::new(5)));
+        assert!(character_hit_transitions.is_defending(SequenceId::new(6)));
+        assert!(!character_hit_transitions.is_defending(SequenceId::new(0)));
+    }
+
+    #[test]
+    fn is_not_defending_when_character_has_no_defend_sequence() {
+        let character_hit_transitions = character_hit_transitions(None);
+
+        assert!(!character_hit_transitions.is_defending(SequenceId::new(6)));
+    }
+
+    fn character_hit_transitions(defend: Option<SequenceId>) -> CharacterHitTransitions {
+        CharacterHitTransitions::new(
+            SequenceId::new(1),
+            SequenceId::new(2),
+            SequenceId::new(3),
+            SequenceId::new(4),
+            defend,
+            SequenceId::new(6),
+            SequenceId::new(7),
+        )
+    }
+}
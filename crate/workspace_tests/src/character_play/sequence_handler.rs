@@ -1,5 +1,6 @@
 mod common;
 mod dash_attack;
+mod defend;
 mod dodge;
 mod jump;
 mod jump_attack;
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test {
+    use character_model::{config::CharacterSequenceName, play::RunCounter};
+    use game_input_model::play::ControllerInput;
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::{Grounding, HealthPoints};
+    use sequence_model::play::SequenceStatus;
+
+    use character_play::{
+        sequence_handler::{CharacterSequenceHandler, Defend},
+        CharacterSequenceUpdateComponents,
+    };
+
+    #[test]
+    fn no_update_when_defend_held() {
+        let input = ControllerInput::new(0., 0., true, false, false, false);
+
+        assert_eq!(
+            None,
+            update(&input, CharacterSequenceName::Defend, SequenceStatus::End)
+        );
+    }
+
+    #[test]
+    fn reverts_to_stand_when_defend_released() {
+        let input = ControllerInput::new(0., 0., false, false, false, false);
+
+        assert_eq!(
+            Some(CharacterSequenceName::Stand),
+            update(
+                &input,
+                CharacterSequenceName::Defend,
+                SequenceStatus::Ongoing
+            )
+        );
+    }
+
+    #[test]
+    fn no_update_when_defend_hit_not_ended() {
+        let input = ControllerInput::new(0., 0., false, false, false, false);
+
+        assert_eq!(
+            None,
+            update(
+                &input,
+                CharacterSequenceName::DefendHit,
+                SequenceStatus::Ongoing
+            )
+        );
+    }
+
+    #[test]
+    fn reverts_to_defend_when_defend_hit_ended_and_defend_held() {
+        let input = ControllerInput::new(0., 0., true, false, false, false);
+
+        assert_eq!(
+            Some(CharacterSequenceName::Defend),
+            update(
+                &input,
+                CharacterSequenceName::DefendHit,
+                SequenceStatus::End
+            )
+        );
+    }
+
+    fn update(
+        input: &ControllerInput,
+        character_sequence_name: CharacterSequenceName,
+        sequence_status: SequenceStatus,
+    ) -> Option<CharacterSequenceName> {
+        Defend::update(CharacterSequenceUpdateComponents::new(
+            input,
+            HealthPoints::default(),
+            character_sequence_name,
+            sequence_status,
+            &Position::default(),
+            &Velocity::default(),
+            Mirrored::default(),
+            Grounding::OnGround,
+            RunCounter::default(),
+        ))
+    }
+}
@@ -2,6 +2,7 @@
 mod tests {
     use std::{any, str::FromStr};
 
+    use ai_model::config::AiDifficulty;
     use amethyst::{
         core::TransformBundle,
         ecs::{World, WorldExt},
@@ -20,6 +21,7 @@ mod tests {
     use character_selection_model::CharacterSelections;
     use game_input_model::config::ControlBindings;
     use object_type::ObjectType;
+    use team_model::play::TeamCounter;
 
     use character_selection::CharacterSelectionSystem;
 
@@ -73,6 +75,78 @@ mod tests {
         )
     }
 
+    #[test]
+    fn cycles_cpu_difficulty_on_cpu_event() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| send_event(world, cpu_event()))
+            .with_assertion(|world| assert_cpu_difficulty(world, Some(AiDifficulty::Easy)))
+            .with_effect(|world| send_event(world, cpu_event()))
+            .with_assertion(|world| assert_cpu_difficulty(world, Some(AiDifficulty::Normal)))
+            .with_effect(|world| send_event(world, cpu_event()))
+            .with_assertion(|world| assert_cpu_difficulty(world, Some(AiDifficulty::Hard)))
+            .with_effect(|world| send_event(world, cpu_event()))
+            .with_assertion(|world| assert_cpu_difficulty(world, None))
+            .run_isolated()
+    }
+
+    fn cpu_event() -> AssetSelectionEvent {
+        AssetSelectionEvent::Cpu {
+            entity: None,
+            controller_id: 123,
+        }
+    }
+
+    fn assert_cpu_difficulty(world: &mut World, ai_difficulty_expected: Option<AiDifficulty>) {
+        let character_selections = world.read_resource::<CharacterSelections>();
+
+        assert_eq!(
+            ai_difficulty_expected,
+            character_selections.cpu_difficulties.get(&123).copied()
+        );
+    }
+
+    #[test]
+    fn cycles_team_on_team_event() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| send_event(world, team_event()))
+            .with_assertion(|world| assert_team(world, Some(TeamCounter::new(1))))
+            .with_effect(|world| send_event(world, team_event()))
+            .with_assertion(|world| assert_team(world, Some(TeamCounter::new(2))))
+            .with_effect(|world| send_event(world, team_event()))
+            .with_assertion(|world| assert_team(world, Some(TeamCounter::new(3))))
+            .with_effect(|world| send_event(world, team_event()))
+            .with_assertion(|world| assert_team(world, Some(TeamCounter::new(4))))
+            .with_effect(|world| send_event(world, team_event()))
+            .with_assertion(|world| assert_team(world, None))
+            .run_isolated()
+    }
+
+    fn team_event() -> AssetSelectionEvent {
+        AssetSelectionEvent::Team {
+            entity: None,
+            controller_id: 123,
+        }
+    }
+
+    fn assert_team(world: &mut World, team_counter_expected: Option<TeamCounter>) {
+        let character_selections = world.read_resource::<CharacterSelections>();
+
+        assert_eq!(
+            team_counter_expected,
+            character_selections.teams.get(&123).copied()
+        );
+    }
+
     fn run_test(
         SetupParams {
             with_character_selection_initial,
@@ -6,6 +6,7 @@ mod tests {
         Error,
     };
     use application_test_support::AutexousiousApplication;
+    use collision_audio_model::config::CollisionSfxId;
     use collision_model::{
         config::{Hit, Interaction, InteractionKind},
         play::HitEvent,
@@ -22,7 +23,23 @@ mod tests {
                 let entity_from = world.create_entity().build();
                 let entity_to = world.create_entity().build();
 
-                let event = HitEvent::new(entity_from, entity_to, interaction(), body());
+                let event = HitEvent::new(entity_from, entity_to, interaction(None), body());
+                send_event(world, event);
+            })
+            .with_assertion(|_world| {})
+            .run_isolated()
+    }
+
+    #[test]
+    fn plays_sound_on_hit_event_with_unknown_sfx() -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(HitSfxSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().build();
+
+                let sfx = CollisionSfxId::new(String::from("unknown"));
+                let event = HitEvent::new(entity_from, entity_to, interaction(Some(sfx)), body());
                 send_event(world, event);
             })
             .with_assertion(|_world| {})
@@ -34,8 +51,12 @@ mod tests {
         ec.single_write(event)
     } // kcov-ignore
 
-    fn interaction() -> Interaction {
-        Interaction::new(InteractionKind::Hit(Hit::default()), vec![], true)
+    fn interaction(sfx: Option<CollisionSfxId>) -> Interaction {
+        let hit = Hit {
+            sfx,
+            ..Default::default()
+        };
+        Interaction::new(InteractionKind::Hit(hit), vec![], true)
     }
 
     fn body() -> Volume {
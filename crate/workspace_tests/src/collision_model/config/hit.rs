@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod test {
+    use collision_audio_model::config::CollisionSfxId;
     use kinematic_model::config::Acceleration;
     use object_status_model::config::StunPoints;
     use serde_yaml;
@@ -27,8 +28,22 @@ acceleration: { x: -1, y: 2 }
             4,
             StunPoints::new(5),
             Acceleration::new(-1, 2, 0),
+            None,
         );
 
         assert_eq!(expected, hit_deserialized);
     }
+
+    #[test]
+    fn deserialize_hit_with_sfx() {
+        let hit_deserialized =
+            serde_yaml::from_str::<Hit>("sfx: hit_slash").expect("Failed to deserialize `Hit`.");
+
+        let expected = Hit {
+            sfx: Some(CollisionSfxId::new(String::from("hit_slash"))),
+            ..Default::default()
+        };
+
+        assert_eq!(expected, hit_deserialized);
+    }
 }
@@ -1,13 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use kinematic_model::config::Acceleration;
+    use kinematic_model::config::{Acceleration, Position};
     use object_status_model::config::StunPoints;
     use serde::Deserialize;
     use serde_yaml;
     use shape_model::Volume;
 
     use collision_model::config::{
-        Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind, Interactions,
+        Grab, Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind, Interactions, Throw,
     };
 
     const ITR_PHYSICAL_ALL_SPECIFIED: &str = r#"---
@@ -30,6 +30,17 @@ interactions:
 interactions:
   - { hit: { hit_limit: 2 }, bounds: [{ sphere: { x: 1, y: 1, r: 1 } }] }
 "#;
+    const ITR_GRAB: &str = r#"---
+interactions:
+  - { grab: { offset: { x: 20, z: 1 } }, bounds: [{ sphere: { x: 1, y: 1, r: 1 } }] }
+"#;
+    const ITR_THROW: &str = r#"---
+interactions:
+  - throw:
+      hp_damage: 30
+      acceleration: { x: 8, y: 10 }
+    bounds: [{ sphere: { x: 1, y: 1, r: 1 } }]
+"#;
 
     #[test]
     fn itr_physical_specify_all_fields() {
@@ -44,6 +55,7 @@ interactions:
                 sp_damage: 50,
                 stun: StunPoints::new(33),
                 acceleration: Acceleration::new(-1, 2, 0),
+                sfx: None,
             }),
             bounds: vec![Volume::Sphere {
                 x: 1,
@@ -95,6 +107,42 @@ interactions:
         assert_eq!(Interactions::new(interactions), frame.interactions);
     }
 
+    #[test]
+    fn itr_grab() {
+        let frame = serde_yaml::from_str::<InteractionsFrame>(ITR_GRAB)
+            .expect("Failed to deserialize frame.");
+
+        let interactions = vec![Interaction {
+            bounds: vec![Volume::Sphere {
+                x: 1,
+                y: 1,
+                z: 0,
+                r: 1,
+            }],
+            kind: InteractionKind::Grab(Grab::new(Position::new(20, 0, 1))),
+            multiple: Default::default(),
+        }]; // kcov-ignore
+        assert_eq!(Interactions::new(interactions), frame.interactions);
+    }
+
+    #[test]
+    fn itr_throw() {
+        let frame = serde_yaml::from_str::<InteractionsFrame>(ITR_THROW)
+            .expect("Failed to deserialize frame.");
+
+        let interactions = vec![Interaction {
+            bounds: vec![Volume::Sphere {
+                x: 1,
+                y: 1,
+                z: 0,
+                r: 1,
+            }],
+            kind: InteractionKind::Throw(Throw::new(30, Acceleration::new(8, 10, 0))),
+            multiple: Default::default(),
+        }]; // kcov-ignore
+        assert_eq!(Interactions::new(interactions), frame.interactions);
+    }
+
     /// Needed because the YAML deserializer does not support deserializing values directly.
     #[derive(Debug, Deserialize)]
     struct InteractionsFrame {
@@ -1 +1,3 @@
+mod sweep_and_prune;
 mod system;
+mod volume_intersector;
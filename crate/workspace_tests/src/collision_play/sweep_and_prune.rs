@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use collision_play::{SweepAndPrune, SweepBounds};
+
+    #[test]
+    fn pairs_interactions_with_overlapping_bodies() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.interaction_bounds_insert(SweepBounds::new(0, 0., 10.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(1, 5., 15.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(2, -10., -1.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(3, 11., 20.));
+
+        let mut candidate_pairs = Vec::new();
+        sweep_and_prune.candidate_pairs(&mut candidate_pairs);
+
+        assert_eq!(vec![(0, 1)], candidate_pairs);
+    }
+
+    #[test]
+    fn includes_bodies_that_touch_interaction_bounds() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.interaction_bounds_insert(SweepBounds::new(0, 0., 10.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(1, -10., 0.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(2, 10., 20.));
+
+        let mut candidate_pairs = Vec::new();
+        sweep_and_prune.candidate_pairs(&mut candidate_pairs);
+
+        assert_eq!(
+            vec![(0, 1), (0, 2)],
+            candidate_pairs_sorted(candidate_pairs)
+        );
+    }
+
+    #[test]
+    fn does_not_pair_object_with_itself() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.interaction_bounds_insert(SweepBounds::new(0, 0., 10.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(0, 0., 10.));
+        sweep_and_prune.interaction_bounds_insert(SweepBounds::new(1, 5., 15.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(1, 5., 15.));
+
+        let mut candidate_pairs = Vec::new();
+        sweep_and_prune.candidate_pairs(&mut candidate_pairs);
+
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            candidate_pairs_sorted(candidate_pairs)
+        );
+    }
+
+    #[test]
+    fn clear_removes_all_bounds() {
+        let mut sweep_and_prune = SweepAndPrune::new();
+        sweep_and_prune.interaction_bounds_insert(SweepBounds::new(0, 0., 10.));
+        sweep_and_prune.body_bounds_insert(SweepBounds::new(1, 0., 10.));
+        sweep_and_prune.clear();
+
+        let mut candidate_pairs = Vec::new();
+        sweep_and_prune.candidate_pairs(&mut candidate_pairs);
+
+        assert!(candidate_pairs.is_empty());
+    }
+
+    fn candidate_pairs_sorted(mut candidate_pairs: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+        candidate_pairs.sort();
+        candidate_pairs
+    }
+}
@@ -7,13 +7,13 @@ mod tests {
     };
     use amethyst_test::AmethystApplication;
     use collision_model::{
-        config::{Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind},
+        config::{Grab, Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind, Throw},
         play::{CollisionEvent, ContactEvent},
     };
     use pretty_assertions::assert_eq;
     use shape_model::Volume;
     use spawn_model::play::SpawnParent;
-    use team_model::play::{IndependentCounter, Team};
+    use team_model::play::{FriendlyFire, IndependentCounter, Team};
 
     use collision_play::ContactDetectionSystem;
 
@@ -24,7 +24,9 @@ mod tests {
         run_test(
             SpawnParentVariant::NoSpawnParent,
             TeamsVariant::NoTeam,
-            |entity_from, entity_to| vec![contact_event(entity_from, entity_to)],
+            FriendlyFire(false),
+            hit(),
+            |entity_from, entity_to| vec![contact_event(entity_from, entity_to, hit())],
         )
     }
 
@@ -33,6 +35,8 @@ mod tests {
         run_test(
             SpawnParentVariant::HitterEntityIsSpawnParent,
             TeamsVariant::NoTeam,
+            FriendlyFire(false),
+            hit(),
             |_, _| vec![],
         )
     }
@@ -42,6 +46,8 @@ mod tests {
         run_test(
             SpawnParentVariant::HitEntityIsSpawnParent,
             TeamsVariant::NoTeam,
+            FriendlyFire(false),
+            hit(),
             |_, _| vec![],
         )
     }
@@ -51,7 +57,9 @@ mod tests {
         run_test(
             SpawnParentVariant::NoSpawnParent,
             TeamsVariant::DifferentTeam,
-            |entity_from, entity_to| vec![contact_event(entity_from, entity_to)],
+            FriendlyFire(false),
+            hit(),
+            |entity_from, entity_to| vec![contact_event(entity_from, entity_to, hit())],
         )
     }
 
@@ -60,16 +68,74 @@ mod tests {
         run_test(
             SpawnParentVariant::NoSpawnParent,
             TeamsVariant::SameTeam,
+            FriendlyFire(false),
+            hit(),
             |_, _| vec![],
         )
     }
 
+    #[test]
+    fn inserts_contact_event_when_entities_on_same_team_with_friendly_fire() -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::NoSpawnParent,
+            TeamsVariant::SameTeam,
+            FriendlyFire(true),
+            hit(),
+            |entity_from, entity_to| vec![contact_event(entity_from, entity_to, hit())],
+        )
+    }
+
+    #[test]
+    fn does_not_insert_grab_contact_event_when_entities_on_same_team_with_friendly_fire(
+    ) -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::NoSpawnParent,
+            TeamsVariant::SameTeam,
+            FriendlyFire(true),
+            InteractionKind::Grab(Grab::default()),
+            |_, _| vec![],
+        )
+    }
+
+    #[test]
+    fn does_not_insert_throw_contact_event_when_entities_on_same_team_with_friendly_fire(
+    ) -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::NoSpawnParent,
+            TeamsVariant::SameTeam,
+            FriendlyFire(true),
+            InteractionKind::Throw(Throw::default()),
+            |_, _| vec![],
+        )
+    }
+
+    #[test]
+    fn inserts_grab_contact_event_when_entities_on_different_teams_with_friendly_fire(
+    ) -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::NoSpawnParent,
+            TeamsVariant::DifferentTeam,
+            FriendlyFire(true),
+            InteractionKind::Grab(Grab::default()),
+            |entity_from, entity_to| {
+                vec![contact_event(
+                    entity_from,
+                    entity_to,
+                    InteractionKind::Grab(Grab::default()),
+                )]
+            },
+        )
+    }
+
     fn run_test(
         spawn_parent_variant: SpawnParentVariant,
         teams_variant: TeamsVariant,
+        friendly_fire: FriendlyFire,
+        interaction_kind: InteractionKind,
         events_expected_fn: fn(Entity, Entity) -> Vec<ContactEvent>,
     ) -> Result<(), Error> {
         AmethystApplication::blank()
+            .with_resource(friendly_fire)
             .with_system(ContactDetectionSystem::new(), "", &[])
             .with_effect(setup_event_reader)
             .with_effect(move |world| {
@@ -114,7 +180,10 @@ mod tests {
                     TeamsVariant::NoTeam => {}
                 }
 
-                send_event(world, collision_event(entity_from, entity_to));
+                send_event(
+                    world,
+                    collision_event(entity_from, entity_to, interaction_kind.clone()),
+                );
 
                 world.insert((entity_from, entity_to));
             })
@@ -139,34 +208,42 @@ mod tests {
         ec.single_write(event)
     } // kcov-ignore
 
-    fn collision_event(entity_from: Entity, entity_to: Entity) -> CollisionEvent {
+    fn collision_event(
+        entity_from: Entity,
+        entity_to: Entity,
+        interaction_kind: InteractionKind,
+    ) -> CollisionEvent {
         CollisionEvent::new(
             entity_from,
             entity_to,
-            interaction(HitLimit::Limit(HIT_LIMIT)),
+            interaction(interaction_kind),
             body(),
         )
     }
 
-    fn contact_event(entity_from: Entity, entity_to: Entity) -> ContactEvent {
+    fn contact_event(
+        entity_from: Entity,
+        entity_to: Entity,
+        interaction_kind: InteractionKind,
+    ) -> ContactEvent {
         ContactEvent::new(
             entity_from,
             entity_to,
-            interaction(HitLimit::Limit(HIT_LIMIT)),
+            interaction(interaction_kind),
             body(),
         )
     }
 
-    fn interaction(hit_limit: HitLimit) -> Interaction {
-        Interaction::new(
-            InteractionKind::Hit(Hit {
-                repeat_delay: HitRepeatDelay::new(4),
-                hit_limit,
-                ..Default::default()
-            }),
-            vec![],
-            true,
-        )
+    fn hit() -> InteractionKind {
+        InteractionKind::Hit(Hit {
+            repeat_delay: HitRepeatDelay::new(4),
+            hit_limit: HitLimit::Limit(HIT_LIMIT),
+            ..Default::default()
+        })
+    }
+
+    fn interaction(interaction_kind: InteractionKind) -> Interaction {
+        Interaction::new(interaction_kind, vec![], true)
     }
 
     fn body() -> Volume {
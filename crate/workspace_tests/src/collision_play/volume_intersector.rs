@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::core::math::Vector3;
+    use shape_model::{Axis, Volume};
+
+    use collision_play::VolumeIntersector;
+
+    const OFFSETS: [f32; 2] = [0., 0.];
+
+    #[test]
+    fn sphere_intersects_sphere_within_radii() {
+        let sphere = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 10,
+        };
+
+        assert!(intersects(Vector3::new(19., 0., 0.), &sphere, &sphere));
+        assert!(!intersects(Vector3::new(21., 0., 0.), &sphere, &sphere));
+    }
+
+    #[test]
+    fn box_intersects_box_contained_within_it() {
+        let volume_box_large = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 100,
+            h: 100,
+            d: 100,
+        };
+        let volume_box_small = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 10,
+            h: 10,
+            d: 10,
+        };
+
+        assert!(intersects(
+            Vector3::new(40., 40., 40.),
+            &volume_box_large,
+            &volume_box_small
+        ));
+        assert!(intersects(
+            Vector3::new(-40., -40., -40.),
+            &volume_box_small,
+            &volume_box_large
+        ));
+        assert!(!intersects(
+            Vector3::new(101., 40., 40.),
+            &volume_box_large,
+            &volume_box_small
+        ));
+    }
+
+    #[test]
+    fn box_intersects_sphere() {
+        let volume_box = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 10,
+            h: 10,
+            d: 10,
+        };
+        let sphere = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 5,
+            r: 5,
+        };
+
+        assert!(intersects(Vector3::new(14., 5., 0.), &volume_box, &sphere));
+        assert!(!intersects(Vector3::new(16., 5., 0.), &volume_box, &sphere));
+        assert!(intersects(Vector3::new(-14., -5., 0.), &sphere, &volume_box));
+    }
+
+    #[test]
+    fn mirrored_sphere_is_negated_along_x_axis() {
+        let sphere = Volume::Sphere {
+            x: 20,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+        let sphere_origin = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+        let relative_pos = Vector3::new(-20., 0., 0.);
+
+        assert!(!VolumeIntersector::intersects(
+            &relative_pos,
+            (&sphere, OFFSETS, false),
+            (&sphere_origin, OFFSETS, false),
+        ));
+        assert!(VolumeIntersector::intersects(
+            &relative_pos,
+            (&sphere, OFFSETS, true),
+            (&sphere_origin, OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn sprite_offsets_shift_sphere() {
+        let sphere = Volume::Sphere {
+            x: 30,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+        let sphere_origin = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+        let relative_pos = Vector3::new(0., 0., 0.);
+
+        assert!(!VolumeIntersector::intersects(
+            &relative_pos,
+            (&sphere, OFFSETS, false),
+            (&sphere_origin, OFFSETS, false),
+        ));
+        assert!(VolumeIntersector::intersects(
+            &relative_pos,
+            (&sphere, [30., 0.], false),
+            (&sphere_origin, OFFSETS, false),
+        ));
+    }
+
+    #[test]
+    fn cylinder_intersects_box_when_axial_and_radial_overlap() {
+        let cylinder = Volume::Cylinder {
+            axis: Axis::X,
+            center: 0,
+            r: 5,
+            l: 20,
+        };
+        let volume_box = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 10,
+            h: 10,
+            d: 10,
+        };
+
+        assert!(intersects(Vector3::new(9., 0., 0.), &cylinder, &volume_box));
+        assert!(!intersects(
+            Vector3::new(11., 0., 0.),
+            &cylinder,
+            &volume_box
+        ));
+        assert!(!intersects(
+            Vector3::new(0., 6., 0.),
+            &cylinder,
+            &volume_box
+        ));
+    }
+
+    #[test]
+    fn cylinder_intersects_sphere() {
+        let cylinder = Volume::Cylinder {
+            axis: Axis::Z,
+            center: 0,
+            r: 5,
+            l: 20,
+        };
+        let sphere = Volume::Sphere {
+            x: 0,
+            y: 0,
+            z: 0,
+            r: 5,
+        };
+
+        assert!(intersects(Vector3::new(9., 0., 10.), &cylinder, &sphere));
+        assert!(!intersects(Vector3::new(9., 0., 16.), &cylinder, &sphere));
+        assert!(intersects(Vector3::new(-9., 0., -10.), &sphere, &cylinder));
+    }
+
+    #[test]
+    fn cylinder_intersects_cylinder_with_same_axis() {
+        let cylinder = Volume::Cylinder {
+            axis: Axis::X,
+            center: 0,
+            r: 5,
+            l: 20,
+        };
+
+        assert!(intersects(Vector3::new(15., 9., 0.), &cylinder, &cylinder));
+        assert!(!intersects(
+            Vector3::new(15., 11., 0.),
+            &cylinder,
+            &cylinder
+        ));
+        assert!(!intersects(Vector3::new(21., 0., 0.), &cylinder, &cylinder));
+    }
+
+    #[test]
+    fn cylinder_intersects_cylinder_with_perpendicular_axis() {
+        let cylinder_x = Volume::Cylinder {
+            axis: Axis::X,
+            center: 0,
+            r: 5,
+            l: 20,
+        };
+        let cylinder_y = Volume::Cylinder {
+            axis: Axis::Y,
+            center: 0,
+            r: 5,
+            l: 20,
+        };
+
+        assert!(intersects(
+            Vector3::new(14., 0., 0.),
+            &cylinder_x,
+            &cylinder_y
+        ));
+        assert!(intersects(
+            Vector3::new(0., 0., 9.),
+            &cylinder_x,
+            &cylinder_y
+        ));
+        assert!(!intersects(
+            Vector3::new(0., 0., 11.),
+            &cylinder_x,
+            &cylinder_y
+        ));
+        assert!(!intersects(
+            Vector3::new(16., 0., 0.),
+            &cylinder_x,
+            &cylinder_y
+        ));
+    }
+
+    #[test]
+    fn x_range_accounts_for_mirroring() {
+        let volume_box = Volume::Box {
+            x: 10,
+            y: 0,
+            z: 0,
+            w: 20,
+            h: 10,
+            d: 10,
+        };
+
+        assert_eq!(
+            (10., 30.),
+            VolumeIntersector::x_range(&volume_box, 0., false)
+        );
+        assert_eq!(
+            (-30., -10.),
+            VolumeIntersector::x_range(&volume_box, 0., true)
+        );
+    }
+
+    fn intersects(relative_pos: Vector3<f32>, interaction: &Volume, body: &Volume) -> bool {
+        VolumeIntersector::intersects(
+            &relative_pos,
+            (interaction, OFFSETS, false),
+            (body, OFFSETS, false),
+        )
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use desync_model::play::{EntityChecksum, GameChecksum, GameChecksums, GameDesync};
+    use network_input_model::play::FrameNumber;
+    use network_session_model::play::SessionDeviceId;
+
+    #[test]
+    fn desync_detect_returns_none_when_checksums_match() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10), (1, 11)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 10), (1, 11)]));
+
+        assert_eq!(None, game_checksums.desync_detect(1));
+        assert_eq!(None, game_checksums.game_desync());
+    }
+
+    #[test]
+    fn desync_detect_returns_none_until_local_checksum_is_calculated() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 10), (1, 99)]));
+
+        assert_eq!(None, game_checksums.desync_detect(1));
+
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10), (1, 11)]));
+
+        assert_eq!(
+            Some(GameDesync::new(
+                FrameNumber::new(60),
+                SessionDeviceId::new(2),
+                1
+            )),
+            game_checksums.desync_detect(1)
+        );
+    }
+
+    #[test]
+    fn desync_detect_returns_first_divergent_frame_and_entity() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10), (1, 11)]));
+        game_checksums.insert_local(FrameNumber::new(120), entity_checksums(&[(0, 20), (1, 21)]));
+        game_checksums.insert_remote(game_checksum(2, 120, &[(0, 99), (1, 99)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 10), (1, 99)]));
+
+        let game_desync = Some(GameDesync::new(
+            FrameNumber::new(60),
+            SessionDeviceId::new(2),
+            1,
+        ));
+        assert_eq!(game_desync, game_checksums.desync_detect(1));
+        assert_eq!(game_desync, game_checksums.game_desync());
+    }
+
+    #[test]
+    fn desync_detect_returns_entity_when_entity_count_differs() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 10), (1, 11)]));
+
+        assert_eq!(
+            Some(GameDesync::new(
+                FrameNumber::new(60),
+                SessionDeviceId::new(2),
+                1
+            )),
+            game_checksums.desync_detect(1)
+        );
+    }
+
+    #[test]
+    fn desync_detect_returns_entity_missing_on_one_device() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10), (2, 12)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 10), (1, 11), (2, 12)]));
+
+        assert_eq!(
+            Some(GameDesync::new(
+                FrameNumber::new(60),
+                SessionDeviceId::new(2),
+                1
+            )),
+            game_checksums.desync_detect(1)
+        );
+    }
+
+    #[test]
+    fn desync_detect_pairs_checksums_by_entity_id() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10), (1, 11)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(1, 11), (0, 10)]));
+
+        assert_eq!(None, game_checksums.desync_detect(1));
+    }
+
+    #[test]
+    fn desync_detect_returns_none_after_first_desync() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10)]));
+        game_checksums.insert_local(FrameNumber::new(120), entity_checksums(&[(0, 20)]));
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 99)]));
+        game_checksums.desync_detect(1);
+
+        game_checksums.insert_remote(game_checksum(2, 120, &[(0, 99)]));
+
+        assert_eq!(None, game_checksums.desync_detect(1));
+        assert_eq!(
+            Some(GameDesync::new(
+                FrameNumber::new(60),
+                SessionDeviceId::new(2),
+                0
+            )),
+            game_checksums.game_desync()
+        );
+    }
+
+    #[test]
+    fn desync_detect_discards_local_checksums_compared_with_every_device() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10)]));
+        game_checksums.insert_local(FrameNumber::new(120), entity_checksums(&[(0, 20)]));
+        game_checksums.insert_local(FrameNumber::new(180), entity_checksums(&[(0, 30)]));
+        game_checksums.insert_remote(game_checksum(2, 120, &[(0, 20)]));
+
+        assert_eq!(None, game_checksums.desync_detect(2));
+        assert_eq!(3, game_checksums.local_len());
+
+        game_checksums.insert_remote(game_checksum(3, 120, &[(0, 20)]));
+
+        assert_eq!(None, game_checksums.desync_detect(2));
+        assert_eq!(1, game_checksums.local_len());
+    }
+
+    #[test]
+    fn insert_remote_discards_checksums_for_frames_already_compared() {
+        let mut game_checksums = GameChecksums::new();
+        game_checksums.insert_local(FrameNumber::new(60), entity_checksums(&[(0, 10)]));
+        game_checksums.insert_local(FrameNumber::new(120), entity_checksums(&[(0, 20)]));
+        game_checksums.insert_remote(game_checksum(2, 120, &[(0, 20)]));
+        game_checksums.desync_detect(1);
+
+        game_checksums.insert_remote(game_checksum(2, 60, &[(0, 99)]));
+
+        assert_eq!(None, game_checksums.desync_detect(1));
+        assert_eq!(None, game_checksums.game_desync());
+        assert_eq!(0, game_checksums.local_len());
+    }
+
+    fn game_checksum(
+        session_device_id: u64,
+        frame_number: u64,
+        checksums: &[(u32, u64)],
+    ) -> GameChecksum {
+        GameChecksum::new(
+            SessionDeviceId::new(session_device_id),
+            FrameNumber::new(frame_number),
+            entity_checksums(checksums),
+        )
+    }
+
+    fn entity_checksums(checksums: &[(u32, u64)]) -> Vec<EntityChecksum> {
+        checksums
+            .iter()
+            .map(|(entity_id, checksum)| EntityChecksum::new(*entity_id, *checksum))
+            .collect::<Vec<EntityChecksum>>()
+    }
+}
@@ -0,0 +1 @@
+mod game_checksum_recorder;
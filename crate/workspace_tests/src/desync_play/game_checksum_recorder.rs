@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, SystemData, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use desync_model::play::EntityChecksum;
+    use game_model::play::GameEntityId;
+    use network_input_model::play::FrameNumber;
+    use object_model::play::HealthPoints;
+    use sequence_model::loaded::SequenceId;
+
+    use desync_play::{GameChecksumRecorder, GameChecksumResources};
+
+    #[test]
+    fn is_checksum_frame_returns_true_for_multiples_of_interval() {
+        assert!(GameChecksumRecorder::is_checksum_frame(
+            FrameNumber::new(0),
+            60
+        ));
+        assert!(GameChecksumRecorder::is_checksum_frame(
+            FrameNumber::new(120),
+            60
+        ));
+        assert!(!GameChecksumRecorder::is_checksum_frame(
+            FrameNumber::new(61),
+            60
+        ));
+        assert!(!GameChecksumRecorder::is_checksum_frame(
+            FrameNumber::new(0),
+            0
+        ));
+    }
+
+    #[test]
+    fn calculate_returns_different_checksum_when_components_differ() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GameChecksumResources as SystemData>::setup)
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(GameEntityId::new(3))
+                    .with(SequenceId::new(1))
+                    .with(HealthPoints::new(100))
+                    .build();
+                // Not game play entities.
+                world.create_entity().with(HealthPoints::new(100)).build();
+                world.create_entity().with(SequenceId::new(1)).build();
+                world.insert(entity);
+
+                let entity_checksums = GameChecksumRecorder::calculate(&world.system_data());
+                world.insert(entity_checksums);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<HealthPoints>()
+                    .insert(entity, HealthPoints::new(99))
+                    .expect("Failed to insert `HealthPoints` component.");
+
+                let entity_checksums_before = world.read_resource::<Vec<EntityChecksum>>();
+                let entity_checksums_after = GameChecksumRecorder::calculate(&world.system_data());
+
+                assert_eq!(1, entity_checksums_before.len());
+                assert_eq!(1, entity_checksums_after.len());
+                assert_eq!(3, entity_checksums_after[0].entity_id);
+                assert_ne!(
+                    entity_checksums_before[0].checksum,
+                    entity_checksums_after[0].checksum
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn calculate_returns_checksums_in_game_entity_id_order() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GameChecksumResources as SystemData>::setup)
+            .with_assertion(|world| {
+                world
+                    .create_entity()
+                    .with(GameEntityId::new(1))
+                    .with(SequenceId::new(1))
+                    .build();
+                world
+                    .create_entity()
+                    .with(GameEntityId::new(0))
+                    .with(SequenceId::new(1))
+                    .build();
+
+                let entity_checksums = GameChecksumRecorder::calculate(&world.system_data());
+
+                let entity_ids = entity_checksums
+                    .iter()
+                    .map(|entity_checksum| entity_checksum.entity_id)
+                    .collect::<Vec<u32>>();
+                assert_eq!(vec![0, 1], entity_ids);
+            })
+            .run()
+    }
+}
@@ -1,4 +1,5 @@
 mod controller_input_update_system;
 mod game_input_to_control_input_system;
+mod input_history_update_system;
 mod input_to_game_input_system;
 mod shared_controller_input_update_system;
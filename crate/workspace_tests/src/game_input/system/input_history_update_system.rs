@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, ControlAction, InputCommand},
+        play::{
+            AxisMoveEventData, ControlActionEventData, ControlInputEvent, InputHistory,
+            InputHistoryEntry,
+        },
+    };
+    use mirrored_model::play::Mirrored;
+
+    use game_input::InputHistoryUpdateSystem;
+
+    #[test]
+    fn records_presses_and_directions_relative_to_facing() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                InputHistoryUpdateSystem::new(),
+                any::type_name::<InputHistoryUpdateSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let e0 = world.create_entity().with(Mirrored::new(false)).build();
+                let e1 = world.create_entity().with(Mirrored::new(true)).build();
+
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .drain_vec_write(&mut vec![
+                        axis_moved(e0, Axis::X, 1.),
+                        axis_moved(e0, Axis::Z, -1.),
+                        axis_moved(e0, Axis::Z, 0.),
+                        ControlInputEvent::ControlActionPress(ControlActionEventData {
+                            controller_id: 0,
+                            entity: e0,
+                            control_action: ControlAction::Attack,
+                        }),
+                        ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                            controller_id: 0,
+                            entity: e0,
+                            control_action: ControlAction::Attack,
+                        }),
+                        axis_moved(e1, Axis::X, 1.),
+                    ]); // kcov-ignore
+
+                world.insert((e0, e1));
+            })
+            .with_assertion(|world| {
+                let (e0, e1) = *world.read_resource::<(Entity, Entity)>();
+                let input_histories = world.read_storage::<InputHistory>();
+
+                let commands = |entity| {
+                    input_histories
+                        .get(entity)
+                        .map(|input_history| {
+                            input_history
+                                .entries
+                                .iter()
+                                .map(|entry| entry.command)
+                                .collect::<Vec<InputCommand>>()
+                        })
+                        .unwrap_or_default()
+                };
+                assert_eq!(
+                    vec![
+                        InputCommand::Forward,
+                        InputCommand::Up,
+                        InputCommand::Attack
+                    ],
+                    commands(e0)
+                );
+                assert_eq!(vec![InputCommand::Back], commands(e1));
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_commands_at_the_current_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                InputHistoryUpdateSystem::new(),
+                any::type_name::<InputHistoryUpdateSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(|world| {
+                let mut input_history = InputHistory::new();
+                input_history.push(InputCommand::Defend);
+
+                let entity = world.create_entity().with(input_history).build();
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .single_write(ControlInputEvent::ControlActionPress(
+                        ControlActionEventData {
+                            controller_id: 0,
+                            entity,
+                            control_action: ControlAction::Jump,
+                        },
+                    ));
+
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let input_histories = world.read_storage::<InputHistory>();
+                let input_history = input_histories
+                    .get(entity)
+                    .expect("Expected `InputHistory` component to exist.");
+
+                assert_eq!(1, input_history.tick);
+                assert_eq!(
+                    vec![
+                        InputHistoryEntry::new(0, InputCommand::Defend),
+                        InputHistoryEntry::new(1, InputCommand::Jump),
+                    ],
+                    input_history.entries.iter().copied().collect::<Vec<_>>()
+                );
+            })
+            .run()
+    }
+
+    fn axis_moved(entity: Entity, axis: Axis, value: f32) -> ControlInputEvent {
+        ControlInputEvent::AxisMoved(AxisMoveEventData {
+            controller_id: 0,
+            entity,
+            axis,
+            value,
+        })
+    } // kcov-ignore
+}
@@ -1 +1,4 @@
+mod axis_digitizer;
+mod gamepad_assignments;
+mod input_history;
 mod move_direction;
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use game_input_model::{
+        config::{
+            Axis, ControllerConfig, GamepadConfig, PlayerAxisControl, PlayerInputConfig,
+            PlayerInputConfigs,
+        },
+        play::{AxisDigitizer, ControllerIdOffset, GamepadAssignments},
+        GameInputEvent,
+    };
+
+    #[test]
+    fn rounds_axis_value_when_gamepad_assigned() {
+        let (player_input_configs, gamepad_assignments) = setup(true);
+        let mut axis_digitizer = AxisDigitizer::new();
+
+        let digitize = |axis_digitizer: &mut AxisDigitizer, value| {
+            axis_digitizer.digitize(
+                &player_input_configs,
+                ControllerIdOffset::new(0),
+                &gamepad_assignments,
+                axis_moved(value),
+            )
+        };
+
+        assert_eq!(Some(axis_moved(1.)), digitize(&mut axis_digitizer, 0.6));
+        // Value is unchanged after rounding.
+        assert_eq!(None, digitize(&mut axis_digitizer, 0.9));
+        assert_eq!(Some(axis_moved(0.)), digitize(&mut axis_digitizer, 0.3));
+        assert_eq!(Some(axis_moved(-1.)), digitize(&mut axis_digitizer, -0.5));
+    }
+
+    #[test]
+    fn passes_through_axis_value_when_gamepad_not_assigned() {
+        let (player_input_configs, gamepad_assignments) = setup(false);
+        let mut axis_digitizer = AxisDigitizer::new();
+
+        assert_eq!(
+            Some(axis_moved(0.3)),
+            axis_digitizer.digitize(
+                &player_input_configs,
+                ControllerIdOffset::new(0),
+                &gamepad_assignments,
+                axis_moved(0.3),
+            )
+        );
+    }
+
+    fn setup(gamepad_assigned: bool) -> (PlayerInputConfigs, GamepadAssignments) {
+        let mut controller_config = ControllerConfig::new(HashMap::new(), HashMap::new());
+        controller_config.gamepad = Some(GamepadConfig::default());
+        let player_input_configs = PlayerInputConfigs::new(vec![PlayerInputConfig::new(
+            String::from("zero"),
+            controller_config,
+        )]);
+
+        let mut gamepad_assignments = GamepadAssignments::default();
+        if gamepad_assigned {
+            gamepad_assignments.assign(&player_input_configs, 0);
+        }
+
+        (player_input_configs, gamepad_assignments)
+    }
+
+    fn axis_moved(value: f32) -> GameInputEvent {
+        GameInputEvent::AxisMoved {
+            axis: PlayerAxisControl::new(0, Axis::X),
+            value,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use game_input_model::{
+        config::{ControllerConfig, GamepadConfig, PlayerInputConfig, PlayerInputConfigs},
+        play::GamepadAssignments,
+    };
+
+    #[test]
+    fn assign_uses_first_controller_with_gamepad_config() {
+        let player_input_configs = player_input_configs();
+        let mut gamepad_assignments = GamepadAssignments::default();
+
+        assert_eq!(
+            Some(1),
+            gamepad_assignments.assign(&player_input_configs, 5)
+        );
+        assert_eq!(
+            Some(2),
+            gamepad_assignments.assign(&player_input_configs, 7)
+        );
+    }
+
+    #[test]
+    fn assign_returns_existing_controller_when_gamepad_already_assigned() {
+        let player_input_configs = player_input_configs();
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(&player_input_configs, 5);
+
+        assert_eq!(
+            Some(1),
+            gamepad_assignments.assign(&player_input_configs, 5)
+        );
+        assert_eq!(1, gamepad_assignments.len());
+    }
+
+    #[test]
+    fn assign_returns_none_when_no_free_controller() {
+        let player_input_configs = player_input_configs();
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(&player_input_configs, 5);
+        gamepad_assignments.assign(&player_input_configs, 7);
+
+        assert_eq!(None, gamepad_assignments.assign(&player_input_configs, 9));
+    }
+
+    #[test]
+    fn unassign_frees_controller_for_next_gamepad() {
+        let player_input_configs = player_input_configs();
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(&player_input_configs, 5);
+        gamepad_assignments.assign(&player_input_configs, 7);
+
+        assert_eq!(Some(1), gamepad_assignments.unassign(5));
+        assert_eq!(None, gamepad_assignments.unassign(5));
+        assert_eq!(
+            Some(1),
+            gamepad_assignments.assign(&player_input_configs, 9)
+        );
+    }
+
+    fn player_input_configs() -> PlayerInputConfigs {
+        let player_input_configs = (0..3)
+            .map(|index| {
+                let mut controller_config = ControllerConfig::new(HashMap::new(), HashMap::new());
+                if index > 0 {
+                    controller_config.gamepad = Some(GamepadConfig::default());
+                }
+                PlayerInputConfig::new(format!("{}", index), controller_config)
+            })
+            .collect::<Vec<PlayerInputConfig>>();
+
+        PlayerInputConfigs::new(player_input_configs)
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{InputCommand, InputCommandSequence},
+        play::{InputHistory, INPUT_HISTORY_LIMIT},
+    };
+
+    #[test]
+    fn matches_when_recent_commands_equal_sequence() {
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Jump);
+        input_history.tick();
+        input_history.push(InputCommand::Defend);
+        input_history.tick();
+        input_history.push(InputCommand::Forward);
+        input_history.tick();
+        input_history.push(InputCommand::Attack);
+
+        assert!(input_history.matches(&defend_forward_attack(3)));
+    }
+
+    #[test]
+    fn does_not_match_when_first_command_is_outside_window() {
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Defend);
+        input_history.tick();
+        input_history.push(InputCommand::Forward);
+        input_history.tick();
+        input_history.tick();
+        input_history.push(InputCommand::Attack);
+
+        assert!(!input_history.matches(&defend_forward_attack(2)));
+    }
+
+    #[test]
+    fn does_not_match_when_another_command_is_entered_in_between() {
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Defend);
+        input_history.push(InputCommand::Forward);
+        input_history.push(InputCommand::Up);
+        input_history.push(InputCommand::Attack);
+
+        assert!(!input_history.matches(&defend_forward_attack(20)));
+    }
+
+    #[test]
+    fn does_not_match_when_fewer_commands_than_sequence() {
+        let mut input_history = InputHistory::new();
+        input_history.push(InputCommand::Forward);
+        input_history.push(InputCommand::Attack);
+
+        assert!(!input_history.matches(&defend_forward_attack(20)));
+    }
+
+    #[test]
+    fn discards_oldest_command_when_over_limit() {
+        let mut input_history = InputHistory::new();
+        (0..=INPUT_HISTORY_LIMIT).for_each(|_| input_history.push(InputCommand::Jump));
+
+        assert_eq!(INPUT_HISTORY_LIMIT, input_history.entries.len());
+    }
+
+    fn defend_forward_attack(window: u32) -> InputCommandSequence {
+        InputCommandSequence::new(
+            vec![
+                InputCommand::Defend,
+                InputCommand::Forward,
+                InputCommand::Attack,
+            ],
+            window,
+        )
+    }
+}
@@ -3,14 +3,21 @@ mod tests {
     use std::{collections::HashMap, convert::TryFrom};
 
     use amethyst::{
-        input::{Axis as InputAxis, BindingError, Bindings, Button},
+        input::{
+            Axis as InputAxis, BindingError, Bindings, Button, ControllerAxis, ControllerButton,
+        },
         winit::VirtualKeyCode,
+        Error,
     };
     use hamcrest::prelude::*;
 
-    use game_input_model::config::{
-        Axis, ControlAction, ControlBindings, ControllerConfig, PlayerActionControl,
-        PlayerAxisControl, PlayerInputConfig, PlayerInputConfigs,
+    use game_input_model::{
+        config::{
+            Axis, ControlAction, ControlBindings, ControllerConfig, GamepadAxis, GamepadConfig,
+            PlayerActionControl, PlayerAxisControl, PlayerInputConfig, PlayerInputConfigs,
+        },
+        loaded::{ControlAxis, ControlButton},
+        play::{ControllerIdOffset, GamepadAssignments},
     };
 
     #[test]
@@ -140,6 +147,150 @@ mod tests {
         }
     }
 
+    #[test]
+    fn generate_bindings_includes_assigned_gamepad() -> Result<(), Error> {
+        let mut player_input_configs = player_input_configs();
+        let mut gamepad_axes = HashMap::new();
+        gamepad_axes.insert(Axis::X, GamepadAxis::new(ControllerAxis::LeftX, false));
+        let mut gamepad_actions = HashMap::new();
+        gamepad_actions.insert(ControlAction::Jump, ControllerButton::A);
+        player_input_configs[1].controller_config.gamepad =
+            Some(GamepadConfig::new(gamepad_axes, gamepad_actions, 0.2, 0.5));
+
+        let mut gamepad_assignments = GamepadAssignments::default();
+        gamepad_assignments.assign(&player_input_configs, 3);
+
+        let bindings = player_input_configs
+            .generate_bindings(ControllerIdOffset::new(0), &gamepad_assignments)?;
+
+        assert_eq!(
+            Some(&InputAxis::Multiple(vec![
+                InputAxis::Emulated {
+                    neg: Button::Key(VirtualKeyCode::Left),
+                    pos: Button::Key(VirtualKeyCode::Right),
+                },
+                InputAxis::Controller {
+                    controller_id: 3,
+                    axis: ControllerAxis::LeftX,
+                    invert: false,
+                    dead_zone: 0.2,
+                },
+            ])),
+            bindings.axis(&PlayerAxisControl::new(1, Axis::X))
+        );
+        let jump_bindings = bindings
+            .action_bindings(&PlayerActionControl::new(1, ControlAction::Jump))
+            .map(|combination| combination.to_vec())
+            .collect::<Vec<Vec<Button>>>();
+        assert_eq!(
+            vec![
+                vec![Button::Key(VirtualKeyCode::O)],
+                vec![Button::Controller(3, ControllerButton::A)],
+            ],
+            jump_bindings
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn control_button_returns_binding_for_button() {
+        let player_input_configs = player_input_configs();
+
+        assert_eq!(
+            Some((0, ControlButton::Axis(ControlAxis::Right))),
+            player_input_configs.control_button(Button::Key(VirtualKeyCode::D))
+        );
+        assert_eq!(
+            Some((1, ControlButton::Action(ControlAction::Jump))),
+            player_input_configs.control_button(Button::Key(VirtualKeyCode::O))
+        );
+        assert_eq!(
+            None,
+            player_input_configs.control_button(Button::Key(VirtualKeyCode::Z))
+        );
+    }
+
+    #[test]
+    fn rebind_replaces_button_when_unbound() -> Result<(), Error> {
+        let mut player_input_configs = player_input_configs();
+
+        let conflict = player_input_configs.rebind(
+            0,
+            ControlButton::Axis(ControlAxis::Left),
+            Button::Key(VirtualKeyCode::Z),
+        )?;
+
+        assert_eq!(None, conflict);
+        assert_eq!(
+            Some(Button::Key(VirtualKeyCode::Z)),
+            player_input_configs.button(0, ControlButton::Axis(ControlAxis::Left))
+        );
+        assert!(Bindings::<ControlBindings>::try_from(&player_input_configs).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rebind_swaps_buttons_when_button_bound_to_other_control_button() -> Result<(), Error> {
+        let mut player_input_configs = player_input_configs();
+
+        let conflict = player_input_configs.rebind(
+            0,
+            ControlButton::Action(ControlAction::Jump),
+            Button::Key(VirtualKeyCode::O),
+        )?;
+
+        assert_eq!(
+            Some((1, ControlButton::Action(ControlAction::Jump))),
+            conflict
+        );
+        assert_eq!(
+            Some(Button::Key(VirtualKeyCode::O)),
+            player_input_configs.button(0, ControlButton::Action(ControlAction::Jump))
+        );
+        assert_eq!(
+            Some(Button::Key(VirtualKeyCode::Key1)),
+            player_input_configs.button(1, ControlButton::Action(ControlAction::Jump))
+        );
+        assert!(Bindings::<ControlBindings>::try_from(&player_input_configs).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rebind_returns_error_when_control_button_not_configured() {
+        let mut player_input_configs = player_input_configs();
+
+        let rebind_result = player_input_configs.rebind(
+            0,
+            ControlButton::Axis(ControlAxis::Up),
+            Button::Key(VirtualKeyCode::Z),
+        );
+
+        assert!(rebind_result.is_err());
+        assert_eq!(
+            None,
+            player_input_configs.control_button(Button::Key(VirtualKeyCode::Z))
+        );
+    }
+
+    fn player_input_configs() -> PlayerInputConfigs {
+        let controller_config_0 =
+            controller_config([VirtualKeyCode::A, VirtualKeyCode::D, VirtualKeyCode::Key1]);
+        let controller_config_1 = controller_config([
+            VirtualKeyCode::Left,
+            VirtualKeyCode::Right,
+            VirtualKeyCode::O,
+        ]);
+
+        let player_input_config_0 =
+            PlayerInputConfig::new(String::from("zero"), controller_config_0);
+        let player_input_config_1 =
+            PlayerInputConfig::new(String::from("one"), controller_config_1);
+        PlayerInputConfigs::new(vec![player_input_config_0, player_input_config_1])
+    }
+
     fn controller_config(keys: [VirtualKeyCode; 3]) -> ControllerConfig {
         let mut axes = HashMap::new();
         axes.insert(
@@ -1,3 +1,4 @@
+mod game_entities_loader;
 mod game_loading_bundle;
 mod game_loading_status;
 mod system;
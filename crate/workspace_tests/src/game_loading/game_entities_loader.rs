@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Entity, WorldExt},
+        Error,
+    };
+    use application_test_support::AutexousiousApplication;
+    use game_model::play::GameEntities;
+    use object_type::ObjectType;
+
+    use game_loading::{GameEntitiesLoader, GameLoadingStatus};
+
+    #[test]
+    fn spawns_character_and_map_entities_again() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_effect(|world| {
+                let entities = world
+                    .write_resource::<GameEntities>()
+                    .drain()
+                    .collect::<Vec<Entity>>();
+                world
+                    .delete_entities(&entities)
+                    .expect("Failed to delete game entities.");
+
+                GameEntitiesLoader::load(world);
+            })
+            .with_assertion(|world| {
+                let game_entities = &*world.read_resource::<GameEntities>();
+
+                assert_eq!(
+                    Some(1),
+                    game_entities
+                        .objects
+                        .get(&ObjectType::Character)
+                        .map(Vec::len)
+                );
+                assert!(
+                    !game_entities.map_layers.is_empty(),
+                    "Expected map to be loaded."
+                );
+                assert!(world.read_resource::<GameLoadingStatus>().loaded());
+            })
+            .run_isolated()
+    }
+}
@@ -16,7 +16,7 @@ mod tests {
     use assets_test::MAP_FADE_SLUG;
     use character_selection_model::CharacterSelections;
     use game_model::play::GameEntities;
-    use game_play_hud::{CpBar, HpBar};
+    use game_play_hud::{ComboBar, ComboCount, CpBar, HpBar};
     use kinematic_model::config::Position;
     use loading_model::loaded::{AssetLoadStage, LoadStage};
     use map_selection::MapSelectionStatus;
@@ -94,7 +94,8 @@ mod tests {
     }
 
     #[test]
-    fn creates_hp_and_cp_bar_entities_per_character_selection() -> Result<(), Error> {
+    fn creates_hp_cp_combo_bar_and_combo_count_entities_per_character_selection(
+    ) -> Result<(), Error> {
         run_test(
             |world| {
                 let mut game_loading_status = GameLoadingStatus::new();
@@ -102,10 +103,16 @@ mod tests {
                 world.insert(game_loading_status);
             },
             |world| {
-                let (hp_bars, cp_bars) =
-                    world.system_data::<(ReadStorage<'_, HpBar>, ReadStorage<'_, CpBar>)>();
+                let (hp_bars, cp_bars, combo_bars, combo_counts) = world.system_data::<(
+                    ReadStorage<'_, HpBar>,
+                    ReadStorage<'_, CpBar>,
+                    ReadStorage<'_, ComboBar>,
+                    ReadStorage<'_, ComboCount>,
+                )>();
                 assert_eq!(1, (&hp_bars).join().count());
                 assert_eq!(1, (&cp_bars).join().count());
+                assert_eq!(1, (&combo_bars).join().count());
+                assert_eq!(1, (&combo_counts).join().count());
             },
         )
     }
@@ -14,9 +14,9 @@ mod tests {
     };
     use character_selection_model::CharacterSelections;
     use game_input_model::play::InputControlled;
-    use game_model::play::GameEntities;
+    use game_model::play::{GameEntities, GameEntityId};
     use object_type::ObjectType;
-    use team_model::play::{IndependentCounter, Team};
+    use team_model::play::{IndependentCounter, Team, TeamCounter};
 
     use game_loading::{
         CharacterAugmentStatus, CharacterSelectionSpawningSystem, GameLoadingStatus,
@@ -129,6 +129,82 @@ mod tests {
         )
     }
 
+    #[test]
+    fn spawns_characters_on_selected_team() -> Result<(), Error> {
+        run_test(
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
+                world.insert(game_loading_status);
+
+                let asset_id = first_character_asset_id(world);
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, asset_id);
+                character_selections.selections.insert(1, asset_id);
+                character_selections.teams.insert(0, TeamCounter::new(2));
+                world.insert(character_selections);
+            },
+            |world| {
+                let (input_controlleds, teams) = world.system_data::<TestSystemData<'_>>();
+                let mut components = (&input_controlleds, &teams)
+                    .join()
+                    .map(|(input_controlled, team)| (input_controlled.controller_id, *team))
+                    .collect::<Vec<_>>();
+                components.sort_by_key(|(controller_id, _)| *controller_id);
+
+                assert_eq!(
+                    vec![
+                        (0, Team::Number(TeamCounter::new(2))),
+                        (1, Team::Independent(IndependentCounter::new(0))),
+                    ],
+                    components
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn spawns_characters_with_game_entity_ids_in_controller_id_order() -> Result<(), Error> {
+        run_test(
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
+                world.insert(game_loading_status);
+
+                let asset_id = first_character_asset_id(world);
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(5, asset_id);
+                character_selections.selections.insert(2, asset_id);
+                character_selections.selections.insert(9, asset_id);
+                world.insert(character_selections);
+            },
+            |world| {
+                let (input_controlleds, game_entity_ids) = world.system_data::<(
+                    ReadStorage<'_, InputControlled>,
+                    ReadStorage<'_, GameEntityId>,
+                )>();
+                let mut components = (&input_controlleds, &game_entity_ids)
+                    .join()
+                    .map(|(input_controlled, game_entity_id)| {
+                        (input_controlled.controller_id, *game_entity_id)
+                    })
+                    .collect::<Vec<_>>();
+                components.sort_by_key(|(controller_id, _)| *controller_id);
+
+                assert_eq!(
+                    vec![
+                        (2, GameEntityId::new(0)),
+                        (5, GameEntityId::new(1)),
+                        (9, GameEntityId::new(2)),
+                    ],
+                    components
+                );
+            },
+        )
+    }
+
     fn run_test(setup_fn: fn(&mut World), assertion_fn: fn(&mut World)) -> Result<(), Error> {
         AutexousiousApplication::config_base()
             .with_effect(<CharacterSelectionSpawningSystem as System>::SystemData::setup)
@@ -1,8 +1,13 @@
 #[cfg(test)]
 mod test {
-    use amethyst::Error;
+    use amethyst::{ecs::WorldExt, Error};
     use amethyst_test::AmethystApplication;
+    use application_test_support::AutexousiousApplication;
     use game_input_model::config::ControlBindings;
+    use game_loading::GameLoadingStatus;
+    use game_play_model::{GamePlayStatus, GamePlayStepStatus};
+    use network_input_model::play::FrameNumber;
+    use state_registry::StateId;
 
     use game_play::GamePlayBundle;
 
@@ -12,4 +17,23 @@ mod test {
             .with_bundle(GamePlayBundle::new())
             .run()
     }
+
+    // Like `--headless`, the application has no window or audio device. Assets are processed by
+    // the `RenderEmptyBundle` and the `Source` processor.
+    #[test]
+    fn steps_game_play_without_renderer() -> Result<(), Error> {
+        AutexousiousApplication::game_base()
+            .with_bundle(GamePlayBundle::new())
+            .with_effect(|world| {
+                world.insert(StateId::GamePlay);
+                world.insert(GamePlayStatus::Playing);
+                world.insert(GamePlayStepStatus::Ready);
+                world.insert(FrameNumber::new(0));
+            })
+            .with_assertion(|world| {
+                assert!(world.read_resource::<GameLoadingStatus>().loaded());
+                assert_eq!(FrameNumber::new(1), *world.read_resource::<FrameNumber>());
+            })
+            .run_isolated()
+    }
 }
@@ -1,9 +1,16 @@
+mod character_grab_effect_system;
 mod character_hit_effect_system;
+mod character_respawn_system;
 mod character_sequence_update_system;
+mod combo_reset_system;
+mod frame_number_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
 mod game_play_end_transition_system;
+mod game_play_pause_menu_system;
 mod game_play_removal_augment_system;
+mod game_play_round_start_system;
+mod game_stats_tracking_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
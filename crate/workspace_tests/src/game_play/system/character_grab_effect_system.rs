@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::{CharacterGrabTransitions, CharacterHitTransitions};
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_model::{
+        config::{Grab, Interaction, InteractionKind, Throw},
+        play::{ContactEvent, HeldBy, Holding},
+    };
+    use kinematic_model::config::{Acceleration, Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use game_play::CharacterGrabEffectSystem;
+
+    const SEQUENCE_ID_STAND: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_STUN: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_FALLING: SequenceId = SequenceId(2);
+    const SEQUENCE_ID_GRABBING: SequenceId = SequenceId(3);
+    const SEQUENCE_ID_HELD: SequenceId = SequenceId(4);
+    const SEQUENCE_ID_THROWN: SequenceId = SequenceId(5);
+
+    #[test]
+    fn grab_attaches_contacted_character_to_grabber() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let grab = Grab::new(Position::new(10, 0, 1));
+                let entities =
+                    characters_create(world, Mirrored::new(true), Some(SEQUENCE_ID_GRABBING));
+                let event = contact_event(entities, InteractionKind::Grab(grab));
+                send_event(world, event);
+
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, held) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&Holding::new(held)),
+                    world.read_storage::<Holding>().get(holder)
+                );
+                assert_eq!(
+                    Some(&HeldBy::new(holder)),
+                    world.read_storage::<HeldBy>().get(held)
+                );
+                assert_eq!(
+                    Some(&TargetObject::new(holder)),
+                    world.read_storage::<TargetObject>().get(held)
+                );
+                assert_eq!(
+                    Some(&ChaseModeStick::new(Some(Position::new(-10., 0., 1.)))),
+                    world.read_storage::<ChaseModeStick>().get(held)
+                );
+                assert_eq!(
+                    Some(&Mirrored::new(false)),
+                    world.read_storage::<Mirrored>().get(held)
+                );
+
+                let sequence_ids = world.read_storage::<SequenceId>();
+                assert_eq!(Some(&SEQUENCE_ID_GRABBING), sequence_ids.get(holder));
+                assert_eq!(Some(&SEQUENCE_ID_HELD), sequence_ids.get(held));
+            })
+            .run()
+    }
+
+    #[test]
+    fn grab_does_nothing_when_grabber_has_no_grabbing_sequence() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entities = characters_create(world, Mirrored::new(false), None);
+                let event = contact_event(entities, InteractionKind::Grab(Grab::default()));
+                send_event(world, event);
+
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, held) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(held).is_none());
+
+                let sequence_ids = world.read_storage::<SequenceId>();
+                assert_eq!(Some(&SEQUENCE_ID_STAND), sequence_ids.get(holder));
+                assert_eq!(Some(&SEQUENCE_ID_STAND), sequence_ids.get(held));
+            })
+            .run()
+    }
+
+    #[test]
+    fn throw_releases_held_character_with_damage_and_acceleration() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entities =
+                    characters_create(world, Mirrored::new(true), Some(SEQUENCE_ID_GRABBING));
+                characters_attach(world, entities);
+
+                let throw = Throw::new(30, Acceleration::new(5, 8, 0));
+                let event = contact_event(entities, InteractionKind::Throw(throw));
+                send_event(world, event);
+
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, held) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(held).is_none());
+                assert!(world.read_storage::<TargetObject>().get(held).is_none());
+                assert!(world.read_storage::<ChaseModeStick>().get(held).is_none());
+                assert_eq!(
+                    Some(&HealthPoints::new(70)),
+                    world.read_storage::<HealthPoints>().get(held)
+                );
+                assert_eq!(
+                    Some(&Velocity::new(-5., 8., 0.)),
+                    world.read_storage::<Velocity<f32>>().get(held)
+                );
+                assert_eq!(
+                    Some(&SEQUENCE_ID_THROWN),
+                    world.read_storage::<SequenceId>().get(held)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn releases_held_character_when_holder_is_stunned() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entities =
+                    characters_create(world, Mirrored::new(false), Some(SEQUENCE_ID_GRABBING));
+                characters_attach(world, entities);
+
+                let (holder, _) = entities;
+                world
+                    .write_storage::<SequenceId>()
+                    .insert(holder, SEQUENCE_ID_STUN)
+                    .expect("Failed to insert `SequenceId` component.");
+
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, held) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(held).is_none());
+                assert_eq!(
+                    Some(&SEQUENCE_ID_THROWN),
+                    world.read_storage::<SequenceId>().get(held)
+                );
+            })
+            .run()
+    }
+
+    /// Returns the `(holder, held)` character entities.
+    fn characters_create(
+        world: &mut World,
+        holder_mirrored: Mirrored,
+        grabbing: Option<SequenceId>,
+    ) -> (Entity, Entity) {
+        let character_hit_transitions = CharacterHitTransitions::new(
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_FALLING,
+            None,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+        );
+        let character_grab_transitions =
+            CharacterGrabTransitions::new(grabbing, Some(SEQUENCE_ID_HELD), SEQUENCE_ID_THROWN);
+
+        let holder = world
+            .create_entity()
+            .with(holder_mirrored)
+            .with(HealthPoints::new(100))
+            .with(Velocity::<f32>::default())
+            .with(SEQUENCE_ID_STAND)
+            .with(character_hit_transitions)
+            .with(character_grab_transitions)
+            .build();
+        let held = world
+            .create_entity()
+            .with(holder_mirrored)
+            .with(HealthPoints::new(100))
+            .with(Velocity::<f32>::new(1., 2., 3.))
+            .with(SEQUENCE_ID_STAND)
+            .with(character_hit_transitions)
+            .with(character_grab_transitions)
+            .build();
+
+        (holder, held)
+    }
+
+    fn characters_attach(world: &mut World, (holder, held): (Entity, Entity)) {
+        world
+            .write_storage::<Holding>()
+            .insert(holder, Holding::new(held))
+            .expect("Failed to insert `Holding` component.");
+        world
+            .write_storage::<HeldBy>()
+            .insert(held, HeldBy::new(holder))
+            .expect("Failed to insert `HeldBy` component.");
+        world
+            .write_storage::<TargetObject>()
+            .insert(held, TargetObject::new(holder))
+            .expect("Failed to insert `TargetObject` component.");
+        world
+            .write_storage::<ChaseModeStick>()
+            .insert(held, ChaseModeStick::default())
+            .expect("Failed to insert `ChaseModeStick` component.");
+
+        let mut sequence_ids = world.write_storage::<SequenceId>();
+        sequence_ids
+            .insert(holder, SEQUENCE_ID_GRABBING)
+            .expect("Failed to insert `SequenceId` component.");
+        sequence_ids
+            .insert(held, SEQUENCE_ID_HELD)
+            .expect("Failed to insert `SequenceId` component.");
+    }
+
+    fn contact_event((holder, held): (Entity, Entity), kind: InteractionKind) -> ContactEvent {
+        ContactEvent::new(holder, held, Interaction::new(kind, vec![], true), body())
+    }
+
+    fn send_event(world: &mut World, event: ContactEvent) {
+        let mut ec = world.write_resource::<EventChannel<ContactEvent>>();
+        ec.single_write(event)
+    } // kcov-ignore
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
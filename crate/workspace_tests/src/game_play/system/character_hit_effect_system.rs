@@ -5,7 +5,7 @@ use amethyst::{
 };
 use character_model::loaded::CharacterHitTransitions;
 use collision_model::{
-    config::{Hit, Interaction, InteractionKind},
+    config::{Hit, InteractionKind},
     play::HitEvent,
 };
 use derivative::Derivative;
@@ -94,24 +94,21 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     Some(stun_points),
                     Some(velocity),
                     Some(sequence_id),
+                    &InteractionKind::Hit(Hit {
+                        hp_damage,
+                        stun,
+                        acceleration,
+                        ..
+                    }),
                 ) = (
                     character_hit_transitions,
                     health_points,
                     stun_points,
                     velocity,
                     sequence_id,
+                    &ev.interaction.kind,
                 ) {
                     // TODO: Split this system with health check system.
-                    let Interaction {
-                        kind:
-                            InteractionKind::Hit(Hit {
-                                hp_damage,
-                                stun,
-                                acceleration,
-                                ..
-                            }),
-                        ..
-                    } = ev.interaction;
                     if health_points.0 < hp_damage {
                         *health_points = HealthPoints(0);
                     } else {
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::{play::Stocks, GamePlayStatus};
+    use object_model::play::HealthPoints;
+
+    use game_play::CharacterRespawnSystem;
+
+    #[test]
+    fn respawns_object_when_health_points_zero_and_stocks_remaining() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(0),
+            },
+            ExpectedParams {
+                stocks: Stocks::new(2),
+                health_points: HealthPoints::new(100),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_respawn_object_on_last_stock() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                stocks: Stocks::new(1),
+                health_points: HealthPoints::new(0),
+            },
+            ExpectedParams {
+                stocks: Stocks::new(1),
+                health_points: HealthPoints::new(0),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_respawn_object_with_health_points() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(10),
+            },
+            ExpectedParams {
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(10),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_respawn_object_when_game_play_is_not_playing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::RoundEnded,
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(0),
+            },
+            ExpectedParams {
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(0),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            game_play_status,
+            stocks: stocks_setup,
+            health_points: health_points_setup,
+        }: SetupParams,
+        ExpectedParams {
+            stocks: stocks_expected,
+            health_points: health_points_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(game_play_status)
+            .with_system(
+                CharacterRespawnSystem::new(),
+                any::type_name::<CharacterRespawnSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let entity = world
+                    .create_entity()
+                    .with(stocks_setup)
+                    .with(health_points_setup)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let stockses = world.read_storage::<Stocks>();
+                let health_pointses = world.read_storage::<HealthPoints>();
+
+                assert_eq!(Some(&stocks_expected), stockses.get(entity));
+                assert_eq!(Some(&health_points_expected), health_pointses.get(entity));
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        game_play_status: GamePlayStatus,
+        stocks: Stocks,
+        health_points: HealthPoints,
+    }
+
+    struct ExpectedParams {
+        stocks: Stocks,
+        health_points: HealthPoints,
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterHitTransitions;
+    use game_play_model::play::Combo;
+    use sequence_model::loaded::SequenceId;
+
+    use game_play::ComboResetSystem;
+
+    const SEQUENCE_ID_STAND: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_STUN: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_FALLING: SequenceId = SequenceId(2);
+
+    #[test]
+    fn keeps_combo_when_character_is_stunned() -> Result<(), Error> {
+        run_test(SEQUENCE_ID_STUN, true)
+    }
+
+    #[test]
+    fn keeps_combo_when_character_is_falling() -> Result<(), Error> {
+        run_test(SEQUENCE_ID_FALLING, true)
+    }
+
+    #[test]
+    fn removes_combo_when_character_returns_to_neutral_sequence() -> Result<(), Error> {
+        run_test(SEQUENCE_ID_STAND, false)
+    }
+
+    fn run_test(sequence_id: SequenceId, combo_expected: bool) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ComboResetSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let attacker = world.create_entity().build();
+                let character_hit_transitions = CharacterHitTransitions::new(
+                    SEQUENCE_ID_STUN,
+                    SEQUENCE_ID_STUN,
+                    SEQUENCE_ID_STUN,
+                    SEQUENCE_ID_FALLING,
+                    None,
+                    SEQUENCE_ID_STUN,
+                    SEQUENCE_ID_STUN,
+                );
+                let entity = world
+                    .create_entity()
+                    .with(character_hit_transitions)
+                    .with(sequence_id)
+                    .with(Combo::new(attacker, 3))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let combos = world.read_storage::<Combo>();
+
+                assert_eq!(combo_expected, combos.contains(entity));
+            })
+            .run()
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{ecs::WorldExt, Error};
+    use amethyst_test::AmethystApplication;
+    use network_input_model::play::FrameNumber;
+
+    use game_play::FrameNumberUpdateSystem;
+
+    #[test]
+    fn increments_frame_number() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(FrameNumberUpdateSystem::new(), "", &[])
+            .with_resource(FrameNumber::new(0))
+            .with_effect(|world| world.insert(FrameNumber::new(3)))
+            .with_assertion(|world| {
+                assert_eq!(FrameNumber::new(4), *world.read_resource::<FrameNumber>());
+            })
+            .run()
+    }
+}
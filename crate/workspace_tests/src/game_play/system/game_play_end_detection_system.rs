@@ -7,7 +7,7 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use game_play_model::{GamePlayEvent, GamePlayStatus};
+    use game_play_model::{config::MatchRules, GamePlayEvent, GamePlayStatus};
     use game_stats_model::play::{WinOutcome, WinStatus};
     use object_model::play::HealthPoints;
     use std::any;
@@ -20,6 +20,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Ended,
+                match_rules: MatchRules::default(),
+                round_outcomes: vec![],
                 objects: vec![
                     ObjectStatus {
                         team: Team::Number(TeamCounter::new(0)),
@@ -45,6 +47,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules::default(),
+                round_outcomes: vec![],
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -59,7 +63,10 @@ mod tests {
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
                 game_play_events: vec![GamePlayEvent::End],
-                win_status: WinStatus::new(WinOutcome::WinLoss { winning_team }),
+                win_status: win_status(
+                    WinOutcome::WinLoss { winning_team },
+                    vec![WinOutcome::WinLoss { winning_team }],
+                ),
             },
         )
     }
@@ -71,6 +78,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules::default(),
+                round_outcomes: vec![],
                 objects: vec![
                     ObjectStatus {
                         team: winning_team,
@@ -89,7 +98,10 @@ mod tests {
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
                 game_play_events: vec![GamePlayEvent::End],
-                win_status: WinStatus::new(WinOutcome::WinLoss { winning_team }),
+                win_status: win_status(
+                    WinOutcome::WinLoss { winning_team },
+                    vec![WinOutcome::WinLoss { winning_team }],
+                ),
             },
         )
     }
@@ -99,6 +111,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules::default(),
+                round_outcomes: vec![],
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -113,7 +127,7 @@ mod tests {
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
                 game_play_events: vec![GamePlayEvent::End],
-                win_status: WinStatus::new(WinOutcome::Draw),
+                win_status: win_status(WinOutcome::Draw, vec![WinOutcome::Draw]),
             },
         )
     }
@@ -124,6 +138,8 @@ mod tests {
         run_test(
             SetupParams {
                 game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules::default(),
+                round_outcomes: vec![],
                 objects: vec![
                     ObjectStatus {
                         team: Team::Independent(IndependentCounter::new(0)),
@@ -143,9 +159,153 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sends_round_end_event_when_round_won_and_match_not_decided() -> Result<(), Error> {
+        let winning_team = Team::Number(TeamCounter::new(0));
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules {
+                    rounds: 3,
+                    ..Default::default()
+                },
+                round_outcomes: vec![],
+                objects: vec![
+                    ObjectStatus {
+                        team: winning_team,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Dead,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::RoundEnded,
+                game_play_events: vec![GamePlayEvent::RoundEnd],
+                win_status: win_status(
+                    WinOutcome::None,
+                    vec![WinOutcome::WinLoss { winning_team }],
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_when_team_wins_majority_of_rounds() -> Result<(), Error> {
+        let winning_team = Team::Number(TeamCounter::new(0));
+        let losing_team = Team::Independent(IndependentCounter::new(1));
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules {
+                    rounds: 3,
+                    ..Default::default()
+                },
+                round_outcomes: vec![
+                    WinOutcome::WinLoss { winning_team },
+                    WinOutcome::WinLoss {
+                        winning_team: losing_team,
+                    },
+                ],
+                objects: vec![
+                    ObjectStatus {
+                        team: winning_team,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: losing_team,
+                        liveness: Liveness::Dead,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: win_status(
+                    WinOutcome::WinLoss { winning_team },
+                    vec![
+                        WinOutcome::WinLoss { winning_team },
+                        WinOutcome::WinLoss {
+                            winning_team: losing_team,
+                        },
+                        WinOutcome::WinLoss { winning_team },
+                    ],
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_to_team_with_most_health_points_when_time_is_up(
+    ) -> Result<(), Error> {
+        let winning_team = Team::Independent(IndependentCounter::new(0));
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules {
+                    time_limit: Some(0),
+                    ..Default::default()
+                },
+                round_outcomes: vec![],
+                objects: vec![
+                    ObjectStatus {
+                        team: winning_team,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Injured,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: win_status(
+                    WinOutcome::WinLoss { winning_team },
+                    vec![WinOutcome::WinLoss { winning_team }],
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_game_play_end_event_with_draw_when_time_is_up_and_health_points_equal(
+    ) -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                match_rules: MatchRules {
+                    time_limit: Some(0),
+                    ..Default::default()
+                },
+                round_outcomes: vec![],
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(0)),
+                        liveness: Liveness::Injured,
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Injured,
+                    },
+                ],
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: win_status(WinOutcome::Draw, vec![WinOutcome::Draw]),
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             game_play_status: game_play_status_setup,
+            match_rules,
+            round_outcomes,
             objects,
         }: SetupParams,
         ExpectedParams {
@@ -156,6 +316,8 @@ mod tests {
     ) -> Result<(), Error> {
         AmethystApplication::blank()
             .with_resource(game_play_status_setup)
+            .with_resource(match_rules)
+            .with_resource(win_status(WinOutcome::None, round_outcomes))
             .with_setup(GamePlayEndDetectionSystemData::setup)
             .with_setup(register_event_reader)
             .with_effect(move |world| {
@@ -164,6 +326,7 @@ mod tests {
 
                     let health_points = match liveness {
                         Liveness::Alive => HealthPoints(100),
+                        Liveness::Injured => HealthPoints(50),
                         Liveness::Dead => HealthPoints(0),
                     };
 
@@ -177,7 +340,7 @@ mod tests {
             ) // kcov-ignore
             .with_assertion(move |world| {
                 let game_play_status = *world.read_resource::<GamePlayStatus>();
-                let win_status = *world.read_resource::<WinStatus>();
+                let win_status = world.read_resource::<WinStatus>().clone();
 
                 assert_eq!(game_play_status_expected, game_play_status);
                 assert_eq!(win_status_expected, win_status);
@@ -206,8 +369,16 @@ mod tests {
         assert_eq!(game_play_events_expected, game_play_events_actual);
     }
 
+    fn win_status(outcome: WinOutcome, round_outcomes: Vec<WinOutcome>) -> WinStatus {
+        let mut win_status = WinStatus::new(outcome);
+        win_status.round_outcomes = round_outcomes;
+        win_status
+    }
+
     struct SetupParams {
         game_play_status: GamePlayStatus,
+        match_rules: MatchRules,
+        round_outcomes: Vec<WinOutcome>,
         objects: Vec<ObjectStatus>,
     }
 
@@ -224,6 +395,7 @@ mod tests {
 
     enum Liveness {
         Alive,
+        Injured,
         Dead,
     }
 }
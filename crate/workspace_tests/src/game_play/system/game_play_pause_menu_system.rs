@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use amethyst::{
+        ecs::{Builder, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetIdMappings, AssetItemIds, ItemId, ItemIds},
+    };
+    use game_play_model::{play::GamePlayPauseEntity, GamePlayStatus};
+    use state_registry::StateId;
+
+    use game_play::GamePlayPauseMenuSystem;
+
+    #[test]
+    fn spawns_pause_menu_when_game_play_is_paused() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Paused,
+                game_play_status_next: GamePlayStatus::Paused,
+            },
+            ExpectedParams {
+                entity_count: 2,
+                entity_count_next: 2,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_spawn_pause_menu_when_game_play_is_playing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Playing,
+                game_play_status_next: GamePlayStatus::Playing,
+            },
+            ExpectedParams {
+                entity_count: 0,
+                entity_count_next: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_spawn_pause_menu_when_state_id_is_not_game_play() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                state_id: StateId::ControlSettings,
+                game_play_status: GamePlayStatus::Paused,
+                game_play_status_next: GamePlayStatus::Paused,
+            },
+            ExpectedParams {
+                entity_count: 0,
+                entity_count_next: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn deletes_pause_menu_when_game_play_is_resumed() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Paused,
+                game_play_status_next: GamePlayStatus::Playing,
+            },
+            ExpectedParams {
+                entity_count: 2,
+                entity_count_next: 0,
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            state_id,
+            game_play_status,
+            game_play_status_next,
+        }: SetupParams,
+        ExpectedParams {
+            entity_count,
+            entity_count_next,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(state_id)
+            .with_resource(game_play_status)
+            .with_setup(|world| {
+                let item_id_0 = ItemId::new(world.create_entity().build());
+                let item_id_1 = ItemId::new(world.create_entity().build());
+
+                let mut asset_id_mappings = AssetIdMappings::new();
+                let asset_id = asset_id_mappings.insert(
+                    AssetSlug::from_str("test/game_play_pause")
+                        .expect("Expected slug to be valid."),
+                );
+                let mut asset_item_ids = AssetItemIds::default();
+                asset_item_ids.insert(asset_id, ItemIds::new(vec![item_id_0, item_id_1]));
+
+                world.insert(asset_id_mappings);
+                world.insert(asset_item_ids);
+            })
+            .with_system(GamePlayPauseMenuSystem::new(), "", &[])
+            .with_assertion(move |world| assert_pause_entity_count(world, entity_count))
+            .with_effect(move |world| world.insert(game_play_status_next))
+            .with_effect(|_| {}) // Wait for one tick to allow entities to be deleted.
+            .with_assertion(move |world| assert_pause_entity_count(world, entity_count_next))
+            .run()
+    }
+
+    fn assert_pause_entity_count(world: &mut World, entity_count: usize) {
+        world.maintain();
+        let game_play_pause_entities = world.read_storage::<GamePlayPauseEntity>();
+
+        assert_eq!(entity_count, game_play_pause_entities.count());
+    }
+
+    struct SetupParams {
+        state_id: StateId,
+        game_play_status: GamePlayStatus,
+        game_play_status_next: GamePlayStatus,
+    }
+
+    struct ExpectedParams {
+        entity_count: usize,
+        entity_count_next: usize,
+    }
+}
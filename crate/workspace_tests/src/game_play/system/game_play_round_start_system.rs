@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::{
+        config::MatchRules,
+        play::{RoundClock, RoundStartDelayClock, Stocks},
+        GamePlayEvent, GamePlayStatus,
+    };
+    use object_model::play::HealthPoints;
+
+    use game_play::GamePlayRoundStartSystem;
+
+    #[test]
+    fn starts_round_and_resets_objects_when_delay_complete() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::RoundEnded,
+                round_start_delay_clock: RoundStartDelayClock::new(1),
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![GamePlayEvent::RoundStart],
+                stocks: Stocks::new(3),
+                health_points: HealthPoints::new(100),
+                round_clock: RoundClock::new(600),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_start_round_when_delay_ongoing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::RoundEnded,
+                round_start_delay_clock: RoundStartDelayClock::new(2),
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::RoundEnded,
+                game_play_events: vec![],
+                stocks: Stocks::new(1),
+                health_points: HealthPoints::new(0),
+                round_clock: RoundClock::new_with_value(600, 600),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_start_round_when_round_has_not_ended() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Ended,
+                round_start_delay_clock: RoundStartDelayClock::new(0),
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![],
+                stocks: Stocks::new(1),
+                health_points: HealthPoints::new(0),
+                round_clock: RoundClock::new_with_value(600, 600),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            game_play_status: game_play_status_setup,
+            round_start_delay_clock,
+        }: SetupParams,
+        ExpectedParams {
+            game_play_status: game_play_status_expected,
+            game_play_events: game_play_events_expected,
+            stocks: stocks_expected,
+            health_points: health_points_expected,
+            round_clock: round_clock_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(game_play_status_setup)
+            .with_resource(MatchRules::new(Some(600), 3, 3, 60))
+            .with_resource(RoundClock::new_with_value(600, 600))
+            .with_resource(round_start_delay_clock)
+            .with_system(
+                GamePlayRoundStartSystem::new(),
+                any::type_name::<GamePlayRoundStartSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(register_event_reader)
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(Stocks::new(1))
+                    .with(HealthPoints::new(0))
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                {
+                    let stockses = world.read_storage::<Stocks>();
+                    let health_pointses = world.read_storage::<HealthPoints>();
+
+                    assert_eq!(Some(&stocks_expected), stockses.get(entity));
+                    assert_eq!(Some(&health_points_expected), health_pointses.get(entity));
+                }
+                assert_eq!(
+                    game_play_status_expected,
+                    *world.read_resource::<GamePlayStatus>()
+                );
+                assert_eq!(round_clock_expected, *world.read_resource::<RoundClock>());
+
+                let game_play_events = {
+                    let game_play_ec = world.read_resource::<EventChannel<GamePlayEvent>>();
+                    let mut game_play_event_rid = world.write_resource::<ReaderId<GamePlayEvent>>();
+                    game_play_ec
+                        .read(&mut *game_play_event_rid)
+                        .copied()
+                        .collect::<Vec<GamePlayEvent>>()
+                };
+                assert_eq!(game_play_events_expected, game_play_events);
+            })
+            .run()
+    }
+
+    fn register_event_reader(world: &mut World) {
+        let reader_id = world
+            .write_resource::<EventChannel<GamePlayEvent>>()
+            .register_reader(); // kcov-ignore
+        world.insert(reader_id);
+    }
+
+    struct SetupParams {
+        game_play_status: GamePlayStatus,
+        round_start_delay_clock: RoundStartDelayClock,
+    }
+
+    struct ExpectedParams {
+        game_play_status: GamePlayStatus,
+        game_play_events: Vec<GamePlayEvent>,
+        stocks: Stocks,
+        health_points: HealthPoints,
+        round_clock: RoundClock,
+    }
+}
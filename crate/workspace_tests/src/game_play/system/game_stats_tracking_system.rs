@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod tests {
+    use std::{any, collections::BTreeMap};
+
+    use ai_model::{config::AiDifficulty, play::AiControlled};
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use game_input_model::play::InputControlled;
+    use game_play_model::{play::Combo, GamePlayStatus};
+    use game_stats_model::play::{GameStats, PlayerStats};
+    use object_model::play::{HealthPoints, SkillPoints};
+    use shape_model::Volume;
+    use spawn_model::play::SpawnParent;
+    use tracker::Last;
+
+    use game_play::GameStatsTrackingSystem;
+
+    #[test]
+    fn records_damage_and_hits_for_attacker_and_hit_player() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            |world| {
+                let (entity_from, entity_to) = attacker_and_cpu(world, 80, 100);
+                vec![hit_event(entity_from, entity_to, 20)]
+            },
+            game_stats(
+                PlayerStats {
+                    damage_dealt: 20,
+                    hits_landed: 1,
+                    max_combo: 1,
+                    ..PlayerStats::new(false)
+                },
+                PlayerStats {
+                    damage_taken: 20,
+                    ..PlayerStats::new(true)
+                },
+            ),
+        )
+    }
+
+    #[test]
+    fn records_ko_with_damage_limited_to_health_points_lost() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            |world| {
+                let (entity_from, entity_to) = attacker_and_cpu(world, 0, 10);
+                vec![hit_event(entity_from, entity_to, 20)]
+            },
+            game_stats(
+                PlayerStats {
+                    damage_dealt: 10,
+                    hits_landed: 1,
+                    kos: 1,
+                    max_combo: 1,
+                    ..PlayerStats::new(false)
+                },
+                PlayerStats {
+                    damage_taken: 10,
+                    ..PlayerStats::new(true)
+                },
+            ),
+        )
+    }
+
+    #[test]
+    fn attributes_spawned_object_hits_to_spawning_player() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            |world| {
+                let (entity_from, entity_to) = attacker_and_cpu(world, 80, 100);
+                let entity_spawned = world
+                    .create_entity()
+                    .with(SpawnParent::new(entity_from))
+                    .build();
+                vec![hit_event(entity_spawned, entity_to, 20)]
+            },
+            game_stats(
+                PlayerStats {
+                    damage_dealt: 20,
+                    hits_landed: 1,
+                    max_combo: 1,
+                    ..PlayerStats::new(false)
+                },
+                PlayerStats {
+                    damage_taken: 20,
+                    ..PlayerStats::new(true)
+                },
+            ),
+        )
+    }
+
+    #[test]
+    fn records_max_combo_from_hit_object_combo() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            |world| {
+                let (entity_from, entity_to) = attacker_and_cpu(world, 60, 100);
+                world
+                    .write_storage::<Combo>()
+                    .insert(entity_to, Combo::new(entity_from, 2))
+                    .expect("Failed to insert `Combo` component.");
+                vec![
+                    hit_event(entity_from, entity_to, 20),
+                    hit_event(entity_from, entity_to, 20),
+                ]
+            },
+            game_stats(
+                PlayerStats {
+                    damage_dealt: 40,
+                    hits_landed: 2,
+                    max_combo: 2,
+                    ..PlayerStats::new(false)
+                },
+                PlayerStats {
+                    damage_taken: 40,
+                    ..PlayerStats::new(true)
+                },
+            ),
+        )
+    }
+
+    #[test]
+    fn records_skill_points_used() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            |world| {
+                let (entity_from, _entity_to) = attacker_and_cpu(world, 100, 100);
+                world
+                    .write_storage::<SkillPoints>()
+                    .insert(entity_from, SkillPoints::new(70))
+                    .expect("Failed to insert `SkillPoints` component.");
+                world
+                    .write_storage::<Last<SkillPoints>>()
+                    .insert(entity_from, Last(SkillPoints::new(100)))
+                    .expect("Failed to insert `Last<SkillPoints>` component.");
+                vec![]
+            },
+            game_stats(
+                PlayerStats {
+                    sp_used: 30,
+                    ..PlayerStats::new(false)
+                },
+                PlayerStats::new(true),
+            ),
+        )
+    }
+
+    #[test]
+    fn does_not_record_stats_when_game_play_is_not_playing() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Ended,
+            |world| {
+                let (entity_from, entity_to) = attacker_and_cpu(world, 80, 100);
+                vec![hit_event(entity_from, entity_to, 20)]
+            },
+            GameStats::default(),
+        )
+    }
+
+    fn run_test(
+        game_play_status: GamePlayStatus,
+        setup_fn: fn(&mut World) -> Vec<HitEvent>,
+        game_stats_expected: GameStats,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(game_play_status)
+            .with_system(
+                GameStatsTrackingSystem::new(),
+                any::type_name::<GameStatsTrackingSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                let hit_events = setup_fn(world);
+                world
+                    .write_resource::<EventChannel<HitEvent>>()
+                    .iter_write(hit_events);
+            })
+            .with_assertion(move |world| {
+                let game_stats = world.read_resource::<GameStats>();
+
+                assert_eq!(game_stats_expected, *game_stats);
+            })
+            .run()
+    }
+
+    /// Returns an `InputControlled` attacker and an `AiControlled` object that it hits.
+    fn attacker_and_cpu(
+        world: &mut World,
+        health_points: u32,
+        last_health_points: u32,
+    ) -> (Entity, Entity) {
+        let entity_from = world
+            .create_entity()
+            .with(InputControlled::new(0))
+            .with(HealthPoints::new(100))
+            .with(Last(HealthPoints::new(100)))
+            .build();
+        let entity_to = world
+            .create_entity()
+            .with(AiControlled::new(1, AiDifficulty::Normal))
+            .with(HealthPoints::new(health_points))
+            .with(Last(HealthPoints::new(last_health_points)))
+            .build();
+
+        (entity_from, entity_to)
+    }
+
+    fn game_stats(player_stats_0: PlayerStats, player_stats_1: PlayerStats) -> GameStats {
+        let mut player_stats = BTreeMap::new();
+        player_stats.insert(0, player_stats_0);
+        player_stats.insert(1, player_stats_1);
+        GameStats::new(player_stats)
+    }
+
+    fn hit_event(entity_from: Entity, entity_to: Entity, hp_damage: u32) -> HitEvent {
+        let hit = Hit {
+            hp_damage,
+            ..Default::default()
+        };
+        HitEvent::new(
+            entity_from,
+            entity_to,
+            Interaction::new(InteractionKind::Hit(hit), vec![], true),
+            body(),
+        )
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+}
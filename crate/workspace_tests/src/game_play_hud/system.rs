@@ -1,2 +1,4 @@
+mod combo_bar_update_system;
+mod combo_count_update_system;
 mod cp_bar_update_system;
 mod hp_bar_update_system;
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::PrefabData,
+        core::{math::Vector3, Transform, TransformBundle},
+        ecs::{Builder, Entity, System, SystemData, World, WorldExt},
+        renderer::{types::DefaultBackend, RenderEmptyBundle},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::play::Combo;
+
+    use game_play_hud::{ComboBarPrefab, ComboBarUpdateSystem};
+
+    #[test]
+    fn sets_transform_x_and_scale_from_combo_count() -> Result<(), Error> {
+        run_test(
+            4,
+            //   4  *   5. =  20.
+            // 100. -  20. =  80. (missing length)
+            // -80  /   2. = -40. (half sprite width shift)
+            // -40. + 123. =  83. (parent shift)
+            Vector3::new(83., 438., 790.),
+            20.,
+        )
+    }
+
+    #[test]
+    fn hides_combo_bar_for_single_hit() -> Result<(), Error> {
+        run_test(1, Vector3::new(73., 438., 790.), 0.)
+    }
+
+    fn run_test(
+        combo_count: u32,
+        translation_expected: Vector3<f32>,
+        scale_x_expected: f32,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle(RenderEmptyBundle::<DefaultBackend>::new())
+            .with_effect(move |world| {
+                <ComboBarPrefab as PrefabData>::SystemData::setup(world);
+                <ComboBarUpdateSystem as System>::SystemData::setup(world);
+
+                let mut transform = Transform::default();
+                transform.set_translation_x(123.);
+                transform.set_translation_y(456.);
+                transform.set_translation_z(789.);
+
+                let char_entity = world.create_entity().with(transform).build();
+                world
+                    .create_entity()
+                    .with(Combo::new(char_entity, combo_count))
+                    .build();
+
+                let combo_bar_entity = combo_bar_entity(world, char_entity);
+                world.insert(combo_bar_entity);
+            })
+            .with_system_single(ComboBarUpdateSystem::new(), "", &[])
+            .with_assertion(move |world| {
+                let combo_bar_entity = *world.read_resource::<Entity>();
+
+                let transforms = world.read_storage::<Transform>();
+                let transform = transforms
+                    .get(combo_bar_entity)
+                    .expect("Expected combo bar to have `Transform` component.");
+
+                assert_eq!(&translation_expected, transform.translation());
+                assert_eq!(scale_x_expected, transform.scale()[0]);
+            })
+            .run_isolated()
+    }
+
+    fn combo_bar_entity(world: &mut World, char_entity: Entity) -> Entity {
+        let combo_bar_entity = world.create_entity().build();
+
+        let mut combo_bar_prefab_system_data =
+            world.system_data::<<ComboBarPrefab as PrefabData>::SystemData>();
+        let combo_bar_prefab = ComboBarPrefab::new(char_entity);
+
+        combo_bar_prefab
+            .add_to_entity(
+                combo_bar_entity,
+                &mut combo_bar_prefab_system_data,
+                &[],
+                &[],
+            )
+            .expect("`ComboBarPrefab` failed to augment entity.");
+
+        combo_bar_entity
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::PrefabData,
+        core::Transform,
+        ecs::{Builder, Entity, System, SystemData, World, WorldExt},
+        renderer::Camera,
+        ui::{UiText, UiTransform},
+        Error,
+    };
+    use application_test_support::AutexousiousApplication;
+    use application_ui::ThemeLoader;
+    use game_play_model::play::Combo;
+
+    use game_play_hud::{ComboCountPrefab, ComboCountUpdateSystem};
+
+    #[test]
+    fn sets_text_and_position_from_combo_count() -> Result<(), Error> {
+        // x: 123. - (500. - 400.) = 23.
+        // y: 456. - 32. - (300. - 300.) = 424.
+        run_test(4, "4 hits", 23., 424.)
+    }
+
+    #[test]
+    fn hides_combo_count_for_single_hit() -> Result<(), Error> {
+        run_test(1, "", 23., 424.)
+    }
+
+    fn run_test(
+        combo_count: u32,
+        text_expected: &'static str,
+        local_x_expected: f32,
+        local_y_expected: f32,
+    ) -> Result<(), Error> {
+        AutexousiousApplication::ui_base()
+            .with_effect(move |world| {
+                ThemeLoader::load(world).expect("Failed to load `Theme`.");
+                <ComboCountPrefab as PrefabData>::SystemData::setup(world);
+                <ComboCountUpdateSystem as System>::SystemData::setup(world);
+
+                let mut camera_transform = Transform::default();
+                camera_transform.set_translation_x(500.);
+                camera_transform.set_translation_y(300.);
+                world
+                    .create_entity()
+                    .with(Camera::standard_2d(800., 600.))
+                    .with(camera_transform)
+                    .build();
+
+                let mut transform = Transform::default();
+                transform.set_translation_x(123.);
+                transform.set_translation_y(456.);
+                transform.set_translation_z(789.);
+
+                let char_entity = world.create_entity().with(transform).build();
+                world
+                    .create_entity()
+                    .with(Combo::new(char_entity, combo_count))
+                    .build();
+
+                let combo_count_entity = combo_count_entity(world, char_entity);
+                world.insert(combo_count_entity);
+            })
+            .with_system_single(ComboCountUpdateSystem::new(), "", &[])
+            .with_assertion(move |world| {
+                let combo_count_entity = *world.read_resource::<Entity>();
+
+                let ui_texts = world.read_storage::<UiText>();
+                let ui_text = ui_texts
+                    .get(combo_count_entity)
+                    .expect("Expected combo count to have `UiText` component.");
+                assert_eq!(text_expected, ui_text.text);
+
+                let ui_transforms = world.read_storage::<UiTransform>();
+                let ui_transform = ui_transforms
+                    .get(combo_count_entity)
+                    .expect("Expected combo count to have `UiTransform` component.");
+                assert_eq!(local_x_expected, ui_transform.local_x);
+                assert_eq!(local_y_expected, ui_transform.local_y);
+            })
+            .run_isolated()
+    }
+
+    fn combo_count_entity(world: &mut World, char_entity: Entity) -> Entity {
+        let combo_count_entity = world.create_entity().build();
+
+        let mut combo_count_prefab_system_data =
+            world.system_data::<<ComboCountPrefab as PrefabData>::SystemData>();
+        let combo_count_prefab = ComboCountPrefab::new(char_entity);
+
+        combo_count_prefab
+            .add_to_entity(
+                combo_count_entity,
+                &mut combo_count_prefab_system_data,
+                &[],
+                &[],
+            )
+            .expect("`ComboCountPrefab` failed to augment entity.");
+
+        combo_count_entity
+    }
+}
@@ -0,0 +1 @@
+mod combo_scaling;
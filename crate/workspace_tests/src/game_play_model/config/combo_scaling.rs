@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use game_play_model::config::ComboScaling;
+
+    #[test]
+    fn first_hit_is_not_scaled() {
+        let combo_scaling = ComboScaling::new(0.5, 0.75, 0.1);
+
+        assert_eq!(1., combo_scaling.hp_damage_multiplier(1));
+        assert_eq!(1., combo_scaling.stun_multiplier(1));
+    }
+
+    #[test]
+    fn multiplier_is_applied_for_each_hit_after_the_first() {
+        let combo_scaling = ComboScaling::new(0.5, 0.75, 0.1);
+
+        assert_eq!(0.25, combo_scaling.hp_damage_multiplier(3));
+        assert_eq!(0.5625, combo_scaling.stun_multiplier(3));
+    }
+
+    #[test]
+    fn multiplier_is_bounded_by_min() {
+        let combo_scaling = ComboScaling::new(0.5, 0.75, 0.3);
+
+        assert_eq!(0.3, combo_scaling.hp_damage_multiplier(10));
+        assert_eq!(0.3, combo_scaling.stun_multiplier(10));
+    }
+}
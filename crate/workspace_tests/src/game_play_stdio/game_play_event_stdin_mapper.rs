@@ -23,6 +23,7 @@ mod tests {
     test_mapping!(maps_restart_event, Restart);
     test_mapping!(maps_pause_event, Pause);
     test_mapping!(maps_resume_event, Resume);
+    test_mapping!(maps_control_settings_event, ControlSettings);
     test_mapping!(maps_end_event, End);
     test_mapping!(maps_end_stats_event, EndStats);
 }
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod test {
+    use amethyst::ui::Anchor;
+    use kinematic_model::config::PositionInit;
+    use serde_yaml;
+    use ui_label_model::config::UiLabel;
+    use ui_model_spi::config::Dimensions;
+
+    use game_stats_ui_model::config::{GameStatsUi, PlayerStatsWidgetTemplate, PlayerStatsWidgets};
+
+    const GAME_STATS_UI_YAML_ALL: &str = r#"
+player_stats:
+  position: { x: 50, y: 400, z: 11 }
+
+  player_stats_widget_template:
+    dimensions: { w: 700, h: 40 }
+
+    player_name:
+      position   : { x: 0, y: 5 }
+      dimensions : { w: 150, h: 30 }
+      align      : "BottomLeft"
+      font_colour: [1.0, 1.0, 1.0, 1.0]
+      font_size  : 30
+
+    stats:
+      position   : { x: 150, y: 5 }
+      dimensions : { w: 550, h: 30 }
+      align      : "BottomLeft"
+      font_colour: [0.7, 0.7, 0.7, 1.0]
+      font_size  : 20
+"#;
+
+    #[test]
+    fn deserialize_game_stats_ui_yaml_all() {
+        let game_stats_ui = serde_yaml::from_str::<GameStatsUi>(GAME_STATS_UI_YAML_ALL)
+            .expect("Failed to deserialize `GameStatsUi`.");
+
+        let player_name = UiLabel {
+            position: PositionInit { x: 0, y: 5, z: 0 },
+            dimensions: Dimensions { w: 150, h: 30 },
+            align: Anchor::BottomLeft,
+            font_colour: [1.0, 1.0, 1.0, 1.0],
+            font_size: 30,
+            ..Default::default()
+        };
+
+        let stats = UiLabel {
+            position: PositionInit { x: 150, y: 5, z: 0 },
+            dimensions: Dimensions { w: 550, h: 30 },
+            align: Anchor::BottomLeft,
+            font_colour: [0.7, 0.7, 0.7, 1.0],
+            font_size: 20,
+            ..Default::default()
+        };
+
+        let player_stats_widget_template = PlayerStatsWidgetTemplate {
+            dimensions: Dimensions { w: 700, h: 40 },
+            player_name,
+            stats,
+        };
+
+        let player_stats = PlayerStatsWidgets {
+            position: PositionInit {
+                x: 50,
+                y: 400,
+                z: 11,
+            },
+            player_stats_widget_template,
+        };
+
+        let game_stats_ui_expected = GameStatsUi { player_stats };
+
+        assert_eq!(game_stats_ui_expected, game_stats_ui);
+    }
+}
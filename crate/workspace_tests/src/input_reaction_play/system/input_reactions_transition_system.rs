@@ -62,6 +62,32 @@ mod tests {
         )
     }
 
+    #[test]
+    fn inserts_defend_transition_for_defend_press_event() -> Result<(), Error> {
+        let mut controller_input = ControllerInput::default();
+        controller_input.defend = true;
+
+        run_test(
+            SetupParams {
+                sequence_id: SequenceId::new(0),
+                controller_input,
+                control_input_event_fn: Some(|entity| {
+                    let control_action_event_data = ControlActionEventData {
+                        controller_id: 0,
+                        entity,
+                        control_action: ControlAction::Defend,
+                    };
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                }),
+                charge_tracker_clock: ChargeTrackerClock::new_with_value(100, 100),
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(16),
+                charge_use_events_fn: None,
+            },
+        )
+    }
+
     #[test]
     fn inserts_transition_for_action_release_event() -> Result<(), Error> {
         run_test(
@@ -586,6 +612,10 @@ mod tests {
             SequenceNameString::Name(CharacterSequenceName::LieFaceDown),
             SequenceId::new(15),
         );
+        sequence_id_mappings.insert(
+            SequenceNameString::Name(CharacterSequenceName::Defend),
+            SequenceId::new(16),
+        );
         sequence_id_mappings
     }
 
@@ -9,6 +9,8 @@ extern crate hamcrest;
 #[macro_use]
 extern crate pretty_assertions;
 
+#[cfg(test)]
+mod ai_play;
 #[cfg(test)]
 mod application;
 #[cfg(test)]
@@ -36,8 +38,12 @@ mod asset_ui_model;
 #[cfg(test)]
 mod audio_loading;
 #[cfg(test)]
+mod audio_model;
+#[cfg(test)]
 mod audio_play;
 #[cfg(test)]
+mod audio_settings_stdio;
+#[cfg(test)]
 mod background_loading;
 #[cfg(test)]
 mod background_model;
@@ -72,6 +78,10 @@ mod collision_play;
 #[cfg(test)]
 mod debug_util_amethyst;
 #[cfg(test)]
+mod desync_model;
+#[cfg(test)]
+mod desync_play;
+#[cfg(test)]
 mod energy_loading;
 #[cfg(test)]
 mod energy_model;
@@ -96,8 +106,12 @@ mod game_play;
 #[cfg(test)]
 mod game_play_hud;
 #[cfg(test)]
+mod game_play_model;
+#[cfg(test)]
 mod game_play_stdio;
 #[cfg(test)]
+mod game_stats_ui_model;
+#[cfg(test)]
 mod input_reaction_loading;
 #[cfg(test)]
 mod input_reaction_model;
@@ -122,6 +136,12 @@ mod map_selection;
 #[cfg(test)]
 mod map_selection_ui_model;
 #[cfg(test)]
+mod music_loading;
+#[cfg(test)]
+mod music_play;
+#[cfg(test)]
+mod network_input_model;
+#[cfg(test)]
 mod network_input_play;
 #[cfg(test)]
 mod network_mode_selection_stdio;
@@ -136,6 +156,12 @@ mod object_status_play;
 #[cfg(test)]
 mod parent_play;
 #[cfg(test)]
+mod replay_play;
+#[cfg(test)]
+mod rollback_model;
+#[cfg(test)]
+mod rollback_play;
+#[cfg(test)]
 mod sequence_loading;
 #[cfg(test)]
 mod sequence_play;
@@ -152,6 +178,8 @@ mod session_lobby_play;
 #[cfg(test)]
 mod session_lobby_ui_model;
 #[cfg(test)]
+mod shape_model;
+#[cfg(test)]
 mod spawn_loading;
 #[cfg(test)]
 mod spawn_model;
@@ -187,3 +215,5 @@ mod ui_loading;
 mod ui_model;
 #[cfg(test)]
 mod ui_play;
+#[cfg(test)]
+mod weapon_play;
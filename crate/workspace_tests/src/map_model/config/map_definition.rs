@@ -5,9 +5,11 @@ mod test {
     use kinematic_model::config::PositionInit;
     use sequence_model::config::{SequenceEndTransition, Wait};
     use serde_yaml;
+    use shape_model::Volume;
     use sprite_model::config::{SpriteFrame, SpriteItem, SpriteRef, SpriteSequence};
 
     use map_model::config::{MapBounds, MapDefinition, MapHeader};
+    use music_model::config::MusicId;
 
     const MAP_NO_SPRITE_SEQUENCES: &str = r#"---
 header:
@@ -33,6 +35,23 @@ layers:
     frames: [{ wait: 1, sprite: { sheet: 0, index: 0 } }]
 "#;
 
+    const MAP_WITH_MUSIC: &str = r#"---
+header:
+  name: "Map with music"
+  bounds: { x: 1, y: 2, z: 3, width: 800, height: 600, depth: 200 }
+
+music: "battle"
+"#;
+
+    const MAP_WITH_TERRAIN: &str = r#"---
+header:
+  name: "Map with terrain"
+  bounds: { x: 1, y: 2, z: 3, width: 800, height: 600, depth: 200 }
+
+terrain:
+  - { box: { x: 100, y: 0, z: 0, w: 200, h: 50, d: 200 } }
+"#;
+
     #[test]
     fn deserialize_minimal_definition() {
         let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_NO_SPRITE_SEQUENCES)
@@ -88,4 +107,37 @@ layers:
 
         assert_eq!(expected, map_definition);
     }
+
+    #[test]
+    fn deserialize_with_music() {
+        let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_WITH_MUSIC)
+            .expect("Failed to deserialize map definition.");
+
+        let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
+        let header = MapHeader::new("Map with music".to_string(), bounds);
+        let mut expected = MapDefinition::new(header, BackgroundDefinition::default());
+        expected.music = Some(MusicId::new(String::from("battle")));
+
+        assert_eq!(expected, map_definition);
+    }
+
+    #[test]
+    fn deserialize_with_terrain() {
+        let map_definition = serde_yaml::from_str::<MapDefinition>(MAP_WITH_TERRAIN)
+            .expect("Failed to deserialize map definition.");
+
+        let bounds = MapBounds::new(1, 2, 3, 800, 600, 200);
+        let header = MapHeader::new("Map with terrain".to_string(), bounds);
+        let mut expected = MapDefinition::new(header, BackgroundDefinition::default());
+        expected.terrain = vec![Volume::Box {
+            x: 100,
+            y: 0,
+            z: 0,
+            w: 200,
+            h: 50,
+            d: 200,
+        }];
+
+        assert_eq!(expected, map_definition);
+    }
 }
@@ -0,0 +1 @@
+mod music_loading_bundle;
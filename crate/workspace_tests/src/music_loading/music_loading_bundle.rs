@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use amethyst::{assets::AssetStorage, ecs::WorldExt, Error};
+    use amethyst_test::AmethystApplication;
+    use music_model::{config::MusicTracks, loaded::MusicMap, MusicLoadingStatus};
+
+    use music_loading::MusicLoadingBundle;
+
+    #[test]
+    fn bundle_build_adds_music_resources() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(MusicLoadingBundle::new(PathBuf::default()))
+            .with_assertion(|world| {
+                // Panics if the Systems weren't added
+                world.read_resource::<AssetStorage<MusicTracks>>();
+
+                world.read_resource::<MusicLoadingStatus>();
+                world.read_resource::<MusicMap>();
+            })
+            .run()
+    }
+}
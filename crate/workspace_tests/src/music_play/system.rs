@@ -0,0 +1 @@
+mod music_play_system;
@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, str::FromStr, time::Duration};
+
+    use amethyst::{
+        assets::{AssetStorage, Loader, ProgressCounter},
+        audio::Source,
+        ecs::{Read, ReadExpect, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use audio_loading::AudioLoader;
+    use map_selection_model::MapSelection;
+    use music_model::{
+        config::MusicId,
+        loaded::{AssetMusicId, MusicMap, MusicSource},
+        play::MusicPlayback,
+        MusicEvent,
+    };
+    use state_registry::{StateId, StateIdUpdateEvent};
+
+    use music_play::MusicPlaySystem;
+
+    #[test]
+    fn selects_state_music_when_state_changes() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: None,
+                state_id: Some(StateId::CharacterSelection),
+                music_event: None,
+            },
+            ExpectedParams {
+                music_id: Some("menu"),
+            },
+        )
+    }
+
+    #[test]
+    fn selects_map_music_when_game_play_begins() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: Some("menu"),
+                state_id: Some(StateId::GamePlay),
+                music_event: None,
+            },
+            ExpectedParams {
+                music_id: Some("battle"),
+            },
+        )
+    }
+
+    #[test]
+    fn keeps_music_when_state_has_no_music() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: Some("menu"),
+                state_id: Some(StateId::MapSelection),
+                music_event: None,
+            },
+            ExpectedParams {
+                music_id: Some("menu"),
+            },
+        )
+    }
+
+    #[test]
+    fn query_does_not_change_music() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: Some("menu"),
+                state_id: None,
+                music_event: Some(MusicEvent::Query),
+            },
+            ExpectedParams {
+                music_id: Some("menu"),
+            },
+        )
+    }
+
+    #[test]
+    fn skip_selects_next_music() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: Some("battle"),
+                state_id: None,
+                music_event: Some(MusicEvent::Skip),
+            },
+            ExpectedParams {
+                music_id: Some("menu"),
+            },
+        )
+    }
+
+    #[test]
+    fn skip_wraps_to_first_music() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                music_id: Some("menu"),
+                state_id: None,
+                music_event: Some(MusicEvent::Skip),
+            },
+            ExpectedParams {
+                music_id: Some("battle"),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            music_id: music_id_setup,
+            state_id,
+            music_event,
+        }: SetupParams,
+        ExpectedParams {
+            music_id: music_id_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(MusicPlaySystem::new(), "", &[])
+            .with_effect(move |world| {
+                setup_music(world);
+                world.insert(MusicPlayback::new(music_id_setup.map(music_id)));
+
+                if let Some(state_id) = state_id {
+                    world
+                        .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                        .single_write(StateIdUpdateEvent::new(state_id, None));
+                }
+                if let Some(music_event) = music_event {
+                    world
+                        .write_resource::<EventChannel<MusicEvent>>()
+                        .single_write(music_event);
+                }
+            })
+            .with_assertion(move |world| {
+                let music_playback = world.read_resource::<MusicPlayback>();
+
+                assert_eq!(
+                    MusicPlayback::new(music_id_expected.map(music_id)),
+                    *music_playback
+                );
+            })
+            .run()
+    }
+
+    fn setup_music(world: &mut World) {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let character_selection_id = asset_id_mappings.insert(slug("test/character_selection"));
+        asset_id_mappings.insert(slug("test/map_selection"));
+        let map_id = asset_id_mappings.insert(slug("test/map"));
+
+        let mut asset_music_id = AssetMusicId::new();
+        asset_music_id.insert(character_selection_id, music_id("menu"));
+        asset_music_id.insert(map_id, music_id("battle"));
+
+        let music_map = {
+            let mut progress_counter = ProgressCounter::new();
+            let (loader, source_assets) =
+                world.system_data::<(ReadExpect<'_, Loader>, Read<'_, AssetStorage<Source>>)>();
+            let mut music_source = || {
+                let source_handle = AudioLoader::load(
+                    &loader,
+                    &source_assets,
+                    &mut progress_counter,
+                    Path::new("test/sfx/empty.wav"),
+                );
+                MusicSource::new(source_handle, Duration::default(), None)
+            };
+
+            let mut music_map = MusicMap::default();
+            music_map.insert(music_id("battle"), music_source());
+            music_map.insert(music_id("menu"), music_source());
+            music_map
+        };
+
+        world.insert(asset_id_mappings);
+        world.insert(asset_music_id);
+        world.insert(music_map);
+        world.insert(MapSelection::Id(map_id));
+    }
+
+    fn slug(slug_str: &str) -> AssetSlug {
+        AssetSlug::from_str(slug_str).expect("Expected asset slug to be valid.")
+    }
+
+    fn music_id(music_id: &str) -> MusicId {
+        MusicId::new(String::from(music_id))
+    }
+
+    struct SetupParams {
+        music_id: Option<&'static str>,
+        state_id: Option<StateId>,
+        music_event: Option<MusicEvent>,
+    }
+
+    struct ExpectedParams {
+        music_id: Option<&'static str>,
+    }
+}
@@ -0,0 +1 @@
+mod network_input_buffer;
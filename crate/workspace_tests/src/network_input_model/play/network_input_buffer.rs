@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        loaded::PlayerControllers,
+        GameInputEvent,
+    };
+    use network_input_model::play::{FrameNumber, NetworkInputBuffer, NetworkInputFrame};
+    use network_session_model::play::{
+        SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+    };
+
+    #[test]
+    fn is_complete_returns_false_when_no_input_for_frame() {
+        let network_input_buffer = NetworkInputBuffer::new();
+
+        assert!(!network_input_buffer.is_complete(FrameNumber::new(0), &session_devices()));
+    }
+
+    #[test]
+    fn is_complete_returns_false_when_input_missing_from_device() {
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(1, 0, vec![]));
+
+        assert!(!network_input_buffer.is_complete(FrameNumber::new(0), &session_devices()));
+    }
+
+    #[test]
+    fn is_complete_returns_true_when_input_received_from_all_devices() {
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(1, 0, vec![]));
+        network_input_buffer.insert(network_input_frame(2, 0, vec![]));
+
+        assert!(network_input_buffer.is_complete(FrameNumber::new(0), &session_devices()));
+        assert!(!network_input_buffer.is_complete(FrameNumber::new(1), &session_devices()));
+    }
+
+    #[test]
+    fn take_returns_events_ordered_by_session_device_id() {
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(2, 0, vec![press(1)]));
+        network_input_buffer.insert(network_input_frame(1, 0, vec![press(0)]));
+        network_input_buffer.insert(network_input_frame(1, 1, vec![press(2)]));
+
+        assert_eq!(
+            vec![press(0), press(1)],
+            network_input_buffer.take(FrameNumber::new(0))
+        );
+        assert_eq!(
+            vec![press(2)],
+            network_input_buffer.take(FrameNumber::new(1))
+        );
+        assert!(network_input_buffer.is_empty());
+    }
+
+    #[test]
+    fn take_discards_earlier_frames() {
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(1, 0, vec![press(0)]));
+        network_input_buffer.insert(network_input_frame(1, 1, vec![press(1)]));
+
+        assert_eq!(
+            vec![press(1)],
+            network_input_buffer.take(FrameNumber::new(1))
+        );
+        assert!(network_input_buffer.is_empty());
+    }
+
+    fn session_devices() -> SessionDevices {
+        SessionDevices::new(vec![
+            SessionDevice::new(
+                SessionDeviceId::new(1),
+                SessionDeviceName::from(String::from("one")),
+                PlayerControllers::default(),
+            ),
+            SessionDevice::new(
+                SessionDeviceId::new(2),
+                SessionDeviceName::from(String::from("two")),
+                PlayerControllers::default(),
+            ),
+        ])
+    }
+
+    fn network_input_frame(
+        session_device_id: u64,
+        frame_number: u64,
+        game_input_events: Vec<GameInputEvent>,
+    ) -> NetworkInputFrame {
+        NetworkInputFrame::new(
+            SessionDeviceId::new(session_device_id),
+            FrameNumber::new(frame_number),
+            game_input_events,
+        )
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
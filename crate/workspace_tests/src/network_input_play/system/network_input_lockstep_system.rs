@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        loaded::PlayerControllers,
+        GameInputEvent,
+    };
+    use game_play_model::{GamePlayStatus, GamePlayStepStatus};
+    use network_input_model::play::{
+        FrameNumber, LockstepFrameNumbers, NetworkInputBuffer, NetworkInputFrame,
+    };
+    use network_session_model::{
+        config::SessionServerConfig,
+        play::{SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices, SessionStatus},
+    };
+    use state_registry::StateId;
+
+    use network_input_play::NetworkInputLockstepSystemDesc;
+
+    #[test]
+    fn ready_when_not_in_session() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Playing,
+                network_input_frames: vec![],
+            },
+            ExpectedParams {
+                game_play_step_status: GamePlayStepStatus::Ready,
+                step: FrameNumber::new(0),
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn waits_when_input_missing_from_device() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Playing,
+                network_input_frames: vec![network_input_frame(1, vec![press(0)])],
+            },
+            ExpectedParams {
+                game_play_step_status: GamePlayStepStatus::Waiting,
+                step: FrameNumber::new(0),
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn steps_when_input_received_from_all_devices() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Playing,
+                network_input_frames: vec![
+                    network_input_frame(2, vec![press(1)]),
+                    network_input_frame(1, vec![press(0)]),
+                ],
+            },
+            ExpectedParams {
+                game_play_step_status: GamePlayStepStatus::Ready,
+                step: FrameNumber::new(1),
+                game_input_events: vec![press(0), press(1)],
+            },
+        )
+    }
+
+    #[test]
+    fn paused_when_game_play_is_paused() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                state_id: StateId::GamePlay,
+                game_play_status: GamePlayStatus::Paused,
+                network_input_frames: vec![
+                    network_input_frame(2, vec![press(1)]),
+                    network_input_frame(1, vec![press(0)]),
+                ],
+            },
+            ExpectedParams {
+                game_play_step_status: GamePlayStepStatus::Paused,
+                step: FrameNumber::new(0),
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_status,
+            state_id,
+            game_play_status,
+            network_input_frames,
+        }: SetupParams,
+        ExpectedParams {
+            game_play_step_status: game_play_step_status_expected,
+            step: step_expected,
+            game_input_events: game_input_events_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_resource(session_status)
+            .with_resource(state_id)
+            .with_resource(game_play_status)
+            .with_resource(session_devices())
+            .with_resource(SessionServerConfig {
+                rollback_frames: 0,
+                ..Default::default()
+            })
+            .with_resource({
+                let mut network_input_buffer = NetworkInputBuffer::new();
+                network_input_frames
+                    .into_iter()
+                    .for_each(|network_input_frame| {
+                        network_input_buffer.insert(network_input_frame)
+                    });
+                network_input_buffer
+            })
+            .with_system_desc(NetworkInputLockstepSystemDesc::default(), "", &[])
+            .with_assertion(move |world| {
+                let (
+                    game_play_step_status,
+                    lockstep_frame_numbers,
+                    mut game_input_event_rid,
+                    game_input_ec,
+                ) = world.system_data::<(
+                    Read<'_, GamePlayStepStatus>,
+                    Read<'_, LockstepFrameNumbers>,
+                    WriteExpect<'_, ReaderId<GameInputEvent>>,
+                    Read<'_, EventChannel<GameInputEvent>>,
+                )>();
+                let game_input_events = game_input_ec
+                    .read(&mut *game_input_event_rid)
+                    .copied()
+                    .collect::<Vec<GameInputEvent>>();
+
+                assert_eq!(game_play_step_status_expected, *game_play_step_status);
+                assert_eq!(step_expected, lockstep_frame_numbers.step);
+                assert_eq!(game_input_events_expected, game_input_events);
+            })
+            .run()
+    }
+
+    fn setup_game_input_event_reader(world: &mut World) {
+        let game_input_event_rid = world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .register_reader();
+        world.insert(game_input_event_rid);
+    }
+
+    fn session_devices() -> SessionDevices {
+        SessionDevices::new(vec![
+            SessionDevice::new(
+                SessionDeviceId::new(1),
+                SessionDeviceName::from(String::from("one")),
+                PlayerControllers::default(),
+            ),
+            SessionDevice::new(
+                SessionDeviceId::new(2),
+                SessionDeviceName::from(String::from("two")),
+                PlayerControllers::default(),
+            ),
+        ])
+    }
+
+    fn network_input_frame(
+        session_device_id: u64,
+        game_input_events: Vec<GameInputEvent>,
+    ) -> NetworkInputFrame {
+        NetworkInputFrame::new(
+            SessionDeviceId::new(session_device_id),
+            FrameNumber::new(0),
+            game_input_events,
+        )
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+
+    struct SetupParams {
+        session_status: SessionStatus,
+        state_id: StateId,
+        game_play_status: GamePlayStatus,
+        network_input_frames: Vec<NetworkInputFrame>,
+    }
+
+    struct ExpectedParams {
+        game_play_step_status: GamePlayStepStatus,
+        step: FrameNumber,
+        game_input_events: Vec<GameInputEvent>,
+    }
+}
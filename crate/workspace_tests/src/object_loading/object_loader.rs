@@ -3,7 +3,7 @@ mod test {
     use amethyst::{
         assets::{AssetStorage, ProgressCounter},
         core::TransformBundle,
-        ecs::{Read, SystemData, WorldExt},
+        ecs::{Read, SystemData, World, WorldExt},
         renderer::{types::DefaultBackend, RenderEmptyBundle, SpriteSheet, Texture},
         Error,
     };
@@ -13,8 +13,10 @@ mod test {
     use assets_test::{CHAR_BAT_PATH, CHAR_BAT_SLUG};
     use character_model::config::{CharacterDefinition, CharacterSequence};
     use collision_loading::CollisionLoadingBundle;
+    use collision_model::{config::Body, loaded::BodySequence};
     use object_model::loaded::Object;
     use sequence_loading::SequenceLoadingBundle;
+    use shape_model::Volume;
     use spawn_loading::SpawnLoadingBundle;
     use sprite_loading::SpriteLoader;
     use sprite_model::config::SpritesDefinition;
@@ -31,46 +33,8 @@ mod test {
             .with_bundle(SequenceLoadingBundle::new())
             .with_setup(|world| TestSystemData::setup(world))
             .with_effect(|world| {
-                let asset_record = AssetRecord::new(CHAR_BAT_SLUG.clone(), CHAR_BAT_PATH.clone());
-
-                let character_definition = AppFile::load_in::<CharacterDefinition, _>(
-                    &asset_record.path,
-                    "object.yaml",
-                    Format::Yaml,
-                )
-                .expect("Failed to load object.yaml into CharacterDefinition");
-
-                let object = {
-                    let sprites_definition = AppFile::load_in::<SpritesDefinition, _>(
-                        &asset_record.path,
-                        "sprites.yaml",
-                        Format::Yaml,
-                    )
-                    .expect("Failed to load sprites_definition.");
-
-                    let (object_loader_system_data, texture_assets, sprite_sheet_assets) =
-                        world.system_data::<TestSystemData>();
-
-                    // TODO: <https://gitlab.com/azriel91/autexousious/issues/94>
-                    let sprite_sheet_handles = SpriteLoader::load(
-                        &mut ProgressCounter::default(),
-                        &object_loader_system_data.loader,
-                        &texture_assets,
-                        &sprite_sheet_assets,
-                        &sprites_definition,
-                        &asset_record.path,
-                    )
-                    .expect("Failed to load sprites.");
-                    let sprite_sheet_handles = &sprite_sheet_handles;
-
-                    ObjectLoader::load::<CharacterSequence>(
-                        ObjectLoaderParams::from((
-                            &object_loader_system_data,
-                            sprite_sheet_handles.as_slice(),
-                        )),
-                        &character_definition.object_definition,
-                    )
-                };
+                let character_definition = character_definition();
+                let object = object_load(world, &character_definition);
 
                 world.insert(object);
             })
@@ -100,6 +64,107 @@ mod test {
             .run_isolated()
     }
 
+    #[test]
+    fn drops_invalid_volumes() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(TransformBundle::new())
+            .with_bundle(RenderEmptyBundle::<DefaultBackend>::new())
+            .with_bundle(CollisionLoadingBundle::new())
+            .with_bundle(SpawnLoadingBundle::new())
+            .with_bundle(SequenceLoadingBundle::new())
+            .with_setup(|world| TestSystemData::setup(world))
+            .with_effect(|world| {
+                let mut character_definition = character_definition();
+                character_definition
+                    .object_definition
+                    .sequences
+                    .values_mut()
+                    .flat_map(|sequence| sequence.object_sequence.sequence.frames.iter_mut())
+                    .for_each(|frame| {
+                        frame.object_frame.body = Body::new(vec![VOLUME_VALID, VOLUME_INVALID]);
+                    });
+                let object = object_load(world, &character_definition);
+
+                world.insert(object);
+            })
+            .with_assertion(|world| {
+                let object = world.read_resource::<Object>();
+                let (body_sequence_assets, body_assets) = world.system_data::<(
+                    Read<'_, AssetStorage<BodySequence>>,
+                    Read<'_, AssetStorage<Body>>,
+                )>();
+
+                let body_sequence_handle = object
+                    .body_sequence_handles
+                    .first()
+                    .expect("Expected object to have a `BodySequence`.");
+                let body_handle = body_sequence_assets
+                    .get(body_sequence_handle)
+                    .and_then(|body_sequence| body_sequence.first())
+                    .expect("Expected `BodySequence` to have a `Body`.");
+                let body = body_assets
+                    .get(body_handle)
+                    .expect("Expected `Body` to be loaded.");
+
+                assert_eq!(&Body::new(vec![VOLUME_VALID]), body);
+            })
+            .run_isolated()
+    }
+
+    fn character_definition() -> CharacterDefinition {
+        let asset_record = AssetRecord::new(CHAR_BAT_SLUG.clone(), CHAR_BAT_PATH.clone());
+
+        AppFile::load_in::<CharacterDefinition, _>(&asset_record.path, "object.yaml", Format::Yaml)
+            .expect("Failed to load object.yaml into CharacterDefinition")
+    }
+
+    fn object_load(world: &mut World, character_definition: &CharacterDefinition) -> Object {
+        let asset_record = AssetRecord::new(CHAR_BAT_SLUG.clone(), CHAR_BAT_PATH.clone());
+        let sprites_definition = AppFile::load_in::<SpritesDefinition, _>(
+            &asset_record.path,
+            "sprites.yaml",
+            Format::Yaml,
+        )
+        .expect("Failed to load sprites_definition.");
+
+        let (object_loader_system_data, texture_assets, sprite_sheet_assets) =
+            world.system_data::<TestSystemData>();
+
+        // TODO: <https://gitlab.com/azriel91/autexousious/issues/94>
+        let sprite_sheet_handles = SpriteLoader::load(
+            &mut ProgressCounter::default(),
+            &object_loader_system_data.loader,
+            &texture_assets,
+            &sprite_sheet_assets,
+            &sprites_definition,
+            &asset_record.path,
+        )
+        .expect("Failed to load sprites.");
+        let sprite_sheet_handles = &sprite_sheet_handles;
+
+        ObjectLoader::load::<CharacterSequence>(
+            ObjectLoaderParams::from((&object_loader_system_data, sprite_sheet_handles.as_slice())),
+            &character_definition.object_definition,
+        )
+    }
+
+    const VOLUME_VALID: Volume = Volume::Box {
+        x: 0,
+        y: 0,
+        z: 0,
+        w: 10,
+        h: 10,
+        d: 10,
+    };
+    const VOLUME_INVALID: Volume = Volume::Box {
+        x: 0,
+        y: 0,
+        z: 0,
+        w: 0,
+        h: 10,
+        d: 10,
+    };
+
     type TestSystemData<'s> = (
         ObjectLoaderSystemData<'s>,
         Read<'s, AssetStorage<Texture>>,
@@ -2,3 +2,4 @@ mod object_acceleration_system;
 mod object_gravity_system;
 mod object_grounding_system;
 mod object_mirroring_system;
+mod object_terrain_collision_system;
@@ -12,10 +12,11 @@ mod tests {
     use map_loading::MapLoadingBundle;
     use map_model::{
         config::MapBounds,
-        loaded::{AssetMargins, Margins},
+        loaded::{AssetMargins, AssetTerrain, Margins, Terrain},
     };
     use map_selection_model::MapSelection;
     use object_model::play::Grounding;
+    use shape_model::VolumeBounds;
 
     use object_play::ObjectGroundingSystem;
 
@@ -25,6 +26,7 @@ mod tests {
             SetupParams {
                 grounding: Grounding::Airborne,
                 position: Position::new(0., 200., 0.),
+                terrain: Vec::new(),
             },
             ExpectedParams {
                 grounding: Grounding::OnGround,
@@ -38,6 +40,7 @@ mod tests {
             SetupParams {
                 grounding: Grounding::OnGround,
                 position: Position::new(0., 190., 0.),
+                terrain: Vec::new(),
             },
             ExpectedParams {
                 grounding: Grounding::Underground,
@@ -51,6 +54,49 @@ mod tests {
             SetupParams {
                 grounding: Grounding::OnGround,
                 position: Position::new(0., 210., 0.),
+                terrain: Vec::new(),
+            },
+            ExpectedParams {
+                grounding: Grounding::Airborne,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_on_ground_when_on_terrain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::Airborne,
+                position: Position::new(100., 250., 100.),
+                terrain: vec![VolumeBounds::new([50., 200., 50.], [150., 250., 150.])],
+            },
+            ExpectedParams {
+                grounding: Grounding::OnGround,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_airborne_when_beside_terrain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::OnGround,
+                position: Position::new(10., 250., 100.),
+                terrain: vec![VolumeBounds::new([50., 200., 50.], [150., 250., 150.])],
+            },
+            ExpectedParams {
+                grounding: Grounding::Airborne,
+            },
+        )
+    }
+
+    #[test]
+    fn sets_grounding_to_airborne_when_above_terrain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                grounding: Grounding::OnGround,
+                position: Position::new(100., 260., 100.),
+                terrain: vec![VolumeBounds::new([50., 200., 50.], [150., 250., 150.])],
             },
             ExpectedParams {
                 grounding: Grounding::Airborne,
@@ -62,6 +108,7 @@ mod tests {
         SetupParams {
             grounding,
             position,
+            terrain,
         }: SetupParams,
         ExpectedParams {
             grounding: grounding_expected,
@@ -72,6 +119,14 @@ mod tests {
             .with_setup(setup_system_data)
             .with_setup(setup_map_selection)
             .with_effect(move |world| {
+                let asset_id = world
+                    .read_resource::<MapSelection>()
+                    .asset_id()
+                    .expect("Expected `MapSelection` asset ID to exist.");
+                world
+                    .write_resource::<AssetTerrain>()
+                    .insert(asset_id, Terrain::new(terrain));
+
                 let entity = world.create_entity().with(grounding).with(position).build();
 
                 world.insert(entity);
@@ -121,6 +176,7 @@ mod tests {
     struct SetupParams {
         grounding: Grounding,
         position: Position<f32>,
+        terrain: Vec<VolumeBounds>,
     }
 
     struct ExpectedParams {
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use std::{any, str::FromStr};
+
+    use amethyst::{
+        ecs::{Builder, Entity, Read, System, SystemData, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use kinematic_model::config::{Position, Velocity};
+    use map_model::{
+        loaded::{AssetTerrain, Terrain},
+        play::MapBounded,
+    };
+    use map_selection_model::MapSelection;
+    use shape_model::VolumeBounds;
+
+    use object_play::ObjectTerrainCollisionSystem;
+
+    #[test]
+    fn places_object_on_terrain_when_falling_onto_it() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(100., 245., 100.),
+                velocity: Velocity::new(3., -10., 0.),
+            },
+            ExpectedParams {
+                position: Position::new(100., 250., 100.),
+                velocity: Velocity::new(3., 0., 0.),
+            },
+        )
+    }
+
+    #[test]
+    fn stops_object_against_terrain_when_moving_into_its_side() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(55., 220., 100.),
+                velocity: Velocity::new(10., 0., 0.),
+            },
+            ExpectedParams {
+                position: Position::new(50., 220., 100.),
+                velocity: Velocity::new(0., 0., 0.),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_move_object_outside_terrain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(10., 220., 100.),
+                velocity: Velocity::new(5., 0., 0.),
+            },
+            ExpectedParams {
+                position: Position::new(10., 220., 100.),
+                velocity: Velocity::new(5., 0., 0.),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_move_object_resting_on_terrain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                position: Position::new(100., 250., 100.),
+                velocity: Velocity::new(0., 0., 0.),
+            },
+            ExpectedParams {
+                position: Position::new(100., 250., 100.),
+                velocity: Velocity::new(0., 0., 0.),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams { position, velocity }: SetupParams,
+        ExpectedParams {
+            position: position_expected,
+            velocity: velocity_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(setup_system_data)
+            .with_setup(setup_map_selection)
+            .with_effect(move |world| {
+                let entity = world
+                    .create_entity()
+                    .with(MapBounded)
+                    .with(position)
+                    .with(velocity)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_system_single(
+                ObjectTerrainCollisionSystem::new(),
+                any::type_name::<ObjectTerrainCollisionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let positions = world.read_storage::<Position<f32>>();
+                let velocities = world.read_storage::<Velocity<f32>>();
+                let position_actual = positions
+                    .get(entity)
+                    .copied()
+                    .expect("Expected entity to have `Position<f32>` component.");
+                let velocity_actual = velocities
+                    .get(entity)
+                    .copied()
+                    .expect("Expected entity to have `Velocity<f32>` component.");
+
+                assert_eq!(position_expected, position_actual);
+                assert_eq!(velocity_expected, velocity_actual);
+            })
+            .run()
+    }
+
+    fn setup_system_data(world: &mut World) {
+        <ObjectTerrainCollisionSystem as System<'_>>::SystemData::setup(world);
+        <Read<'_, AssetIdMappings> as SystemData>::setup(world);
+    }
+
+    fn setup_map_selection(world: &mut World) {
+        let map_selection = {
+            let terrain = Terrain::new(vec![VolumeBounds::new(
+                [50., 200., 50.],
+                [150., 250., 150.],
+            )]);
+
+            let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
+            let mut asset_terrain = world.write_resource::<AssetTerrain>();
+            let slug =
+                AssetSlug::from_str("test/empty_map").expect("Expected asset slug to be valid.");
+
+            let asset_id = asset_id_mappings.insert(slug);
+            asset_terrain.insert(asset_id, terrain);
+
+            MapSelection::Id(asset_id)
+        };
+
+        world.insert(map_selection);
+    }
+
+    struct SetupParams {
+        position: Position<f32>,
+        velocity: Velocity<f32>,
+    }
+
+    struct ExpectedParams {
+        position: Position<f32>,
+        velocity: Velocity<f32>,
+    }
+}
@@ -1 +1,2 @@
+mod guard_points_recovery_system;
 mod stun_points_reduction_system;
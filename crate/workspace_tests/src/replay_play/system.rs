@@ -0,0 +1,2 @@
+mod replay_playback_system;
+mod replay_record_system;
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, str::FromStr};
+
+    use amethyst::{
+        ecs::{Builder, Entity, Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::config::AssetSlug;
+    use game_input_model::{
+        config::{ControlAction, ControlArgs, ControlInputEventArgs, PlayerActionControl},
+        play::{ControlActionEventData, ControlInputEvent, InputControlled},
+        GameInputEvent,
+    };
+    use network_input_model::play::FrameNumber;
+    use replay_model::{
+        config::{Replay, ReplayFrame},
+        play::ReplayMode,
+    };
+    use state_registry::StateId;
+
+    use replay_play::ReplayPlaybackSystemDesc;
+
+    #[test]
+    fn sends_recorded_game_input_events_during_game_play() -> Result<(), Error> {
+        run_test(StateId::GamePlay, vec![press(0), press(1)])
+    }
+
+    #[test]
+    fn does_not_send_game_input_events_outside_game_play() -> Result<(), Error> {
+        run_test(StateId::CharacterSelection, vec![])
+    }
+
+    #[test]
+    fn sends_recorded_game_input_events_when_game_play_reaches_frame() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_resource(StateId::GamePlay)
+            .with_resource(FrameNumber::new(1))
+            .with_resource(ReplayMode::Playback(replay()))
+            .with_system_desc(ReplayPlaybackSystemDesc::default(), "", &[])
+            .with_assertion(|world| {
+                assert_eq!(vec![press(0), press(1)], game_input_events(world));
+            })
+            // Game play has not stepped, so no input is sent.
+            .with_assertion(|world| {
+                assert_eq!(Vec::<GameInputEvent>::new(), game_input_events(world));
+            })
+            .with_effect(|world| world.insert(FrameNumber::new(2)))
+            .with_assertion(|world| {
+                assert_eq!(vec![press(2)], game_input_events(world));
+            })
+            .run()
+    }
+
+    #[test]
+    fn sends_recorded_control_input_events_to_input_controlled_entity() -> Result<(), Error> {
+        let mut replay = replay();
+        replay.frames = vec![ReplayFrame::new(
+            FrameNumber::new(0),
+            vec![],
+            vec![ControlInputEventArgs {
+                controller_id: 0,
+                control: ControlArgs::ActionPressed {
+                    action: ControlAction::Jump,
+                },
+            }],
+        )];
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(<Read<'_, EventChannel<ControlInputEvent>> as SystemData>::setup)
+            .with_setup(|world| {
+                let control_input_event_rid = world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .register_reader();
+                world.insert(control_input_event_rid);
+
+                world.register::<InputControlled>();
+                let entity = world.create_entity().with(InputControlled::new(0)).build();
+                world.insert(entity);
+            })
+            .with_resource(StateId::GamePlay)
+            .with_resource(ReplayMode::Playback(replay))
+            .with_system_desc(ReplayPlaybackSystemDesc::default(), "", &[])
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let (mut control_input_event_rid, control_input_ec) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<ControlInputEvent>>,
+                    Read<'_, EventChannel<ControlInputEvent>>,
+                )>();
+                let control_input_events = control_input_ec
+                    .read(&mut *control_input_event_rid)
+                    .copied()
+                    .collect::<Vec<ControlInputEvent>>();
+
+                assert_eq!(
+                    vec![ControlInputEvent::ControlActionPress(
+                        ControlActionEventData {
+                            controller_id: 0,
+                            entity,
+                            control_action: ControlAction::Jump,
+                        }
+                    )],
+                    control_input_events
+                );
+            })
+            .run()
+    }
+
+    fn run_test(
+        state_id: StateId,
+        game_input_events_expected: Vec<GameInputEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_resource(state_id)
+            .with_resource(ReplayMode::Playback(replay()))
+            .with_system_desc(ReplayPlaybackSystemDesc::default(), "", &[])
+            .with_assertion(move |world| {
+                assert_eq!(game_input_events_expected, game_input_events(world));
+            })
+            .run()
+    }
+
+    fn game_input_events(world: &World) -> Vec<GameInputEvent> {
+        let (mut game_input_event_rid, game_input_ec) = world.system_data::<(
+            WriteExpect<'_, ReaderId<GameInputEvent>>,
+            Read<'_, EventChannel<GameInputEvent>>,
+        )>();
+        game_input_ec
+            .read(&mut *game_input_event_rid)
+            .copied()
+            .collect::<Vec<GameInputEvent>>()
+    }
+
+    fn setup_game_input_event_reader(world: &mut World) {
+        let game_input_event_rid = world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .register_reader();
+        world.insert(game_input_event_rid);
+    }
+
+    fn replay() -> Replay {
+        let asset_slug = AssetSlug::from_str("test/char").expect("Expected slug to be valid.");
+        let mut character_selections = BTreeMap::new();
+        character_selections.insert(0, asset_slug.clone());
+
+        Replay::new(
+            Replay::VERSION,
+            0,
+            character_selections,
+            asset_slug,
+            vec![
+                ReplayFrame::new(FrameNumber::new(0), vec![press(0), press(1)], vec![]),
+                ReplayFrame::new(FrameNumber::new(2), vec![press(2)], vec![]),
+            ],
+        )
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, str::FromStr};
+
+    use amethyst::{
+        ecs::{Builder, Entity, Read, SystemData, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use character_selection_model::CharacterSelections;
+    use game_input_model::{
+        config::{ControlAction, ControlArgs, ControlInputEventArgs, PlayerActionControl},
+        play::{ControlActionEventData, ControlInputEvent, InputControlled},
+        GameInputEvent,
+    };
+    use map_selection_model::MapSelection;
+    use network_input_model::play::FrameNumber;
+    use replay_model::{
+        config::{Replay, ReplayFrame},
+        play::ReplayMode,
+    };
+    use state_registry::{StateId, StateIdUpdateEvent};
+    use tempfile::tempdir;
+
+    use replay_play::ReplayRecordSystemDesc;
+
+    #[test]
+    fn writes_replay_when_game_play_ends() -> Result<(), Error> {
+        let replay_dir = tempdir()?;
+        let replay_path = replay_dir.path().join("replay.yaml");
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(<Read<'_, EventChannel<StateIdUpdateEvent>> as SystemData>::setup)
+            .with_resource(ReplayMode::Record(replay_path.clone()))
+            .with_resource(StateId::GamePlay)
+            .with_setup(|world| {
+                let mut asset_id_mappings = AssetIdMappings::new();
+                let char_id = asset_id_mappings.insert(slug("test/char"));
+                let map_id = asset_id_mappings.insert(slug("test/map"));
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, char_id);
+
+                world.insert(asset_id_mappings);
+                world.insert(character_selections);
+                world.insert(MapSelection::Id(map_id));
+            })
+            .with_system_desc(ReplayRecordSystemDesc::default(), "", &[])
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::GamePlay,
+                        Some(StateId::GameLoading),
+                    ));
+                world
+                    .write_resource::<EventChannel<GameInputEvent>>()
+                    .single_write(press(0));
+            })
+            .with_effect(|world| {
+                world.insert(StateId::CharacterSelection);
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::CharacterSelection,
+                        Some(StateId::GamePlay),
+                    ));
+            })
+            .with_assertion(move |_world| {
+                let replay = read_replay(&replay_path);
+
+                assert_eq!(Replay::VERSION, replay.version);
+                assert_eq!(0, replay.rng_seed);
+                assert_eq!(
+                    Some(&slug("test/char")),
+                    replay.character_selections.get(&0)
+                );
+                assert_eq!(slug("test/map"), replay.map_selection);
+                assert_eq!(
+                    vec![ReplayFrame::new(
+                        FrameNumber::new(0),
+                        vec![press(0)],
+                        vec![]
+                    )],
+                    replay.frames
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_input_on_game_play_frame_number() -> Result<(), Error> {
+        let replay_dir = tempdir()?;
+        let replay_path = replay_dir.path().join("replay.yaml");
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(<Read<'_, EventChannel<StateIdUpdateEvent>> as SystemData>::setup)
+            .with_resource(ReplayMode::Record(replay_path.clone()))
+            .with_resource(StateId::GamePlay)
+            .with_resource(FrameNumber::new(0))
+            .with_setup(|world| {
+                let mut asset_id_mappings = AssetIdMappings::new();
+                let char_id = asset_id_mappings.insert(slug("test/char"));
+                let map_id = asset_id_mappings.insert(slug("test/map"));
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, char_id);
+
+                world.insert(asset_id_mappings);
+                world.insert(character_selections);
+                world.insert(MapSelection::Id(map_id));
+            })
+            .with_system_desc(ReplayRecordSystemDesc::default(), "", &[])
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::GamePlay,
+                        Some(StateId::GameLoading),
+                    ));
+                world
+                    .write_resource::<EventChannel<GameInputEvent>>()
+                    .single_write(press(0));
+            })
+            // Game play has not stepped, such as while paused, so input is on the same frame.
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<GameInputEvent>>()
+                    .single_write(press(1));
+            })
+            .with_effect(|world| {
+                world.insert(FrameNumber::new(3));
+                world
+                    .write_resource::<EventChannel<GameInputEvent>>()
+                    .single_write(press(2));
+            })
+            .with_effect(|world| {
+                world.insert(StateId::CharacterSelection);
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::CharacterSelection,
+                        Some(StateId::GamePlay),
+                    ));
+            })
+            .with_assertion(move |_world| {
+                let replay = read_replay(&replay_path);
+
+                assert_eq!(
+                    vec![
+                        ReplayFrame::new(FrameNumber::new(0), vec![press(0), press(1)], vec![]),
+                        ReplayFrame::new(FrameNumber::new(3), vec![press(2)], vec![]),
+                    ],
+                    replay.frames
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn records_control_input_events_not_sent_from_game_input() -> Result<(), Error> {
+        let replay_dir = tempdir()?;
+        let replay_path = replay_dir.path().join("replay.yaml");
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(<Read<'_, EventChannel<ControlInputEvent>> as SystemData>::setup)
+            .with_setup(<Read<'_, EventChannel<StateIdUpdateEvent>> as SystemData>::setup)
+            .with_resource(ReplayMode::Record(replay_path.clone()))
+            .with_resource(StateId::GamePlay)
+            .with_setup(|world| {
+                let mut asset_id_mappings = AssetIdMappings::new();
+                let char_id = asset_id_mappings.insert(slug("test/char"));
+                let map_id = asset_id_mappings.insert(slug("test/map"));
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, char_id);
+
+                world.insert(asset_id_mappings);
+                world.insert(character_selections);
+                world.insert(MapSelection::Id(map_id));
+            })
+            .with_system_desc(ReplayRecordSystemDesc::default(), "", &[])
+            .with_effect(|world| {
+                let entity = world.create_entity().with(InputControlled::new(0)).build();
+                // Computer controlled characters do not have `InputControlled`.
+                let entity_ai = world.create_entity().build();
+
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::GamePlay,
+                        Some(StateId::GameLoading),
+                    ));
+                world
+                    .write_resource::<EventChannel<GameInputEvent>>()
+                    .single_write(press(0));
+                world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .iter_write(vec![
+                        // Sent from the `GameInputEvent`.
+                        control_press(entity, ControlAction::Attack),
+                        // Sent from stdin.
+                        control_press(entity, ControlAction::Jump),
+                        control_press(entity_ai, ControlAction::Defend),
+                    ]);
+            })
+            .with_effect(|world| {
+                world.insert(StateId::CharacterSelection);
+                world
+                    .write_resource::<EventChannel<StateIdUpdateEvent>>()
+                    .single_write(StateIdUpdateEvent::new(
+                        StateId::CharacterSelection,
+                        Some(StateId::GamePlay),
+                    ));
+            })
+            .with_assertion(move |_world| {
+                let replay = read_replay(&replay_path);
+
+                assert_eq!(
+                    vec![ReplayFrame::new(
+                        FrameNumber::new(0),
+                        vec![press(0)],
+                        vec![ControlInputEventArgs {
+                            controller_id: 0,
+                            control: ControlArgs::ActionPressed {
+                                action: ControlAction::Jump
+                            },
+                        }],
+                    )],
+                    replay.frames
+                );
+            })
+            .run()
+    }
+
+    fn read_replay(replay_path: &PathBuf) -> Replay {
+        let replay_yaml = fs::read_to_string(replay_path).expect("Expected replay to be written.");
+        serde_yaml::from_str(&replay_yaml).expect("Expected replay to be valid.")
+    }
+
+    fn slug(asset_slug: &str) -> AssetSlug {
+        AssetSlug::from_str(asset_slug).expect("Expected slug to be valid.")
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+
+    fn control_press(entity: Entity, control_action: ControlAction) -> ControlInputEvent {
+        ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 0,
+            entity,
+            control_action,
+        })
+    }
+}
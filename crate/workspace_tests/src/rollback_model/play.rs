@@ -0,0 +1 @@
+mod rollback_predictions;
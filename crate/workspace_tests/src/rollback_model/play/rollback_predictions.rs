@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        GameInputEvent,
+    };
+    use network_input_model::play::{FrameNumber, NetworkInputBuffer, NetworkInputFrame};
+    use network_session_model::play::SessionDeviceId;
+
+    use rollback_model::play::RollbackPredictions;
+
+    #[test]
+    fn mispredicted_frame_returns_none_when_input_not_received() {
+        let mut rollback_predictions = RollbackPredictions::new();
+        rollback_predictions.insert(FrameNumber::new(0), vec![SessionDeviceId::new(2)]);
+
+        let network_input_buffer = NetworkInputBuffer::new();
+
+        assert_eq!(
+            None,
+            rollback_predictions.mispredicted_frame(&network_input_buffer)
+        );
+        assert!(rollback_predictions.contains(FrameNumber::new(0)));
+    }
+
+    #[test]
+    fn mispredicted_frame_returns_none_and_confirms_prediction_when_no_events_received() {
+        let mut rollback_predictions = RollbackPredictions::new();
+        rollback_predictions.insert(FrameNumber::new(0), vec![SessionDeviceId::new(2)]);
+
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(2, 0, vec![]));
+
+        assert_eq!(
+            None,
+            rollback_predictions.mispredicted_frame(&network_input_buffer)
+        );
+        assert!(!rollback_predictions.contains(FrameNumber::new(0)));
+    }
+
+    #[test]
+    fn mispredicted_frame_returns_earliest_frame_with_received_events() {
+        let mut rollback_predictions = RollbackPredictions::new();
+        rollback_predictions.insert(FrameNumber::new(1), vec![SessionDeviceId::new(2)]);
+        rollback_predictions.insert(FrameNumber::new(2), vec![SessionDeviceId::new(2)]);
+        rollback_predictions.insert(FrameNumber::new(3), vec![SessionDeviceId::new(2)]);
+
+        let mut network_input_buffer = NetworkInputBuffer::new();
+        network_input_buffer.insert(network_input_frame(2, 1, vec![]));
+        network_input_buffer.insert(network_input_frame(2, 2, vec![press(0)]));
+        network_input_buffer.insert(network_input_frame(2, 3, vec![press(0)]));
+
+        assert_eq!(
+            Some(FrameNumber::new(2)),
+            rollback_predictions.mispredicted_frame(&network_input_buffer)
+        );
+    }
+
+    #[test]
+    fn insert_ignores_frame_when_no_devices_predicted() {
+        let mut rollback_predictions = RollbackPredictions::new();
+        rollback_predictions.insert(FrameNumber::new(0), Vec::<SessionDeviceId>::new());
+
+        assert!(!rollback_predictions.contains(FrameNumber::new(0)));
+    }
+
+    fn network_input_frame(
+        session_device_id: u64,
+        frame_number: u64,
+        game_input_events: Vec<GameInputEvent>,
+    ) -> NetworkInputFrame {
+        NetworkInputFrame::new(
+            SessionDeviceId::new(session_device_id),
+            FrameNumber::new(frame_number),
+            game_input_events,
+        )
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
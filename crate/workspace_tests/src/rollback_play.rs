@@ -0,0 +1,2 @@
+mod game_play_rollback;
+mod game_play_snapshotter;
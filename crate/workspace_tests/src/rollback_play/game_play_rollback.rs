@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{
+            Builder, DispatcherBuilder, Read, ReadStorage, System, SystemData, World, WorldExt,
+            Write,
+        },
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        loaded::{PlayerController, PlayerControllers},
+        play::{ControlInputEvent, InputControlled, SharedInputControlled},
+        GameInputEvent,
+    };
+    use game_stats_model::play::{GameStats, PlayerStats};
+    use kinematic_model::config::Position;
+    use map_model::play::OutOfBoundsDeleteClock;
+    use map_play::MapOutOfBoundsDeletionSystem;
+    use network_input_model::play::{
+        FrameNumber, LockstepFrameNumbers, NetworkInputBuffer, NetworkInputFrame,
+    };
+    use network_session_model::{
+        config::SessionServerConfig,
+        play::{SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices, SessionStatus},
+    };
+    use sequence_model::loaded::SequenceId;
+    use state_registry::StateId;
+
+    use rollback_play::{GamePlayRollback, GamePlaySnapshotResources};
+
+    #[test]
+    fn restores_entities_deleted_in_mispredicted_frames() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(setup_system_data)
+            .with_setup(setup_session)
+            .with_assertion(|world| {
+                let mut dispatcher = DispatcherBuilder::new()
+                    .with(MapOutOfBoundsDeletionSystem::new(), "", &[])
+                    .build();
+                dispatcher.setup(world);
+
+                // Deleted while frame 1 is simulated.
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(0))
+                    .with(Position::<f32>::new(1., 2., 3.))
+                    .with(OutOfBoundsDeleteClock::new(2))
+                    .build();
+
+                let mut game_play_rollback = GamePlayRollback::new();
+                game_play_rollback.update(world, &mut dispatcher);
+                game_play_rollback.update(world, &mut dispatcher);
+
+                assert!(world.is_alive(entity));
+                assert!(world.read_storage::<SequenceId>().get(entity).is_none());
+
+                // Input for frame 0 differs from the prediction.
+                frame_0_input_mispredict(world);
+                game_play_rollback.update(world, &mut dispatcher);
+
+                assert_eq!(
+                    FrameNumber::new(3),
+                    world.read_resource::<LockstepFrameNumbers>().step
+                );
+                assert!(world.is_alive(entity));
+
+                // Frame 1 is confirmed, so the deletion is final.
+                {
+                    let mut network_input_buffer = world.write_resource::<NetworkInputBuffer>();
+                    network_input_buffer.insert(NetworkInputFrame::new(
+                        SessionDeviceId::new(1),
+                        FrameNumber::new(1),
+                        vec![],
+                    ));
+                    network_input_buffer.insert(NetworkInputFrame::new(
+                        SessionDeviceId::new(2),
+                        FrameNumber::new(1),
+                        vec![],
+                    ));
+                }
+                game_play_rollback.update(world, &mut dispatcher);
+                world.maintain();
+
+                assert!(!world.is_alive(entity));
+            })
+            .run()
+    }
+
+    #[test]
+    fn restores_game_stats_when_rolling_back() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(setup_system_data)
+            .with_setup(setup_session)
+            .with_assertion(|world| {
+                let mut dispatcher = DispatcherBuilder::new()
+                    .with(HitsLandedIncrementSystem, "", &[])
+                    .build();
+                dispatcher.setup(world);
+
+                let mut game_play_rollback = GamePlayRollback::new();
+                game_play_rollback.update(world, &mut dispatcher);
+                game_play_rollback.update(world, &mut dispatcher);
+
+                assert_eq!(2, hits_landed(world));
+
+                frame_0_input_mispredict(world);
+                game_play_rollback.update(world, &mut dispatcher);
+
+                // Frames 0 and 1 are re-simulated, and frame 2 is simulated.
+                assert_eq!(
+                    FrameNumber::new(3),
+                    world.read_resource::<LockstepFrameNumbers>().step
+                );
+                assert_eq!(3, hits_landed(world));
+            })
+            .run()
+    }
+
+    fn setup_system_data(world: &mut World) {
+        <GamePlaySnapshotResources as SystemData>::setup(world);
+        <(
+            ReadStorage<'_, InputControlled>,
+            ReadStorage<'_, SharedInputControlled>,
+            Write<'_, EventChannel<ControlInputEvent>>,
+            Write<'_, NetworkInputBuffer>,
+            Write<'_, LockstepFrameNumbers>,
+            Read<'_, SessionDevices>,
+        ) as SystemData>::setup(world);
+    }
+
+    fn setup_session(world: &mut World) {
+        world.insert(SessionStatus::HostEstablished);
+        world.insert(StateId::GamePlay);
+        world.insert(SessionServerConfig {
+            rollback_frames: 8,
+            checksum_interval: 0,
+            ..Default::default()
+        });
+        world.insert(SessionDevices::new(vec![
+            session_device(1, "p0"),
+            session_device(2, "p1"),
+        ]));
+    }
+
+    fn frame_0_input_mispredict(world: &mut World) {
+        let mut network_input_buffer = world.write_resource::<NetworkInputBuffer>();
+        network_input_buffer.insert(NetworkInputFrame::new(
+            SessionDeviceId::new(1),
+            FrameNumber::new(0),
+            vec![press(0)],
+        ));
+        network_input_buffer.insert(NetworkInputFrame::new(
+            SessionDeviceId::new(2),
+            FrameNumber::new(0),
+            vec![],
+        ));
+    }
+
+    fn hits_landed(world: &World) -> u32 {
+        world
+            .read_resource::<GameStats>()
+            .get(&0)
+            .map(|player_stats| player_stats.hits_landed)
+            .unwrap_or(0)
+    }
+
+    fn session_device(id: u64, controller_name: &str) -> SessionDevice {
+        SessionDevice {
+            id: SessionDeviceId::new(id),
+            name: SessionDeviceName::from(String::from(controller_name)),
+            player_controllers: PlayerControllers::new(vec![PlayerController::new(
+                0,
+                String::from(controller_name),
+            )]),
+        }
+    }
+
+    fn press(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+
+    /// Lands a hit for the player with controller `0` every frame.
+    #[derive(Debug)]
+    struct HitsLandedIncrementSystem;
+
+    impl<'s> System<'s> for HitsLandedIncrementSystem {
+        type SystemData = Write<'s, GameStats>;
+
+        fn run(&mut self, mut game_stats: Self::SystemData) {
+            game_stats
+                .entry(0)
+                .or_insert_with(|| PlayerStats::new(false))
+                .hits_landed += 1;
+        }
+    }
+}
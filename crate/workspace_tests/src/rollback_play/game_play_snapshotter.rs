@@ -0,0 +1,296 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::play::AiReactionClock;
+    use amethyst::{
+        ecs::{Builder, Entity, SystemData, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::{
+        play::{Combo, RoundClock, Stocks},
+        GamePlayStatus,
+    };
+    use game_stats_model::play::{WinOutcome, WinStatus};
+    use kinematic_model::config::Position;
+    use object_model::play::HealthPoints;
+    use sequence_model::loaded::SequenceId;
+
+    use rollback_play::{GamePlaySnapshotResources, GamePlaySnapshotter};
+
+    #[test]
+    fn restore_resets_component_values_and_deletes_spawned_entities() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(1))
+                    .with(HealthPoints::new(100))
+                    .with(Position::<f32>::new(1., 2., 3.))
+                    .build();
+                world.insert(entity);
+
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<HealthPoints>()
+                    .insert(entity, HealthPoints::new(40))
+                    .expect("Failed to insert `HealthPoints` component.");
+                world.write_storage::<Position<f32>>().remove(entity);
+
+                let entity_spawned = world.create_entity().with(SequenceId::new(0)).build();
+                world.insert(EntitySpawned(entity_spawned));
+            })
+            .with_effect(|world| {
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(&mut world.system_data(), &game_play_snapshot, &[]);
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let EntitySpawned(entity_spawned) = *world.read_resource::<EntitySpawned>();
+
+                assert_eq!(
+                    Some(&HealthPoints::new(100)),
+                    world.read_storage::<HealthPoints>().get(entity)
+                );
+                assert_eq!(
+                    Some(&Position::<f32>::new(1., 2., 3.)),
+                    world.read_storage::<Position<f32>>().get(entity)
+                );
+                assert!(!world.is_alive(entity_spawned));
+            })
+            .run()
+    }
+
+    #[test]
+    fn restore_resets_ai_reaction_clocks() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(1))
+                    .with(AiReactionClock::new_with_value(10, 3))
+                    .build();
+                world.insert(entity);
+
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<AiReactionClock>()
+                    .insert(entity, AiReactionClock::new_with_value(10, 5))
+                    .expect("Failed to insert `AiReactionClock` component.");
+            })
+            .with_effect(|world| {
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(&mut world.system_data(), &game_play_snapshot, &[]);
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&AiReactionClock::new_with_value(10, 3)),
+                    world.read_storage::<AiReactionClock>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn restore_resets_combos() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                let entity = world.create_entity().with(SequenceId::new(1)).build();
+                world
+                    .write_storage::<Combo>()
+                    .insert(entity, Combo::new(entity, 1))
+                    .expect("Failed to insert `Combo` component.");
+                world.insert(entity);
+
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<Combo>()
+                    .insert(entity, Combo::new(entity, 3))
+                    .expect("Failed to insert `Combo` component.");
+            })
+            .with_effect(|world| {
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(&mut world.system_data(), &game_play_snapshot, &[]);
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&Combo::new(entity, 1)),
+                    world.read_storage::<Combo>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn restore_resets_stocks_and_round_resources() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                world.insert(GamePlayStatus::Playing);
+                world.insert(RoundClock::new_with_value(100, 10));
+
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(1))
+                    .with(Stocks::new(2))
+                    .build();
+                world.insert(entity);
+
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<Stocks>()
+                    .insert(entity, Stocks::new(1))
+                    .expect("Failed to insert `Stocks` component.");
+
+                world.insert(GamePlayStatus::RoundEnded);
+                world.insert(RoundClock::new_with_value(100, 11));
+                world
+                    .write_resource::<WinStatus>()
+                    .round_outcomes
+                    .push(WinOutcome::Draw);
+            })
+            .with_effect(|world| {
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(&mut world.system_data(), &game_play_snapshot, &[]);
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&Stocks::new(2)),
+                    world.read_storage::<Stocks>().get(entity)
+                );
+                assert_eq!(
+                    GamePlayStatus::Playing,
+                    *world.read_resource::<GamePlayStatus>()
+                );
+                assert_eq!(
+                    RoundClock::new_with_value(100, 10),
+                    *world.read_resource::<RoundClock>()
+                );
+                assert_eq!(WinStatus::default(), *world.read_resource::<WinStatus>());
+            })
+            .run()
+    }
+
+    #[test]
+    fn restore_revives_withheld_entities_in_snapshot() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                let entity = world
+                    .create_entity()
+                    .with(SequenceId::new(1))
+                    .with(HealthPoints::new(100))
+                    .with(Position::<f32>::new(1., 2., 3.))
+                    .build();
+                world.insert(entity);
+
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                GamePlaySnapshotter::withhold(&mut world.system_data(), &[entity]);
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert!(world.is_alive(entity));
+                assert!(world.read_storage::<SequenceId>().get(entity).is_none());
+                assert!(world.read_storage::<HealthPoints>().get(entity).is_none());
+                assert!(world.read_storage::<Position<f32>>().get(entity).is_none());
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(
+                    &mut world.system_data(),
+                    &game_play_snapshot,
+                    &[entity],
+                );
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert!(world.is_alive(entity));
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(entity)
+                );
+                assert_eq!(
+                    Some(&HealthPoints::new(100)),
+                    world.read_storage::<HealthPoints>().get(entity)
+                );
+                assert_eq!(
+                    Some(&Position::<f32>::new(1., 2., 3.)),
+                    world.read_storage::<Position<f32>>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn restore_deletes_withheld_entities_spawned_after_snapshot() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<GamePlaySnapshotResources as SystemData>::setup)
+            .with_effect(|world| {
+                let game_play_snapshot = GamePlaySnapshotter::capture(&world.system_data());
+                world.insert(game_play_snapshot);
+            })
+            .with_effect(|world| {
+                let entity_spawned = world.create_entity().with(SequenceId::new(0)).build();
+                world.insert(EntitySpawned(entity_spawned));
+
+                GamePlaySnapshotter::withhold(&mut world.system_data(), &[entity_spawned]);
+                world.maintain();
+            })
+            .with_effect(|world| {
+                let EntitySpawned(entity_spawned) = *world.read_resource::<EntitySpawned>();
+                let game_play_snapshot = world.remove().expect("Expected `GamePlaySnapshot`.");
+                GamePlaySnapshotter::restore(
+                    &mut world.system_data(),
+                    &game_play_snapshot,
+                    &[entity_spawned],
+                );
+                world.maintain();
+            })
+            .with_assertion(|world| {
+                let EntitySpawned(entity_spawned) = *world.read_resource::<EntitySpawned>();
+
+                assert!(!world.is_alive(entity_spawned));
+            })
+            .run()
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct EntitySpawned(Entity);
+}
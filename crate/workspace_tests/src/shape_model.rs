@@ -0,0 +1 @@
+mod volume_bounds;
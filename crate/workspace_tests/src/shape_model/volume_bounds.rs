@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use shape_model::{Axis, Volume, VolumeBounds};
+
+    #[test]
+    fn from_volume_box_subtracts_offsets() {
+        let volume = Volume::Box {
+            x: 10,
+            y: 20,
+            z: 30,
+            w: 5,
+            h: 6,
+            d: 7,
+        };
+
+        let bounds = VolumeBounds::from_volume(&volume, [1., 2.], false);
+
+        assert_eq!(VolumeBounds::new([9., 18., 30.], [14., 24., 37.]), bounds);
+    }
+
+    #[test]
+    fn from_volume_box_mirrored_negates_x() {
+        let volume = Volume::Box {
+            x: 10,
+            y: 20,
+            z: 30,
+            w: 5,
+            h: 6,
+            d: 7,
+        };
+
+        let bounds = VolumeBounds::from_volume(&volume, [1., 2.], true);
+
+        assert_eq!(VolumeBounds::new([-14., 18., 30.], [-9., 24., 37.]), bounds);
+    }
+
+    #[test]
+    fn from_volume_cylinder_extends_along_axis() {
+        let volume = Volume::Cylinder {
+            axis: Axis::Z,
+            center: 10,
+            r: 2,
+            l: 8,
+        };
+
+        let bounds = VolumeBounds::from_volume(&volume, [0., 0.], false);
+
+        assert_eq!(VolumeBounds::new([-2., -2., 6.], [2., 2., 14.]), bounds);
+    }
+
+    #[test]
+    fn from_volume_sphere() {
+        let volume = Volume::Sphere {
+            x: 10,
+            y: 20,
+            z: 30,
+            r: 5,
+        };
+
+        let bounds = VolumeBounds::from_volume(&volume, [0., 0.], false);
+
+        assert_eq!(VolumeBounds::new([5., 15., 25.], [15., 25., 35.]), bounds);
+    }
+
+    #[test]
+    fn union_encloses_both_bounds() {
+        let bounds_0 = VolumeBounds::new([0., 5., 0.], [10., 10., 10.]);
+        let bounds_1 = VolumeBounds::new([-5., 0., 5.], [5., 20., 8.]);
+
+        assert_eq!(
+            VolumeBounds::new([-5., 0., 0.], [10., 20., 10.]),
+            bounds_0.union(bounds_1)
+        );
+    }
+
+    #[test]
+    fn translate_moves_min_and_max() {
+        let bounds = VolumeBounds::new([0., 0., 0.], [10., 10., 10.]);
+
+        assert_eq!(
+            VolumeBounds::new([1., 2., 3.], [11., 12., 13.]),
+            bounds.translate([1., 2., 3.])
+        );
+    }
+
+    #[test]
+    fn intersects_when_overlapping_on_all_axes() {
+        let bounds_0 = VolumeBounds::new([0., 0., 0.], [10., 10., 10.]);
+        let bounds_1 = VolumeBounds::new([5., 5., 5.], [15., 15., 15.]);
+
+        assert!(bounds_0.intersects(&bounds_1));
+    }
+
+    #[test]
+    fn does_not_intersect_when_touching() {
+        let bounds_0 = VolumeBounds::new([0., 0., 0.], [10., 10., 10.]);
+        let bounds_1 = VolumeBounds::new([0., 10., 0.], [10., 20., 10.]);
+
+        assert!(!bounds_0.intersects(&bounds_1));
+        assert!(bounds_0.overlaps_on(&bounds_1, 0));
+        assert!(!bounds_0.overlaps_on(&bounds_1, 1));
+    }
+}
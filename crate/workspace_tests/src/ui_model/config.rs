@@ -193,6 +193,7 @@ sequences:
             buttons,
             display_control_buttons: true,
             sequences,
+            music: None,
         };
 
         assert_eq!(ui_definition_expected, ui_definition);
@@ -0,0 +1,2 @@
+mod weapon_held_system;
+mod weapon_pick_up_system;
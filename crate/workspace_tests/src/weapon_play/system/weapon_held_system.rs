@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::{AssetStorage, Handle, Loader},
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterHitTransitions;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_loading::CollisionLoadingBundle;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind, Interactions},
+        play::{HeldBy, Holding},
+    };
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+    use weapon_model::loaded::WeaponTransitions;
+
+    use weapon_play::WeaponHeldSystem;
+
+    const SEQUENCE_ID_ON_GROUND: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_HELD: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_THROWN: SequenceId = SequenceId(2);
+
+    const SEQUENCE_ID_STAND: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_STUN: SequenceId = SequenceId(1);
+
+    #[test]
+    fn drops_weapon_when_holder_is_stunned() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponHeldSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entities = holder_and_weapon_create(world, Mirrored::new(false));
+
+                let (holder, _) = entities;
+                world
+                    .write_storage::<SequenceId>()
+                    .insert(holder, SEQUENCE_ID_STUN)
+                    .expect("Failed to insert `SequenceId` component.");
+
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(weapon).is_none());
+                assert!(world.read_storage::<TargetObject>().get(weapon).is_none());
+                assert!(world.read_storage::<ChaseModeStick>().get(weapon).is_none());
+                assert_eq!(
+                    Some(&Velocity::default()),
+                    world.read_storage::<Velocity<f32>>().get(weapon)
+                );
+                assert_eq!(
+                    Some(&SEQUENCE_ID_THROWN),
+                    world.read_storage::<SequenceId>().get(weapon)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn weapon_faces_same_direction_as_holder() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponHeldSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entities = holder_and_weapon_create(world, Mirrored::new(true));
+                world.insert(entities);
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&HeldBy::new(holder)),
+                    world.read_storage::<HeldBy>().get(weapon)
+                );
+                assert_eq!(
+                    Some(&Mirrored::new(true)),
+                    world.read_storage::<Mirrored>().get(weapon)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn replaces_holder_interactions_with_weapon_interactions_when_attacking() -> Result<(), Error> {
+        run_interactions_test(
+            vec![Interaction::new(
+                InteractionKind::Hit(Hit::default()),
+                vec![],
+                true,
+            )],
+            true,
+        )
+    }
+
+    #[test]
+    fn keeps_holder_interactions_when_not_attacking() -> Result<(), Error> {
+        run_interactions_test(vec![], false)
+    }
+
+    fn run_interactions_test(
+        holder_interactions: Vec<Interaction>,
+        expect_replaced: bool,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(CollisionLoadingBundle::new())
+            .with_system(WeaponHeldSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let holder_interactions_handle =
+                    interactions_load(world, Interactions::new(holder_interactions.clone()));
+                let weapon_interactions_handle = interactions_load(
+                    world,
+                    Interactions::new(vec![Interaction::new(
+                        InteractionKind::Hit(Hit::default()),
+                        vec![],
+                        true,
+                    )]),
+                );
+
+                world.insert((holder_interactions_handle, weapon_interactions_handle));
+            })
+            .with_effect(|world| {
+                let (holder_interactions_handle, weapon_interactions_handle) = world
+                    .read_resource::<(Handle<Interactions>, Handle<Interactions>)>()
+                    .clone();
+                let (holder, weapon) = holder_and_weapon_create(world, Mirrored::new(false));
+
+                {
+                    let mut interactions_handles = world.write_storage::<Handle<Interactions>>();
+                    interactions_handles
+                        .insert(holder, holder_interactions_handle)
+                        .expect("Failed to insert `Handle<Interactions>` component.");
+                    interactions_handles
+                        .insert(weapon, weapon_interactions_handle)
+                        .expect("Failed to insert `Handle<Interactions>` component.");
+                }
+
+                world.insert((holder, weapon));
+            })
+            .with_assertion(move |world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+                let interactions_handles = world.read_storage::<Handle<Interactions>>();
+
+                let holder_interactions_handle = interactions_handles.get(holder);
+                let weapon_interactions_handle = interactions_handles.get(weapon);
+
+                assert_eq!(
+                    expect_replaced,
+                    holder_interactions_handle == weapon_interactions_handle
+                );
+            })
+            .run()
+    }
+
+    fn interactions_load(world: &World, interactions: Interactions) -> Handle<Interactions> {
+        let loader = world.read_resource::<Loader>();
+        let interactions_assets = world.read_resource::<AssetStorage<Interactions>>();
+
+        loader.load_from_data(interactions, (), &interactions_assets)
+    }
+
+    /// Returns the `(holder, weapon)` entities, with the weapon held by the holder.
+    fn holder_and_weapon_create(world: &mut World, holder_mirrored: Mirrored) -> (Entity, Entity) {
+        let character_hit_transitions = CharacterHitTransitions::new(
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+            None,
+            SEQUENCE_ID_STUN,
+            SEQUENCE_ID_STUN,
+        );
+
+        let holder = world
+            .create_entity()
+            .with(holder_mirrored)
+            .with(SEQUENCE_ID_STAND)
+            .with(character_hit_transitions)
+            .build();
+        let weapon = world
+            .create_entity()
+            .with(Mirrored::default())
+            .with(Velocity::<f32>::new(1., 2., 3.))
+            .with(SEQUENCE_ID_HELD)
+            .with(WeaponTransitions::new(
+                SEQUENCE_ID_ON_GROUND,
+                SEQUENCE_ID_HELD,
+                SEQUENCE_ID_THROWN,
+            ))
+            .with(HeldBy::new(holder))
+            .with(TargetObject::new(holder))
+            .with(ChaseModeStick::default())
+            .build();
+
+        world
+            .write_storage::<Holding>()
+            .insert(holder, Holding::new(weapon))
+            .expect("Failed to insert `Holding` component.");
+
+        (holder, weapon)
+    }
+}
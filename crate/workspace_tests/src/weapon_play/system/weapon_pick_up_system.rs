@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_model::play::{HeldBy, Holding};
+    use game_input_model::{
+        config::ControlAction,
+        play::{ControlActionEventData, ControlInputEvent},
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use sequence_model::loaded::SequenceId;
+    use weapon_model::loaded::WeaponTransitions;
+
+    use weapon_play::{WeaponPickUpSystem, WEAPON_PICK_UP_DISTANCE};
+
+    const SEQUENCE_ID_ON_GROUND: SequenceId = SequenceId(0);
+    const SEQUENCE_ID_HELD: SequenceId = SequenceId(1);
+    const SEQUENCE_ID_THROWN: SequenceId = SequenceId(2);
+    const SEQUENCE_ID_HOLDER_ATTACK: SequenceId = SequenceId(3);
+
+    #[test]
+    fn picks_up_nearest_weapon_on_attack_press() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let holder = holder_create(world);
+                let weapon_far = weapon_create(world, Position::new(20., 0., 0.));
+                let weapon_near = weapon_create(world, Position::new(-10., 0., 0.));
+                send_event(world, press(holder, ControlAction::Attack));
+
+                world.insert((holder, weapon_near, weapon_far));
+            })
+            .with_assertion(|world| {
+                let (holder, weapon_near, weapon_far) =
+                    *world.read_resource::<(Entity, Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&Holding::new(weapon_near)),
+                    world.read_storage::<Holding>().get(holder)
+                );
+                assert_eq!(
+                    Some(&HeldBy::new(holder)),
+                    world.read_storage::<HeldBy>().get(weapon_near)
+                );
+                assert_eq!(
+                    Some(&TargetObject::new(holder)),
+                    world.read_storage::<TargetObject>().get(weapon_near)
+                );
+                assert_eq!(
+                    Some(&ChaseModeStick::new(None)),
+                    world.read_storage::<ChaseModeStick>().get(weapon_near)
+                );
+                assert!(world.read_storage::<HeldBy>().get(weapon_far).is_none());
+
+                let sequence_ids = world.read_storage::<SequenceId>();
+                assert_eq!(Some(&SEQUENCE_ID_HELD), sequence_ids.get(weapon_near));
+                assert_eq!(Some(&SEQUENCE_ID_ON_GROUND), sequence_ids.get(weapon_far));
+            })
+            .run()
+    }
+
+    #[test]
+    fn picks_up_weapon_without_consuming_attack_press() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                // Reader for systems that react to the same input, such as input reactions.
+                let control_input_event_rid = world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .register_reader();
+                world.insert(control_input_event_rid);
+
+                let holder = holder_create(world);
+                // Sequence that an input reaction transitioned to on the same `Attack` press.
+                world
+                    .write_storage::<SequenceId>()
+                    .insert(holder, SEQUENCE_ID_HOLDER_ATTACK)
+                    .expect("Failed to insert `SequenceId` component.");
+                let weapon = weapon_create(world, Position::new(0., 0., 0.));
+                send_event(world, press(holder, ControlAction::Attack));
+
+                world.insert((holder, weapon));
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&Holding::new(weapon)),
+                    world.read_storage::<Holding>().get(holder)
+                );
+                assert_eq!(
+                    Some(&SEQUENCE_ID_HOLDER_ATTACK),
+                    world.read_storage::<SequenceId>().get(holder)
+                );
+
+                let control_input_ec = world.read_resource::<EventChannel<ControlInputEvent>>();
+                let mut control_input_event_rid =
+                    world.write_resource::<ReaderId<ControlInputEvent>>();
+                let control_input_events = control_input_ec
+                    .read(&mut control_input_event_rid)
+                    .copied()
+                    .collect::<Vec<ControlInputEvent>>();
+                assert_eq!(
+                    vec![press(holder, ControlAction::Attack)],
+                    control_input_events
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_pick_up_weapon_out_of_reach() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let holder = holder_create(world);
+                let weapon =
+                    weapon_create(world, Position::new(WEAPON_PICK_UP_DISTANCE + 1., 0., 0.));
+                send_event(world, press(holder, ControlAction::Attack));
+
+                world.insert((holder, weapon));
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(weapon).is_none());
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_pick_up_weapon_that_is_not_on_ground() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let holder = holder_create(world);
+                let weapon = weapon_create(world, Position::new(0., 0., 0.));
+                world
+                    .write_storage::<SequenceId>()
+                    .insert(weapon, SEQUENCE_ID_THROWN)
+                    .expect("Failed to insert `SequenceId` component.");
+                send_event(world, press(holder, ControlAction::Attack));
+
+                world.insert((holder, weapon));
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert_eq!(
+                    Some(&SEQUENCE_ID_THROWN),
+                    world.read_storage::<SequenceId>().get(weapon)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_pick_up_weapon_on_non_attack_press() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(WeaponPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let holder = holder_create(world);
+                let weapon = weapon_create(world, Position::new(0., 0., 0.));
+                send_event(world, press(holder, ControlAction::Jump));
+
+                world.insert((holder, weapon));
+            })
+            .with_assertion(|world| {
+                let (holder, weapon) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(world.read_storage::<Holding>().get(holder).is_none());
+                assert!(world.read_storage::<HeldBy>().get(weapon).is_none());
+            })
+            .run()
+    }
+
+    fn holder_create(world: &mut World) -> Entity {
+        world
+            .create_entity()
+            .with(Position::<f32>::new(0., 0., 0.))
+            .build()
+    }
+
+    fn weapon_create(world: &mut World, position: Position<f32>) -> Entity {
+        world
+            .create_entity()
+            .with(position)
+            .with(Velocity::<f32>::new(1., 2., 3.))
+            .with(SEQUENCE_ID_ON_GROUND)
+            .with(WeaponTransitions::new(
+                SEQUENCE_ID_ON_GROUND,
+                SEQUENCE_ID_HELD,
+                SEQUENCE_ID_THROWN,
+            ))
+            .build()
+    }
+
+    fn press(entity: Entity, control_action: ControlAction) -> ControlInputEvent {
+        ControlInputEvent::ControlActionPress(ControlActionEventData {
+            controller_id: 0,
+            entity,
+            control_action,
+        })
+    }
+
+    fn send_event(world: &mut World, event: ControlInputEvent) {
+        let mut ec = world.write_resource::<EventChannel<ControlInputEvent>>();
+        ec.single_write(event)
+    } // kcov-ignore
+}